@@ -0,0 +1,46 @@
+pub use sea_orm_migration::prelude::*;
+
+mod m20240101_000001_create_tables;
+mod m20240601_000001_add_level_request_status;
+mod m20240701_000001_create_request_config;
+mod m20240801_000001_add_guild_id;
+mod m20240901_000001_create_user_role;
+mod m20241001_000001_create_revoked_token;
+mod m20241101_000001_add_level_metadata;
+mod m20241201_000001_add_level_queue;
+mod m20250101_000001_add_request_limits;
+mod m20250201_000001_create_request_ban;
+mod m20250301_000001_create_review_assignment;
+mod m20250401_000001_create_review_revision;
+mod m20250501_000001_add_review_rubric;
+mod m20250601_000001_create_reviewer_activity;
+mod m20250701_000001_create_level_request_archive;
+mod m20250801_000001_add_review_revision_rubric;
+mod m20250901_000001_add_moderator_sent_at;
+
+pub struct Migrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for Migrator {
+	fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+		vec![
+			Box::new(m20240101_000001_create_tables::Migration),
+			Box::new(m20240601_000001_add_level_request_status::Migration),
+			Box::new(m20240701_000001_create_request_config::Migration),
+			Box::new(m20240801_000001_add_guild_id::Migration),
+			Box::new(m20240901_000001_create_user_role::Migration),
+			Box::new(m20241001_000001_create_revoked_token::Migration),
+			Box::new(m20241101_000001_add_level_metadata::Migration),
+			Box::new(m20241201_000001_add_level_queue::Migration),
+			Box::new(m20250101_000001_add_request_limits::Migration),
+			Box::new(m20250201_000001_create_request_ban::Migration),
+			Box::new(m20250301_000001_create_review_assignment::Migration),
+			Box::new(m20250401_000001_create_review_revision::Migration),
+			Box::new(m20250501_000001_add_review_rubric::Migration),
+			Box::new(m20250601_000001_create_reviewer_activity::Migration),
+			Box::new(m20250701_000001_create_level_request_archive::Migration),
+			Box::new(m20250801_000001_add_review_revision_rubric::Migration),
+			Box::new(m20250901_000001_add_moderator_sent_at::Migration),
+		]
+	}
+}
@@ -0,0 +1,291 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(User::Table)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(User::DiscordId)
+							.big_unsigned()
+							.not_null()
+							.primary_key()
+					)
+					.col(ColumnDef::new(User::Timestamp).timestamp().null())
+					.to_owned()
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(Reviewer::Table)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(Reviewer::DiscordId)
+							.big_unsigned()
+							.not_null()
+							.primary_key()
+					)
+					.col(
+						ColumnDef::new(Reviewer::Active)
+							.tiny_integer()
+							.not_null()
+							.default(1)
+					)
+					.to_owned()
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(LevelRequest::Table)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(LevelRequest::LevelId)
+							.big_unsigned()
+							.not_null()
+							.primary_key()
+					)
+					.col(
+						ColumnDef::new(LevelRequest::DiscordId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(LevelRequest::DiscordMessageId)
+							.big_unsigned()
+							.null()
+							.unique_key()
+					)
+					.col(ColumnDef::new(LevelRequest::Name).string().null())
+					.col(ColumnDef::new(LevelRequest::Author).string().null())
+					.col(
+						ColumnDef::new(LevelRequest::RequestRating)
+							.enumeration(
+								Alias::new("request_rating"),
+								[
+									Alias::new("one"),
+									Alias::new("two"),
+									Alias::new("three"),
+									Alias::new("four"),
+									Alias::new("five"),
+									Alias::new("six"),
+									Alias::new("seven"),
+									Alias::new("eight"),
+									Alias::new("nine"),
+									Alias::new("ten")
+								]
+							)
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(LevelRequest::LevelLength)
+							.enumeration(
+								Alias::new("level_length"),
+								[
+									Alias::new("tiny"),
+									Alias::new("short"),
+									Alias::new("medium"),
+									Alias::new("long"),
+									Alias::new("extra_long"),
+									Alias::new("platformer")
+								]
+							)
+							.null()
+					)
+					.col(
+						ColumnDef::new(LevelRequest::YouTubeVideoLink)
+							.string()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(LevelRequest::HasRequestedFeedback)
+							.tiny_integer()
+							.not_null()
+							.default(0)
+					)
+					.col(
+						ColumnDef::new(LevelRequest::Notify)
+							.tiny_integer()
+							.not_null()
+							.default(0)
+					)
+					.col(
+						ColumnDef::new(LevelRequest::Timestamp)
+							.timestamp()
+							.not_null()
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("fk_level_request_user")
+							.from(LevelRequest::Table, LevelRequest::DiscordId)
+							.to(User::Table, User::DiscordId)
+							.on_update(ForeignKeyAction::NoAction)
+							.on_delete(ForeignKeyAction::NoAction)
+					)
+					.to_owned()
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(Review::Table)
+					.if_not_exists()
+					.col(ColumnDef::new(Review::LevelId).big_unsigned().not_null())
+					.col(ColumnDef::new(Review::DiscordId).big_unsigned().not_null())
+					.col(ColumnDef::new(Review::MessageId).big_unsigned().not_null())
+					.col(ColumnDef::new(Review::ReviewContent).text().not_null())
+					.primary_key(Index::create().col(Review::LevelId).col(Review::DiscordId))
+					.foreign_key(
+						ForeignKey::create()
+							.name("fk_review_level_request")
+							.from(Review::Table, Review::LevelId)
+							.to(LevelRequest::Table, LevelRequest::LevelId)
+							.on_update(ForeignKeyAction::NoAction)
+							.on_delete(ForeignKeyAction::Cascade)
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("fk_review_reviewer")
+							.from(Review::Table, Review::DiscordId)
+							.to(Reviewer::Table, Reviewer::DiscordId)
+							.on_update(ForeignKeyAction::NoAction)
+							.on_delete(ForeignKeyAction::NoAction)
+					)
+					.to_owned()
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(Moderator::Table)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(Moderator::LevelId)
+							.big_unsigned()
+							.not_null()
+							.primary_key()
+					)
+					.col(
+						ColumnDef::new(Moderator::Score)
+							.enumeration(
+								Alias::new("score"),
+								[
+									Alias::new("no_rate"),
+									Alias::new("rated"),
+									Alias::new("one"),
+									Alias::new("two"),
+									Alias::new("three"),
+									Alias::new("four"),
+									Alias::new("five"),
+									Alias::new("six"),
+									Alias::new("seven"),
+									Alias::new("eight"),
+									Alias::new("nine"),
+									Alias::new("ten")
+								]
+							)
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(Moderator::Rating)
+							.enumeration(
+								Alias::new("rating"),
+								[
+									Alias::new("rate"),
+									Alias::new("feature"),
+									Alias::new("epic"),
+									Alias::new("legendary"),
+									Alias::new("mythic")
+								]
+							)
+							.not_null()
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("fk_moderator_level_request")
+							.from(Moderator::Table, Moderator::LevelId)
+							.to(LevelRequest::Table, LevelRequest::LevelId)
+							.on_update(ForeignKeyAction::NoAction)
+							.on_delete(ForeignKeyAction::Cascade)
+					)
+					.to_owned()
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(Moderator::Table).to_owned())
+			.await?;
+		manager
+			.drop_table(Table::drop().table(Review::Table).to_owned())
+			.await?;
+		manager
+			.drop_table(Table::drop().table(LevelRequest::Table).to_owned())
+			.await?;
+		manager
+			.drop_table(Table::drop().table(Reviewer::Table).to_owned())
+			.await?;
+		manager
+			.drop_table(Table::drop().table(User::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+enum User {
+	Table,
+	DiscordId,
+	Timestamp
+}
+
+#[derive(DeriveIden)]
+enum Reviewer {
+	Table,
+	DiscordId,
+	Active
+}
+
+#[derive(DeriveIden)]
+enum LevelRequest {
+	Table,
+	LevelId,
+	DiscordId,
+	DiscordMessageId,
+	Name,
+	Author,
+	RequestRating,
+	LevelLength,
+	YouTubeVideoLink,
+	HasRequestedFeedback,
+	Notify,
+	Timestamp
+}
+
+#[derive(DeriveIden)]
+enum Review {
+	Table,
+	LevelId,
+	DiscordId,
+	MessageId,
+	ReviewContent
+}
+
+#[derive(DeriveIden)]
+enum Moderator {
+	Table,
+	LevelId,
+	Score,
+	Rating
+}
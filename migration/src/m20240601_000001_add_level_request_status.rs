@@ -0,0 +1,69 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.alter_table(
+				Table::alter()
+					.table(LevelRequest::Table)
+					.add_column(
+						ColumnDef::new(LevelRequest::Status)
+							.enumeration(
+								Alias::new("request_status"),
+								[
+									Alias::new("pending"),
+									Alias::new("in_review"),
+									Alias::new("reviewed"),
+									Alias::new("sent"),
+									Alias::new("rejected")
+								]
+							)
+							.not_null()
+							.default("pending")
+					)
+					.to_owned()
+			)
+			.await?;
+
+		// SQLite only supports a single column per ALTER TABLE statement
+		manager
+			.alter_table(
+				Table::alter()
+					.table(LevelRequest::Table)
+					.add_column(ColumnDef::new(LevelRequest::ArchivedAt).timestamp().null())
+					.to_owned()
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.alter_table(
+				Table::alter()
+					.table(LevelRequest::Table)
+					.drop_column(LevelRequest::ArchivedAt)
+					.to_owned()
+			)
+			.await?;
+
+		manager
+			.alter_table(
+				Table::alter()
+					.table(LevelRequest::Table)
+					.drop_column(LevelRequest::Status)
+					.to_owned()
+			)
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+enum LevelRequest {
+	Table,
+	Status,
+	ArchivedAt
+}
@@ -0,0 +1,57 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(RequestConfig::Table)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(RequestConfig::Id)
+							.integer()
+							.not_null()
+							.primary_key()
+					)
+					.col(
+						ColumnDef::new(RequestConfig::CooldownDuration)
+							.integer()
+							.not_null()
+							.default(0)
+					)
+					.col(
+						ColumnDef::new(RequestConfig::EnableRequests)
+							.tiny_integer()
+							.not_null()
+							.default(1)
+					)
+					.col(
+						ColumnDef::new(RequestConfig::EnableGdRequests)
+							.tiny_integer()
+							.not_null()
+							.default(1)
+					)
+					.to_owned()
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(RequestConfig::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+enum RequestConfig {
+	Table,
+	Id,
+	CooldownDuration,
+	EnableRequests,
+	EnableGdRequests
+}
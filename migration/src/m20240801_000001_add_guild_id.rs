@@ -0,0 +1,610 @@
+use std::env;
+
+use sea_orm_migration::prelude::*;
+
+/// Rows created before multi-tenancy are assigned to this guild. Defaults to
+/// `0` when unset.
+static LEGACY_GUILD_ID_VAR: &str = "REQUESTX_LEGACY_GUILD_ID";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Every table gains a `guild_id` that is part of its primary key. SQLite
+/// cannot alter primary or foreign keys in place, so each table is renamed,
+/// recreated and copied over.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		rename_tables(manager).await?;
+		create_tables(manager, true).await?;
+		copy_rows(manager, true).await?;
+		drop_legacy_tables(manager).await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		rename_tables(manager).await?;
+		create_tables(manager, false).await?;
+		copy_rows(manager, false).await?;
+		drop_legacy_tables(manager).await
+	}
+}
+
+fn legacy_guild_id() -> u64 {
+	env::var(LEGACY_GUILD_ID_VAR)
+		.ok()
+		.and_then(|guild_id| guild_id.parse::<u64>().ok())
+		.unwrap_or(0)
+}
+
+fn legacy(table: &str) -> Alias { Alias::new(format!("{}_legacy", table)) }
+
+async fn rename_tables(manager: &SchemaManager<'_>) -> Result<(), DbErr> {
+	for table in [
+		User::Table.to_string(),
+		Reviewer::Table.to_string(),
+		LevelRequest::Table.to_string(),
+		Review::Table.to_string(),
+		Moderator::Table.to_string(),
+		RequestConfig::Table.to_string()
+	] {
+		manager
+			.rename_table(
+				Table::rename()
+					.table(Alias::new(&table), legacy(&table))
+					.to_owned()
+			)
+			.await?;
+	}
+	Ok(())
+}
+
+async fn drop_legacy_tables(manager: &SchemaManager<'_>) -> Result<(), DbErr> {
+	for table in [
+		Moderator::Table.to_string(),
+		Review::Table.to_string(),
+		LevelRequest::Table.to_string(),
+		Reviewer::Table.to_string(),
+		User::Table.to_string(),
+		RequestConfig::Table.to_string()
+	] {
+		manager
+			.drop_table(Table::drop().table(legacy(&table)).to_owned())
+			.await?;
+	}
+	Ok(())
+}
+
+async fn create_tables(manager: &SchemaManager<'_>, with_guild: bool) -> Result<(), DbErr> {
+	let mut user = Table::create();
+	user.table(User::Table)
+		.col(ColumnDef::new(User::DiscordId).big_unsigned().not_null())
+		.col(ColumnDef::new(User::Timestamp).timestamp().null());
+	if with_guild {
+		user.col(ColumnDef::new(User::GuildId).big_unsigned().not_null())
+			.primary_key(Index::create().col(User::GuildId).col(User::DiscordId));
+	} else {
+		user.primary_key(Index::create().col(User::DiscordId));
+	}
+	manager.create_table(user).await?;
+
+	let mut reviewer = Table::create();
+	reviewer
+		.table(Reviewer::Table)
+		.col(
+			ColumnDef::new(Reviewer::DiscordId)
+				.big_unsigned()
+				.not_null()
+		)
+		.col(
+			ColumnDef::new(Reviewer::Active)
+				.tiny_integer()
+				.not_null()
+				.default(1)
+		);
+	if with_guild {
+		reviewer
+			.col(ColumnDef::new(Reviewer::GuildId).big_unsigned().not_null())
+			.primary_key(
+				Index::create()
+					.col(Reviewer::GuildId)
+					.col(Reviewer::DiscordId)
+			);
+	} else {
+		reviewer.primary_key(Index::create().col(Reviewer::DiscordId));
+	}
+	manager.create_table(reviewer).await?;
+
+	let mut level_request = Table::create();
+	level_request
+		.table(LevelRequest::Table)
+		.col(
+			ColumnDef::new(LevelRequest::LevelId)
+				.big_unsigned()
+				.not_null()
+		)
+		.col(
+			ColumnDef::new(LevelRequest::DiscordId)
+				.big_unsigned()
+				.not_null()
+		)
+		.col(
+			ColumnDef::new(LevelRequest::DiscordMessageId)
+				.big_unsigned()
+				.null()
+				.unique_key()
+		)
+		.col(ColumnDef::new(LevelRequest::Name).string().null())
+		.col(ColumnDef::new(LevelRequest::Author).string().null())
+		.col(
+			ColumnDef::new(LevelRequest::RequestRating)
+				.enumeration(
+					Alias::new("request_rating"),
+					[
+						Alias::new("one"),
+						Alias::new("two"),
+						Alias::new("three"),
+						Alias::new("four"),
+						Alias::new("five"),
+						Alias::new("six"),
+						Alias::new("seven"),
+						Alias::new("eight"),
+						Alias::new("nine"),
+						Alias::new("ten")
+					]
+				)
+				.not_null()
+		)
+		.col(
+			ColumnDef::new(LevelRequest::LevelLength)
+				.enumeration(
+					Alias::new("level_length"),
+					[
+						Alias::new("tiny"),
+						Alias::new("short"),
+						Alias::new("medium"),
+						Alias::new("long"),
+						Alias::new("extra_long"),
+						Alias::new("platformer")
+					]
+				)
+				.null()
+		)
+		.col(
+			ColumnDef::new(LevelRequest::YouTubeVideoLink)
+				.string()
+				.not_null()
+		)
+		.col(
+			ColumnDef::new(LevelRequest::HasRequestedFeedback)
+				.tiny_integer()
+				.not_null()
+				.default(0)
+		)
+		.col(
+			ColumnDef::new(LevelRequest::Notify)
+				.tiny_integer()
+				.not_null()
+				.default(0)
+		)
+		.col(
+			ColumnDef::new(LevelRequest::Status)
+				.enumeration(
+					Alias::new("request_status"),
+					[
+						Alias::new("pending"),
+						Alias::new("in_review"),
+						Alias::new("reviewed"),
+						Alias::new("sent"),
+						Alias::new("rejected")
+					]
+				)
+				.not_null()
+				.default("pending")
+		)
+		.col(
+			ColumnDef::new(LevelRequest::Timestamp)
+				.timestamp()
+				.not_null()
+		)
+		.col(ColumnDef::new(LevelRequest::ArchivedAt).timestamp().null());
+	if with_guild {
+		level_request
+			.col(
+				ColumnDef::new(LevelRequest::GuildId)
+					.big_unsigned()
+					.not_null()
+			)
+			.primary_key(
+				Index::create()
+					.col(LevelRequest::GuildId)
+					.col(LevelRequest::LevelId)
+			)
+			.foreign_key(
+				ForeignKey::create()
+					.name("fk_guild_level_request_user")
+					.from(
+						LevelRequest::Table,
+						(LevelRequest::GuildId, LevelRequest::DiscordId)
+					)
+					.to(User::Table, (User::GuildId, User::DiscordId))
+					.on_update(ForeignKeyAction::NoAction)
+					.on_delete(ForeignKeyAction::NoAction)
+			);
+	} else {
+		level_request
+			.primary_key(Index::create().col(LevelRequest::LevelId))
+			.foreign_key(
+				ForeignKey::create()
+					.name("fk_level_request_user")
+					.from(LevelRequest::Table, LevelRequest::DiscordId)
+					.to(User::Table, User::DiscordId)
+					.on_update(ForeignKeyAction::NoAction)
+					.on_delete(ForeignKeyAction::NoAction)
+			);
+	}
+	manager.create_table(level_request).await?;
+
+	let mut review = Table::create();
+	review
+		.table(Review::Table)
+		.col(ColumnDef::new(Review::LevelId).big_unsigned().not_null())
+		.col(ColumnDef::new(Review::DiscordId).big_unsigned().not_null())
+		.col(ColumnDef::new(Review::MessageId).big_unsigned().not_null())
+		.col(ColumnDef::new(Review::ReviewContent).text().not_null());
+	if with_guild {
+		review
+			.col(ColumnDef::new(Review::GuildId).big_unsigned().not_null())
+			.primary_key(
+				Index::create()
+					.col(Review::GuildId)
+					.col(Review::LevelId)
+					.col(Review::DiscordId)
+			)
+			.foreign_key(
+				ForeignKey::create()
+					.name("fk_guild_review_level_request")
+					.from(Review::Table, (Review::GuildId, Review::LevelId))
+					.to(
+						LevelRequest::Table,
+						(LevelRequest::GuildId, LevelRequest::LevelId)
+					)
+					.on_update(ForeignKeyAction::NoAction)
+					.on_delete(ForeignKeyAction::Cascade)
+			)
+			.foreign_key(
+				ForeignKey::create()
+					.name("fk_guild_review_reviewer")
+					.from(Review::Table, (Review::GuildId, Review::DiscordId))
+					.to(Reviewer::Table, (Reviewer::GuildId, Reviewer::DiscordId))
+					.on_update(ForeignKeyAction::NoAction)
+					.on_delete(ForeignKeyAction::NoAction)
+			);
+	} else {
+		review
+			.primary_key(Index::create().col(Review::LevelId).col(Review::DiscordId))
+			.foreign_key(
+				ForeignKey::create()
+					.name("fk_review_level_request")
+					.from(Review::Table, Review::LevelId)
+					.to(LevelRequest::Table, LevelRequest::LevelId)
+					.on_update(ForeignKeyAction::NoAction)
+					.on_delete(ForeignKeyAction::Cascade)
+			)
+			.foreign_key(
+				ForeignKey::create()
+					.name("fk_review_reviewer")
+					.from(Review::Table, Review::DiscordId)
+					.to(Reviewer::Table, Reviewer::DiscordId)
+					.on_update(ForeignKeyAction::NoAction)
+					.on_delete(ForeignKeyAction::NoAction)
+			);
+	}
+	manager.create_table(review).await?;
+
+	let mut moderator = Table::create();
+	moderator
+		.table(Moderator::Table)
+		.col(ColumnDef::new(Moderator::LevelId).big_unsigned().not_null())
+		.col(
+			ColumnDef::new(Moderator::Score)
+				.enumeration(
+					Alias::new("score"),
+					[
+						Alias::new("no_rate"),
+						Alias::new("rated"),
+						Alias::new("one"),
+						Alias::new("two"),
+						Alias::new("three"),
+						Alias::new("four"),
+						Alias::new("five"),
+						Alias::new("six"),
+						Alias::new("seven"),
+						Alias::new("eight"),
+						Alias::new("nine"),
+						Alias::new("ten")
+					]
+				)
+				.not_null()
+		)
+		.col(
+			ColumnDef::new(Moderator::Rating)
+				.enumeration(
+					Alias::new("rating"),
+					[
+						Alias::new("rate"),
+						Alias::new("feature"),
+						Alias::new("epic"),
+						Alias::new("legendary"),
+						Alias::new("mythic")
+					]
+				)
+				.not_null()
+		);
+	if with_guild {
+		moderator
+			.col(ColumnDef::new(Moderator::GuildId).big_unsigned().not_null())
+			.primary_key(
+				Index::create()
+					.col(Moderator::GuildId)
+					.col(Moderator::LevelId)
+			)
+			.foreign_key(
+				ForeignKey::create()
+					.name("fk_guild_moderator_level_request")
+					.from(Moderator::Table, (Moderator::GuildId, Moderator::LevelId))
+					.to(
+						LevelRequest::Table,
+						(LevelRequest::GuildId, LevelRequest::LevelId)
+					)
+					.on_update(ForeignKeyAction::NoAction)
+					.on_delete(ForeignKeyAction::Cascade)
+			);
+	} else {
+		moderator
+			.primary_key(Index::create().col(Moderator::LevelId))
+			.foreign_key(
+				ForeignKey::create()
+					.name("fk_moderator_level_request")
+					.from(Moderator::Table, Moderator::LevelId)
+					.to(LevelRequest::Table, LevelRequest::LevelId)
+					.on_update(ForeignKeyAction::NoAction)
+					.on_delete(ForeignKeyAction::Cascade)
+			);
+	}
+	manager.create_table(moderator).await?;
+
+	let mut request_config = Table::create();
+	request_config
+		.table(RequestConfig::Table)
+		.col(
+			ColumnDef::new(RequestConfig::CooldownDuration)
+				.integer()
+				.not_null()
+				.default(0)
+		)
+		.col(
+			ColumnDef::new(RequestConfig::EnableRequests)
+				.tiny_integer()
+				.not_null()
+				.default(1)
+		)
+		.col(
+			ColumnDef::new(RequestConfig::EnableGdRequests)
+				.tiny_integer()
+				.not_null()
+				.default(1)
+		);
+	if with_guild {
+		request_config.col(
+			ColumnDef::new(RequestConfig::GuildId)
+				.big_unsigned()
+				.not_null()
+				.primary_key()
+		);
+	} else {
+		request_config.col(
+			ColumnDef::new(RequestConfig::Id)
+				.integer()
+				.not_null()
+				.primary_key()
+		);
+	}
+	manager.create_table(request_config).await
+}
+
+async fn copy_rows(manager: &SchemaManager<'_>, with_guild: bool) -> Result<(), DbErr> {
+	let guild_id = legacy_guild_id();
+
+	copy_table(
+		manager,
+		User::Table,
+		vec![User::DiscordId, User::Timestamp],
+		with_guild,
+		guild_id
+	)
+	.await?;
+	copy_table(
+		manager,
+		Reviewer::Table,
+		vec![Reviewer::DiscordId, Reviewer::Active],
+		with_guild,
+		guild_id
+	)
+	.await?;
+	copy_table(
+		manager,
+		LevelRequest::Table,
+		vec![
+			LevelRequest::LevelId,
+			LevelRequest::DiscordId,
+			LevelRequest::DiscordMessageId,
+			LevelRequest::Name,
+			LevelRequest::Author,
+			LevelRequest::RequestRating,
+			LevelRequest::LevelLength,
+			LevelRequest::YouTubeVideoLink,
+			LevelRequest::HasRequestedFeedback,
+			LevelRequest::Notify,
+			LevelRequest::Status,
+			LevelRequest::Timestamp,
+			LevelRequest::ArchivedAt,
+		],
+		with_guild,
+		guild_id
+	)
+	.await?;
+	copy_table(
+		manager,
+		Review::Table,
+		vec![
+			Review::LevelId,
+			Review::DiscordId,
+			Review::MessageId,
+			Review::ReviewContent,
+		],
+		with_guild,
+		guild_id
+	)
+	.await?;
+	copy_table(
+		manager,
+		Moderator::Table,
+		vec![Moderator::LevelId, Moderator::Score, Moderator::Rating],
+		with_guild,
+		guild_id
+	)
+	.await?;
+
+	let request_config_columns = vec![
+		RequestConfig::CooldownDuration,
+		RequestConfig::EnableRequests,
+		RequestConfig::EnableGdRequests,
+	];
+	let mut select = Query::select();
+	select
+		.columns(request_config_columns.clone())
+		.from(legacy(&RequestConfig::Table.to_string()));
+	let key_column = if with_guild {
+		select.expr(Expr::val(guild_id));
+		RequestConfig::GuildId
+	} else {
+		select
+			.expr(Expr::val(1))
+			.and_where(Expr::col(RequestConfig::GuildId).eq(guild_id));
+		RequestConfig::Id
+	};
+	manager
+		.exec_stmt(
+			Query::insert()
+				.into_table(RequestConfig::Table)
+				.columns(request_config_columns.into_iter().chain([key_column]))
+				.select_from(select)
+				.map_err(|err| DbErr::Migration(err.to_string()))?
+				.to_owned()
+		)
+		.await
+}
+
+/// Copies `columns` from the legacy table, adding `guild_id` when moving to the
+/// multi-tenant schema and keeping only the legacy guild's rows when moving
+/// back.
+async fn copy_table<T: Iden + Copy + 'static, C: Iden + Clone + 'static>(
+	manager: &SchemaManager<'_>,
+	table: T,
+	columns: Vec<C>,
+	with_guild: bool,
+	guild_id: u64
+) -> Result<(), DbErr> {
+	let mut select = Query::select();
+	select
+		.columns(columns.clone())
+		.from(legacy(&table.to_string()));
+
+	let mut insert = Query::insert();
+	insert.into_table(table);
+	if with_guild {
+		select.expr(Expr::val(guild_id));
+		insert.columns(
+			columns
+				.into_iter()
+				.map(|column| Alias::new(column.to_string()))
+				.chain([Alias::new("guild_id")])
+		);
+	} else {
+		select.and_where(Expr::col(Alias::new("guild_id")).eq(guild_id));
+		insert.columns(columns);
+	}
+
+	manager
+		.exec_stmt(
+			insert
+				.select_from(select)
+				.map_err(|err| DbErr::Migration(err.to_string()))?
+				.to_owned()
+		)
+		.await
+}
+
+#[derive(DeriveIden, Clone, Copy)]
+enum User {
+	Table,
+	GuildId,
+	DiscordId,
+	Timestamp
+}
+
+#[derive(DeriveIden, Clone, Copy)]
+enum Reviewer {
+	Table,
+	GuildId,
+	DiscordId,
+	Active
+}
+
+#[derive(DeriveIden, Clone, Copy)]
+enum LevelRequest {
+	Table,
+	GuildId,
+	LevelId,
+	DiscordId,
+	DiscordMessageId,
+	Name,
+	Author,
+	RequestRating,
+	LevelLength,
+	YouTubeVideoLink,
+	HasRequestedFeedback,
+	Notify,
+	Status,
+	Timestamp,
+	ArchivedAt
+}
+
+#[derive(DeriveIden, Clone, Copy)]
+enum Review {
+	Table,
+	GuildId,
+	LevelId,
+	DiscordId,
+	MessageId,
+	ReviewContent
+}
+
+#[derive(DeriveIden, Clone, Copy)]
+enum Moderator {
+	Table,
+	GuildId,
+	LevelId,
+	Score,
+	Rating
+}
+
+#[derive(DeriveIden, Clone, Copy)]
+enum RequestConfig {
+	Table,
+	Id,
+	GuildId,
+	CooldownDuration,
+	EnableRequests,
+	EnableGdRequests
+}
@@ -0,0 +1,52 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(UserRole::Table)
+					.if_not_exists()
+					.col(ColumnDef::new(UserRole::GuildId).big_unsigned().not_null())
+					.col(
+						ColumnDef::new(UserRole::DiscordId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(UserRole::Role)
+							.enumeration(
+								Alias::new("role"),
+								[Alias::new("moderator"), Alias::new("admin")]
+							)
+							.not_null()
+					)
+					.primary_key(
+						Index::create()
+							.col(UserRole::GuildId)
+							.col(UserRole::DiscordId)
+							.col(UserRole::Role)
+					)
+					.to_owned()
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(UserRole::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+enum UserRole {
+	Table,
+	GuildId,
+	DiscordId,
+	Role
+}
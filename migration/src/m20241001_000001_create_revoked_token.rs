@@ -0,0 +1,58 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(RevokedToken::Table)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(RevokedToken::Jti)
+							.string_len(36)
+							.not_null()
+							.primary_key()
+					)
+					.col(
+						ColumnDef::new(RevokedToken::GuildId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(RevokedToken::ExpiresAt)
+							.timestamp()
+							.not_null()
+					)
+					.to_owned()
+			)
+			.await?;
+
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_revoked_token_expires_at")
+					.table(RevokedToken::Table)
+					.col(RevokedToken::ExpiresAt)
+					.to_owned()
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(RevokedToken::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+enum RevokedToken {
+	Table,
+	Jti,
+	GuildId,
+	ExpiresAt
+}
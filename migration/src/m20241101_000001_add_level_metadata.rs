@@ -0,0 +1,144 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		// SQLite only supports a single column per ALTER TABLE statement
+		for mut level_metadata_column in level_metadata_columns() {
+			manager
+				.alter_table(
+					Table::alter()
+						.table(LevelRequest::Table)
+						.add_column(&mut level_metadata_column)
+						.to_owned()
+				)
+				.await?;
+		}
+		Ok(())
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		for level_metadata_column in level_metadata_columns().iter().rev() {
+			manager
+				.alter_table(
+					Table::alter()
+						.table(LevelRequest::Table)
+						.drop_column(Alias::new(level_metadata_column.get_column_name()))
+						.to_owned()
+				)
+				.await?;
+		}
+		Ok(())
+	}
+}
+
+fn level_metadata_columns() -> Vec<ColumnDef> {
+	vec![
+		ColumnDef::new(LevelRequest::Description)
+			.text()
+			.null()
+			.to_owned(),
+		ColumnDef::new(LevelRequest::AuthorAccountId)
+			.big_unsigned()
+			.null()
+			.to_owned(),
+		ColumnDef::new(LevelRequest::AuthorPlayerId)
+			.big_unsigned()
+			.null()
+			.to_owned(),
+		ColumnDef::new(LevelRequest::Difficulty)
+			.enumeration(
+				Alias::new("level_difficulty"),
+				[
+					Alias::new("not_available"),
+					Alias::new("auto"),
+					Alias::new("easy"),
+					Alias::new("normal"),
+					Alias::new("hard"),
+					Alias::new("harder"),
+					Alias::new("insane"),
+					Alias::new("easy_demon"),
+					Alias::new("medium_demon"),
+					Alias::new("hard_demon"),
+					Alias::new("insane_demon"),
+					Alias::new("extreme_demon")
+				]
+			)
+			.null()
+			.to_owned(),
+		ColumnDef::new(LevelRequest::Stars)
+			.tiny_unsigned()
+			.null()
+			.to_owned(),
+		ColumnDef::new(LevelRequest::FeatureState)
+			.enumeration(
+				Alias::new("feature_state"),
+				[
+					Alias::new("not_featured"),
+					Alias::new("featured"),
+					Alias::new("epic")
+				]
+			)
+			.null()
+			.to_owned(),
+		ColumnDef::new(LevelRequest::Downloads)
+			.unsigned()
+			.null()
+			.to_owned(),
+		ColumnDef::new(LevelRequest::Likes)
+			.integer()
+			.null()
+			.to_owned(),
+		ColumnDef::new(LevelRequest::GameVersion)
+			.string()
+			.null()
+			.to_owned(),
+		ColumnDef::new(LevelRequest::SongId)
+			.big_unsigned()
+			.null()
+			.to_owned(),
+		ColumnDef::new(LevelRequest::SongName)
+			.string()
+			.null()
+			.to_owned(),
+		ColumnDef::new(LevelRequest::SongArtist)
+			.string()
+			.null()
+			.to_owned(),
+		ColumnDef::new(LevelRequest::IsCustomSong)
+			.tiny_integer()
+			.null()
+			.to_owned(),
+		ColumnDef::new(LevelRequest::ObjectCount)
+			.unsigned()
+			.null()
+			.to_owned(),
+		ColumnDef::new(LevelRequest::CoinCount)
+			.tiny_unsigned()
+			.null()
+			.to_owned(),
+	]
+}
+
+#[derive(DeriveIden)]
+enum LevelRequest {
+	Table,
+	Description,
+	AuthorAccountId,
+	AuthorPlayerId,
+	Difficulty,
+	Stars,
+	FeatureState,
+	Downloads,
+	Likes,
+	GameVersion,
+	SongId,
+	SongName,
+	SongArtist,
+	IsCustomSong,
+	ObjectCount,
+	CoinCount
+}
@@ -0,0 +1,72 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.alter_table(
+				Table::alter()
+					.table(LevelRequest::Table)
+					.add_column(ColumnDef::new(LevelRequest::SkippedAt).timestamp().null())
+					.to_owned()
+			)
+			.await?;
+
+		manager
+			.alter_table(
+				Table::alter()
+					.table(RequestConfig::Table)
+					.add_column(
+						ColumnDef::new(RequestConfig::QueuePolicy)
+							.enumeration(
+								Alias::new("queue_policy"),
+								[
+									Alias::new("fifo"),
+									Alias::new("request_rating"),
+									Alias::new("feedback_first"),
+									Alias::new("round_robin")
+								]
+							)
+							.not_null()
+							.default("fifo")
+					)
+					.to_owned()
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.alter_table(
+				Table::alter()
+					.table(RequestConfig::Table)
+					.drop_column(RequestConfig::QueuePolicy)
+					.to_owned()
+			)
+			.await?;
+
+		manager
+			.alter_table(
+				Table::alter()
+					.table(LevelRequest::Table)
+					.drop_column(LevelRequest::SkippedAt)
+					.to_owned()
+			)
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+enum LevelRequest {
+	Table,
+	SkippedAt
+}
+
+#[derive(DeriveIden)]
+enum RequestConfig {
+	Table,
+	QueuePolicy
+}
@@ -0,0 +1,111 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		// SQLite only supports a single column per ALTER TABLE statement
+		for mut user_column in user_columns() {
+			manager
+				.alter_table(
+					Table::alter()
+						.table(User::Table)
+						.add_column(&mut user_column)
+						.to_owned()
+				)
+				.await?;
+		}
+		for mut request_config_column in request_config_columns() {
+			manager
+				.alter_table(
+					Table::alter()
+						.table(RequestConfig::Table)
+						.add_column(&mut request_config_column)
+						.to_owned()
+				)
+				.await?;
+		}
+		Ok(())
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		for request_config_column in request_config_columns().iter().rev() {
+			manager
+				.alter_table(
+					Table::alter()
+						.table(RequestConfig::Table)
+						.drop_column(Alias::new(request_config_column.get_column_name()))
+						.to_owned()
+				)
+				.await?;
+		}
+		for user_column in user_columns().iter().rev() {
+			manager
+				.alter_table(
+					Table::alter()
+						.table(User::Table)
+						.drop_column(Alias::new(user_column.get_column_name()))
+						.to_owned()
+				)
+				.await?;
+		}
+		Ok(())
+	}
+}
+
+/// Per-user overrides of the guild's request limits. A null column falls back
+/// to the guild's limit.
+fn user_columns() -> Vec<ColumnDef> {
+	vec![
+		ColumnDef::new(User::CooldownDuration)
+			.integer()
+			.null()
+			.to_owned(),
+		ColumnDef::new(User::CooldownExempt)
+			.tiny_integer()
+			.not_null()
+			.default(0)
+			.to_owned(),
+		ColumnDef::new(User::MaxOpenRequests)
+			.unsigned()
+			.null()
+			.to_owned(),
+		ColumnDef::new(User::DailyRequestQuota)
+			.unsigned()
+			.null()
+			.to_owned(),
+	]
+}
+
+fn request_config_columns() -> Vec<ColumnDef> {
+	vec![
+		ColumnDef::new(RequestConfig::MaxOpenRequests)
+			.unsigned()
+			.not_null()
+			.default(0)
+			.to_owned(),
+		ColumnDef::new(RequestConfig::DailyRequestQuota)
+			.unsigned()
+			.not_null()
+			.default(0)
+			.to_owned(),
+	]
+}
+
+#[derive(DeriveIden)]
+enum User {
+	Table,
+	CooldownDuration,
+	CooldownExempt,
+	MaxOpenRequests,
+	DailyRequestQuota
+}
+
+#[derive(DeriveIden)]
+enum RequestConfig {
+	Table,
+	MaxOpenRequests,
+	DailyRequestQuota
+}
@@ -0,0 +1,86 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(RequestBan::Table)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(RequestBan::Id)
+							.integer()
+							.not_null()
+							.auto_increment()
+							.primary_key()
+					)
+					.col(
+						ColumnDef::new(RequestBan::GuildId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(RequestBan::TargetType)
+							.enumeration(
+								Alias::new("ban_target_type"),
+								[Alias::new("user"), Alias::new("level")]
+							)
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(RequestBan::TargetId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(ColumnDef::new(RequestBan::Reason).text().null())
+					.col(
+						ColumnDef::new(RequestBan::IssuedBy)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(ColumnDef::new(RequestBan::CreatedAt).timestamp().not_null())
+					.col(ColumnDef::new(RequestBan::ExpiresAt).timestamp().null())
+					.col(ColumnDef::new(RequestBan::LiftedAt).timestamp().null())
+					.col(ColumnDef::new(RequestBan::LiftedBy).big_unsigned().null())
+					.to_owned()
+			)
+			.await?;
+
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_request_ban_target")
+					.table(RequestBan::Table)
+					.col(RequestBan::GuildId)
+					.col(RequestBan::TargetType)
+					.col(RequestBan::TargetId)
+					.to_owned()
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(RequestBan::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+enum RequestBan {
+	Table,
+	Id,
+	GuildId,
+	TargetType,
+	TargetId,
+	Reason,
+	IssuedBy,
+	CreatedAt,
+	ExpiresAt,
+	LiftedAt,
+	LiftedBy
+}
@@ -0,0 +1,108 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(ReviewAssignment::Table)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(ReviewAssignment::Id)
+							.integer()
+							.not_null()
+							.auto_increment()
+							.primary_key()
+					)
+					.col(
+						ColumnDef::new(ReviewAssignment::GuildId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ReviewAssignment::LevelId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ReviewAssignment::DiscordId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ReviewAssignment::AssignedBy)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ReviewAssignment::AssignedAt)
+							.timestamp()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ReviewAssignment::ExpiresAt)
+							.timestamp()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ReviewAssignment::CompletedAt)
+							.timestamp()
+							.null()
+					)
+					.col(
+						ColumnDef::new(ReviewAssignment::ReleasedAt)
+							.timestamp()
+							.null()
+					)
+					.to_owned()
+			)
+			.await?;
+
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_review_assignment_level")
+					.table(ReviewAssignment::Table)
+					.col(ReviewAssignment::GuildId)
+					.col(ReviewAssignment::LevelId)
+					.to_owned()
+			)
+			.await?;
+
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_review_assignment_reviewer")
+					.table(ReviewAssignment::Table)
+					.col(ReviewAssignment::GuildId)
+					.col(ReviewAssignment::DiscordId)
+					.to_owned()
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(ReviewAssignment::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+enum ReviewAssignment {
+	Table,
+	Id,
+	GuildId,
+	LevelId,
+	/// The reviewer the level is assigned to.
+	DiscordId,
+	AssignedBy,
+	AssignedAt,
+	ExpiresAt,
+	CompletedAt,
+	ReleasedAt
+}
@@ -0,0 +1,159 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(ReviewRevision::Table)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(ReviewRevision::Id)
+							.integer()
+							.not_null()
+							.auto_increment()
+							.primary_key()
+					)
+					.col(
+						ColumnDef::new(ReviewRevision::GuildId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ReviewRevision::LevelId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ReviewRevision::DiscordId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ReviewRevision::Revision)
+							.unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ReviewRevision::MessageId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ReviewRevision::ReviewContent)
+							.text()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ReviewRevision::CreatedAt)
+							.timestamp()
+							.not_null()
+					)
+					.to_owned()
+			)
+			.await?;
+
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_review_revision_review")
+					.table(ReviewRevision::Table)
+					.col(ReviewRevision::GuildId)
+					.col(ReviewRevision::LevelId)
+					.col(ReviewRevision::DiscordId)
+					.col(ReviewRevision::Revision)
+					.unique()
+					.to_owned()
+			)
+			.await?;
+
+		// Existing reviews become their own first revision, as their earlier
+		// contents were already overwritten. When they were written is not
+		// known, so they are dated to their level request
+		let mut select = Query::select();
+		select
+			.columns([
+				(Review::Table, Review::GuildId),
+				(Review::Table, Review::LevelId),
+				(Review::Table, Review::DiscordId),
+				(Review::Table, Review::MessageId),
+				(Review::Table, Review::ReviewContent)
+			])
+			.expr(Expr::val(1))
+			.column((LevelRequest::Table, LevelRequest::Timestamp))
+			.from(Review::Table)
+			.inner_join(
+				LevelRequest::Table,
+				Condition::all()
+					.add(
+						Expr::col((LevelRequest::Table, LevelRequest::GuildId))
+							.equals((Review::Table, Review::GuildId))
+					)
+					.add(
+						Expr::col((LevelRequest::Table, LevelRequest::LevelId))
+							.equals((Review::Table, Review::LevelId))
+					)
+			);
+		manager
+			.exec_stmt(
+				Query::insert()
+					.into_table(ReviewRevision::Table)
+					.columns([
+						ReviewRevision::GuildId,
+						ReviewRevision::LevelId,
+						ReviewRevision::DiscordId,
+						ReviewRevision::MessageId,
+						ReviewRevision::ReviewContent,
+						ReviewRevision::Revision,
+						ReviewRevision::CreatedAt
+					])
+					.select_from(select)
+					.map_err(|err| DbErr::Migration(err.to_string()))?
+					.to_owned()
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(ReviewRevision::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+enum ReviewRevision {
+	Table,
+	Id,
+	GuildId,
+	LevelId,
+	/// The reviewer who wrote the revision.
+	DiscordId,
+	/// Numbered from 1 per review.
+	Revision,
+	MessageId,
+	ReviewContent,
+	CreatedAt
+}
+
+#[derive(DeriveIden)]
+enum Review {
+	Table,
+	GuildId,
+	LevelId,
+	DiscordId,
+	MessageId,
+	ReviewContent
+}
+
+#[derive(DeriveIden)]
+enum LevelRequest {
+	Table,
+	GuildId,
+	LevelId,
+	Timestamp
+}
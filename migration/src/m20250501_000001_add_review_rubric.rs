@@ -0,0 +1,87 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		// SQLite only supports a single column per ALTER TABLE statement
+		for mut review_rubric_column in review_rubric_columns() {
+			manager
+				.alter_table(
+					Table::alter()
+						.table(Review::Table)
+						.add_column(&mut review_rubric_column)
+						.to_owned()
+				)
+				.await?;
+		}
+		Ok(())
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		for review_rubric_column in review_rubric_columns().iter().rev() {
+			manager
+				.alter_table(
+					Table::alter()
+						.table(Review::Table)
+						.drop_column(Alias::new(review_rubric_column.get_column_name()))
+						.to_owned()
+				)
+				.await?;
+		}
+		Ok(())
+	}
+}
+
+fn review_rubric_columns() -> Vec<ColumnDef> {
+	vec![
+		ColumnDef::new(Review::RubricScores)
+			.json()
+			.null()
+			.to_owned(),
+		ColumnDef::new(Review::SuggestedScore)
+			.enumeration(
+				Alias::new("score"),
+				[
+					Alias::new("no_rate"),
+					Alias::new("rated"),
+					Alias::new("one"),
+					Alias::new("two"),
+					Alias::new("three"),
+					Alias::new("four"),
+					Alias::new("five"),
+					Alias::new("six"),
+					Alias::new("seven"),
+					Alias::new("eight"),
+					Alias::new("nine"),
+					Alias::new("ten")
+				]
+			)
+			.null()
+			.to_owned(),
+		ColumnDef::new(Review::SuggestedRating)
+			.enumeration(
+				Alias::new("rating"),
+				[
+					Alias::new("rate"),
+					Alias::new("feature"),
+					Alias::new("epic"),
+					Alias::new("legendary"),
+					Alias::new("mythic")
+				]
+			)
+			.null()
+			.to_owned(),
+	]
+}
+
+#[derive(DeriveIden)]
+enum Review {
+	Table,
+	/// Per category scores and comments against the configured rubric.
+	RubricScores,
+	SuggestedScore,
+	SuggestedRating
+}
@@ -0,0 +1,103 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(ReviewerActivity::Table)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(ReviewerActivity::Id)
+							.integer()
+							.not_null()
+							.auto_increment()
+							.primary_key()
+					)
+					.col(
+						ColumnDef::new(ReviewerActivity::GuildId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ReviewerActivity::DiscordId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ReviewerActivity::Active)
+							.tiny_integer()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ReviewerActivity::ChangedAt)
+							.timestamp()
+							.not_null()
+					)
+					.to_owned()
+			)
+			.await?;
+
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_reviewer_activity_reviewer")
+					.table(ReviewerActivity::Table)
+					.col(ReviewerActivity::GuildId)
+					.col(ReviewerActivity::DiscordId)
+					.to_owned()
+			)
+			.await?;
+
+		// Existing reviewers start their history in their current state, as
+		// earlier changes were never recorded
+		let mut select = Query::select();
+		select
+			.columns([Reviewer::GuildId, Reviewer::DiscordId, Reviewer::Active])
+			.expr(Expr::current_timestamp())
+			.from(Reviewer::Table);
+		manager
+			.exec_stmt(
+				Query::insert()
+					.into_table(ReviewerActivity::Table)
+					.columns([
+						ReviewerActivity::GuildId,
+						ReviewerActivity::DiscordId,
+						ReviewerActivity::Active,
+						ReviewerActivity::ChangedAt
+					])
+					.select_from(select)
+					.map_err(|err| DbErr::Migration(err.to_string()))?
+					.to_owned()
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(ReviewerActivity::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+enum ReviewerActivity {
+	Table,
+	Id,
+	GuildId,
+	DiscordId,
+	Active,
+	ChangedAt
+}
+
+#[derive(DeriveIden)]
+enum Reviewer {
+	Table,
+	GuildId,
+	DiscordId,
+	Active
+}
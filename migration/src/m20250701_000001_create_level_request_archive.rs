@@ -0,0 +1,471 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(ArchivedLevelRequest::Table)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::Id)
+							.integer()
+							.not_null()
+							.auto_increment()
+							.primary_key()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::GuildId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::LevelId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::DiscordId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::DiscordMessageId)
+							.big_unsigned()
+							.null()
+					)
+					.col(ColumnDef::new(ArchivedLevelRequest::Name).string().null())
+					.col(ColumnDef::new(ArchivedLevelRequest::Author).string().null())
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::RequestRating)
+							.enumeration(
+								Alias::new("request_rating"),
+								[
+									Alias::new("one"),
+									Alias::new("two"),
+									Alias::new("three"),
+									Alias::new("four"),
+									Alias::new("five"),
+									Alias::new("six"),
+									Alias::new("seven"),
+									Alias::new("eight"),
+									Alias::new("nine"),
+									Alias::new("ten")
+								]
+							)
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::LevelLength)
+							.enumeration(
+								Alias::new("level_length"),
+								[
+									Alias::new("tiny"),
+									Alias::new("short"),
+									Alias::new("medium"),
+									Alias::new("long"),
+									Alias::new("extra_long"),
+									Alias::new("platformer")
+								]
+							)
+							.null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::YouTubeVideoLink)
+							.string()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::HasRequestedFeedback)
+							.tiny_integer()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::Notify)
+							.tiny_integer()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::Status)
+							.enumeration(
+								Alias::new("request_status"),
+								[
+									Alias::new("pending"),
+									Alias::new("in_review"),
+									Alias::new("reviewed"),
+									Alias::new("sent"),
+									Alias::new("rejected")
+								]
+							)
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::Timestamp)
+							.timestamp()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::ArchivedAt)
+							.timestamp()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::Description)
+							.text()
+							.null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::AuthorAccountId)
+							.big_unsigned()
+							.null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::AuthorPlayerId)
+							.big_unsigned()
+							.null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::Difficulty)
+							.enumeration(
+								Alias::new("level_difficulty"),
+								[
+									Alias::new("not_available"),
+									Alias::new("auto"),
+									Alias::new("easy"),
+									Alias::new("normal"),
+									Alias::new("hard"),
+									Alias::new("harder"),
+									Alias::new("insane"),
+									Alias::new("easy_demon"),
+									Alias::new("medium_demon"),
+									Alias::new("hard_demon"),
+									Alias::new("insane_demon"),
+									Alias::new("extreme_demon")
+								]
+							)
+							.null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::Stars)
+							.tiny_unsigned()
+							.null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::FeatureState)
+							.enumeration(
+								Alias::new("feature_state"),
+								[
+									Alias::new("not_featured"),
+									Alias::new("featured"),
+									Alias::new("epic")
+								]
+							)
+							.null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::Downloads)
+							.unsigned()
+							.null()
+					)
+					.col(ColumnDef::new(ArchivedLevelRequest::Likes).integer().null())
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::GameVersion)
+							.string()
+							.null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::SongId)
+							.big_unsigned()
+							.null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::SongName)
+							.string()
+							.null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::SongArtist)
+							.string()
+							.null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::IsCustomSong)
+							.tiny_integer()
+							.null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::ObjectCount)
+							.unsigned()
+							.null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::CoinCount)
+							.tiny_unsigned()
+							.null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::SkippedAt)
+							.timestamp()
+							.null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::ModeratorScore)
+							.enumeration(Alias::new("score"), score_variants())
+							.null()
+					)
+					.col(
+						ColumnDef::new(ArchivedLevelRequest::ModeratorRating)
+							.enumeration(Alias::new("rating"), rating_variants())
+							.null()
+					)
+					.to_owned()
+			)
+			.await?;
+
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_archived_level_request_user")
+					.table(ArchivedLevelRequest::Table)
+					.col(ArchivedLevelRequest::GuildId)
+					.col(ArchivedLevelRequest::DiscordId)
+					.to_owned()
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(ArchivedReview::Table)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(ArchivedReview::Id)
+							.integer()
+							.not_null()
+							.auto_increment()
+							.primary_key()
+					)
+					.col(
+						ColumnDef::new(ArchivedReview::ArchivedLevelRequestId)
+							.integer()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ArchivedReview::GuildId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ArchivedReview::LevelId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ArchivedReview::DiscordId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ArchivedReview::MessageId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ArchivedReview::ReviewContent)
+							.text()
+							.not_null()
+					)
+					.col(ColumnDef::new(ArchivedReview::RubricScores).json().null())
+					.col(
+						ColumnDef::new(ArchivedReview::SuggestedScore)
+							.enumeration(Alias::new("score"), score_variants())
+							.null()
+					)
+					.col(
+						ColumnDef::new(ArchivedReview::SuggestedRating)
+							.enumeration(Alias::new("rating"), rating_variants())
+							.null()
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("fk_archived_review_archived_level_request")
+							.from(
+								ArchivedReview::Table,
+								ArchivedReview::ArchivedLevelRequestId
+							)
+							.to(ArchivedLevelRequest::Table, ArchivedLevelRequest::Id)
+							.on_update(ForeignKeyAction::NoAction)
+							.on_delete(ForeignKeyAction::Cascade)
+					)
+					.to_owned()
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(ArchivedReviewRevision::Table)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(ArchivedReviewRevision::Id)
+							.integer()
+							.not_null()
+							.auto_increment()
+							.primary_key()
+					)
+					.col(
+						ColumnDef::new(ArchivedReviewRevision::ArchivedReviewId)
+							.integer()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ArchivedReviewRevision::Revision)
+							.unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ArchivedReviewRevision::MessageId)
+							.big_unsigned()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ArchivedReviewRevision::ReviewContent)
+							.text()
+							.not_null()
+					)
+					.col(
+						ColumnDef::new(ArchivedReviewRevision::CreatedAt)
+							.timestamp()
+							.not_null()
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("fk_archived_review_revision_archived_review")
+							.from(
+								ArchivedReviewRevision::Table,
+								ArchivedReviewRevision::ArchivedReviewId
+							)
+							.to(ArchivedReview::Table, ArchivedReview::Id)
+							.on_update(ForeignKeyAction::NoAction)
+							.on_delete(ForeignKeyAction::Cascade)
+					)
+					.to_owned()
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(
+				Table::drop()
+					.table(ArchivedReviewRevision::Table)
+					.to_owned()
+			)
+			.await?;
+		manager
+			.drop_table(Table::drop().table(ArchivedReview::Table).to_owned())
+			.await?;
+		manager
+			.drop_table(Table::drop().table(ArchivedLevelRequest::Table).to_owned())
+			.await
+	}
+}
+
+fn score_variants() -> Vec<Alias> {
+	vec![
+		Alias::new("no_rate"),
+		Alias::new("rated"),
+		Alias::new("one"),
+		Alias::new("two"),
+		Alias::new("three"),
+		Alias::new("four"),
+		Alias::new("five"),
+		Alias::new("six"),
+		Alias::new("seven"),
+		Alias::new("eight"),
+		Alias::new("nine"),
+		Alias::new("ten"),
+	]
+}
+
+fn rating_variants() -> Vec<Alias> {
+	vec![
+		Alias::new("rate"),
+		Alias::new("feature"),
+		Alias::new("epic"),
+		Alias::new("legendary"),
+		Alias::new("mythic"),
+	]
+}
+
+/// A level request as it was when the level was requested again, so that the
+/// new request does not overwrite it.
+#[derive(DeriveIden)]
+enum ArchivedLevelRequest {
+	Table,
+	Id,
+	GuildId,
+	LevelId,
+	DiscordId,
+	DiscordMessageId,
+	Name,
+	Author,
+	RequestRating,
+	LevelLength,
+	YouTubeVideoLink,
+	HasRequestedFeedback,
+	Notify,
+	Status,
+	Timestamp,
+	ArchivedAt,
+	Description,
+	AuthorAccountId,
+	AuthorPlayerId,
+	Difficulty,
+	Stars,
+	FeatureState,
+	Downloads,
+	Likes,
+	GameVersion,
+	SongId,
+	SongName,
+	SongArtist,
+	IsCustomSong,
+	ObjectCount,
+	CoinCount,
+	SkippedAt,
+	/// What the moderator sent the level as, if they did.
+	ModeratorScore,
+	ModeratorRating
+}
+
+#[derive(DeriveIden)]
+enum ArchivedReview {
+	Table,
+	Id,
+	ArchivedLevelRequestId,
+	GuildId,
+	LevelId,
+	/// The reviewer who wrote the review.
+	DiscordId,
+	MessageId,
+	ReviewContent,
+	RubricScores,
+	SuggestedScore,
+	SuggestedRating
+}
+
+#[derive(DeriveIden)]
+enum ArchivedReviewRevision {
+	Table,
+	Id,
+	ArchivedReviewId,
+	Revision,
+	MessageId,
+	ReviewContent,
+	CreatedAt
+}
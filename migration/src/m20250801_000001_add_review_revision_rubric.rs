@@ -0,0 +1,98 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		// SQLite only supports a single column per ALTER TABLE statement
+		for table in revision_tables() {
+			for mut revision_rubric_column in revision_rubric_columns() {
+				manager
+					.alter_table(
+						Table::alter()
+							.table(table.clone())
+							.add_column(&mut revision_rubric_column)
+							.to_owned()
+					)
+					.await?;
+			}
+		}
+		Ok(())
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		for table in revision_tables().into_iter().rev() {
+			for revision_rubric_column in revision_rubric_columns().iter().rev() {
+				manager
+					.alter_table(
+						Table::alter()
+							.table(table.clone())
+							.drop_column(Alias::new(revision_rubric_column.get_column_name()))
+							.to_owned()
+					)
+					.await?;
+			}
+		}
+		Ok(())
+	}
+}
+
+fn revision_tables() -> Vec<Alias> {
+	vec![
+		Alias::new("review_revision"),
+		Alias::new("archived_review_revision"),
+	]
+}
+
+fn revision_rubric_columns() -> Vec<ColumnDef> {
+	vec![
+		ColumnDef::new(ReviewRevision::RubricScores)
+			.json()
+			.null()
+			.to_owned(),
+		ColumnDef::new(ReviewRevision::SuggestedScore)
+			.enumeration(
+				Alias::new("score"),
+				[
+					Alias::new("no_rate"),
+					Alias::new("rated"),
+					Alias::new("one"),
+					Alias::new("two"),
+					Alias::new("three"),
+					Alias::new("four"),
+					Alias::new("five"),
+					Alias::new("six"),
+					Alias::new("seven"),
+					Alias::new("eight"),
+					Alias::new("nine"),
+					Alias::new("ten")
+				]
+			)
+			.null()
+			.to_owned(),
+		ColumnDef::new(ReviewRevision::SuggestedRating)
+			.enumeration(
+				Alias::new("rating"),
+				[
+					Alias::new("rate"),
+					Alias::new("feature"),
+					Alias::new("epic"),
+					Alias::new("legendary"),
+					Alias::new("mythic")
+				]
+			)
+			.null()
+			.to_owned(),
+	]
+}
+
+/// The rubric a revision was submitted with, shared by live and archived
+/// revisions.
+#[derive(DeriveIden)]
+enum ReviewRevision {
+	RubricScores,
+	SuggestedScore,
+	SuggestedRating
+}
@@ -0,0 +1,61 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.alter_table(
+				Table::alter()
+					.table(Moderator::Table)
+					.add_column(ColumnDef::new(Moderator::SentAt).timestamp().null())
+					.to_owned()
+			)
+			.await?;
+		manager
+			.alter_table(
+				Table::alter()
+					.table(ArchivedLevelRequest::Table)
+					.add_column(
+						ColumnDef::new(ArchivedLevelRequest::ModeratorSentAt)
+							.timestamp()
+							.null()
+					)
+					.to_owned()
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.alter_table(
+				Table::alter()
+					.table(ArchivedLevelRequest::Table)
+					.drop_column(ArchivedLevelRequest::ModeratorSentAt)
+					.to_owned()
+			)
+			.await?;
+		manager
+			.alter_table(
+				Table::alter()
+					.table(Moderator::Table)
+					.drop_column(Moderator::SentAt)
+					.to_owned()
+			)
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+enum Moderator {
+	Table,
+	SentAt
+}
+
+#[derive(DeriveIden)]
+enum ArchivedLevelRequest {
+	Table,
+	ModeratorSentAt
+}
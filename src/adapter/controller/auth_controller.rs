@@ -1,18 +1,211 @@
-use crate::domain::{
-	model::{
-		api::auth_api::{AuthApiError, AuthApiRequest, AuthApiResponse},
-		auth::claims::Claims
+use rocket_framework::State;
+
+use crate::{
+	domain::model::{
+		api::auth_api::{
+			Auth, AuthApiError, AuthApiRequest, AuthApiResponse, RefreshAuthApiRequest,
+			RefreshToken
+		},
+		auth::claims::TokenType
 	},
-	service::auth_service::AuthService
+	rocket::common::app_state::AppState
 };
 
 #[post("/auth")]
-pub fn generate_jwt(authenticating_user: AuthApiRequest) -> Result<AuthApiResponse, AuthApiError> {
-	let claims = Claims::new(authenticating_user.discord_app_id);
-	let auth_service = AuthService::new(claims);
+pub async fn generate_jwt(
+	app_state: &State<AppState>,
+	authenticating_user: AuthApiRequest
+) -> Result<AuthApiResponse, AuthApiError> {
+	let auth_service = app_state.auth_service();
+	let role_service = app_state.role_service();
+
+	match auth_service
+		.generate_jwt(
+			&role_service,
+			authenticating_user.discord_app_id,
+			authenticating_user.guild_id,
+			authenticating_user.discord_user_id
+		)
+		.await
+	{
+		Ok(token_pair) => Ok(AuthApiResponse::from(token_pair)),
+		Err(generate_jwt_error) => Err(generate_jwt_error.into())
+	}
+}
+
+#[post("/auth/refresh")]
+pub async fn refresh_jwt(
+	app_state: &State<AppState>,
+	refreshing_user: RefreshAuthApiRequest
+) -> Result<AuthApiResponse, AuthApiError> {
+	let auth_service = app_state.auth_service();
+	let role_service = app_state.role_service();
+
+	match auth_service
+		.refresh_jwt(
+			&role_service,
+			refreshing_user.discord_app_id,
+			&refreshing_user.refresh_token
+		)
+		.await
+	{
+		Ok(token_pair) => Ok(AuthApiResponse::from(token_pair)),
+		Err(refresh_jwt_error) => Err(refresh_jwt_error.into())
+	}
+}
+
+#[post("/auth/revoke")]
+pub async fn revoke_jwt(
+	app_state: &State<AppState>,
+	refresh_token: RefreshToken,
+	auth: Auth
+) -> Result<(), AuthApiError> {
+	let auth_service = app_state.auth_service();
+
+	if let RefreshToken(Some(refresh_token)) = refresh_token {
+		let refresh_claims = auth_service
+			.decode_jwt(&refresh_token, auth.discord_app_id, TokenType::Refresh)
+			.await
+			.map_err(Into::<AuthApiError>::into)?;
+		if refresh_claims.guild_id.ne(&auth.guild_id)
+			|| refresh_claims.sub.ne(&auth.discord_user_id)
+		{
+			return Err(AuthApiError::InvalidToken);
+		}
+
+		auth_service
+			.revoke_jwt(
+				refresh_claims.guild_id,
+				&refresh_claims.jti,
+				refresh_claims.expires_at()
+			)
+			.await
+			.map_err(Into::<AuthApiError>::into)?;
+	}
+
+	match auth_service
+		.revoke_jwt(auth.guild_id, &auth.jti, auth.expires_at)
+		.await
+	{
+		Ok(()) => Ok(()),
+		Err(revoke_jwt_error) => Err(revoke_jwt_error.into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rocket_framework::http::{Header, Status};
+
+	use crate::rocket::common::{
+		config::common_config::APP_CONFIG,
+		constants::{
+			ACCESS_TOKEN_HEADER_NAME, AUTHORIZATION_HEADER_NAME, DISCORD_USER_ID_HEADER_NAME,
+			GUILD_ID_HEADER_NAME, REFRESH_TOKEN_HEADER_NAME
+		},
+		testing::{authorized, discord_app_id_header, generate_jwt, test_client}
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_generate_jwt_should_return_access_and_refresh_tokens() {
+		let client = test_client().await;
+
+		let response = client
+			.post("/api/v1/auth")
+			.header(discord_app_id_header())
+			.header(access_token_header())
+			.header(Header::new(GUILD_ID_HEADER_NAME, "6001"))
+			.header(Header::new(DISCORD_USER_ID_HEADER_NAME, "12345"))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Created);
+		assert!(response
+			.headers()
+			.get_one(AUTHORIZATION_HEADER_NAME)
+			.is_some());
+		assert!(response
+			.headers()
+			.get_one(REFRESH_TOKEN_HEADER_NAME)
+			.is_some());
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_generate_jwt_should_reject_invalid_access_token() {
+		let client = test_client().await;
+
+		let response = client
+			.post("/api/v1/auth")
+			.header(discord_app_id_header())
+			.header(Header::new(ACCESS_TOKEN_HEADER_NAME, "wrongaccesstoken"))
+			.header(Header::new(GUILD_ID_HEADER_NAME, "6001"))
+			.header(Header::new(DISCORD_USER_ID_HEADER_NAME, "12345"))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Forbidden);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_generate_jwt_should_reject_missing_headers() {
+		let client = test_client().await;
+
+		let response = client
+			.post("/api/v1/auth")
+			.header(discord_app_id_header())
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Unauthorized);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_refresh_jwt_should_return_new_tokens() {
+		let client = test_client().await;
+		let response = client
+			.post("/api/v1/auth")
+			.header(discord_app_id_header())
+			.header(access_token_header())
+			.header(Header::new(GUILD_ID_HEADER_NAME, "6001"))
+			.header(Header::new(DISCORD_USER_ID_HEADER_NAME, "12345"))
+			.dispatch()
+			.await;
+		let refresh_token = response
+			.headers()
+			.get_one(REFRESH_TOKEN_HEADER_NAME)
+			.unwrap()
+			.to_string();
+
+		let response = client
+			.post("/api/v1/auth/refresh")
+			.header(discord_app_id_header())
+			.header(access_token_header())
+			.header(Header::new(REFRESH_TOKEN_HEADER_NAME, refresh_token))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Created);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_revoke_jwt_should_reject_revoked_token() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6001, 12345).await;
+
+		let response = authorized(client.post("/api/v1/auth/revoke"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+
+		let response = authorized(client.get("/api/v1/user/12345"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Unauthorized);
+	}
 
-	match auth_service.generate_jwt() {
-		Ok(jwt) => Ok(AuthApiResponse::new(jwt)),
-		Err(err) => Err(AuthApiError::from(err))
+	fn access_token_header() -> Header<'static> {
+		Header::new(
+			ACCESS_TOKEN_HEADER_NAME,
+			APP_CONFIG.auth_config.access_token.as_str()
+		)
 	}
 }
@@ -0,0 +1,56 @@
+use rocket_framework::{http::Status, Request};
+
+use crate::domain::model::api::{auth_api::AuthApiError, error_api::ErrorApiResponse};
+
+#[catch(400)]
+pub fn bad_request(request: &Request) -> ErrorApiResponse {
+	error_response(request, Status::BadRequest, "Request was malformed")
+}
+
+#[catch(401)]
+pub fn unauthorized(request: &Request) -> ErrorApiResponse {
+	error_response(request, Status::Unauthorized, "Authentication is required")
+}
+
+#[catch(403)]
+pub fn forbidden(request: &Request) -> ErrorApiResponse {
+	error_response(
+		request,
+		Status::Forbidden,
+		"User is not permitted to do this"
+	)
+}
+
+#[catch(404)]
+pub fn not_found(request: &Request) -> ErrorApiResponse {
+	error_response(request, Status::NotFound, "Resource does not exist")
+}
+
+#[catch(422)]
+pub fn unprocessable_entity(request: &Request) -> ErrorApiResponse {
+	error_response(
+		request,
+		Status::UnprocessableEntity,
+		"Request body could not be processed"
+	)
+}
+
+#[catch(500)]
+pub fn internal_server_error(request: &Request) -> ErrorApiResponse {
+	error_response(
+		request,
+		Status::InternalServerError,
+		"Internal server error"
+	)
+}
+
+/// Reports the auth guard error that failed the request, if any, otherwise a
+/// generic message for the status.
+fn error_response(request: &Request, status: Status, message: &str) -> ErrorApiResponse {
+	match request.local_cache(|| None::<AuthApiError>) {
+		Some(auth_api_error) if auth_api_error.status().eq(&status) => {
+			ErrorApiResponse::new(status, auth_api_error.to_string())
+		}
+		_ => ErrorApiResponse::new(status, message.to_string())
+	}
+}
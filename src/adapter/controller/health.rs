@@ -2,3 +2,19 @@ use crate::domain::model::api::level_request_api::LevelRequestApiResponseError;
 
 #[get("/health")]
 pub fn get_health() -> Result<(), LevelRequestApiResponseError> { Ok(()) }
+
+#[cfg(test)]
+mod tests {
+	use rocket_framework::http::Status;
+
+	use crate::rocket::common::testing::test_client;
+
+	#[rocket_framework::async_test]
+	async fn test_get_health_should_not_require_auth() {
+		let client = test_client().await;
+
+		let response = client.get("/api/v1/health").dispatch().await;
+
+		assert_eq!(response.status(), Status::Ok);
+	}
+}
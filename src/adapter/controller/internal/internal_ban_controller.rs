@@ -0,0 +1,212 @@
+use rocket_framework::{serde::json::Json, State};
+
+use crate::{
+	domain::{
+		model::{
+			api::auth_api::ModeratorAuth,
+			internal::api::internal_ban_api::{
+				BanApiResponseError, CreateBanApiRequest, GetBanApiResponse, GetBansApiResponse
+			}
+		},
+		service::ban_service::BanService
+	},
+	rocket::common::app_state::AppState
+};
+
+#[post("/ban", format = "json", data = "<create_ban_body>")]
+pub async fn create_ban(
+	app_state: &State<AppState>,
+	create_ban_body: Json<CreateBanApiRequest>,
+	auth: ModeratorAuth
+) -> Result<GetBanApiResponse, BanApiResponseError> {
+	let ban_service = app_state.ban_service();
+
+	match ban_service
+		.ban(
+			auth.guild_id,
+			create_ban_body.target(),
+			create_ban_body.reason.clone(),
+			auth.discord_user_id,
+			create_ban_body.expires_at
+		)
+		.await
+	{
+		Ok(ban) => Ok(GetBanApiResponse::from(ban)),
+		Err(create_ban_error) => Err(create_ban_error.into())
+	}
+}
+
+#[get("/bans?<include_inactive>")]
+pub async fn get_bans(
+	app_state: &State<AppState>,
+	include_inactive: Option<bool>,
+	auth: ModeratorAuth
+) -> Result<GetBansApiResponse, BanApiResponseError> {
+	let ban_service = app_state.ban_service();
+
+	match ban_service
+		.get_bans(auth.guild_id, include_inactive.unwrap_or(false))
+		.await
+	{
+		Ok(bans) => Ok(GetBansApiResponse::from(bans)),
+		Err(get_bans_error) => Err(get_bans_error.into())
+	}
+}
+
+#[delete("/ban/<ban_id>")]
+pub async fn lift_ban(
+	app_state: &State<AppState>,
+	ban_id: u64,
+	auth: ModeratorAuth
+) -> Result<GetBanApiResponse, BanApiResponseError> {
+	let ban_service = app_state.ban_service();
+
+	match ban_service
+		.lift_ban(auth.guild_id, ban_id, auth.discord_user_id)
+		.await
+	{
+		Ok(ban) => Ok(GetBanApiResponse::from(ban)),
+		Err(lift_ban_error) => Err(lift_ban_error.into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rocket_framework::{
+		http::Status,
+		serde::json::{json, Value}
+	};
+
+	use crate::rocket::common::testing::{
+		authorized, create_level_request, generate_jwt, test_client, ADMIN_DISCORD_ID,
+		YOUTUBE_VIDEO_LINK
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_create_ban_should_block_user_until_lifted() {
+		let client = test_client().await;
+		let admin_jwt = generate_jwt(&client, 9001, ADMIN_DISCORD_ID).await;
+		let jwt = generate_jwt(&client, 9001, 12345).await;
+
+		let response = authorized(client.post("/api/v1/internal/ban"), &admin_jwt)
+			.json(&json!({
+				"target_type": "User",
+				"target_id": 12345,
+				"reason": "Spamming requests"
+			}))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["issued_by"], ADMIN_DISCORD_ID);
+		let ban_id = body["ban_id"].as_u64().unwrap();
+
+		let response = authorized(client.post("/api/v1/request_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"youtube_video_link": YOUTUBE_VIDEO_LINK,
+				"discord_id": 12345,
+				"request_rating": "Two",
+				"has_requested_feedback": false,
+				"notify": false
+			}))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Forbidden);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["banned"], "User");
+		assert_eq!(body["reason"], "Spamming requests");
+
+		let response = authorized(
+			client.delete(format!("/api/v1/internal/ban/{}", ban_id)),
+			&admin_jwt
+		)
+		.dispatch()
+		.await;
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["lifted_by"], ADMIN_DISCORD_ID);
+
+		create_level_request(&client, &jwt, 99999999, 12345).await;
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_create_ban_should_block_level() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 9002, ADMIN_DISCORD_ID).await;
+
+		let response = authorized(client.post("/api/v1/internal/ban"), &jwt)
+			.json(&json!({ "target_type": "Level", "target_id": 99999999 }))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+
+		let response = authorized(client.post("/api/v1/request_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"youtube_video_link": YOUTUBE_VIDEO_LINK,
+				"discord_id": ADMIN_DISCORD_ID,
+				"request_rating": "Two",
+				"has_requested_feedback": false,
+				"notify": false
+			}))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Forbidden);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["banned"], "Level");
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_bans_should_only_list_active_bans_by_default() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 9003, ADMIN_DISCORD_ID).await;
+		for target_id in [12345, 54321] {
+			let response = authorized(client.post("/api/v1/internal/ban"), &jwt)
+				.json(&json!({ "target_type": "User", "target_id": target_id }))
+				.dispatch()
+				.await;
+			assert_eq!(response.status(), Status::Ok);
+		}
+		let response = authorized(client.get("/api/v1/internal/bans"), &jwt)
+			.dispatch()
+			.await;
+		let body = response.into_json::<Value>().await.unwrap();
+		let ban_id = body["bans"][0]["ban_id"].as_u64().unwrap();
+		authorized(
+			client.delete(format!("/api/v1/internal/ban/{}", ban_id)),
+			&jwt
+		)
+		.dispatch()
+		.await;
+
+		let response = authorized(client.get("/api/v1/internal/bans"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["bans"].as_array().unwrap().len(), 1);
+
+		let response = authorized(
+			client.get("/api/v1/internal/bans?include_inactive=true"),
+			&jwt
+		)
+		.dispatch()
+		.await;
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["bans"].as_array().unwrap().len(), 2);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_create_ban_should_require_moderator_role() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 9004, 12345).await;
+
+		let response = authorized(client.post("/api/v1/internal/ban"), &jwt)
+			.json(&json!({ "target_type": "User", "target_id": 54321 }))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Forbidden);
+	}
+}
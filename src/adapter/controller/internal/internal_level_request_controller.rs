@@ -1,24 +1,21 @@
 use rocket_framework::{serde::json::Json, State};
-use sea_orm::DatabaseConnection;
 
 use crate::{
-	adapter::{
-		geometry_dash::geometry_dash_dashrs_client::GeometryDashDashrsClient,
-		mysql::{
-			mysql_level_request_repository::MySqlLevelRequestRepository,
-			mysql_user_repository::MySqlUserRepository
-		}
-	},
 	domain::{
 		model::{
-			api::{auth_api::Auth, level_request_api::LevelRequestApiResponseError},
+			api::{
+				auth_api::{ModeratorAuth, RequesterAuth},
+				level_request_api::{GetLevelRequestApiResponse, LevelRequestApiResponseError}
+			},
 			internal::api::internal_level_request_api::{
 				InternalUpdateLevelRequestDiscordDataApiResponse,
-				InternalUpdateLevelRequestMessageIdApiRequest
+				InternalUpdateLevelRequestMessageIdApiRequest,
+				InternalUpdateLevelRequestStatusApiRequest
 			}
 		},
-		service::{level_request_service::LevelRequestService, request_service::RequestService}
-	}
+		service::request_service::RequestService
+	},
+	rocket::common::app_state::AppState
 };
 
 #[patch(
@@ -28,18 +25,15 @@ use crate::{
 	rank = 1
 )]
 pub async fn update_level_request_message_id<'a>(
-	db_conn: &State<DatabaseConnection>,
+	app_state: &State<AppState>,
 	update_level_request_message_id_body: Json<InternalUpdateLevelRequestMessageIdApiRequest>,
-	_auth: Auth
+	auth: RequesterAuth
 ) -> Result<InternalUpdateLevelRequestDiscordDataApiResponse, LevelRequestApiResponseError> {
-	let level_request_repository = MySqlLevelRequestRepository::new(db_conn);
-	let user_repository = MySqlUserRepository::new(db_conn);
-	let gd_client = GeometryDashDashrsClient::new();
-	let level_request_service =
-		LevelRequestService::new(&level_request_repository, &user_repository, &gd_client);
+	let level_request_service = app_state.level_request_service();
 
 	match level_request_service
 		.update_level_request_message_id(
+			auth.guild_id,
 			update_level_request_message_id_body.level_id,
 			update_level_request_message_id_body.discord_message_id
 		)
@@ -49,3 +43,113 @@ pub async fn update_level_request_message_id<'a>(
 		Err(update_level_request_error) => Err(update_level_request_error.into())
 	}
 }
+
+#[patch(
+	"/request_level/<level_id>/status",
+	format = "json",
+	data = "<update_level_request_status_body>"
+)]
+pub async fn update_level_request_status<'a>(
+	app_state: &State<AppState>,
+	level_id: u64,
+	update_level_request_status_body: Json<InternalUpdateLevelRequestStatusApiRequest>,
+	auth: ModeratorAuth
+) -> Result<GetLevelRequestApiResponse, LevelRequestApiResponseError> {
+	let level_request_service = app_state.level_request_service();
+
+	match level_request_service
+		.update_level_request_status(
+			auth.guild_id,
+			level_id,
+			update_level_request_status_body.status.into()
+		)
+		.await
+	{
+		Ok(level_request) => Ok(GetLevelRequestApiResponse::from(level_request)),
+		Err(update_level_request_error) => Err(update_level_request_error.into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rocket_framework::{
+		http::Status,
+		serde::json::{json, Value}
+	};
+
+	use crate::rocket::common::testing::{
+		authorized, create_level_request, generate_jwt, test_client, ADMIN_DISCORD_ID
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_update_level_request_message_id_should_save_message_id() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6601, 12345).await;
+		create_level_request(&client, &jwt, 99999999, 12345).await;
+
+		let response = authorized(client.patch("/api/v1/internal/request_level"), &jwt)
+			.json(&json!({ "level_id": 99999999, "discord_message_id": 11111 }))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+
+		let response = authorized(client.get("/api/v1/request_level/99999999"), &jwt)
+			.dispatch()
+			.await;
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["discord_message_id"], 11111);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_update_level_request_status_should_move_request_forward() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6602, ADMIN_DISCORD_ID).await;
+		create_level_request(&client, &jwt, 99999999, 12345).await;
+
+		let response = authorized(
+			client.patch("/api/v1/internal/request_level/99999999/status"),
+			&jwt
+		)
+		.json(&json!({ "status": "InReview" }))
+		.dispatch()
+		.await;
+
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["status"], "InReview");
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_update_level_request_status_should_reject_illegal_transition() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6603, ADMIN_DISCORD_ID).await;
+		create_level_request(&client, &jwt, 99999999, 12345).await;
+
+		let response = authorized(
+			client.patch("/api/v1/internal/request_level/99999999/status"),
+			&jwt
+		)
+		.json(&json!({ "status": "Sent" }))
+		.dispatch()
+		.await;
+
+		assert_eq!(response.status(), Status::Conflict);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_update_level_request_status_should_require_moderator_role() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6604, 12345).await;
+		create_level_request(&client, &jwt, 99999999, 12345).await;
+
+		let response = authorized(
+			client.patch("/api/v1/internal/request_level/99999999/status"),
+			&jwt
+		)
+		.json(&json!({ "status": "Rejected" }))
+		.dispatch()
+		.await;
+
+		assert_eq!(response.status(), Status::Forbidden);
+	}
+}
@@ -1,28 +1,17 @@
 use rocket_framework::{serde::json::Json, State};
-use sea_orm::DatabaseConnection;
 
 use crate::{
-	adapter::{
-		geometry_dash::geometry_dash_dashrs_client::GeometryDashDashrsClient,
-		mysql::{
-			mysql_level_request_repository::MySqlLevelRequestRepository,
-			mysql_review_repository::MySqlReviewRepository,
-			mysql_user_repository::MySqlUserRepository
-		}
-	},
 	domain::{
 		model::{
-			api::{auth_api::Auth, level_review_api::LevelReviewApiResponseError},
+			api::{auth_api::ReviewerAuth, level_review_api::LevelReviewApiResponseError},
 			internal::api::internal_level_review_api::{
 				InternalUpdateLevelReviewDiscordDataApiResponse,
 				InternalUpdateLevelReviewMessageIdApiRequest
 			}
 		},
-		service::{
-			level_request_service::LevelRequestService, level_review_service::LevelReviewService,
-			review_service::ReviewService
-		}
-	}
+		service::review_service::ReviewService
+	},
+	rocket::common::app_state::AppState
 };
 
 #[patch(
@@ -32,21 +21,14 @@ use crate::{
 	rank = 1
 )]
 pub async fn update_level_review_message_id<'a>(
-	db_conn: &State<DatabaseConnection>,
+	app_state: &State<AppState>,
 	update_level_review_message_id_body: Json<InternalUpdateLevelReviewMessageIdApiRequest>,
-	_auth: Auth
+	auth: ReviewerAuth
 ) -> Result<InternalUpdateLevelReviewDiscordDataApiResponse, LevelReviewApiResponseError> {
-	let level_review_repository = MySqlReviewRepository::new(db_conn);
-	let level_request_repository = MySqlLevelRequestRepository::new(db_conn);
-	let user_repository = MySqlUserRepository::new(db_conn);
-	let gd_client = GeometryDashDashrsClient::new();
-	let level_request_service =
-		LevelRequestService::new(&level_request_repository, &user_repository, &gd_client);
-
-	let level_review_service =
-		LevelReviewService::new(&level_review_repository, &level_request_service);
+	let level_review_service = app_state.level_review_service();
 	match level_review_service
 		.update_level_request_thread_id(
+			auth.guild_id,
 			update_level_review_message_id_body.level_id,
 			update_level_review_message_id_body.discord_id,
 			update_level_review_message_id_body.discord_message_id
@@ -57,3 +39,71 @@ pub async fn update_level_review_message_id<'a>(
 		Err(update_level_review_error) => Err(update_level_review_error.into())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use rocket_framework::{
+		http::Status,
+		serde::json::{json, Value}
+	};
+
+	use crate::rocket::common::testing::{
+		authorized, create_level_request, create_reviewer, generate_jwt, test_client
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_update_level_review_message_id_should_save_message_id() {
+		let client = test_client().await;
+		let requester_jwt = generate_jwt(&client, 6701, 54321).await;
+		create_level_request(&client, &requester_jwt, 99999999, 54321).await;
+		create_reviewer(&client, 6701, 12345).await;
+		let jwt = generate_jwt(&client, 6701, 12345).await;
+		let response = authorized(client.post("/api/v1/review_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"reviewer_discord_id": 12345,
+				"discord_message_id": 11111,
+				"review_contents": "Great level"
+			}))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Created);
+
+		let response = authorized(client.patch("/api/v1/internal/review_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"discord_id": 12345,
+				"discord_message_id": 22222
+			}))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+
+		let response = authorized(
+			client.get("/api/v1/review_level/99999999?discord_id=12345"),
+			&jwt
+		)
+		.dispatch()
+		.await;
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["discord_message_id"], 22222);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_update_level_review_message_id_should_return_not_found() {
+		let client = test_client().await;
+		create_reviewer(&client, 6702, 12345).await;
+		let jwt = generate_jwt(&client, 6702, 12345).await;
+
+		let response = authorized(client.patch("/api/v1/internal/review_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"discord_id": 12345,
+				"discord_message_id": 22222
+			}))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::NotFound);
+	}
+}
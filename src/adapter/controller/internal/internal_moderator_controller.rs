@@ -1,37 +1,27 @@
 use rocket_framework::{serde::json::Json, State};
-use sea_orm::DatabaseConnection;
 
 use crate::{
-	adapter::{
-		geometry_dash::geometry_dash_dashrs_client::GeometryDashDashrsClient,
-		mysql::{
-			my_sql_moderator_repository::MySqlModeratorRepository,
-			mysql_level_request_repository::MySqlLevelRequestRepository
-		}
-	},
 	domain::{
 		model::{
-			api::{auth_api::Auth, level_request_api::GetLevelRequestApiResponse},
+			api::{auth_api::ModeratorAuth, level_request_api::GetLevelRequestApiResponse},
 			internal::api::moderator_api::{ModeratorApiResponseError, PostModeratorApiRequest}
 		},
-		service::{moderate_service::ModerateService, moderator_service::ModeratorService}
-	}
+		service::moderate_service::ModerateService
+	},
+	rocket::common::app_state::AppState
 };
 
 #[post("/send_level", format = "json", data = "<send_level_body>")]
 pub async fn send_level<'a>(
-	db_conn: &State<DatabaseConnection>,
+	app_state: &State<AppState>,
 	send_level_body: Json<PostModeratorApiRequest>,
-	_auth: Auth
+	auth: ModeratorAuth
 ) -> Result<GetLevelRequestApiResponse, ModeratorApiResponseError> {
-	let level_request_repository = MySqlLevelRequestRepository::new(db_conn);
-	let moderator_repository = MySqlModeratorRepository::new(db_conn);
-	let gd_client = GeometryDashDashrsClient::new();
-	let moderator_service =
-		ModeratorService::new(&moderator_repository, &level_request_repository, &gd_client);
+	let moderator_service = app_state.moderator_service();
 
 	match moderator_service
 		.send_level(
+			auth.guild_id,
 			send_level_body.level_id,
 			send_level_body.suggested_rating.into(),
 			send_level_body.suggested_score.into()
@@ -42,3 +32,93 @@ pub async fn send_level<'a>(
 		Err(send_level_error) => Err(send_level_error.into())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use rocket_framework::{
+		http::Status,
+		serde::json::{json, Value}
+	};
+
+	use crate::rocket::common::testing::{
+		authorized, create_level_request, generate_jwt, test_client, use_fake_geometry_dash_server,
+		ADMIN_DISCORD_ID
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_send_level_should_mark_request_sent() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6801, ADMIN_DISCORD_ID).await;
+		create_level_request(&client, &jwt, 99999999, 12345).await;
+
+		let response = authorized(client.post("/api/v1/internal/send_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"suggested_score": "Four",
+				"suggested_rating": "Rate"
+			}))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["status"], "Sent");
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_send_level_should_suggest_level_to_geometry_dash() {
+		let client = test_client().await;
+		use_fake_geometry_dash_server(&client, 6804).await;
+		let jwt = generate_jwt(&client, 6804, ADMIN_DISCORD_ID).await;
+		create_level_request(&client, &jwt, 1000001, 12345).await;
+
+		let response = authorized(client.post("/api/v1/internal/send_level"), &jwt)
+			.json(&json!({
+				"level_id": 1000001,
+				"suggested_score": "Four",
+				"suggested_rating": "Feature"
+			}))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["status"], "Sent");
+		assert_eq!(body["level_name"], "Fixture Level");
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_send_level_should_return_not_found() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6802, ADMIN_DISCORD_ID).await;
+
+		let response = authorized(client.post("/api/v1/internal/send_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"suggested_score": "Four",
+				"suggested_rating": "Rate"
+			}))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::NotFound);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_send_level_should_require_moderator_role() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6803, 12345).await;
+		create_level_request(&client, &jwt, 99999999, 12345).await;
+
+		let response = authorized(client.post("/api/v1/internal/send_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"suggested_score": "Four",
+				"suggested_rating": "Rate"
+			}))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Forbidden);
+	}
+}
@@ -1,37 +1,139 @@
-use rocket_framework::{futures::io, serde::json::Json};
+use rocket_framework::{serde::json::Json, State};
 
-use crate::domain::{
-	model::{
-		api::auth_api::Auth,
+use crate::{
+	domain::model::{
+		api::auth_api::{AdminAuth, RequesterAuth},
 		internal::api::internal_request_manager_api::{
-			InternalUpdateRequestConfigApiRequest, InternalUpdateRequestConfigApiResponse
+			InternalGetRequestConfigApiResponse, InternalUpdateRequestConfigApiRequest,
+			InternalUpdateRequestConfigApiResponse, RequestConfigApiResponseError
 		}
 	},
-	service::internal::request_manager_service::RequestManagerService
+	rocket::common::app_state::AppState
 };
 
+#[get("/request_config")]
+pub async fn get_request_config(
+	app_state: &State<AppState>,
+	auth: RequesterAuth
+) -> InternalGetRequestConfigApiResponse {
+	InternalGetRequestConfigApiResponse::from(
+		app_state
+			.request_config_service()
+			.get_request_config(auth.guild_id)
+	)
+}
+
 #[patch(
 	"/request_config",
 	format = "json",
 	data = "<update_request_config_body>"
 )]
 pub async fn update_request_cooldown<'a>(
+	app_state: &State<AppState>,
 	update_request_config_body: Json<InternalUpdateRequestConfigApiRequest>,
-	_auth: Auth
-) -> io::Result<InternalUpdateRequestConfigApiResponse> {
-	let request_manager_service = RequestManagerService {};
+	auth: AdminAuth
+) -> Result<InternalUpdateRequestConfigApiResponse, RequestConfigApiResponseError> {
+	let request_config_service = app_state.request_config_service();
 
-	if let Some(duration_in_minutes) = update_request_config_body.duration_in_minutes {
-		request_manager_service.set_request_cooldown(duration_in_minutes);
+	match request_config_service
+		.update_request_config(
+			auth.guild_id,
+			update_request_config_body.into_inner().into()
+		)
+		.await
+	{
+		Ok(_) => Ok(InternalUpdateRequestConfigApiResponse {}),
+		Err(update_request_config_error) => Err(update_request_config_error.into())
 	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rocket_framework::{
+		http::Status,
+		serde::json::{json, Value}
+	};
+
+	use crate::rocket::common::{
+		config::client_config::CLIENT_CONFIG,
+		testing::{authorized, generate_jwt, test_client, ADMIN_DISCORD_ID, YOUTUBE_VIDEO_LINK}
+	};
 
-	if let Some(enable_requests) = update_request_config_body.enable_requests {
-		request_manager_service.set_enable_request(enable_requests)
+	#[rocket_framework::async_test]
+	async fn test_get_request_config_should_default_to_client_config() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6901, 12345).await;
+
+		let response = authorized(client.get("/api/v1/internal/request_config"), &jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["duration"], CLIENT_CONFIG.cooldown_duration);
+		assert_eq!(body["enable_requests"], CLIENT_CONFIG.enable_requests);
 	}
 
-	if let Some(enable_gd_requests) = update_request_config_body.enable_gd_requests {
-		request_manager_service.set_enable_gd_request(enable_gd_requests)
+	#[rocket_framework::async_test]
+	async fn test_update_request_config_should_disable_requests() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6902, ADMIN_DISCORD_ID).await;
+
+		let response = authorized(client.patch("/api/v1/internal/request_config"), &jwt)
+			.json(&json!({ "duration": 30, "enable_requests": false }))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+
+		let response = authorized(client.get("/api/v1/internal/request_config"), &jwt)
+			.dispatch()
+			.await;
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["duration"], 30);
+		assert_eq!(body["enable_requests"], false);
+
+		let response = authorized(client.post("/api/v1/request_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"youtube_video_link": YOUTUBE_VIDEO_LINK,
+				"discord_id": ADMIN_DISCORD_ID,
+				"request_rating": "Two",
+				"has_requested_feedback": false,
+				"notify": false
+			}))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::ServiceUnavailable);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_update_request_config_should_reject_out_of_range_duration() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6904, ADMIN_DISCORD_ID).await;
+
+		let response = authorized(client.patch("/api/v1/internal/request_config"), &jwt)
+			.json(&json!({ "duration": i32::MAX as u64 + 1 }))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::BadRequest);
+
+		let response = authorized(client.get("/api/v1/internal/request_config"), &jwt)
+			.dispatch()
+			.await;
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["duration"], CLIENT_CONFIG.cooldown_duration);
 	}
 
-	Ok(InternalUpdateRequestConfigApiResponse {})
+	#[rocket_framework::async_test]
+	async fn test_update_request_config_should_require_admin_role() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6903, 12345).await;
+
+		let response = authorized(client.patch("/api/v1/internal/request_config"), &jwt)
+			.json(&json!({ "enable_requests": false }))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Forbidden);
+	}
 }
@@ -1,3 +1,4 @@
+pub mod internal_ban_controller;
 pub mod internal_level_request_controller;
 pub mod internal_level_review_controller;
 pub mod internal_moderator_controller;
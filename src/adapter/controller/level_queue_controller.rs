@@ -0,0 +1,184 @@
+use rocket_framework::State;
+
+use crate::{
+	domain::{
+		model::api::{
+			auth_api::{RequesterAuth, ReviewerAuth},
+			level_queue_api::LevelQueueApiResponseError,
+			level_request_api::GetLevelRequestApiResponse
+		},
+		service::queue_service::QueueService
+	},
+	rocket::common::app_state::AppState
+};
+
+#[get("/queue/next")]
+pub async fn get_next_level_request(
+	app_state: &State<AppState>,
+	auth: RequesterAuth
+) -> Result<GetLevelRequestApiResponse, LevelQueueApiResponseError> {
+	let level_queue_service = app_state.level_queue_service();
+
+	match level_queue_service
+		.get_next_level_request(auth.guild_id)
+		.await
+	{
+		Ok(queued_level_request) => Ok(GetLevelRequestApiResponse::from(queued_level_request)),
+		Err(get_next_level_request_error) => Err(get_next_level_request_error.into())
+	}
+}
+
+#[post("/queue/<level_id>/skip")]
+pub async fn skip_level_request(
+	app_state: &State<AppState>,
+	level_id: u64,
+	auth: ReviewerAuth
+) -> Result<GetLevelRequestApiResponse, LevelQueueApiResponseError> {
+	let level_queue_service = app_state.level_queue_service();
+
+	match level_queue_service
+		.skip_level_request(auth.guild_id, level_id)
+		.await
+	{
+		Ok(queued_level_request) => Ok(GetLevelRequestApiResponse::from(queued_level_request)),
+		Err(skip_level_request_error) => Err(skip_level_request_error.into())
+	}
+}
+
+#[post("/queue/<level_id>/requeue")]
+pub async fn requeue_level_request(
+	app_state: &State<AppState>,
+	level_id: u64,
+	auth: ReviewerAuth
+) -> Result<GetLevelRequestApiResponse, LevelQueueApiResponseError> {
+	let level_queue_service = app_state.level_queue_service();
+
+	match level_queue_service
+		.requeue_level_request(auth.guild_id, level_id)
+		.await
+	{
+		Ok(queued_level_request) => Ok(GetLevelRequestApiResponse::from(queued_level_request)),
+		Err(requeue_level_request_error) => Err(requeue_level_request_error.into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rocket_framework::{
+		http::Status,
+		serde::json::{json, Value}
+	};
+
+	use crate::rocket::common::testing::{
+		authorized, create_level_request, create_reviewer, generate_jwt, test_client,
+		ADMIN_DISCORD_ID, YOUTUBE_VIDEO_LINK
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_get_next_level_request_should_return_oldest_request() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 7101, 12345).await;
+		create_level_request(&client, &jwt, 99999998, 12345).await;
+		create_level_request(&client, &jwt, 99999999, 12345).await;
+
+		let response = authorized(client.get("/api/v1/queue/next"), &jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["level_id"], 99999998);
+		assert_eq!(body["queue_position"], 1);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_next_level_request_should_return_not_found_when_queue_is_empty() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 7102, 12345).await;
+
+		let response = authorized(client.get("/api/v1/queue/next"), &jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::NotFound);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_skip_then_requeue_level_request_should_move_request() {
+		let client = test_client().await;
+		let requester_jwt = generate_jwt(&client, 7103, 54321).await;
+		create_level_request(&client, &requester_jwt, 99999998, 54321).await;
+		create_level_request(&client, &requester_jwt, 99999999, 54321).await;
+		create_reviewer(&client, 7103, 12345).await;
+		let jwt = generate_jwt(&client, 7103, 12345).await;
+
+		let response = authorized(client.post("/api/v1/queue/99999998/skip"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["queue_position"], 2);
+
+		let response = authorized(client.get("/api/v1/queue/next"), &jwt)
+			.dispatch()
+			.await;
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["level_id"], 99999999);
+
+		let response = authorized(client.post("/api/v1/queue/99999998/requeue"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["queue_position"], 1);
+
+		let response = authorized(client.post("/api/v1/queue/99999998/requeue"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Conflict);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_skip_level_request_should_require_reviewer_role() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 7104, 12345).await;
+		create_level_request(&client, &jwt, 99999999, 12345).await;
+
+		let response = authorized(client.post("/api/v1/queue/99999999/skip"), &jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Forbidden);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_next_level_request_should_follow_guild_queue_policy() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 7105, ADMIN_DISCORD_ID).await;
+		create_level_request(&client, &jwt, 99999998, ADMIN_DISCORD_ID).await;
+		let response = authorized(client.post("/api/v1/request_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"youtube_video_link": YOUTUBE_VIDEO_LINK,
+				"discord_id": ADMIN_DISCORD_ID,
+				"request_rating": "Ten",
+				"has_requested_feedback": false,
+				"notify": false
+			}))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Created);
+
+		let response = authorized(client.patch("/api/v1/internal/request_config"), &jwt)
+			.json(&json!({ "queue_policy": "RequestRating" }))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+
+		let response = authorized(client.get("/api/v1/queue/next"), &jwt)
+			.dispatch()
+			.await;
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["level_id"], 99999999);
+	}
+}
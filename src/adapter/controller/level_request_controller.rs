@@ -1,70 +1,95 @@
+use std::collections::HashMap;
+
 use rocket_framework::{serde::json::Json, State};
-use sea_orm::DatabaseConnection;
 
 use crate::{
-	adapter::{
-		geometry_dash::geometry_dash_dashrs_client::GeometryDashDashrsClient,
-		mysql::{
-			mysql_level_request_repository::MySqlLevelRequestRepository,
-			mysql_user_repository::MySqlUserRepository
-		}
-	},
 	domain::{
-		model::api::{
-			auth_api::Auth,
-			level_request_api::{
-				GetLevelRequestApiResponse, LevelRequestApiResponseError,
-				PatchLevelRequestApiRequest, PostLevelRequestApiRequest,
-				PostLevelRequestApiResponse
-			}
+		model::{
+			api::{
+				auth_api::RequesterAuth,
+				level_request_api::{
+					GetLevelRequestApiResponse, GetLevelRequestsApiRequest,
+					GetLevelRequestsApiResponse, LevelRequestApiResponseError,
+					PatchLevelRequestApiRequest, PostLevelRequestApiRequest,
+					PostLevelRequestApiResponse
+				}
+			},
+			auth::role::Role,
+			level_request_query::LevelRequestFilter
 		},
-		service::{level_request_service::LevelRequestService, request_service::RequestService}
-	}
+		service::{queue_service::QueueService, request_service::RequestService}
+	},
+	rocket::common::app_state::AppState
 };
 
 #[get("/request_level/<level_id>")]
 pub async fn get_level_request(
-	db_conn: &State<DatabaseConnection>,
+	app_state: &State<AppState>,
 	level_id: u64,
-	_auth: Auth
+	auth: RequesterAuth
 ) -> Result<GetLevelRequestApiResponse, LevelRequestApiResponseError> {
-	let level_request_repository = MySqlLevelRequestRepository::new(db_conn);
-	let user_repository = MySqlUserRepository::new(db_conn);
-	let gd_client = GeometryDashDashrsClient::new();
-
-	let level_request_service =
-		LevelRequestService::new(&level_request_repository, &user_repository, &gd_client);
+	let level_request_service = app_state.level_request_service();
 
 	match level_request_service
-		.get_level_request(level_id, None)
+		.get_level_request(auth.guild_id, level_id, None)
 		.await
 	{
-		Ok(level_request_info) => Ok(GetLevelRequestApiResponse::from(level_request_info)),
+		Ok(level_request_info) => {
+			let queue_positions = get_queue_positions(app_state, auth.guild_id).await?;
+			Ok(GetLevelRequestApiResponse::from(level_request_info)
+				.with_queue_position(queue_positions.get(&level_id).copied()))
+		}
 		Err(get_level_request_error) => Err(get_level_request_error.into())
 	}
 }
 
+#[get("/request_level?<query..>")]
+pub async fn get_level_requests(
+	app_state: &State<AppState>,
+	query: GetLevelRequestsApiRequest,
+	auth: RequesterAuth
+) -> Result<GetLevelRequestsApiResponse, LevelRequestApiResponseError> {
+	let level_request_service = app_state.level_request_service();
+	let filter = LevelRequestFilter::try_from(&query)?;
+
+	match level_request_service
+		.get_level_requests(
+			auth.guild_id,
+			filter,
+			query.sort_order(),
+			query.page(),
+			query.page_size()
+		)
+		.await
+	{
+		Ok(level_request_page) => {
+			let queue_positions = get_queue_positions(app_state, auth.guild_id).await?;
+			Ok(GetLevelRequestsApiResponse::from(level_request_page)
+				.with_queue_positions(&queue_positions))
+		}
+		Err(get_level_requests_error) => Err(get_level_requests_error.into())
+	}
+}
+
 #[post("/request_level", format = "json", data = "<level_request_body>")]
 pub async fn request_level<'a>(
-	db_conn: &State<DatabaseConnection>,
+	app_state: &State<AppState>,
 	level_request_body: Json<PostLevelRequestApiRequest<'a>>,
-	_auth: Auth
+	auth: RequesterAuth
 ) -> Result<PostLevelRequestApiResponse, LevelRequestApiResponseError> {
-	let level_request_repository = MySqlLevelRequestRepository::new(db_conn);
-	let user_repository = MySqlUserRepository::new(db_conn);
-	let gd_client = GeometryDashDashrsClient::new();
+	if !auth.is_acting_as(level_request_body.discord_id) {
+		return Err(LevelRequestApiResponseError::ActingAsAnotherUser);
+	}
 
-	let level_request_service =
-		LevelRequestService::new(&level_request_repository, &user_repository, &gd_client);
-	let request_rating = level_request_body.request_rating.into();
+	let level_request_service = app_state.level_request_service();
+	let level_id = level_request_body.level_id;
+	let discord_id = level_request_body.discord_id;
 	match level_request_service
 		.make_level_request(
-			level_request_body.level_id,
-			level_request_body.youtube_video_link.to_string(),
-			level_request_body.discord_id,
-			request_rating,
-			level_request_body.has_requested_feedback,
-			level_request_body.notify
+			auth.guild_id,
+			level_id,
+			discord_id,
+			level_request_body.into_inner().into()
 		)
 		.await
 	{
@@ -79,50 +104,383 @@ pub async fn request_level<'a>(
 	data = "<update_level_request_body>"
 )]
 pub async fn update_level_request<'a>(
-	db_conn: &State<DatabaseConnection>,
+	app_state: &State<AppState>,
 	update_level_request_body: Json<PatchLevelRequestApiRequest<'a>>,
-	_auth: Auth
+	auth: RequesterAuth
 ) -> Result<GetLevelRequestApiResponse, LevelRequestApiResponseError> {
-	let level_request_repository = MySqlLevelRequestRepository::new(db_conn);
-	let user_repository = MySqlUserRepository::new(db_conn);
-	let gd_client = GeometryDashDashrsClient::new();
+	if !auth.is_acting_as(update_level_request_body.discord_id) {
+		return Err(LevelRequestApiResponseError::ActingAsAnotherUser);
+	}
 
-	let level_request_service =
-		LevelRequestService::new(&level_request_repository, &user_repository, &gd_client);
+	let level_request_service = app_state.level_request_service();
+	let level_id = update_level_request_body.level_id;
+	let discord_id = update_level_request_body.discord_id;
 
 	match level_request_service
 		.update_level_request(
-			update_level_request_body.level_id,
-			update_level_request_body.discord_id,
-			update_level_request_body
-				.youtube_video_link
-				.map(|s| s.to_string()),
-			update_level_request_body.request_rating.map(|r| r.into()),
-			update_level_request_body.has_requested_feedback,
-			update_level_request_body.notify
+			auth.guild_id,
+			level_id,
+			discord_id,
+			auth.has_role(Role::Admin),
+			update_level_request_body.into_inner().into()
 		)
 		.await
 	{
-		Ok(level_request_info) => Ok(GetLevelRequestApiResponse::from(level_request_info)),
+		Ok(level_request_info) => {
+			let queue_positions = get_queue_positions(app_state, auth.guild_id).await?;
+			Ok(GetLevelRequestApiResponse::from(level_request_info)
+				.with_queue_position(queue_positions.get(&level_id).copied()))
+		}
 		Err(level_request_error) => Err(level_request_error.into())
 	}
 }
 
 #[delete("/request_level/<level_id>")]
 pub async fn delete_level_request<'a>(
-	db_conn: &State<DatabaseConnection>,
+	app_state: &State<AppState>,
 	level_id: u64,
-	_auth: Auth
+	auth: RequesterAuth
 ) -> Result<GetLevelRequestApiResponse, LevelRequestApiResponseError> {
-	let level_request_repository = MySqlLevelRequestRepository::new(db_conn);
-	let user_repository = MySqlUserRepository::new(db_conn);
-	let gd_client = GeometryDashDashrsClient::new();
-
-	let level_request_service =
-		LevelRequestService::new(&level_request_repository, &user_repository, &gd_client);
+	let level_request_service = app_state.level_request_service();
 
-	match level_request_service.delete_level_request(level_id).await {
+	match level_request_service
+		.delete_level_request(
+			auth.guild_id,
+			level_id,
+			auth.discord_user_id,
+			auth.has_role(Role::Admin)
+		)
+		.await
+	{
 		Ok(deleted_level_request) => Ok(GetLevelRequestApiResponse::from(deleted_level_request)),
 		Err(delete_level_request_error) => Err(delete_level_request_error.into())
 	}
 }
+
+async fn get_queue_positions(
+	app_state: &AppState,
+	guild_id: u64
+) -> Result<HashMap<u64, u64>, LevelRequestApiResponseError> {
+	match app_state
+		.level_queue_service()
+		.get_queue_positions(guild_id)
+		.await
+	{
+		Ok(queue_positions) => Ok(queue_positions),
+		Err(get_queue_positions_error) => Err(get_queue_positions_error.into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rocket_framework::{
+		http::Status,
+		serde::json::{json, Value}
+	};
+
+	use crate::rocket::common::testing::{
+		authorized, create_level_request, generate_jwt, test_client, use_fake_geometry_dash_server,
+		ADMIN_DISCORD_ID, YOUTUBE_VIDEO_LINK
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_request_level_should_create_pending_request() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6101, 12345).await;
+
+		let response = authorized(client.post("/api/v1/request_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"youtube_video_link": YOUTUBE_VIDEO_LINK,
+				"discord_id": 12345,
+				"request_rating": "Two",
+				"has_requested_feedback": true,
+				"notify": false
+			}))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Created);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["level_id"], 99999999);
+		assert_eq!(body["discord_id"], 12345);
+		assert_eq!(body["status"], "Pending");
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_request_level_should_reject_duplicate_request() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6102, 12345).await;
+		create_level_request(&client, &jwt, 99999999, 12345).await;
+
+		let response = authorized(client.post("/api/v1/request_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"youtube_video_link": YOUTUBE_VIDEO_LINK,
+				"discord_id": 12345,
+				"request_rating": "Four",
+				"has_requested_feedback": false,
+				"notify": false
+			}))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Conflict);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_request_level_should_reject_acting_as_another_user() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6103, 12345).await;
+
+		let response = authorized(client.post("/api/v1/request_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"youtube_video_link": YOUTUBE_VIDEO_LINK,
+				"discord_id": 54321,
+				"request_rating": "Two",
+				"has_requested_feedback": false,
+				"notify": false
+			}))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Forbidden);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_request_level_should_reject_malformed_youtube_link() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6104, 12345).await;
+
+		let response = authorized(client.post("/api/v1/request_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"youtube_video_link": "not a link",
+				"discord_id": 12345,
+				"request_rating": "Two",
+				"has_requested_feedback": false,
+				"notify": false
+			}))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::BadRequest);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_request_level_should_reject_request_past_daily_quota() {
+		let client = test_client().await;
+		let admin_jwt = generate_jwt(&client, 6111, ADMIN_DISCORD_ID).await;
+		let response = authorized(client.patch("/api/v1/internal/request_config"), &admin_jwt)
+			.json(&json!({ "daily_request_quota": 1 }))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+		let jwt = generate_jwt(&client, 6111, 12345).await;
+		create_level_request(&client, &jwt, 99999998, 12345).await;
+
+		let response = authorized(client.post("/api/v1/request_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"youtube_video_link": YOUTUBE_VIDEO_LINK,
+				"discord_id": 12345,
+				"request_rating": "Two",
+				"has_requested_feedback": false,
+				"notify": false
+			}))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::TooManyRequests);
+		assert!(response.headers().get_one("Retry-After").is_some());
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["quota"], "DailyRequests");
+		assert_eq!(body["limit"], 1);
+		assert!(body["retry_after"].is_number());
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_level_request_should_require_auth() {
+		let client = test_client().await;
+
+		let response = client
+			.get("/api/v1/request_level/99999999")
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Unauthorized);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_level_request_should_return_not_found() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6105, 12345).await;
+
+		let response = authorized(client.get("/api/v1/request_level/99999999"), &jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::NotFound);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_level_requests_should_filter_and_page() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6106, ADMIN_DISCORD_ID).await;
+		create_level_request(&client, &jwt, 99999997, 12345).await;
+		create_level_request(&client, &jwt, 99999998, 12345).await;
+		create_level_request(&client, &jwt, 99999999, 54321).await;
+
+		let response = authorized(
+			client.get("/api/v1/request_level?discord_id=12345&page=1&page_size=1"),
+			&jwt
+		)
+		.dispatch()
+		.await;
+
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["total_count"], 2);
+		assert_eq!(body["level_requests"].as_array().unwrap().len(), 1);
+		assert_eq!(body["level_requests"][0]["level_id"], 99999997);
+		assert_eq!(body["level_requests"][0]["queue_position"], 1);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_level_request_should_return_queue_position() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6110, 12345).await;
+		create_level_request(&client, &jwt, 99999998, 12345).await;
+		create_level_request(&client, &jwt, 99999999, 12345).await;
+
+		let response = authorized(client.get("/api/v1/request_level/99999999"), &jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["queue_position"], 2);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_update_level_request_should_update_owned_request() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6107, 12345).await;
+		create_level_request(&client, &jwt, 99999999, 12345).await;
+
+		let response = authorized(client.patch("/api/v1/request_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"discord_id": 12345,
+				"request_rating": "Four"
+			}))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["request_score"], "Four");
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_delete_level_request_should_reject_unowned_request() {
+		let client = test_client().await;
+		let owner_jwt = generate_jwt(&client, 6108, 12345).await;
+		let jwt = generate_jwt(&client, 6108, 54321).await;
+		create_level_request(&client, &owner_jwt, 99999999, 12345).await;
+
+		let response = authorized(client.delete("/api/v1/request_level/99999999"), &jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Forbidden);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_delete_level_request_should_archive_request() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6109, 12345).await;
+		create_level_request(&client, &jwt, 99999999, 12345).await;
+
+		let response = authorized(client.delete("/api/v1/request_level/99999999"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+
+		let response = authorized(client.get("/api/v1/request_level/99999999"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::NotFound);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_request_level_should_keep_archived_request_when_requested_again() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6112, 12345).await;
+		create_level_request(&client, &jwt, 99999999, 12345).await;
+		let response = authorized(client.delete("/api/v1/request_level/99999999"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+
+		create_level_request(&client, &jwt, 99999999, 12345).await;
+
+		let response = authorized(client.get("/api/v1/user/12345/profile"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["daily_requests"], 2);
+		assert_eq!(body["open_requests"], 1);
+		assert_eq!(body["level_requests"][0]["archived"], false);
+		assert_eq!(body["level_requests"][1]["level_id"], 99999999);
+		assert_eq!(body["level_requests"][1]["archived"], true);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_request_level_should_save_level_info_from_geometry_dash() {
+		let client = test_client().await;
+		use_fake_geometry_dash_server(&client, 6113).await;
+		let jwt = generate_jwt(&client, 6113, 12345).await;
+		create_level_request(&client, &jwt, 1000001, 12345).await;
+
+		let response = authorized(client.get("/api/v1/request_level/1000001"), &jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["level_name"], "Fixture Level");
+		assert_eq!(body["level_author"], "FixtureCreator");
+		assert_eq!(body["level_author_account_id"], 3000001);
+		assert_eq!(body["level_author_player_id"], 2000001);
+		assert_eq!(body["level_stars"], 0);
+		assert_eq!(body["level_downloads"], 1234);
+		assert_eq!(body["level_game_version"], "2.1");
+		assert_eq!(body["level_object_count"], 5000);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_request_level_should_reject_level_rated_on_geometry_dash() {
+		let client = test_client().await;
+		use_fake_geometry_dash_server(&client, 6114).await;
+		let jwt = generate_jwt(&client, 6114, 12345).await;
+
+		let response = authorized(client.post("/api/v1/request_level"), &jwt)
+			.json(&json!({
+				"level_id": 1000002,
+				"youtube_video_link": YOUTUBE_VIDEO_LINK,
+				"discord_id": 12345,
+				"request_rating": "Two",
+				"has_requested_feedback": false,
+				"notify": false
+			}))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::UnprocessableEntity);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["reason"], "AlreadyRated");
+	}
+}
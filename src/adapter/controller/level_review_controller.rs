@@ -1,49 +1,37 @@
 use rocket_framework::{serde::json::Json, State};
-use sea_orm::DatabaseConnection;
 
 use crate::{
-	adapter::{
-		geometry_dash::geometry_dash_dashrs_client::GeometryDashDashrsClient,
-		mysql::{
-			mysql_level_request_repository::MySqlLevelRequestRepository,
-			mysql_review_repository::MySqlReviewRepository,
-			mysql_user_repository::MySqlUserRepository
-		}
-	},
 	domain::{
-		model::api::{
-			auth_api::Auth,
-			level_review_api::{
-				GetLevelReviewApiRespnse, LevelReviewApiRequest, LevelReviewApiResponse,
-				LevelReviewApiResponseError
-			}
+		model::{
+			api::{
+				auth_api::{RequesterAuth, ReviewerAuth},
+				level_review_api::{
+					GetLevelReviewApiRespnse, GetLevelReviewsApiRequest,
+					GetLevelReviewsApiResponse, GetLevelRubricSummaryApiResponse,
+					GetReviewRevisionsApiResponse, GetReviewRubricApiResponse,
+					LevelReviewApiRequest, LevelReviewApiResponse, LevelReviewApiResponseError,
+					ReviewRevisionDiffApiResponse
+				}
+			},
+			auth::role::Role,
+			review_query::LevelReviewFilter
 		},
-		service::{
-			level_request_service::LevelRequestService, level_review_service::LevelReviewService,
-			review_service::ReviewService
-		}
-	}
+		service::review_service::ReviewService
+	},
+	rocket::common::app_state::AppState
 };
 
 #[get("/review_level/<level_id>?<discord_id>")]
 pub async fn get_level_review(
-	db_conn: &State<DatabaseConnection>,
+	app_state: &State<AppState>,
 	level_id: u64,
 	discord_id: u64,
-	_auth: Auth
+	auth: RequesterAuth
 ) -> Result<GetLevelReviewApiRespnse, LevelReviewApiResponseError> {
-	let level_review_repository = MySqlReviewRepository::new(db_conn);
-	let level_request_repository = MySqlLevelRequestRepository::new(db_conn);
-	let user_repository = MySqlUserRepository::new(db_conn);
-	let gd_client = GeometryDashDashrsClient::new();
-	let level_request_service =
-		LevelRequestService::new(&level_request_repository, &user_repository, &gd_client);
-
-	let level_review_service =
-		LevelReviewService::new(&level_review_repository, &level_request_service);
+	let level_review_service = app_state.level_review_service();
 
 	match level_review_service
-		.get_level_review(level_id, u64::from(discord_id))
+		.get_level_review(auth.guild_id, level_id, u64::from(discord_id))
 		.await
 	{
 		Ok(level_review) => Ok(GetLevelReviewApiRespnse::from(level_review)),
@@ -51,28 +39,50 @@ pub async fn get_level_review(
 	}
 }
 
+#[get("/review_level?<query..>")]
+pub async fn get_level_reviews(
+	app_state: &State<AppState>,
+	query: GetLevelReviewsApiRequest,
+	auth: RequesterAuth
+) -> Result<GetLevelReviewsApiResponse, LevelReviewApiResponseError> {
+	let level_review_service = app_state.level_review_service();
+
+	match level_review_service
+		.get_level_reviews(
+			auth.guild_id,
+			LevelReviewFilter::from(&query),
+			query.sort_order(),
+			query.page(),
+			query.page_size()
+		)
+		.await
+	{
+		Ok(level_review_page) => Ok(GetLevelReviewsApiResponse::from(level_review_page)),
+		Err(get_level_reviews_error) => Err(get_level_reviews_error.into())
+	}
+}
+
 #[post("/review_level", format = "json", data = "<level_review_body>")]
 pub async fn review_level<'a>(
-	db_conn: &State<DatabaseConnection>,
+	app_state: &State<AppState>,
 	level_review_body: Json<LevelReviewApiRequest<'a>>,
-	_auth: Auth
+	auth: ReviewerAuth
 ) -> Result<LevelReviewApiResponse, LevelReviewApiResponseError> {
-	let level_review_repository = MySqlReviewRepository::new(db_conn);
-	let level_request_repository = MySqlLevelRequestRepository::new(db_conn);
-	let user_repository = MySqlUserRepository::new(db_conn);
-	let gd_client = GeometryDashDashrsClient::new();
-	let level_request_service =
-		LevelRequestService::new(&level_request_repository, &user_repository, &gd_client);
+	let level_review_service = app_state.level_review_service();
 
-	let level_review_service =
-		LevelReviewService::new(&level_review_repository, &level_request_service);
+	if !auth.is_acting_as(level_review_body.reviewer_discord_id) {
+		return Err(LevelReviewApiResponseError::ActingAsAnotherUser);
+	}
 
+	let level_id = level_review_body.level_id;
+	let reviewer_discord_id = level_review_body.reviewer_discord_id;
 	match level_review_service
 		.review_level(
-			level_review_body.level_id,
-			level_review_body.reviewer_discord_id,
-			level_review_body.discord_message_id,
-			level_review_body.review_contents.to_string()
+			auth.guild_id,
+			level_id,
+			reviewer_discord_id,
+			auth.has_role(Role::Admin),
+			level_review_body.into_inner().into()
 		)
 		.await
 	{
@@ -80,3 +90,431 @@ pub async fn review_level<'a>(
 		Err(level_review_error) => Err(level_review_error.into())
 	}
 }
+
+#[get("/review_level/<level_id>/revisions?<discord_id>")]
+pub async fn get_review_revisions(
+	app_state: &State<AppState>,
+	level_id: u64,
+	discord_id: u64,
+	auth: RequesterAuth
+) -> Result<GetReviewRevisionsApiResponse, LevelReviewApiResponseError> {
+	let level_review_service = app_state.level_review_service();
+
+	match level_review_service
+		.get_review_revisions(auth.guild_id, level_id, discord_id)
+		.await
+	{
+		Ok(review_revisions) => Ok(GetReviewRevisionsApiResponse::new(
+			level_id,
+			discord_id,
+			review_revisions
+		)),
+		Err(get_review_revisions_error) => Err(get_review_revisions_error.into())
+	}
+}
+
+#[get("/review_level/<level_id>/revisions/diff?<discord_id>&<from>&<to>")]
+pub async fn diff_review_revisions(
+	app_state: &State<AppState>,
+	level_id: u64,
+	discord_id: u64,
+	from: u32,
+	to: u32,
+	auth: RequesterAuth
+) -> Result<ReviewRevisionDiffApiResponse, LevelReviewApiResponseError> {
+	let level_review_service = app_state.level_review_service();
+
+	match level_review_service
+		.diff_review_revisions(auth.guild_id, level_id, discord_id, from, to)
+		.await
+	{
+		Ok(review_revision_diff) => Ok(ReviewRevisionDiffApiResponse::from(review_revision_diff)),
+		Err(diff_review_revisions_error) => Err(diff_review_revisions_error.into())
+	}
+}
+
+#[get("/review_rubric")]
+pub async fn get_review_rubric(
+	app_state: &State<AppState>,
+	_auth: RequesterAuth
+) -> GetReviewRubricApiResponse {
+	GetReviewRubricApiResponse::from(&app_state.app_config.review_rubric_config)
+}
+
+#[get("/review_level/<level_id>/scores")]
+pub async fn get_level_rubric_summary(
+	app_state: &State<AppState>,
+	level_id: u64,
+	auth: RequesterAuth
+) -> Result<GetLevelRubricSummaryApiResponse, LevelReviewApiResponseError> {
+	let level_review_service = app_state.level_review_service();
+
+	match level_review_service
+		.get_level_rubric_summary(auth.guild_id, level_id)
+		.await
+	{
+		Ok(level_rubric_summary) => {
+			Ok(GetLevelRubricSummaryApiResponse::from(level_rubric_summary))
+		}
+		Err(get_level_rubric_summary_error) => Err(get_level_rubric_summary_error.into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rocket_framework::{
+		http::Status,
+		serde::json::{json, Value}
+	};
+
+	use crate::rocket::common::testing::{
+		authorized, create_level_request, create_reviewer, generate_jwt, test_client
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_review_level_should_create_then_update_review() {
+		let client = test_client().await;
+		let requester_jwt = generate_jwt(&client, 6201, 54321).await;
+		create_level_request(&client, &requester_jwt, 99999999, 54321).await;
+		create_reviewer(&client, 6201, 12345).await;
+		let jwt = generate_jwt(&client, 6201, 12345).await;
+
+		let response = authorized(client.post("/api/v1/review_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"reviewer_discord_id": 12345,
+				"discord_message_id": 11111,
+				"review_contents": "Great level"
+			}))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Created);
+
+		let response = authorized(client.post("/api/v1/review_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"reviewer_discord_id": 12345,
+				"discord_message_id": 22222,
+				"review_contents": "Even better level"
+			}))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+
+		let response = authorized(
+			client.get("/api/v1/review_level/99999999?discord_id=12345"),
+			&requester_jwt
+		)
+		.dispatch()
+		.await;
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["review_contents"], "Even better level");
+		assert_eq!(body["discord_message_id"], 11111);
+
+		let response = authorized(client.get("/api/v1/request_level/99999999"), &jwt)
+			.dispatch()
+			.await;
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["status"], "Reviewed");
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_review_level_should_require_reviewer_role() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6202, 12345).await;
+		create_level_request(&client, &jwt, 99999999, 12345).await;
+
+		let response = authorized(client.post("/api/v1/review_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"reviewer_discord_id": 12345,
+				"discord_message_id": 11111,
+				"review_contents": "Great level"
+			}))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Forbidden);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_review_level_should_return_not_found_for_unrequested_level() {
+		let client = test_client().await;
+		create_reviewer(&client, 6203, 12345).await;
+		let jwt = generate_jwt(&client, 6203, 12345).await;
+
+		let response = authorized(client.post("/api/v1/review_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"reviewer_discord_id": 12345,
+				"discord_message_id": 11111,
+				"review_contents": "Great level"
+			}))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::NotFound);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_level_review_should_return_not_found() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6204, 12345).await;
+
+		let response = authorized(
+			client.get("/api/v1/review_level/99999999?discord_id=54321"),
+			&jwt
+		)
+		.dispatch()
+		.await;
+
+		assert_eq!(response.status(), Status::NotFound);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_review_level_should_keep_every_revision() {
+		let client = test_client().await;
+		let requester_jwt = generate_jwt(&client, 9201, 54321).await;
+		create_level_request(&client, &requester_jwt, 99999999, 54321).await;
+		create_reviewer(&client, 9201, 12345).await;
+		let jwt = generate_jwt(&client, 9201, 12345).await;
+
+		for (discord_message_id, review_contents, scores) in [
+			(11111, "Great level\nBad sync", json!([])),
+			(
+				22222,
+				"Great level\nGood sync",
+				json!([{"category": "sync", "score": 9, "comment": null}])
+			)
+		] {
+			authorized(client.post("/api/v1/review_level"), &jwt)
+				.json(&json!({
+					"level_id": 99999999,
+					"reviewer_discord_id": 12345,
+					"discord_message_id": discord_message_id,
+					"review_contents": review_contents,
+					"scores": scores
+				}))
+				.dispatch()
+				.await;
+		}
+
+		let response = authorized(
+			client.get("/api/v1/review_level/99999999/revisions?discord_id=12345"),
+			&requester_jwt
+		)
+		.dispatch()
+		.await;
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["revisions"][0]["revision"], 1);
+		assert_eq!(body["revisions"][0]["discord_message_id"], 11111);
+		assert_eq!(
+			body["revisions"][0]["review_contents"],
+			"Great level\nBad sync"
+		);
+		assert_eq!(body["revisions"][1]["revision"], 2);
+		assert_eq!(body["revisions"][1]["discord_message_id"], 22222);
+		assert_eq!(body["revisions"][1]["scores"][0]["score"], 9);
+
+		let response = authorized(
+			client.get("/api/v1/review_level/99999999/revisions/diff?discord_id=12345&from=1&to=2"),
+			&requester_jwt
+		)
+		.dispatch()
+		.await;
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(
+			body["lines"],
+			json!([
+				{"change": "Unchanged", "line": "Great level"},
+				{"change": "Removed", "line": "Bad sync"},
+				{"change": "Added", "line": "Good sync"}
+			])
+		);
+		assert_eq!(
+			body["rubric_score_changes"],
+			json!([{
+				"category": "sync",
+				"from": null,
+				"to": {"category": "sync", "score": 9, "comment": null}
+			}])
+		);
+
+		let response = authorized(
+			client.get("/api/v1/review_level/99999999/revisions/diff?discord_id=12345&from=1&to=3"),
+			&requester_jwt
+		)
+		.dispatch()
+		.await;
+		assert_eq!(response.status(), Status::NotFound);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_level_reviews_should_filter_search_and_page() {
+		let client = test_client().await;
+		let requester_jwt = generate_jwt(&client, 9202, 54321).await;
+		let other_requester_jwt = generate_jwt(&client, 9202, 65432).await;
+		create_level_request(&client, &requester_jwt, 99999998, 54321).await;
+		create_level_request(&client, &other_requester_jwt, 99999999, 65432).await;
+		create_reviewer(&client, 9202, 12345).await;
+		create_reviewer(&client, 9202, 23456).await;
+		let jwt = generate_jwt(&client, 9202, 12345).await;
+		let other_jwt = generate_jwt(&client, 9202, 23456).await;
+
+		for (level_id, reviewer_discord_id, reviewer_jwt, review_contents) in [
+			(99999998, 12345, &jwt, "Great gameplay, bad sync"),
+			(99999999, 12345, &jwt, "Decent deco"),
+			(99999999, 23456, &other_jwt, "Great deco, 100% fair")
+		] {
+			let response = authorized(client.post("/api/v1/review_level"), reviewer_jwt)
+				.json(&json!({
+					"level_id": level_id,
+					"reviewer_discord_id": reviewer_discord_id,
+					"discord_message_id": 11111,
+					"review_contents": review_contents
+				}))
+				.dispatch()
+				.await;
+			assert_eq!(response.status(), Status::Created);
+		}
+
+		let response = authorized(
+			client.get("/api/v1/review_level?level_id=99999999"),
+			&requester_jwt
+		)
+		.dispatch()
+		.await;
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["total_count"], 2);
+
+		let response = authorized(
+			client.get("/api/v1/review_level?reviewer_discord_id=12345&page=2&page_size=1"),
+			&requester_jwt
+		)
+		.dispatch()
+		.await;
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["total_count"], 2);
+		assert_eq!(body["level_reviews"][0]["level_id"], 99999999);
+		assert_eq!(body["level_reviews"][0]["review_contents"], "Decent deco");
+
+		let response = authorized(
+			client.get("/api/v1/review_level?requester_discord_id=54321"),
+			&requester_jwt
+		)
+		.dispatch()
+		.await;
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["total_count"], 1);
+		assert_eq!(body["level_reviews"][0]["level_id"], 99999998);
+
+		let response = authorized(
+			client.get("/api/v1/review_level?search=great%20DECO"),
+			&requester_jwt
+		)
+		.dispatch()
+		.await;
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["total_count"], 1);
+		assert_eq!(body["level_reviews"][0]["reviewer_discord_id"], 23456);
+
+		let response = authorized(
+			client.get("/api/v1/review_level?search=100%25"),
+			&requester_jwt
+		)
+		.dispatch()
+		.await;
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["total_count"], 1);
+
+		let response = authorized(client.get("/api/v1/review_level?page=0"), &requester_jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::BadRequest);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_review_level_should_score_rubric_and_summarize_level() {
+		let client = test_client().await;
+		let requester_jwt = generate_jwt(&client, 9203, 54321).await;
+		create_level_request(&client, &requester_jwt, 99999999, 54321).await;
+		create_reviewer(&client, 9203, 12345).await;
+		create_reviewer(&client, 9203, 23456).await;
+		let jwt = generate_jwt(&client, 9203, 12345).await;
+		let other_jwt = generate_jwt(&client, 9203, 23456).await;
+
+		let response = authorized(client.get("/api/v1/review_rubric"), &requester_jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["categories"][0], "gameplay");
+		assert_eq!(body["max_score"], 10);
+
+		let response = authorized(client.post("/api/v1/review_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"reviewer_discord_id": 12345,
+				"discord_message_id": 11111,
+				"review_contents": "Great level",
+				"scores": [{"category": "music", "score": 5}]
+			}))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::BadRequest);
+
+		for (reviewer_discord_id, reviewer_jwt, gameplay_score, suggested_rating) in
+			[(12345, &jwt, 6, "Feature"), (23456, &other_jwt, 9, "Epic")]
+		{
+			let response = authorized(client.post("/api/v1/review_level"), reviewer_jwt)
+				.json(&json!({
+					"level_id": 99999999,
+					"reviewer_discord_id": reviewer_discord_id,
+					"discord_message_id": 11111,
+					"review_contents": "Great level",
+					"scores": [
+						{"category": "gameplay", "score": gameplay_score},
+						{"category": "sync", "score": 8, "comment": "On beat"}
+					],
+					"suggested_score": "Seven",
+					"suggested_rating": suggested_rating
+				}))
+				.dispatch()
+				.await;
+			assert_eq!(response.status(), Status::Created);
+		}
+
+		let response = authorized(
+			client.get("/api/v1/review_level/99999999?discord_id=12345"),
+			&requester_jwt
+		)
+		.dispatch()
+		.await;
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["scores"][1]["comment"], "On beat");
+		assert_eq!(body["suggested_score"], "Seven");
+		assert_eq!(body["suggested_rating"], "Feature");
+
+		let response = authorized(
+			client.get("/api/v1/review_level/99999999/scores"),
+			&requester_jwt
+		)
+		.dispatch()
+		.await;
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["review_count"], 2);
+		assert_eq!(body["categories"][0]["category"], "gameplay");
+		assert_eq!(body["categories"][0]["average_score"], 7.5);
+		assert_eq!(body["categories"][0]["min_score"], 6);
+		assert_eq!(body["categories"][1]["score_count"], 0);
+		assert_eq!(
+			body["suggested_scores"],
+			json!([{"suggested_score": "Seven", "count": 2}])
+		);
+	}
+}
@@ -1,7 +1,11 @@
 pub mod auth_controller;
+pub mod catcher;
 pub mod health;
 pub mod internal;
+pub mod level_queue_controller;
 pub mod level_request_controller;
 pub mod level_review_controller;
+pub mod review_assignment_controller;
 pub mod reviewer_controller;
+pub mod role_controller;
 pub mod user_controller;
@@ -0,0 +1,326 @@
+use rocket_framework::{serde::json::Json, State};
+
+use crate::{
+	domain::{
+		model::{
+			api::{
+				auth_api::{ModeratorAuth, ReviewerAuth},
+				review_assignment_api::{
+					AssignLevelApiRequest, GetAssignedLevelsApiResponse,
+					ReviewAssignmentApiResponse, ReviewAssignmentApiResponseError
+				}
+			},
+			auth::role::Role
+		},
+		service::review_assignment_service::ReviewAssignmentService
+	},
+	rocket::common::app_state::AppState
+};
+
+#[get("/review_assignments")]
+pub async fn get_assigned_levels(
+	app_state: &State<AppState>,
+	auth: ReviewerAuth
+) -> Result<GetAssignedLevelsApiResponse, ReviewAssignmentApiResponseError> {
+	let review_assignment_service = app_state.level_review_assignment_service();
+
+	match review_assignment_service
+		.get_assigned_levels(auth.guild_id, auth.discord_user_id)
+		.await
+	{
+		Ok(assigned_levels) => Ok(GetAssignedLevelsApiResponse::from(assigned_levels)),
+		Err(get_assigned_levels_error) => Err(get_assigned_levels_error.into())
+	}
+}
+
+#[post("/review_level/<level_id>/claim")]
+pub async fn claim_level(
+	app_state: &State<AppState>,
+	level_id: u64,
+	auth: ReviewerAuth
+) -> Result<ReviewAssignmentApiResponse, ReviewAssignmentApiResponseError> {
+	let review_assignment_service = app_state.level_review_assignment_service();
+
+	match review_assignment_service
+		.claim_level(auth.guild_id, level_id, auth.discord_user_id)
+		.await
+	{
+		Ok(assignment) => Ok(ReviewAssignmentApiResponse::from(assignment)),
+		Err(claim_level_error) => Err(claim_level_error.into())
+	}
+}
+
+#[delete("/review_level/<level_id>/claim")]
+pub async fn release_level(
+	app_state: &State<AppState>,
+	level_id: u64,
+	auth: ReviewerAuth
+) -> Result<ReviewAssignmentApiResponse, ReviewAssignmentApiResponseError> {
+	let review_assignment_service = app_state.level_review_assignment_service();
+
+	match review_assignment_service
+		.release_level(
+			auth.guild_id,
+			level_id,
+			auth.discord_user_id,
+			auth.has_role(Role::Moderator)
+		)
+		.await
+	{
+		Ok(assignment) => Ok(ReviewAssignmentApiResponse::from(assignment)),
+		Err(release_level_error) => Err(release_level_error.into())
+	}
+}
+
+#[put(
+	"/review_level/<level_id>/assignment",
+	format = "json",
+	data = "<assign_level_body>"
+)]
+pub async fn assign_level(
+	app_state: &State<AppState>,
+	level_id: u64,
+	assign_level_body: Json<AssignLevelApiRequest>,
+	auth: ModeratorAuth
+) -> Result<ReviewAssignmentApiResponse, ReviewAssignmentApiResponseError> {
+	let review_assignment_service = app_state.level_review_assignment_service();
+
+	match review_assignment_service
+		.assign_level(
+			auth.guild_id,
+			level_id,
+			assign_level_body.reviewer_discord_id,
+			auth.discord_user_id
+		)
+		.await
+	{
+		Ok(assignment) => Ok(ReviewAssignmentApiResponse::from(assignment)),
+		Err(assign_level_error) => Err(assign_level_error.into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rocket_framework::{
+		http::Status,
+		serde::json::{json, Value}
+	};
+
+	use crate::rocket::common::testing::{
+		authorized, create_level_request, create_reviewer, generate_jwt, test_client,
+		ADMIN_DISCORD_ID
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_claim_level_should_keep_other_reviewers_from_reviewing() {
+		let client = test_client().await;
+		let requester_jwt = generate_jwt(&client, 9101, 54321).await;
+		create_level_request(&client, &requester_jwt, 99999999, 54321).await;
+		create_reviewer(&client, 9101, 12345).await;
+		create_reviewer(&client, 9101, 23456).await;
+		let jwt = generate_jwt(&client, 9101, 12345).await;
+		let other_jwt = generate_jwt(&client, 9101, 23456).await;
+
+		let response = authorized(client.post("/api/v1/review_level/99999999/claim"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+
+		let response = authorized(
+			client.post("/api/v1/review_level/99999999/claim"),
+			&other_jwt
+		)
+		.dispatch()
+		.await;
+		assert_eq!(response.status(), Status::Conflict);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["assigned_to"], 12345);
+
+		let response = authorized(client.post("/api/v1/review_level"), &other_jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"reviewer_discord_id": 23456,
+				"discord_message_id": 11111,
+				"review_contents": "Great level"
+			}))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Conflict);
+
+		let response = authorized(client.get("/api/v1/review_assignments"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(
+			body["assigned_levels"][0]["level_request"]["level_id"],
+			99999999
+		);
+		assert_eq!(
+			body["assigned_levels"][0]["level_request"]["status"],
+			"InReview"
+		);
+
+		let response = authorized(client.post("/api/v1/review_level"), &jwt)
+			.json(&json!({
+				"level_id": 99999999,
+				"reviewer_discord_id": 12345,
+				"discord_message_id": 11111,
+				"review_contents": "Great level"
+			}))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Created);
+
+		let response = authorized(client.get("/api/v1/review_assignments"), &jwt)
+			.dispatch()
+			.await;
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["assigned_levels"], json!([]));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_release_level_should_let_another_reviewer_claim() {
+		let client = test_client().await;
+		let requester_jwt = generate_jwt(&client, 9102, 54321).await;
+		create_level_request(&client, &requester_jwt, 99999999, 54321).await;
+		create_reviewer(&client, 9102, 12345).await;
+		create_reviewer(&client, 9102, 23456).await;
+		let jwt = generate_jwt(&client, 9102, 12345).await;
+		let other_jwt = generate_jwt(&client, 9102, 23456).await;
+
+		let response = authorized(client.post("/api/v1/review_level/99999999/claim"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+
+		let response = authorized(client.delete("/api/v1/review_level/99999999/claim"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert!(body["released_at"].is_string());
+
+		let response = authorized(
+			client.post("/api/v1/review_level/99999999/claim"),
+			&other_jwt
+		)
+		.dispatch()
+		.await;
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["reviewer_discord_id"], 23456);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_release_level_should_return_level_to_queue() {
+		let client = test_client().await;
+		let requester_jwt = generate_jwt(&client, 9105, 54321).await;
+		create_level_request(&client, &requester_jwt, 99999999, 54321).await;
+		create_reviewer(&client, 9105, 12345).await;
+		let jwt = generate_jwt(&client, 9105, 12345).await;
+
+		let response = authorized(client.post("/api/v1/review_level/99999999/claim"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+		let response = authorized(client.get("/api/v1/queue/next"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::NotFound);
+
+		let response = authorized(client.delete("/api/v1/review_level/99999999/claim"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+
+		let response = authorized(client.get("/api/v1/queue/next"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["level_id"], 99999999);
+		assert_eq!(body["status"], "Pending");
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_claim_level_should_ignore_assignments_of_replaced_request() {
+		let client = test_client().await;
+		let requester_jwt = generate_jwt(&client, 9106, 54321).await;
+		create_level_request(&client, &requester_jwt, 99999999, 54321).await;
+		create_reviewer(&client, 9106, 12345).await;
+		create_reviewer(&client, 9106, 23456).await;
+		let jwt = generate_jwt(&client, 9106, 12345).await;
+		let other_jwt = generate_jwt(&client, 9106, 23456).await;
+
+		let response = authorized(client.post("/api/v1/review_level/99999999/claim"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+		let response = authorized(
+			client.delete("/api/v1/request_level/99999999"),
+			&requester_jwt
+		)
+		.dispatch()
+		.await;
+		assert_eq!(response.status(), Status::Ok);
+		create_level_request(&client, &requester_jwt, 99999999, 54321).await;
+
+		let response = authorized(
+			client.post("/api/v1/review_level/99999999/claim"),
+			&other_jwt
+		)
+		.dispatch()
+		.await;
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["reviewer_discord_id"], 23456);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_assign_level_should_take_turns_between_active_reviewers() {
+		let client = test_client().await;
+		let requester_jwt = generate_jwt(&client, 9103, 54321).await;
+		create_level_request(&client, &requester_jwt, 99999999, 54321).await;
+		create_level_request(&client, &requester_jwt, 88888888, 54321).await;
+		create_reviewer(&client, 9103, 12345).await;
+		create_reviewer(&client, 9103, 23456).await;
+		let admin_jwt = generate_jwt(&client, 9103, ADMIN_DISCORD_ID).await;
+
+		let response = authorized(
+			client.put("/api/v1/review_level/99999999/assignment"),
+			&admin_jwt
+		)
+		.json(&json!({}))
+		.dispatch()
+		.await;
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["reviewer_discord_id"], 12345);
+		assert_eq!(body["assigned_by"], ADMIN_DISCORD_ID);
+
+		let response = authorized(
+			client.put("/api/v1/review_level/88888888/assignment"),
+			&admin_jwt
+		)
+		.json(&json!({}))
+		.dispatch()
+		.await;
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["reviewer_discord_id"], 23456);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_claim_level_should_require_reviewer() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 9104, 54321).await;
+		create_level_request(&client, &jwt, 99999999, 54321).await;
+
+		let response = authorized(client.post("/api/v1/review_level/99999999/claim"), &jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Forbidden);
+	}
+}
@@ -1,33 +1,37 @@
 use rocket_framework::{serde::json::Json, State};
-use sea_orm::DatabaseConnection;
 
 use crate::{
-	adapter::mysql::mysql_reviewer_repository::MySqlReviewerRepository,
 	domain::{
-		model::api::{
-			auth_api::Auth,
-			reviewer_api::{
-				CreateReviewerApiRequest, GetReviewerApiResponse, ReviewerApiResponseError
-			}
+		model::{
+			api::{
+				auth_api::{AdminAuth, RequesterAuth},
+				reviewer_api::{
+					CreateReviewerApiRequest, GetReviewerApiResponse,
+					GetReviewerLeaderboardApiRequest, GetReviewerLeaderboardApiResponse,
+					GetReviewerStatsApiRequest, GetReviewerStatsApiResponse,
+					ReviewerApiResponseError
+				}
+			},
+			reviewer_stats::StatsRange
 		},
 		service::{
-			level_reviewer_service::LevelReviewerService, reviewer_service::ReviewerService
+			reviewer_service::ReviewerService, reviewer_stats_service::ReviewerStatsService
 		}
-	}
+	},
+	rocket::common::app_state::AppState
 };
 
 #[get("/reviewer/<reviewer_discord_id>?<is_active>")]
 pub async fn get_reviewer(
-	db_conn: &State<DatabaseConnection>,
+	app_state: &State<AppState>,
 	reviewer_discord_id: u64,
 	is_active: bool,
-	_auth: Auth
+	auth: RequesterAuth
 ) -> Result<GetReviewerApiResponse, ReviewerApiResponseError> {
-	let reviewer_repository = MySqlReviewerRepository::new(&db_conn);
-	let reviewer_service = LevelReviewerService::new(&reviewer_repository);
+	let reviewer_service = app_state.level_reviewer_service();
 
 	match reviewer_service
-		.get_reviewer(reviewer_discord_id, Some(is_active))
+		.get_reviewer(auth.guild_id, reviewer_discord_id, Some(is_active))
 		.await
 	{
 		Ok(reviewer) => Ok(GetReviewerApiResponse::from(reviewer)),
@@ -35,17 +39,62 @@ pub async fn get_reviewer(
 	}
 }
 
+#[get("/reviewer/<reviewer_discord_id>/stats?<query..>")]
+pub async fn get_reviewer_stats(
+	app_state: &State<AppState>,
+	reviewer_discord_id: u64,
+	query: GetReviewerStatsApiRequest,
+	auth: RequesterAuth
+) -> Result<GetReviewerStatsApiResponse, ReviewerApiResponseError> {
+	let reviewer_stats_service = app_state.level_reviewer_stats_service();
+	let range = StatsRange::try_from(&query)?;
+
+	match reviewer_stats_service
+		.get_reviewer_stats(auth.guild_id, reviewer_discord_id, range, query.period())
+		.await
+	{
+		Ok(reviewer_stats) => Ok(GetReviewerStatsApiResponse::from(reviewer_stats)),
+		Err(get_reviewer_stats_error) => Err(get_reviewer_stats_error.into())
+	}
+}
+
+#[get("/reviewer/leaderboard?<query..>")]
+pub async fn get_reviewer_leaderboard(
+	app_state: &State<AppState>,
+	query: GetReviewerLeaderboardApiRequest,
+	auth: RequesterAuth
+) -> Result<GetReviewerLeaderboardApiResponse, ReviewerApiResponseError> {
+	let reviewer_stats_service = app_state.level_reviewer_stats_service();
+	let range = StatsRange::try_from(&query)?;
+
+	match reviewer_stats_service
+		.get_reviewer_leaderboard(
+			auth.guild_id,
+			query.metric(),
+			range,
+			query.period(),
+			query.limit()
+		)
+		.await
+	{
+		Ok(leaderboard) => Ok(GetReviewerLeaderboardApiResponse::from(leaderboard)),
+		Err(get_reviewer_leaderboard_error) => Err(get_reviewer_leaderboard_error.into())
+	}
+}
+
 #[post("/reviewer", format = "json", data = "<create_reviewer_api_request>")]
 pub async fn create_reviewer(
-	db_conn: &State<DatabaseConnection>,
+	app_state: &State<AppState>,
 	create_reviewer_api_request: Json<CreateReviewerApiRequest>,
-	_auth: Auth
+	auth: AdminAuth
 ) -> Result<(), ReviewerApiResponseError> {
-	let reviewer_repository = MySqlReviewerRepository::new(&db_conn);
-	let reviewer_service = LevelReviewerService::new(&reviewer_repository);
+	let reviewer_service = app_state.level_reviewer_service();
 
 	match reviewer_service
-		.create_reviewer(create_reviewer_api_request.reviewer_discord_id)
+		.create_reviewer(
+			auth.guild_id,
+			create_reviewer_api_request.reviewer_discord_id
+		)
 		.await
 	{
 		Ok(()) => Ok(()),
@@ -55,15 +104,244 @@ pub async fn create_reviewer(
 
 #[delete("/reviewer/<reviewer_discord_id>")]
 pub async fn remove_reviewer(
-	db_conn: &State<DatabaseConnection>,
+	app_state: &State<AppState>,
 	reviewer_discord_id: u64,
-	_auth: Auth
+	auth: AdminAuth
 ) -> Result<(), ReviewerApiResponseError> {
-	let reviewer_repository = MySqlReviewerRepository::new(&db_conn);
-	let reviewer_service = LevelReviewerService::new(&reviewer_repository);
+	let reviewer_service = app_state.level_reviewer_service();
 
-	match reviewer_service.remove_reviewer(reviewer_discord_id).await {
+	match reviewer_service
+		.remove_reviewer(auth.guild_id, reviewer_discord_id)
+		.await
+	{
 		Ok(()) => Ok(()),
 		Err(create_reviewer_error) => Err(create_reviewer_error.into())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use rocket_framework::{
+		http::Status,
+		local::asynchronous::Client,
+		serde::json::{json, Value}
+	};
+
+	use crate::rocket::common::testing::{
+		authorized, create_level_request, create_reviewer, generate_jwt, test_client,
+		ADMIN_DISCORD_ID
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_create_reviewer_should_add_active_reviewer() {
+		let client = test_client().await;
+		create_reviewer(&client, 6301, 12345).await;
+		let jwt = generate_jwt(&client, 6301, 54321).await;
+
+		let response = authorized(client.get("/api/v1/reviewer/12345?is_active=true"), &jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["reviewer_discord_id"], 12345);
+		assert_eq!(body["is_active"], true);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_create_reviewer_should_require_admin_role() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6302, 12345).await;
+
+		let response = authorized(client.post("/api/v1/reviewer"), &jwt)
+			.json(&json!({ "reviewer_discord_id": 12345 }))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Forbidden);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_remove_reviewer_should_deactivate_reviewer() {
+		let client = test_client().await;
+		create_reviewer(&client, 6303, 12345).await;
+		let jwt = generate_jwt(&client, 6303, ADMIN_DISCORD_ID).await;
+
+		let response = authorized(client.delete("/api/v1/reviewer/12345"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+
+		let response = authorized(client.get("/api/v1/reviewer/12345?is_active=true"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::NotFound);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_remove_reviewer_should_return_not_found() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6304, ADMIN_DISCORD_ID).await;
+
+		let response = authorized(client.delete("/api/v1/reviewer/12345"), &jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::NotFound);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_reviewer_stats_should_count_written_reviews() {
+		let client = test_client().await;
+		let requester_jwt = generate_jwt(&client, 9301, 54321).await;
+		create_level_request(&client, &requester_jwt, 99999999, 54321).await;
+		create_reviewer(&client, 9301, 12345).await;
+		let jwt = generate_jwt(&client, 9301, 12345).await;
+		review_level(&client, &jwt, 99999999, 12345).await;
+
+		let response = authorized(
+			client.get("/api/v1/reviewer/12345/stats?period=day"),
+			&requester_jwt
+		)
+		.dispatch()
+		.await;
+
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["reviewer_discord_id"], 12345);
+		assert_eq!(body["review_count"], 1);
+		assert_eq!(body["reviews_per_period"][0]["review_count"], 1);
+		assert_eq!(body["sent_level_count"], 0);
+		assert!(body["average_turnaround_in_seconds"].is_i64());
+		assert_eq!(body["activity"][0]["is_active"], true);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_reviewer_stats_should_only_count_levels_sent_after_review() {
+		let client = test_client().await;
+		let requester_jwt = generate_jwt(&client, 9306, 54321).await;
+		create_level_request(&client, &requester_jwt, 99999998, 54321).await;
+		create_level_request(&client, &requester_jwt, 99999999, 54321).await;
+		create_reviewer(&client, 9306, 12345).await;
+		let jwt = generate_jwt(&client, 9306, 12345).await;
+		let moderator_jwt = generate_jwt(&client, 9306, ADMIN_DISCORD_ID).await;
+		send_level(&client, &moderator_jwt, 99999998).await;
+		review_level(&client, &jwt, 99999998, 12345).await;
+		review_level(&client, &jwt, 99999999, 12345).await;
+		send_level(&client, &moderator_jwt, 99999999).await;
+
+		let response = authorized(client.get("/api/v1/reviewer/12345/stats"), &requester_jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["review_count"], 2);
+		assert_eq!(body["sent_level_count"], 1);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_reviewer_stats_should_keep_activity_history() {
+		let client = test_client().await;
+		create_reviewer(&client, 9302, 12345).await;
+		let jwt = generate_jwt(&client, 9302, ADMIN_DISCORD_ID).await;
+		authorized(client.delete("/api/v1/reviewer/12345"), &jwt)
+			.dispatch()
+			.await;
+
+		let response = authorized(client.get("/api/v1/reviewer/12345/stats"), &jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["is_active"], false);
+		assert_eq!(body["review_count"], 0);
+		assert_eq!(body["average_turnaround_in_seconds"], Value::Null);
+		assert_eq!(body["activity"][0]["is_active"], true);
+		assert_eq!(body["activity"][1]["is_active"], false);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_reviewer_stats_should_return_not_found() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 9303, 54321).await;
+
+		let response = authorized(client.get("/api/v1/reviewer/12345/stats"), &jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::NotFound);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_reviewer_leaderboard_should_rank_reviewers_by_review_count() {
+		let client = test_client().await;
+		let requester_jwt = generate_jwt(&client, 9304, 54321).await;
+		create_level_request(&client, &requester_jwt, 99999999, 54321).await;
+		let other_requester_jwt = generate_jwt(&client, 9304, 65432).await;
+		create_level_request(&client, &other_requester_jwt, 99999998, 65432).await;
+		create_reviewer(&client, 9304, 12345).await;
+		create_reviewer(&client, 9304, 23456).await;
+		let jwt = generate_jwt(&client, 9304, 12345).await;
+		review_level(&client, &jwt, 99999999, 12345).await;
+		review_level(&client, &jwt, 99999998, 12345).await;
+		let other_jwt = generate_jwt(&client, 9304, 23456).await;
+		review_level(&client, &other_jwt, 99999999, 23456).await;
+
+		let response = authorized(
+			client.get("/api/v1/reviewer/leaderboard?sort=review_count"),
+			&requester_jwt
+		)
+		.dispatch()
+		.await;
+
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["leaderboard"][0]["rank"], 1);
+		assert_eq!(body["leaderboard"][0]["reviewer_discord_id"], 12345);
+		assert_eq!(body["leaderboard"][0]["review_count"], 2);
+		assert_eq!(body["leaderboard"][1]["rank"], 2);
+		assert_eq!(body["leaderboard"][1]["reviewer_discord_id"], 23456);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_reviewer_leaderboard_should_reject_malformed_timestamp() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 9305, 54321).await;
+
+		let response = authorized(
+			client.get("/api/v1/reviewer/leaderboard?from_timestamp=yesterday"),
+			&jwt
+		)
+		.dispatch()
+		.await;
+
+		assert_eq!(response.status(), Status::BadRequest);
+	}
+
+	async fn review_level(client: &Client, jwt: &str, level_id: u64, reviewer_discord_id: u64) {
+		let response = authorized(client.post("/api/v1/review_level"), jwt)
+			.json(&json!({
+				"level_id": level_id,
+				"reviewer_discord_id": reviewer_discord_id,
+				"discord_message_id": 11111,
+				"review_contents": "Great level"
+			}))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Created);
+	}
+
+	async fn send_level(client: &Client, jwt: &str, level_id: u64) {
+		let response = authorized(client.post("/api/v1/internal/send_level"), jwt)
+			.json(&json!({
+				"level_id": level_id,
+				"suggested_score": "Four",
+				"suggested_rating": "Rate"
+			}))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+	}
+}
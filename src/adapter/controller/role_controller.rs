@@ -0,0 +1,157 @@
+use rocket_framework::{serde::json::Json, State};
+
+use crate::{
+	domain::{
+		model::api::{
+			auth_api::{AdminAuth, RequesterAuth},
+			role_api::{
+				CreateUserRoleApiRequest, GetUserRolesApiResponse, Role, RoleApiResponseError
+			}
+		},
+		service::role_service::RoleService
+	},
+	rocket::common::app_state::AppState
+};
+
+#[get("/role/<discord_id>")]
+pub async fn get_roles(
+	app_state: &State<AppState>,
+	discord_id: u64,
+	auth: RequesterAuth
+) -> Result<GetUserRolesApiResponse, RoleApiResponseError> {
+	let role_service = app_state.role_service();
+
+	match role_service.get_roles(auth.guild_id, discord_id).await {
+		Ok(roles) => Ok(GetUserRolesApiResponse::new(discord_id, roles)),
+		Err(get_roles_error) => Err(get_roles_error.into())
+	}
+}
+
+#[post("/role", format = "json", data = "<create_user_role_api_request>")]
+pub async fn add_role(
+	app_state: &State<AppState>,
+	create_user_role_api_request: Json<CreateUserRoleApiRequest>,
+	auth: AdminAuth
+) -> Result<(), RoleApiResponseError> {
+	let role_service = app_state.role_service();
+
+	match role_service
+		.add_role(
+			auth.guild_id,
+			create_user_role_api_request.discord_id,
+			create_user_role_api_request.role.into()
+		)
+		.await
+	{
+		Ok(()) => Ok(()),
+		Err(add_role_error) => Err(add_role_error.into())
+	}
+}
+
+#[delete("/role/<discord_id>?<role>")]
+pub async fn remove_role(
+	app_state: &State<AppState>,
+	discord_id: u64,
+	role: Role,
+	auth: AdminAuth
+) -> Result<(), RoleApiResponseError> {
+	let role_service = app_state.role_service();
+
+	match role_service
+		.remove_role(auth.guild_id, discord_id, role.into())
+		.await
+	{
+		Ok(()) => Ok(()),
+		Err(remove_role_error) => Err(remove_role_error.into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rocket_framework::{
+		http::Status,
+		serde::json::{json, Value}
+	};
+
+	use crate::rocket::common::testing::{authorized, generate_jwt, test_client, ADMIN_DISCORD_ID};
+
+	#[rocket_framework::async_test]
+	async fn test_add_role_should_grant_role() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6401, ADMIN_DISCORD_ID).await;
+
+		let response = authorized(client.post("/api/v1/role"), &jwt)
+			.json(&json!({ "discord_id": 12345, "role": "moderator" }))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+
+		let response = authorized(client.get("/api/v1/role/12345"), &jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["roles"], json!(["requester", "moderator"]));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_add_role_should_reject_unassignable_role() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6402, ADMIN_DISCORD_ID).await;
+
+		let response = authorized(client.post("/api/v1/role"), &jwt)
+			.json(&json!({ "discord_id": 12345, "role": "reviewer" }))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::BadRequest);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_add_role_should_require_admin_role() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6403, 12345).await;
+
+		let response = authorized(client.post("/api/v1/role"), &jwt)
+			.json(&json!({ "discord_id": 12345, "role": "admin" }))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Forbidden);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_remove_role_should_return_not_found() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6404, ADMIN_DISCORD_ID).await;
+
+		let response = authorized(client.delete("/api/v1/role/12345?role=moderator"), &jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::NotFound);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_remove_role_should_take_effect_before_token_expires() {
+		let client = test_client().await;
+		let admin_jwt = generate_jwt(&client, 6405, ADMIN_DISCORD_ID).await;
+		let response = authorized(client.post("/api/v1/role"), &admin_jwt)
+			.json(&json!({ "discord_id": 12345, "role": "admin" }))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+		let jwt = generate_jwt(&client, 6405, 12345).await;
+
+		let response = authorized(client.delete("/api/v1/role/12345?role=admin"), &admin_jwt)
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+
+		let response = authorized(client.post("/api/v1/role"), &jwt)
+			.json(&json!({ "discord_id": 23456, "role": "moderator" }))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Forbidden);
+	}
+}
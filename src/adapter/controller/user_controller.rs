@@ -1,29 +1,227 @@
-use rocket_framework::State;
-use sea_orm::DatabaseConnection;
+use rocket_framework::{serde::json::Json, State};
 
 use crate::{
-	adapter::mysql::mysql_user_repository::MySqlUserRepository,
 	domain::{
-		model::api::{
-			auth_api::Auth,
-			user_api::{DiscordUserApiResponseError, GetDiscordUserApiResponse}
+		model::{
+			api::{
+				auth_api::{AdminAuth, RequesterAuth},
+				user_api::{
+					DiscordUserApiResponseError, GetDiscordUserApiResponse,
+					GetUserProfileApiResponse, UpdateRequestLimitsApiRequest
+				}
+			},
+			request_limits::RequestLimits
 		},
-		service::{discord_user_service::DiscordUserService, user_service::UserService}
-	}
+		service::user_service::UserService
+	},
+	rocket::common::app_state::AppState
 };
 
 #[get("/user/<discord_user_id>")]
 pub async fn get_user(
-	db_conn: &State<DatabaseConnection>,
+	app_state: &State<AppState>,
 	discord_user_id: u64,
-	_auth: Auth
+	auth: RequesterAuth
 ) -> Result<GetDiscordUserApiResponse, DiscordUserApiResponseError> {
-	let user_repository = MySqlUserRepository::new(db_conn);
-
-	let user_service = DiscordUserService::new(&user_repository);
+	let user_service = app_state.discord_user_service();
 
-	match user_service.get_user(discord_user_id).await {
-		Ok(discord_user) => Ok(GetDiscordUserApiResponse::from(discord_user)),
+	match user_service.get_user(auth.guild_id, discord_user_id).await {
+		Ok(discord_user) => Ok(GetDiscordUserApiResponse::new(
+			discord_user,
+			RequestLimits::new(
+				&app_state.request_manager.get_request_config(auth.guild_id),
+				&discord_user.request_limit_overrides
+			)
+		)),
 		Err(get_discord_user_error) => Err(get_discord_user_error.into())
 	}
 }
+
+#[get("/user/<discord_user_id>/profile")]
+pub async fn get_user_profile(
+	app_state: &State<AppState>,
+	discord_user_id: u64,
+	auth: RequesterAuth
+) -> Result<GetUserProfileApiResponse, DiscordUserApiResponseError> {
+	let user_service = app_state.discord_user_service();
+
+	match user_service
+		.get_user_profile(auth.guild_id, discord_user_id)
+		.await
+	{
+		Ok(user_profile) => Ok(GetUserProfileApiResponse::from(user_profile)),
+		Err(get_user_profile_error) => Err(get_user_profile_error.into())
+	}
+}
+
+#[put(
+	"/user/<discord_user_id>/request_limits",
+	format = "json",
+	data = "<update_request_limits_body>"
+)]
+pub async fn update_request_limits(
+	app_state: &State<AppState>,
+	discord_user_id: u64,
+	update_request_limits_body: Json<UpdateRequestLimitsApiRequest>,
+	auth: AdminAuth
+) -> Result<GetDiscordUserApiResponse, DiscordUserApiResponseError> {
+	let user_service = app_state.discord_user_service();
+
+	match user_service
+		.update_request_limit_overrides(
+			auth.guild_id,
+			discord_user_id,
+			update_request_limits_body.into_inner().into()
+		)
+		.await
+	{
+		Ok(discord_user) => Ok(GetDiscordUserApiResponse::new(
+			discord_user,
+			RequestLimits::new(
+				&app_state.request_manager.get_request_config(auth.guild_id),
+				&discord_user.request_limit_overrides
+			)
+		)),
+		Err(update_request_limits_error) => Err(update_request_limits_error.into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rocket_framework::{
+		http::Status,
+		serde::json::{json, Value}
+	};
+
+	use crate::rocket::common::testing::{
+		authorized, create_level_request, generate_jwt, test_client, ADMIN_DISCORD_ID
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_get_user_should_return_last_request_time() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6501, 12345).await;
+		create_level_request(&client, &jwt, 99999999, 12345).await;
+
+		let response = authorized(client.get("/api/v1/user/12345"), &jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["discord_user_id"], 12345);
+		assert!(body["last_request_time"].is_string());
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_user_should_return_not_found() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6502, 12345).await;
+
+		let response = authorized(client.get("/api/v1/user/12345"), &jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::NotFound);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_user_profile_should_return_requests_and_limits() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6505, 12345).await;
+		create_level_request(&client, &jwt, 99999999, 12345).await;
+
+		let response = authorized(client.get("/api/v1/user/12345/profile"), &jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["discord_user_id"], 12345);
+		assert_eq!(body["open_requests"], 1);
+		assert_eq!(body["daily_requests"], 1);
+		assert_eq!(body["cooldown_remaining"], 0);
+		assert!(body["ban"].is_null());
+		assert_eq!(body["level_requests"][0]["level_id"], 99999999);
+		assert_eq!(body["level_requests"][0]["status"], "Pending");
+		assert_eq!(body["level_requests"][0]["reviewed"], false);
+		assert_eq!(body["level_requests"][0]["sent"], false);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_user_profile_should_return_active_ban() {
+		let client = test_client().await;
+		let admin_jwt = generate_jwt(&client, 6506, ADMIN_DISCORD_ID).await;
+		let jwt = generate_jwt(&client, 6506, 12345).await;
+		create_level_request(&client, &jwt, 99999999, 12345).await;
+
+		let response = authorized(client.post("/api/v1/internal/ban"), &admin_jwt)
+			.json(&json!({
+				"target_type": "User",
+				"target_id": 12345,
+				"reason": "Spamming requests"
+			}))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+
+		let response = authorized(client.get("/api/v1/user/12345/profile"), &jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["ban"]["reason"], "Spamming requests");
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_user_profile_should_return_not_found() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6507, 12345).await;
+
+		let response = authorized(client.get("/api/v1/user/12345/profile"), &jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::NotFound);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_update_request_limits_should_return_effective_limits() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6503, ADMIN_DISCORD_ID).await;
+
+		let response = authorized(client.put("/api/v1/user/12345/request_limits"), &jwt)
+			.json(&json!({
+				"cooldown_exempt": true,
+				"max_open_requests": 3
+			}))
+			.dispatch()
+			.await;
+		assert_eq!(response.status(), Status::Ok);
+
+		let response = authorized(client.get("/api/v1/user/12345"), &jwt)
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Ok);
+		let body = response.into_json::<Value>().await.unwrap();
+		assert_eq!(body["request_cooldown"], 0);
+		assert_eq!(body["cooldown_exempt"], true);
+		assert_eq!(body["max_open_requests"], 3);
+		assert!(body["last_request_time"].is_null());
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_update_request_limits_should_require_admin() {
+		let client = test_client().await;
+		let jwt = generate_jwt(&client, 6504, 12345).await;
+
+		let response = authorized(client.put("/api/v1/user/12345/request_limits"), &jwt)
+			.json(&json!({ "cooldown_exempt": true }))
+			.dispatch()
+			.await;
+
+		assert_eq!(response.status(), Status::Forbidden);
+	}
+}
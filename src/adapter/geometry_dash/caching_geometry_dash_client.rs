@@ -0,0 +1,282 @@
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+	time::{Duration, Instant}
+};
+
+use rocket_framework::tokio::sync::OnceCell;
+
+use crate::{
+	adapter::geometry_dash::geometry_dash_client::GeometryDashClient,
+	domain::model::{
+		error::geometry_dash::geometry_dash_dashrs_error::GeometryDashDashrsError,
+		gd_level::GDLevel, moderator::Moderator
+	}
+};
+
+type LevelLookup = Result<GDLevel, GeometryDashDashrsError>;
+
+/// Caches level lookups from the wrapped client. Levels that do not exist are
+/// cached for their own, usually shorter, TTL, and concurrent lookups for the
+/// same level share a single call to Geometry Dash.
+pub struct CachingGeometryDashClient<G: GeometryDashClient> {
+	gd_client: G,
+	level_ttl: Duration,
+	level_not_found_ttl: Duration,
+	cached_levels: Mutex<HashMap<u64, CachedLevel>>,
+	in_flight_lookups: Mutex<HashMap<u64, Arc<OnceCell<LevelLookup>>>>
+}
+
+struct CachedLevel {
+	lookup: LevelLookup,
+	expires_at: Instant
+}
+
+impl<G: GeometryDashClient> GeometryDashClient for CachingGeometryDashClient<G> {
+	async fn get_gd_level_info(&self, level_id: u64) -> Result<GDLevel, GeometryDashDashrsError> {
+		if let Some(cached_lookup) = self.get_cached_level(level_id) {
+			debug!("Serving level {} from cache", level_id);
+			return cached_lookup;
+		}
+
+		let in_flight_lookup = self
+			.in_flight_lookups
+			.lock()
+			.unwrap()
+			.entry(level_id)
+			.or_default()
+			.clone();
+		let lookup = in_flight_lookup
+			.get_or_init(|| self.fetch_level(level_id))
+			.await
+			.clone();
+
+		let mut in_flight_lookups = self.in_flight_lookups.lock().unwrap();
+		if in_flight_lookups
+			.get(&level_id)
+			.is_some_and(|current_lookup| Arc::ptr_eq(current_lookup, &in_flight_lookup))
+		{
+			in_flight_lookups.remove(&level_id);
+		}
+		lookup
+	}
+
+	async fn send_gd_level(
+		&self,
+		moderator_request: Moderator
+	) -> Result<(), GeometryDashDashrsError> {
+		self.gd_client.send_gd_level(moderator_request).await
+	}
+}
+
+impl<G: GeometryDashClient> CachingGeometryDashClient<G> {
+	pub fn new(gd_client: G, level_ttl: Duration, level_not_found_ttl: Duration) -> Self {
+		CachingGeometryDashClient {
+			gd_client,
+			level_ttl,
+			level_not_found_ttl,
+			cached_levels: Mutex::new(HashMap::new()),
+			in_flight_lookups: Mutex::new(HashMap::new())
+		}
+	}
+
+	fn get_cached_level(&self, level_id: u64) -> Option<LevelLookup> {
+		let cached_levels = self.cached_levels.lock().unwrap();
+		cached_levels
+			.get(&level_id)
+			.filter(|cached_level| cached_level.expires_at > Instant::now())
+			.map(|cached_level| cached_level.lookup.clone())
+	}
+
+	async fn fetch_level(&self, level_id: u64) -> LevelLookup {
+		let lookup = self.gd_client.get_gd_level_info(level_id).await;
+		let ttl = match lookup {
+			Ok(_) => self.level_ttl,
+			Err(GeometryDashDashrsError::LevelNotFoundError(_)) => self.level_not_found_ttl,
+			Err(_) => return lookup
+		};
+
+		let now = Instant::now();
+		let mut cached_levels = self.cached_levels.lock().unwrap();
+		cached_levels.retain(|_, cached_level| cached_level.expires_at > now);
+		if !ttl.is_zero() {
+			cached_levels.insert(
+				level_id,
+				CachedLevel {
+					lookup: lookup.clone(),
+					expires_at: now + ttl
+				}
+			);
+		}
+		lookup
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{sync::Arc, time::Duration};
+
+	use rocket_framework::{futures::future::join_all, tokio::time::sleep};
+
+	use crate::{
+		adapter::geometry_dash::{
+			caching_geometry_dash_client::CachingGeometryDashClient,
+			geometry_dash_client::{GeometryDashClient, MockGeometryDashClient}
+		},
+		domain::model::{
+			error::geometry_dash::geometry_dash_dashrs_error::GeometryDashDashrsError,
+			gd_level::{FeatureState, GDLevel, LevelCreator, LevelDifficulty, LevelLength},
+			moderator::Moderator
+		}
+	};
+
+	/// Takes a while to answer, so that lookups made meanwhile overlap.
+	struct SlowGeometryDashClient(MockGeometryDashClient);
+
+	impl GeometryDashClient for SlowGeometryDashClient {
+		async fn get_gd_level_info(
+			&self,
+			level_id: u64
+		) -> Result<GDLevel, GeometryDashDashrsError> {
+			sleep(Duration::from_millis(50)).await;
+			self.0.get_gd_level_info(level_id).await
+		}
+
+		async fn send_gd_level(
+			&self,
+			moderator_request: Moderator
+		) -> Result<(), GeometryDashDashrsError> {
+			self.0.send_gd_level(moderator_request).await
+		}
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_gd_level_info_should_serve_cached_level_until_it_expires() {
+		let mut mock_gd_client = MockGeometryDashClient::new();
+		mock_gd_client
+			.expect_get_gd_level_info()
+			.times(2)
+			.returning(|_| Ok(gd_level()));
+		let gd_client = CachingGeometryDashClient::new(
+			mock_gd_client,
+			Duration::from_millis(100),
+			Duration::ZERO
+		);
+
+		assert!(gd_client.get_gd_level_info(99999999).await.is_ok());
+		assert!(gd_client.get_gd_level_info(99999999).await.is_ok());
+		sleep(Duration::from_millis(150)).await;
+		assert!(gd_client.get_gd_level_info(99999999).await.is_ok());
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_gd_level_info_should_cache_missing_level_for_its_own_ttl() {
+		let mut mock_gd_client = MockGeometryDashClient::new();
+		mock_gd_client
+			.expect_get_gd_level_info()
+			.times(2)
+			.returning(|level_id| Err(GeometryDashDashrsError::LevelNotFoundError(level_id)));
+		let gd_client = CachingGeometryDashClient::new(
+			mock_gd_client,
+			Duration::from_secs(600),
+			Duration::from_millis(100)
+		);
+
+		for _ in 0..2 {
+			assert_eq!(
+				gd_client.get_gd_level_info(99999999).await.unwrap_err(),
+				GeometryDashDashrsError::LevelNotFoundError(99999999)
+			);
+		}
+		sleep(Duration::from_millis(150)).await;
+		assert_eq!(
+			gd_client.get_gd_level_info(99999999).await.unwrap_err(),
+			GeometryDashDashrsError::LevelNotFoundError(99999999)
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_gd_level_info_should_not_cache_http_errors() {
+		let http_error = GeometryDashDashrsError::HttpError(Arc::new(
+			reqwest::Client::new()
+				.get("not a url")
+				.send()
+				.await
+				.unwrap_err()
+		));
+		let mut mock_gd_client = MockGeometryDashClient::new();
+		mock_gd_client
+			.expect_get_gd_level_info()
+			.times(2)
+			.returning(move |_| Err(http_error.clone()));
+		let gd_client = CachingGeometryDashClient::new(
+			mock_gd_client,
+			Duration::from_secs(600),
+			Duration::from_secs(60)
+		);
+
+		for _ in 0..2 {
+			assert!(matches!(
+				gd_client.get_gd_level_info(99999999).await,
+				Err(GeometryDashDashrsError::HttpError(_))
+			));
+		}
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_gd_level_info_should_not_cache_with_zero_ttl() {
+		let mut mock_gd_client = MockGeometryDashClient::new();
+		mock_gd_client
+			.expect_get_gd_level_info()
+			.times(3)
+			.returning(|_| Ok(gd_level()));
+		let gd_client =
+			CachingGeometryDashClient::new(mock_gd_client, Duration::ZERO, Duration::ZERO);
+
+		for _ in 0..3 {
+			assert!(gd_client.get_gd_level_info(99999999).await.is_ok());
+		}
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_gd_level_info_should_share_one_call_between_concurrent_lookups() {
+		let mut mock_gd_client = MockGeometryDashClient::new();
+		mock_gd_client
+			.expect_get_gd_level_info()
+			.times(1)
+			.returning(|_| Ok(gd_level()));
+		// Nothing is cached, so only sharing the in-flight call avoids more calls
+		let gd_client = CachingGeometryDashClient::new(
+			SlowGeometryDashClient(mock_gd_client),
+			Duration::ZERO,
+			Duration::ZERO
+		);
+
+		let lookups = join_all((0..10).map(|_| gd_client.get_gd_level_info(99999999))).await;
+
+		assert_eq!(lookups.len(), 10);
+		assert!(lookups.iter().all(|lookup| lookup.is_ok()));
+	}
+
+	fn gd_level() -> GDLevel {
+		GDLevel {
+			name: "Level Name".to_string(),
+			description: None,
+			creator: LevelCreator {
+				name: "Level Creator".to_string(),
+				account_id: 1234,
+				player_id: 5678
+			},
+			level_length: LevelLength::Medium,
+			difficulty: LevelDifficulty::Hard,
+			stars: 0,
+			feature_state: FeatureState::NotFeatured,
+			downloads: 100,
+			likes: 10,
+			game_version: None,
+			song: None,
+			object_count: Some(5000),
+			coin_count: 0
+		}
+	}
+}
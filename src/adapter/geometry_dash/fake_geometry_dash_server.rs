@@ -0,0 +1,88 @@
+use std::path::PathBuf;
+
+use rocket_framework::{form::Form, tokio::fs, Build, FromForm, Rocket, State};
+
+use crate::rocket::common::constants::FAKE_GEOMETRY_DASH_SERVER_PATH;
+
+/// What Geometry Dash answers with when nothing matched the request.
+static NOT_FOUND_RESPONSE: &str = "-1";
+
+/// Stands in for the Geometry Dash servers so levels can be requested and sent
+/// without network access. A level exists when `levels/<level_id>.txt` in the
+/// fixtures directory holds its canned `getGJLevels21` response.
+pub struct FakeGeometryDashServer {
+	fixtures_path: PathBuf
+}
+
+#[derive(FromForm)]
+pub struct GetLevelsForm {
+	#[field(name = "str")]
+	search: String
+}
+
+#[derive(FromForm)]
+pub struct SuggestStarsForm {
+	#[field(name = "levelID")]
+	level_id: u64
+}
+
+#[post("/getGJLevels21.php", data = "<get_levels_form>")]
+pub async fn get_levels(
+	fake_server: &State<FakeGeometryDashServer>,
+	get_levels_form: Form<GetLevelsForm>
+) -> String {
+	match get_levels_form.search.parse::<u64>() {
+		Ok(level_id) => fake_server
+			.get_level_response(level_id)
+			.await
+			.unwrap_or_else(|| NOT_FOUND_RESPONSE.to_string()),
+		Err(_) => NOT_FOUND_RESPONSE.to_string()
+	}
+}
+
+#[post("/suggestGJStars20.php", data = "<suggest_stars_form>")]
+pub async fn suggest_stars(
+	fake_server: &State<FakeGeometryDashServer>,
+	suggest_stars_form: Form<SuggestStarsForm>
+) -> String {
+	match fake_server
+		.get_level_response(suggest_stars_form.level_id)
+		.await
+	{
+		Some(_) => {
+			info!(
+				"Fake Geometry Dash server accepted level {}",
+				suggest_stars_form.level_id
+			);
+			String::from("1")
+		}
+		None => NOT_FOUND_RESPONSE.to_string()
+	}
+}
+
+impl FakeGeometryDashServer {
+	async fn get_level_response(&self, level_id: u64) -> Option<String> {
+		let level_fixture_path = self
+			.fixtures_path
+			.join("levels")
+			.join(format!("{}.txt", level_id));
+		fs::read_to_string(level_fixture_path)
+			.await
+			.ok()
+			.map(|level_response| level_response.trim_end().to_string())
+	}
+}
+
+pub fn mount_fake_geometry_dash_server(
+	rocket: Rocket<Build>,
+	fixtures_path: &str
+) -> Rocket<Build> {
+	rocket
+		.manage(FakeGeometryDashServer {
+			fixtures_path: PathBuf::from(fixtures_path)
+		})
+		.mount(
+			FAKE_GEOMETRY_DASH_SERVER_PATH,
+			routes![get_levels, suggest_stars]
+		)
+}
@@ -1,13 +1,14 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, sync::Arc, time::Duration};
 use dash_rs::{
 	request::{account::LoginRequest, level::LevelsRequest, moderator::SuggestStarsRequest},
-	response::parse_get_gj_levels_response
+	response::parse_get_gj_levels_response,
+	ResponseError
 };
 use dash_rs::ProcessError::Base64;
 use dash_rs::request::account::AuthenticatedUser;
 use reqwest::{
 	header::{HeaderMap, HeaderValue},
-	Client
+	Client, Response
 };
 
 use crate::{
@@ -27,7 +28,8 @@ use crate::{
 };
 
 pub struct GeometryDashDashrsClient {
-	client: Client
+	client: Client,
+	base_url: String
 }
 
 impl GeometryDashClient for GeometryDashDashrsClient {
@@ -38,14 +40,24 @@ impl GeometryDashClient for GeometryDashDashrsClient {
 		info!("Calling Geometry Dash servers for level {}", level_id);
 		let raw_response_result = self
 			.client
-			.post(get_level_info_request.to_url())
+			.post(self.endpoint_url(&get_level_info_request.to_url()))
 			.body(get_level_info_request.to_string())
 			.send()
-			.await;
+			.await
+			.and_then(Response::error_for_status);
 
 		match raw_response_result {
 			Ok(raw_response) => {
-				let parsed_response = raw_response.text().await.unwrap();
+				let parsed_response = match raw_response.text().await {
+					Ok(parsed_response) => parsed_response,
+					Err(request_err) => {
+						error!(
+							"Error reading response from Geometry Dash servers: {}",
+							request_err
+						);
+						return Err(HttpError(Arc::new(request_err)));
+					}
+				};
 
 				let gd_level_info_result = parse_get_gj_levels_response(&parsed_response);
 				match gd_level_info_result {
@@ -59,6 +71,7 @@ impl GeometryDashClient for GeometryDashDashrsClient {
 							None => Err(LevelNotFoundError(level_id))
 						}
 					}
+					Err(ResponseError::NotFound) => Err(LevelNotFoundError(level_id)),
 					Err(dashrs_error) => {
 						error!(
 							"Error parsing response from Geometry Dash servers: {}",
@@ -70,7 +83,7 @@ impl GeometryDashClient for GeometryDashDashrsClient {
 			}
 			Err(request_err) => {
 				error!("Error calling Geometry Dash servers: {}", request_err);
-				Err(HttpError(request_err))
+				Err(HttpError(Arc::new(request_err)))
 			}
 		}
 	}
@@ -95,15 +108,25 @@ impl GeometryDashClient for GeometryDashDashrsClient {
 		);
 		let raw_response_result = self
 			.client
-			.post(send_level_request.to_url())
+			.post(self.endpoint_url(&send_level_request.to_url()))
 			.body(send_level_request.to_string())
 			.header(CONTENT_TYPE, APPLICATION_FORM_URL_ENCODED)
 			.send()
-			.await;
+			.await
+			.and_then(Response::error_for_status);
 
 		match raw_response_result {
 			Ok(raw_response) => {
-				let parsed_response = raw_response.text().await.unwrap();
+				let parsed_response = match raw_response.text().await {
+					Ok(parsed_response) => parsed_response,
+					Err(request_err) => {
+						error!(
+							"Error reading response from Geometry Dash servers: {}",
+							request_err
+						);
+						return Err(HttpError(Arc::new(request_err)));
+					}
+				};
 
 				if parsed_response.eq("1") {
 					Ok(())
@@ -113,14 +136,14 @@ impl GeometryDashClient for GeometryDashDashrsClient {
 			}
 			Err(request_err) => {
 				error!("Error calling Geometry Dash servers: {}", request_err);
-				Err(HttpError(request_err))
+				Err(HttpError(Arc::new(request_err)))
 			}
 		}
 	}
 }
 
 impl GeometryDashDashrsClient {
-	pub fn new() -> Self {
+	pub fn new(base_url: &str, connect_timeout: Duration, read_timeout: Duration) -> Self {
 		let mut default_headers = HeaderMap::new();
 		default_headers.insert(
 			CONTENT_TYPE,
@@ -129,8 +152,18 @@ impl GeometryDashDashrsClient {
 		GeometryDashDashrsClient {
 			client: Client::builder()
 				.default_headers(default_headers)
+				.connect_timeout(connect_timeout)
+				.read_timeout(read_timeout)
 				.build()
-				.expect("Client::new")
+				.expect("Client::new"),
+			base_url: base_url.trim_end_matches('/').to_string()
 		}
 	}
+
+	/// dash-rs builds its URLs against the official servers, so only the
+	/// endpoint is kept and joined onto the configured base URL.
+	fn endpoint_url(&self, dashrs_url: &str) -> String {
+		let endpoint = dashrs_url.rsplit('/').next().unwrap_or_default();
+		format!("{}/{}", self.base_url, endpoint)
+	}
 }
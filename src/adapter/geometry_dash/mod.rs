@@ -1,2 +1,5 @@
+pub mod caching_geometry_dash_client;
+pub mod fake_geometry_dash_server;
 pub mod geometry_dash_client;
 pub mod geometry_dash_dashrs_client;
+pub mod resilient_geometry_dash_client;
@@ -0,0 +1,436 @@
+use std::{
+	sync::Mutex,
+	time::{Duration, Instant}
+};
+
+use rocket_framework::tokio::time::sleep;
+
+use crate::{
+	adapter::geometry_dash::geometry_dash_client::GeometryDashClient,
+	domain::model::{
+		error::geometry_dash::geometry_dash_dashrs_error::GeometryDashDashrsError,
+		gd_level::GDLevel, moderator::Moderator
+	}
+};
+
+/// Retries level lookups that failed to reach Geometry Dash and stops calling
+/// it altogether for a while after too many consecutive failures. Skipped
+/// calls fail with `CircuitOpenError`.
+pub struct ResilientGeometryDashClient<G: GeometryDashClient> {
+	gd_client: G,
+	max_retries: u32,
+	retry_backoff: Duration,
+	circuit_breaker: CircuitBreaker
+}
+
+impl<G: GeometryDashClient> GeometryDashClient for ResilientGeometryDashClient<G> {
+	async fn get_gd_level_info(&self, level_id: u64) -> Result<GDLevel, GeometryDashDashrsError> {
+		if !self.circuit_breaker.try_acquire() {
+			warn!(
+				"Circuit breaker is open, skipping lookup for level {}",
+				level_id
+			);
+			return Err(GeometryDashDashrsError::CircuitOpenError);
+		}
+
+		let mut attempt = 0;
+		loop {
+			match self.gd_client.get_gd_level_info(level_id).await {
+				Err(GeometryDashDashrsError::HttpError(_)) if attempt < self.max_retries => {
+					let backoff = self.jittered_backoff(attempt);
+					attempt += 1;
+					warn!(
+						"Retrying lookup for level {} in {}ms (attempt {} of {})",
+						level_id,
+						backoff.as_millis(),
+						attempt,
+						self.max_retries
+					);
+					sleep(backoff).await;
+				}
+				gd_level_result => {
+					self.circuit_breaker.record(&gd_level_result);
+					return gd_level_result;
+				}
+			}
+		}
+	}
+
+	async fn send_gd_level(
+		&self,
+		moderator_request: Moderator
+	) -> Result<(), GeometryDashDashrsError> {
+		if !self.circuit_breaker.try_acquire() {
+			warn!(
+				"Circuit breaker is open, skipping send for level {}",
+				moderator_request.level_id
+			);
+			return Err(GeometryDashDashrsError::CircuitOpenError);
+		}
+
+		let send_level_result = self.gd_client.send_gd_level(moderator_request).await;
+		self.circuit_breaker.record(&send_level_result);
+		send_level_result
+	}
+}
+
+impl<G: GeometryDashClient> ResilientGeometryDashClient<G> {
+	pub fn new(
+		gd_client: G,
+		max_retries: u32,
+		retry_backoff: Duration,
+		failure_threshold: u32,
+		reset_timeout: Duration
+	) -> Self {
+		ResilientGeometryDashClient {
+			gd_client,
+			max_retries,
+			retry_backoff,
+			circuit_breaker: CircuitBreaker::new(failure_threshold, reset_timeout)
+		}
+	}
+
+	/// Exponential backoff with equal jitter, so callers that failed together
+	/// do not all retry together.
+	fn jittered_backoff(&self, attempt: u32) -> Duration {
+		let backoff = self
+			.retry_backoff
+			.saturating_mul(2u32.saturating_pow(attempt));
+		let half_backoff_in_millis = (backoff.as_millis() / 2) as u64;
+		Duration::from_millis(half_backoff_in_millis + fastrand::u64(..=half_backoff_in_millis))
+	}
+}
+
+struct CircuitBreaker {
+	failure_threshold: u32,
+	reset_timeout: Duration,
+	state: Mutex<CircuitState>
+}
+
+enum CircuitState {
+	Closed {
+		consecutive_failures: u32
+	},
+	Open {
+		until: Instant
+	},
+	/// A single trial call is allowed through. If it never reports back, for
+	/// example because the request was dropped, another trial is allowed after
+	/// `reset_timeout`.
+	HalfOpen {
+		since: Instant
+	}
+}
+
+impl CircuitBreaker {
+	fn new(failure_threshold: u32, reset_timeout: Duration) -> Self {
+		CircuitBreaker {
+			failure_threshold,
+			reset_timeout,
+			state: Mutex::new(CircuitState::Closed {
+				consecutive_failures: 0
+			})
+		}
+	}
+
+	fn try_acquire(&self) -> bool {
+		let mut state = self.state.lock().unwrap();
+		let now = Instant::now();
+		match *state {
+			CircuitState::Closed { .. } => true,
+			CircuitState::Open { until } if now < until => false,
+			CircuitState::HalfOpen { since } if now < since + self.reset_timeout => false,
+			CircuitState::Open { .. } | CircuitState::HalfOpen { .. } => {
+				info!("Circuit breaker is half open, trying Geometry Dash servers again");
+				*state = CircuitState::HalfOpen { since: now };
+				true
+			}
+		}
+	}
+
+	/// Only failures to reach Geometry Dash count against it. A level that does
+	/// not exist is still a successful call.
+	fn record<T>(&self, result: &Result<T, GeometryDashDashrsError>) {
+		let mut state = self.state.lock().unwrap();
+		match (result, &*state) {
+			(
+				Err(GeometryDashDashrsError::HttpError(_)),
+				CircuitState::Closed {
+					consecutive_failures
+				}
+			) if consecutive_failures + 1 < self.failure_threshold => {
+				*state = CircuitState::Closed {
+					consecutive_failures: consecutive_failures + 1
+				};
+			}
+			(Err(GeometryDashDashrsError::HttpError(_)), _) => {
+				error!(
+					"Geometry Dash servers are unavailable, opening circuit breaker for {}s",
+					self.reset_timeout.as_secs()
+				);
+				*state = CircuitState::Open {
+					until: Instant::now() + self.reset_timeout
+				};
+			}
+			(_, CircuitState::Closed { .. }) => {
+				*state = CircuitState::Closed {
+					consecutive_failures: 0
+				};
+			}
+			_ => {
+				info!("Geometry Dash servers are reachable, closing circuit breaker");
+				*state = CircuitState::Closed {
+					consecutive_failures: 0
+				};
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{sync::Arc, time::Duration};
+
+	use mockall::Sequence;
+	use rocket_framework::tokio::{join, time::sleep};
+
+	use crate::{
+		adapter::geometry_dash::{
+			geometry_dash_client::{GeometryDashClient, MockGeometryDashClient},
+			resilient_geometry_dash_client::ResilientGeometryDashClient
+		},
+		domain::model::{
+			error::geometry_dash::geometry_dash_dashrs_error::GeometryDashDashrsError,
+			gd_level::{FeatureState, GDLevel, LevelCreator, LevelDifficulty, LevelLength},
+			moderator::Moderator
+		}
+	};
+
+	/// Takes a while to answer, so that calls made meanwhile overlap.
+	struct SlowGeometryDashClient(MockGeometryDashClient);
+
+	impl GeometryDashClient for SlowGeometryDashClient {
+		async fn get_gd_level_info(
+			&self,
+			level_id: u64
+		) -> Result<GDLevel, GeometryDashDashrsError> {
+			sleep(Duration::from_millis(50)).await;
+			self.0.get_gd_level_info(level_id).await
+		}
+
+		async fn send_gd_level(
+			&self,
+			moderator_request: Moderator
+		) -> Result<(), GeometryDashDashrsError> {
+			self.0.send_gd_level(moderator_request).await
+		}
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_gd_level_info_should_retry_http_errors_up_to_max_retries() {
+		let http_error = http_error().await;
+		let mut mock_gd_client = MockGeometryDashClient::new();
+		mock_gd_client
+			.expect_get_gd_level_info()
+			.times(3)
+			.returning(move |_| Err(http_error.clone()));
+		let gd_client = resilient_gd_client(mock_gd_client, 2, 10, Duration::from_secs(30));
+
+		assert!(matches!(
+			gd_client.get_gd_level_info(99999999).await,
+			Err(GeometryDashDashrsError::HttpError(_))
+		));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_gd_level_info_should_return_level_once_retry_succeeds() {
+		let http_error = http_error().await;
+		let mut sequence = Sequence::new();
+		let mut mock_gd_client = MockGeometryDashClient::new();
+		mock_gd_client
+			.expect_get_gd_level_info()
+			.times(1)
+			.in_sequence(&mut sequence)
+			.returning(move |_| Err(http_error.clone()));
+		mock_gd_client
+			.expect_get_gd_level_info()
+			.times(1)
+			.in_sequence(&mut sequence)
+			.returning(|_| Ok(gd_level()));
+		let gd_client = resilient_gd_client(mock_gd_client, 2, 10, Duration::from_secs(30));
+
+		assert!(gd_client.get_gd_level_info(99999999).await.is_ok());
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_gd_level_info_should_not_retry_other_errors() {
+		let mut sequence = Sequence::new();
+		let mut mock_gd_client = MockGeometryDashClient::new();
+		mock_gd_client
+			.expect_get_gd_level_info()
+			.times(1)
+			.in_sequence(&mut sequence)
+			.returning(|level_id| Err(GeometryDashDashrsError::LevelNotFoundError(level_id)));
+		mock_gd_client
+			.expect_get_gd_level_info()
+			.times(1)
+			.in_sequence(&mut sequence)
+			.returning(|_| Err(GeometryDashDashrsError::DashrsError(String::from("-1"))));
+		let gd_client = resilient_gd_client(mock_gd_client, 2, 10, Duration::from_secs(30));
+
+		assert_eq!(
+			gd_client.get_gd_level_info(99999999).await.unwrap_err(),
+			GeometryDashDashrsError::LevelNotFoundError(99999999)
+		);
+		assert_eq!(
+			gd_client.get_gd_level_info(99999999).await.unwrap_err(),
+			GeometryDashDashrsError::DashrsError(String::from("-1"))
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_gd_level_info_should_open_then_half_open_then_close_circuit() {
+		let http_error = http_error().await;
+		let mut sequence = Sequence::new();
+		let mut mock_gd_client = MockGeometryDashClient::new();
+		mock_gd_client
+			.expect_get_gd_level_info()
+			.times(2)
+			.in_sequence(&mut sequence)
+			.returning(move |_| Err(http_error.clone()));
+		mock_gd_client
+			.expect_get_gd_level_info()
+			.times(2)
+			.in_sequence(&mut sequence)
+			.returning(|_| Ok(gd_level()));
+		let gd_client = resilient_gd_client(mock_gd_client, 0, 2, Duration::from_millis(100));
+
+		// Closed until the second consecutive failure
+		for _ in 0..2 {
+			assert!(matches!(
+				gd_client.get_gd_level_info(99999999).await,
+				Err(GeometryDashDashrsError::HttpError(_))
+			));
+		}
+		// Open, so Geometry Dash is not called
+		assert_eq!(
+			gd_client.get_gd_level_info(99999999).await.unwrap_err(),
+			GeometryDashDashrsError::CircuitOpenError
+		);
+
+		// Half open once the reset timeout passed, and the trial closes it again
+		sleep(Duration::from_millis(150)).await;
+		assert!(gd_client.get_gd_level_info(99999999).await.is_ok());
+		assert!(gd_client.get_gd_level_info(99999999).await.is_ok());
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_gd_level_info_should_reopen_circuit_when_trial_fails() {
+		let http_error = http_error().await;
+		let mut mock_gd_client = MockGeometryDashClient::new();
+		mock_gd_client
+			.expect_get_gd_level_info()
+			.times(2)
+			.returning(move |_| Err(http_error.clone()));
+		let gd_client = resilient_gd_client(mock_gd_client, 0, 1, Duration::from_millis(100));
+
+		assert!(matches!(
+			gd_client.get_gd_level_info(99999999).await,
+			Err(GeometryDashDashrsError::HttpError(_))
+		));
+		sleep(Duration::from_millis(150)).await;
+		assert!(matches!(
+			gd_client.get_gd_level_info(99999999).await,
+			Err(GeometryDashDashrsError::HttpError(_))
+		));
+		assert_eq!(
+			gd_client.get_gd_level_info(99999999).await.unwrap_err(),
+			GeometryDashDashrsError::CircuitOpenError
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_gd_level_info_should_only_let_one_trial_through_half_open_circuit() {
+		let http_error = http_error().await;
+		let mut sequence = Sequence::new();
+		let mut mock_gd_client = MockGeometryDashClient::new();
+		mock_gd_client
+			.expect_get_gd_level_info()
+			.times(1)
+			.in_sequence(&mut sequence)
+			.returning(move |_| Err(http_error.clone()));
+		mock_gd_client
+			.expect_get_gd_level_info()
+			.times(1)
+			.in_sequence(&mut sequence)
+			.returning(|_| Ok(gd_level()));
+		let gd_client = ResilientGeometryDashClient::new(
+			SlowGeometryDashClient(mock_gd_client),
+			0,
+			Duration::from_millis(10),
+			1,
+			Duration::from_millis(100)
+		);
+
+		assert!(gd_client.get_gd_level_info(99999999).await.is_err());
+		sleep(Duration::from_millis(150)).await;
+
+		let (trial_lookup, concurrent_lookup) = join!(
+			gd_client.get_gd_level_info(99999999),
+			gd_client.get_gd_level_info(99999999)
+		);
+		assert!(trial_lookup.is_ok());
+		assert_eq!(
+			concurrent_lookup.unwrap_err(),
+			GeometryDashDashrsError::CircuitOpenError
+		);
+	}
+
+	fn resilient_gd_client(
+		mock_gd_client: MockGeometryDashClient,
+		max_retries: u32,
+		failure_threshold: u32,
+		reset_timeout: Duration
+	) -> ResilientGeometryDashClient<MockGeometryDashClient> {
+		ResilientGeometryDashClient::new(
+			mock_gd_client,
+			max_retries,
+			Duration::from_millis(10),
+			failure_threshold,
+			reset_timeout
+		)
+	}
+
+	/// A request that failed before it was sent, since it had no valid URL.
+	async fn http_error() -> GeometryDashDashrsError {
+		GeometryDashDashrsError::HttpError(Arc::new(
+			reqwest::Client::new()
+				.get("not a url")
+				.send()
+				.await
+				.unwrap_err()
+		))
+	}
+
+	fn gd_level() -> GDLevel {
+		GDLevel {
+			name: "Level Name".to_string(),
+			description: None,
+			creator: LevelCreator {
+				name: "Level Creator".to_string(),
+				account_id: 1234,
+				player_id: 5678
+			},
+			level_length: LevelLength::Medium,
+			difficulty: LevelDifficulty::Hard,
+			stars: 0,
+			feature_state: FeatureState::NotFeatured,
+			downloads: 100,
+			likes: 10,
+			game_version: None,
+			song: None,
+			object_count: Some(5000),
+			coin_count: 0
+		}
+	}
+}
@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{DbErr, InsertResult};
+
+use crate::adapter::mysql::model::{
+	request_ban, request_ban::ActiveModel, sea_orm_active_enums::BanTargetType
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait BanRepository {
+	async fn create_record(&self, record: ActiveModel) -> Result<InsertResult<ActiveModel>, DbErr>;
+
+	async fn get_record(
+		&self,
+		guild_id: u64,
+		ban_id: u64
+	) -> Result<Option<request_ban::Model>, DbErr>;
+
+	/// The newest ban on the target that has neither been lifted nor expired
+	/// by `now`.
+	async fn get_active_record(
+		&self,
+		guild_id: u64,
+		target_type: BanTargetType,
+		target_id: u64,
+		now: DateTime<Utc>
+	) -> Result<Option<request_ban::Model>, DbErr>;
+
+	/// Every ban in the guild, newest first. Given `active_at`, only bans that
+	/// are active at that time.
+	async fn get_records(
+		&self,
+		guild_id: u64,
+		active_at: Option<DateTime<Utc>>
+	) -> Result<Vec<request_ban::Model>, DbErr>;
+
+	async fn update_record(&self, record: ActiveModel) -> Result<request_ban::Model, DbErr>;
+}
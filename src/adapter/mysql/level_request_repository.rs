@@ -1,4 +1,5 @@
-use sea_orm::{DbErr, DeleteResult, InsertResult};
+use chrono::{DateTime, Utc};
+use sea_orm::{Condition, DbErr, InsertResult, Order};
 
 use crate::adapter::mysql::model::{level_request, level_request::ActiveModel};
 
@@ -6,15 +7,72 @@ use crate::adapter::mysql::model::{level_request, level_request::ActiveModel};
 pub trait LevelRequestRepository {
 	async fn create_record(&self, record: ActiveModel) -> Result<InsertResult<ActiveModel>, DbErr>;
 
-	async fn get_record(&self, level_id: u64) -> Result<Option<level_request::Model>, DbErr>;
+	async fn get_record(
+		&self,
+		guild_id: u64,
+		level_id: u64
+	) -> Result<Option<level_request::Model>, DbErr>;
+
+	async fn get_record_ignore_archived(
+		&self,
+		guild_id: u64,
+		level_id: u64
+	) -> Result<Option<level_request::Model>, DbErr>;
+
+	/// Moves the archived request for the level to the archive tables along
+	/// with its reviews, their revisions and the moderator's decision, drops
+	/// its review assignments, then inserts `record` in its place. Either all
+	/// of it happens or none of it.
+	async fn replace_archived_record(
+		&self,
+		record: ActiveModel
+	) -> Result<InsertResult<ActiveModel>, DbErr>;
 
 	async fn get_record_filter_feedback(
 		&self,
+		guild_id: u64,
 		level_id: u64,
 		has_requested_feedback: bool
 	) -> Result<Option<level_request::Model>, DbErr>;
 
-	async fn update_record(&self, record: ActiveModel) -> Result<level_request::Model, DbErr>;
+	async fn get_records(
+		&self,
+		guild_id: u64,
+		condition: Condition,
+		order: Order,
+		offset: u64,
+		limit: u64
+	) -> Result<Vec<level_request::Model>, DbErr>;
 
-	async fn delete_record(&self, record: ActiveModel) -> Result<DeleteResult, DbErr>;
+	/// Every pending, unarchived level request in the guild, oldest first.
+	async fn get_queued_records(&self, guild_id: u64) -> Result<Vec<level_request::Model>, DbErr>;
+
+	/// Moves in-review, unarchived level requests back to pending when they
+	/// have been assigned for review since they were made but no assignment is
+	/// active at `now` anymore, such as when it expired without a review.
+	/// Returns how many were moved.
+	async fn requeue_unassigned_records(&self, now: DateTime<Utc>) -> Result<u64, DbErr>;
+
+	/// Same as `requeue_unassigned_records`, for a single level request.
+	async fn requeue_unassigned_record(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		now: DateTime<Utc>
+	) -> Result<u64, DbErr>;
+
+	/// Every level request the user made since the given time, oldest first.
+	/// Archived requests are included, including ones replaced by a new
+	/// request for the same level, so that deleting a request does not give
+	/// it back.
+	async fn get_user_records_since(
+		&self,
+		guild_id: u64,
+		discord_id: u64,
+		since: DateTime<Utc>
+	) -> Result<Vec<level_request::Model>, DbErr>;
+
+	async fn count_records(&self, guild_id: u64, condition: Condition) -> Result<u64, DbErr>;
+
+	async fn update_record(&self, record: ActiveModel) -> Result<level_request::Model, DbErr>;
 }
@@ -1,12 +1,26 @@
+pub mod ban_repository;
 pub mod level_request_repository;
 pub(crate) mod model;
 
 pub mod moderator_repository;
 pub mod my_sql_moderator_repository;
+pub mod mysql_ban_repository;
 pub mod mysql_level_request_repository;
+pub mod mysql_request_config_repository;
+pub mod mysql_review_assignment_repository;
 pub mod mysql_review_repository;
+pub mod mysql_review_revision_repository;
+pub mod mysql_reviewer_activity_repository;
 pub mod mysql_reviewer_repository;
+pub mod mysql_revoked_token_repository;
 pub mod mysql_user_repository;
+pub mod mysql_user_role_repository;
+pub mod request_config_repository;
+pub mod review_assignment_repository;
 pub mod review_repository;
+pub mod review_revision_repository;
+pub mod reviewer_activity_repository;
 pub mod reviewer_repository;
+pub mod revoked_token_repository;
 pub mod user_repository;
+pub mod user_role_repository;
@@ -2,16 +2,23 @@
 
 use sea_orm::entity::prelude::*;
 
-use super::sea_orm_active_enums::{LevelLength, RequestRating};
+use super::{
+	sea_orm_active_enums::{
+		FeatureState, LevelDifficulty, LevelLength, RequestRating, RequestStatus
+	},
+	snowflake::Snowflake
+};
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
 #[sea_orm(table_name = "level_request")]
 pub struct Model {
 	#[sea_orm(primary_key, auto_increment = false)]
-	pub level_id: u64,
-	pub discord_id: u64,
+	pub guild_id: Snowflake,
+	#[sea_orm(primary_key, auto_increment = false)]
+	pub level_id: Snowflake,
+	pub discord_id: Snowflake,
 	#[sea_orm(unique)]
-	pub discord_message_id: Option<u64>,
+	pub discord_message_id: Option<Snowflake>,
 	pub name: Option<String>,
 	pub author: Option<String>,
 	pub request_rating: RequestRating,
@@ -19,7 +26,26 @@ pub struct Model {
 	pub you_tube_video_link: String,
 	pub has_requested_feedback: i8,
 	pub notify: i8,
-	pub timestamp: DateTimeUtc
+	pub status: RequestStatus,
+	pub timestamp: DateTimeUtc,
+	pub archived_at: Option<DateTimeUtc>,
+	#[sea_orm(column_type = "Text", nullable)]
+	pub description: Option<String>,
+	pub author_account_id: Option<Snowflake>,
+	pub author_player_id: Option<Snowflake>,
+	pub difficulty: Option<LevelDifficulty>,
+	pub stars: Option<u8>,
+	pub feature_state: Option<FeatureState>,
+	pub downloads: Option<u32>,
+	pub likes: Option<i32>,
+	pub game_version: Option<String>,
+	pub song_id: Option<Snowflake>,
+	pub song_name: Option<String>,
+	pub song_artist: Option<String>,
+	pub is_custom_song: Option<i8>,
+	pub object_count: Option<u32>,
+	pub coin_count: Option<u8>,
+	pub skipped_at: Option<DateTimeUtc>
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -30,8 +56,8 @@ pub enum Relation {
 	Review,
 	#[sea_orm(
 		belongs_to = "super::user::Entity",
-		from = "Column::DiscordId",
-		to = "super::user::Column::DiscordId",
+		from = "(Column::GuildId, Column::DiscordId)",
+		to = "(super::user::Column::GuildId, super::user::Column::DiscordId)",
 		on_update = "NoAction",
 		on_delete = "NoAction"
 	)]
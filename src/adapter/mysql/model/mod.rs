@@ -2,9 +2,20 @@
 
 pub mod prelude;
 
+pub mod archived_level_request;
+pub mod archived_review;
+pub mod archived_review_revision;
 pub mod level_request;
 pub mod moderator;
+pub mod request_ban;
+pub mod request_config;
 pub mod review;
+pub mod review_assignment;
+pub mod review_revision;
 pub mod reviewer;
+pub mod reviewer_activity;
+pub mod revoked_token;
 pub mod sea_orm_active_enums;
+pub mod snowflake;
 pub mod user;
+pub mod user_role;
@@ -2,23 +2,29 @@
 
 use sea_orm::entity::prelude::*;
 
-use super::sea_orm_active_enums::{Rating, Score};
+use super::{
+	sea_orm_active_enums::{Rating, Score},
+	snowflake::Snowflake
+};
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
 #[sea_orm(table_name = "moderator")]
 pub struct Model {
 	#[sea_orm(primary_key, auto_increment = false)]
-	pub level_id: u64,
+	pub guild_id: Snowflake,
+	#[sea_orm(primary_key, auto_increment = false)]
+	pub level_id: Snowflake,
 	pub score: Score,
-	pub rating: Rating
+	pub rating: Rating,
+	pub sent_at: Option<DateTimeUtc>
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
 	#[sea_orm(
 		belongs_to = "super::level_request::Entity",
-		from = "Column::LevelId",
-		to = "super::level_request::Column::LevelId",
+		from = "(Column::GuildId, Column::LevelId)",
+		to = "(super::level_request::Column::GuildId, super::level_request::Column::LevelId)",
 		on_update = "NoAction",
 		on_delete = "Cascade"
 	)]
@@ -1,6 +1,13 @@
 //! `SeaORM` Entity. Generated by sea-orm-codegen 0.12.5
 
 pub use super::{
+	archived_level_request::Entity as ArchivedLevelRequest,
+	archived_review::Entity as ArchivedReview,
+	archived_review_revision::Entity as ArchivedReviewRevision,
 	level_request::Entity as LevelRequest, moderator::Entity as Moderator,
-	review::Entity as Review, reviewer::Entity as Reviewer, user::Entity as User
+	request_ban::Entity as RequestBan, request_config::Entity as RequestConfig,
+	review::Entity as Review, review_assignment::Entity as ReviewAssignment,
+	review_revision::Entity as ReviewRevision, reviewer::Entity as Reviewer,
+	reviewer_activity::Entity as ReviewerActivity, revoked_token::Entity as RevokedToken,
+	user::Entity as User, user_role::Entity as UserRole
 };
@@ -2,31 +2,41 @@
 
 use sea_orm::entity::prelude::*;
 
+use super::{
+	sea_orm_active_enums::{Rating, Score},
+	snowflake::Snowflake
+};
+
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
 #[sea_orm(table_name = "review")]
 pub struct Model {
 	#[sea_orm(primary_key, auto_increment = false)]
-	pub level_id: u64,
+	pub guild_id: Snowflake,
+	#[sea_orm(primary_key, auto_increment = false)]
+	pub level_id: Snowflake,
 	#[sea_orm(primary_key, auto_increment = false)]
-	pub discord_id: u64,
-	pub message_id: u64,
-	pub review_content: String
+	pub discord_id: Snowflake,
+	pub message_id: Snowflake,
+	pub review_content: String,
+	pub rubric_scores: Option<Json>,
+	pub suggested_score: Option<Score>,
+	pub suggested_rating: Option<Rating>
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
 	#[sea_orm(
 		belongs_to = "super::level_request::Entity",
-		from = "Column::LevelId",
-		to = "super::level_request::Column::LevelId",
+		from = "(Column::GuildId, Column::LevelId)",
+		to = "(super::level_request::Column::GuildId, super::level_request::Column::LevelId)",
 		on_update = "NoAction",
 		on_delete = "Cascade"
 	)]
 	LevelRequest,
 	#[sea_orm(
 		belongs_to = "super::reviewer::Entity",
-		from = "Column::DiscordId",
-		to = "super::reviewer::Column::DiscordId",
+		from = "(Column::GuildId, Column::DiscordId)",
+		to = "(super::reviewer::Column::GuildId, super::reviewer::Column::DiscordId)",
 		on_update = "NoAction",
 		on_delete = "NoAction"
 	)]
@@ -2,11 +2,15 @@
 
 use sea_orm::entity::prelude::*;
 
+use super::snowflake::Snowflake;
+
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
 #[sea_orm(table_name = "reviewer")]
 pub struct Model {
 	#[sea_orm(primary_key, auto_increment = false)]
-	pub discord_id: u64,
+	pub guild_id: Snowflake,
+	#[sea_orm(primary_key, auto_increment = false)]
+	pub discord_id: Snowflake,
 	pub active: i8
 }
 
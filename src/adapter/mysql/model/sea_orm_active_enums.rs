@@ -3,7 +3,53 @@
 use sea_orm::entity::prelude::*;
 
 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
-#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "level_length")]
+#[sea_orm(rs_type = "String", db_type = "String(None)")]
+pub enum BanTargetType {
+	#[sea_orm(string_value = "user")]
+	User,
+	#[sea_orm(string_value = "level")]
+	Level
+}
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "String(None)")]
+pub enum FeatureState {
+	#[sea_orm(string_value = "not_featured")]
+	NotFeatured,
+	#[sea_orm(string_value = "featured")]
+	Featured,
+	#[sea_orm(string_value = "epic")]
+	Epic
+}
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "String(None)")]
+pub enum LevelDifficulty {
+	#[sea_orm(string_value = "not_available")]
+	NotAvailable,
+	#[sea_orm(string_value = "auto")]
+	Auto,
+	#[sea_orm(string_value = "easy")]
+	Easy,
+	#[sea_orm(string_value = "normal")]
+	Normal,
+	#[sea_orm(string_value = "hard")]
+	Hard,
+	#[sea_orm(string_value = "harder")]
+	Harder,
+	#[sea_orm(string_value = "insane")]
+	Insane,
+	#[sea_orm(string_value = "easy_demon")]
+	EasyDemon,
+	#[sea_orm(string_value = "medium_demon")]
+	MediumDemon,
+	#[sea_orm(string_value = "hard_demon")]
+	HardDemon,
+	#[sea_orm(string_value = "insane_demon")]
+	InsaneDemon,
+	#[sea_orm(string_value = "extreme_demon")]
+	ExtremeDemon
+}
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "String(None)")]
 pub enum LevelLength {
 	#[sea_orm(string_value = "tiny")]
 	Tiny,
@@ -19,7 +65,19 @@ pub enum LevelLength {
 	Platformer
 }
 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
-#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "rating")]
+#[sea_orm(rs_type = "String", db_type = "String(None)")]
+pub enum QueuePolicy {
+	#[sea_orm(string_value = "fifo")]
+	Fifo,
+	#[sea_orm(string_value = "request_rating")]
+	RequestRating,
+	#[sea_orm(string_value = "feedback_first")]
+	FeedbackFirst,
+	#[sea_orm(string_value = "round_robin")]
+	RoundRobin
+}
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "String(None)")]
 pub enum Rating {
 	#[sea_orm(string_value = "rate")]
 	Rate,
@@ -33,7 +91,7 @@ pub enum Rating {
 	Mythic
 }
 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
-#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "request_rating")]
+#[sea_orm(rs_type = "String", db_type = "String(None)")]
 pub enum RequestRating {
 	#[sea_orm(string_value = "one")]
 	One,
@@ -57,7 +115,29 @@ pub enum RequestRating {
 	Ten
 }
 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
-#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "score")]
+#[sea_orm(rs_type = "String", db_type = "String(None)")]
+pub enum RequestStatus {
+	#[sea_orm(string_value = "pending")]
+	Pending,
+	#[sea_orm(string_value = "in_review")]
+	InReview,
+	#[sea_orm(string_value = "reviewed")]
+	Reviewed,
+	#[sea_orm(string_value = "sent")]
+	Sent,
+	#[sea_orm(string_value = "rejected")]
+	Rejected
+}
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "String(None)")]
+pub enum Role {
+	#[sea_orm(string_value = "moderator")]
+	Moderator,
+	#[sea_orm(string_value = "admin")]
+	Admin
+}
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "String(None)")]
 pub enum Score {
 	#[sea_orm(string_value = "no_rate")]
 	NoRate,
@@ -0,0 +1,68 @@
+use sea_orm::{
+	sea_query::{ArrayType, Nullable, ValueType, ValueTypeErr},
+	ColIdx, ColumnType, DbErr, QueryResult, TryFromU64, TryGetError, TryGetable, Value
+};
+
+/// Discord and Geometry Dash identifiers are unsigned 64 bit integers. MySQL
+/// stores them as `BIGINT UNSIGNED` while SQLite only has signed integers, so
+/// values are read back as `i64` when the backend is unable to decode a `u64`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Snowflake(pub u64);
+
+impl From<u64> for Snowflake {
+	fn from(value: u64) -> Self { Snowflake(value) }
+}
+
+impl From<Snowflake> for u64 {
+	fn from(value: Snowflake) -> Self { value.0 }
+}
+
+impl From<Snowflake> for Value {
+	fn from(value: Snowflake) -> Self { Value::BigUnsigned(Some(value.0)) }
+}
+
+impl TryGetable for Snowflake {
+	fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+		match u64::try_get_by(res, index) {
+			Ok(value) => Ok(Snowflake(value)),
+			Err(TryGetError::DbErr(_)) => {
+				let value = i64::try_get_by(res, index)?;
+				<u64 as TryFrom<i64>>::try_from(value)
+					.map(Snowflake)
+					.map_err(|_| {
+						TryGetError::DbErr(DbErr::Type(format!(
+							"{} is not a valid unsigned identifier",
+							value
+						)))
+					})
+			}
+			Err(err) => Err(err)
+		}
+	}
+}
+
+impl ValueType for Snowflake {
+	fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+		match v {
+			Value::BigUnsigned(Some(value)) => Ok(Snowflake(value)),
+			Value::BigInt(Some(value)) => <u64 as TryFrom<i64>>::try_from(value)
+				.map(Snowflake)
+				.map_err(|_| ValueTypeErr),
+			_ => Err(ValueTypeErr)
+		}
+	}
+
+	fn type_name() -> String { stringify!(Snowflake).to_owned() }
+
+	fn array_type() -> ArrayType { ArrayType::BigUnsigned }
+
+	fn column_type() -> ColumnType { ColumnType::BigUnsigned }
+}
+
+impl Nullable for Snowflake {
+	fn null() -> Value { Value::BigUnsigned(None) }
+}
+
+impl TryFromU64 for Snowflake {
+	fn try_from_u64(n: u64) -> Result<Self, DbErr> { Ok(Snowflake(n)) }
+}
@@ -2,12 +2,20 @@
 
 use sea_orm::entity::prelude::*;
 
+use super::snowflake::Snowflake;
+
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
 #[sea_orm(table_name = "user")]
 pub struct Model {
 	#[sea_orm(primary_key, auto_increment = false)]
-	pub discord_id: u64,
-	pub timestamp: Option<DateTimeUtc>
+	pub guild_id: Snowflake,
+	#[sea_orm(primary_key, auto_increment = false)]
+	pub discord_id: Snowflake,
+	pub timestamp: Option<DateTimeUtc>,
+	pub cooldown_duration: Option<i32>,
+	pub cooldown_exempt: i8,
+	pub max_open_requests: Option<u32>,
+	pub daily_request_quota: Option<u32>
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
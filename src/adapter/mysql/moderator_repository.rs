@@ -1,4 +1,5 @@
-use sea_orm::{DbErr, DeleteResult, InsertResult};
+use chrono::{DateTime, Utc};
+use sea_orm::{DbErr, InsertResult};
 
 use crate::adapter::mysql::model::moderator;
 
@@ -9,12 +10,22 @@ pub trait ModeratorRepository {
 		record: moderator::ActiveModel
 	) -> Result<InsertResult<moderator::ActiveModel>, DbErr>;
 
-	async fn get_record(&self, level_id: u64) -> Result<Option<moderator::Model>, DbErr>;
+	async fn get_record(
+		&self,
+		guild_id: u64,
+		level_id: u64
+	) -> Result<Option<moderator::Model>, DbErr>;
+
+	/// Given `discord_id`, only levels that reviewer reviewed.
+	async fn get_sent_records(
+		&self,
+		guild_id: u64,
+		discord_id: Option<u64>,
+		sent_from: Option<DateTime<Utc>>
+	) -> Result<Vec<moderator::Model>, DbErr>;
 
 	async fn update_record(
 		&self,
 		record: moderator::ActiveModel
 	) -> Result<moderator::Model, DbErr>;
-
-	async fn delete_record(&self, record: moderator::ActiveModel) -> Result<DeleteResult, DbErr>;
 }
@@ -1,35 +1,70 @@
-use sea_orm::{DatabaseConnection, DbConn, DbErr, DeleteResult, EntityTrait, InsertResult};
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use sea_orm::{
+	ColumnTrait, Condition, DatabaseConnection, DbConn, DbErr, EntityTrait, InsertResult,
+	QueryFilter, QuerySelect, QueryTrait
+};
 
 use crate::adapter::mysql::{
 	model::{
+		moderator,
 		moderator::{ActiveModel, Model},
-		prelude::Moderator
+		prelude::{Moderator, Review},
+		review
 	},
 	moderator_repository::ModeratorRepository
 };
 
-pub struct MySqlModeratorRepository<'a> {
-	db_conn: &'a DatabaseConnection
+pub struct MySqlModeratorRepository {
+	db_conn: Arc<DatabaseConnection>
 }
 
-impl<'a> ModeratorRepository for MySqlModeratorRepository<'a> {
+impl ModeratorRepository for MySqlModeratorRepository {
 	async fn create_record(&self, record: ActiveModel) -> Result<InsertResult<ActiveModel>, DbErr> {
-		Moderator::insert(record).exec(self.db_conn).await
+		Moderator::insert(record).exec(self.db_conn.as_ref()).await
 	}
 
-	async fn get_record(&self, level_id: u64) -> Result<Option<Model>, DbErr> {
-		Moderator::find_by_id(level_id).one(self.db_conn).await
+	async fn get_record(&self, guild_id: u64, level_id: u64) -> Result<Option<Model>, DbErr> {
+		Moderator::find_by_id((guild_id.into(), level_id.into()))
+			.one(self.db_conn.as_ref())
+			.await
 	}
 
-	async fn update_record(&self, record: ActiveModel) -> Result<Model, DbErr> {
-		Moderator::update(record).exec(self.db_conn).await
+	async fn get_sent_records(
+		&self,
+		guild_id: u64,
+		discord_id: Option<u64>,
+		sent_from: Option<DateTime<Utc>>
+	) -> Result<Vec<Model>, DbErr> {
+		let reviewed_level_ids = discord_id.map(|discord_id| {
+			Review::find()
+				.select_only()
+				.column(review::Column::LevelId)
+				.filter(review::Column::GuildId.eq(guild_id))
+				.filter(review::Column::DiscordId.eq(discord_id))
+				.into_query()
+		});
+
+		Moderator::find()
+			.filter(moderator::Column::GuildId.eq(guild_id))
+			.filter(moderator::Column::SentAt.is_not_null())
+			.filter(
+				Condition::all()
+					.add_option(sent_from.map(|sent_from| moderator::Column::SentAt.gte(sent_from)))
+					.add_option(reviewed_level_ids.map(|reviewed_level_ids| {
+						moderator::Column::LevelId.in_subquery(reviewed_level_ids)
+					}))
+			)
+			.all(self.db_conn.as_ref())
+			.await
 	}
 
-	async fn delete_record(&self, record: ActiveModel) -> Result<DeleteResult, DbErr> {
-		Moderator::delete(record).exec(self.db_conn).await
+	async fn update_record(&self, record: ActiveModel) -> Result<Model, DbErr> {
+		Moderator::update(record).exec(self.db_conn.as_ref()).await
 	}
 }
 
-impl<'a> MySqlModeratorRepository<'a> {
-	pub fn new(db_conn: &'a DbConn) -> Self { MySqlModeratorRepository { db_conn } }
+impl MySqlModeratorRepository {
+	pub fn new(db_conn: Arc<DbConn>) -> Self { MySqlModeratorRepository { db_conn } }
 }
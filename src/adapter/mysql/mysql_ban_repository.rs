@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use sea_orm::{
+	ColumnTrait, Condition, DatabaseConnection, DbConn, DbErr, EntityTrait, InsertResult, Order,
+	QueryFilter, QueryOrder
+};
+
+use crate::adapter::mysql::{
+	ban_repository::BanRepository,
+	model::{prelude::RequestBan, request_ban, sea_orm_active_enums::BanTargetType}
+};
+
+pub struct MySqlBanRepository {
+	db_conn: Arc<DatabaseConnection>
+}
+
+impl BanRepository for MySqlBanRepository {
+	async fn create_record(
+		&self,
+		record: request_ban::ActiveModel
+	) -> Result<InsertResult<request_ban::ActiveModel>, DbErr> {
+		RequestBan::insert(record).exec(self.db_conn.as_ref()).await
+	}
+
+	async fn get_record(
+		&self,
+		guild_id: u64,
+		ban_id: u64
+	) -> Result<Option<request_ban::Model>, DbErr> {
+		RequestBan::find_by_id(ban_id as i32)
+			.filter(request_ban::Column::GuildId.eq(guild_id))
+			.one(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn get_active_record(
+		&self,
+		guild_id: u64,
+		target_type: BanTargetType,
+		target_id: u64,
+		now: DateTime<Utc>
+	) -> Result<Option<request_ban::Model>, DbErr> {
+		RequestBan::find()
+			.filter(request_ban::Column::GuildId.eq(guild_id))
+			.filter(request_ban::Column::TargetType.eq(target_type))
+			.filter(request_ban::Column::TargetId.eq(target_id))
+			.filter(Self::active_condition(now))
+			.order_by(request_ban::Column::CreatedAt, Order::Desc)
+			.one(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn get_records(
+		&self,
+		guild_id: u64,
+		active_at: Option<DateTime<Utc>>
+	) -> Result<Vec<request_ban::Model>, DbErr> {
+		let mut select = RequestBan::find().filter(request_ban::Column::GuildId.eq(guild_id));
+		if let Some(active_at) = active_at {
+			select = select.filter(Self::active_condition(active_at));
+		}
+
+		select
+			.order_by(request_ban::Column::CreatedAt, Order::Desc)
+			.all(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn update_record(
+		&self,
+		record: request_ban::ActiveModel
+	) -> Result<request_ban::Model, DbErr> {
+		RequestBan::update(record).exec(self.db_conn.as_ref()).await
+	}
+}
+
+impl MySqlBanRepository {
+	pub fn new(db_conn: Arc<DbConn>) -> Self { MySqlBanRepository { db_conn } }
+
+	/// Bans that have not been lifted and have not expired by `now`.
+	fn active_condition(now: DateTime<Utc>) -> Condition {
+		Condition::all()
+			.add(request_ban::Column::LiftedAt.is_null())
+			.add(
+				Condition::any()
+					.add(request_ban::Column::ExpiresAt.is_null())
+					.add(request_ban::Column::ExpiresAt.gt(now))
+			)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+
+	use chrono::{Duration, Utc};
+	use sea_orm::{DatabaseBackend, MockDatabase};
+
+	use crate::{
+		adapter::mysql::{
+			ban_repository::BanRepository, model::sea_orm_active_enums::BanTargetType,
+			mysql_ban_repository::MySqlBanRepository
+		},
+		rocket::common::testing::ban_model
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_get_active_record_should_skip_lifted_and_expired_bans() {
+		let ban = ban_model(1, 1, BanTargetType::User);
+		let db_conn = Arc::new(
+			MockDatabase::new(DatabaseBackend::MySql)
+				.append_query_results([vec![ban.clone()]])
+				.into_connection()
+		);
+		let repository = MySqlBanRepository::new(db_conn.clone());
+
+		assert_eq!(
+			repository
+				.get_active_record(1, BanTargetType::User, 12345, Utc::now())
+				.await
+				.unwrap(),
+			Some(ban)
+		);
+
+		drop(repository);
+		let transaction_log = format!(
+			"{:?}",
+			Arc::try_unwrap(db_conn).unwrap().into_transaction_log()
+		);
+		assert!(transaction_log.contains("`lifted_at` IS NULL"));
+		assert!(transaction_log.contains("`expires_at` IS NULL OR `request_ban`.`expires_at` > ?"));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_records_should_include_inactive_bans_without_active_at() {
+		let mut lifted_ban = ban_model(2, 1, BanTargetType::Level);
+		lifted_ban.lifted_at = Some(Utc::now() - Duration::hours(1));
+		lifted_ban.lifted_by = Some(0.into());
+		let bans = vec![lifted_ban, ban_model(1, 1, BanTargetType::User)];
+		let db_conn = Arc::new(
+			MockDatabase::new(DatabaseBackend::MySql)
+				.append_query_results([bans.clone()])
+				.into_connection()
+		);
+		let repository = MySqlBanRepository::new(db_conn.clone());
+
+		assert_eq!(repository.get_records(1, None).await.unwrap(), bans);
+
+		drop(repository);
+		let transaction_log = format!(
+			"{:?}",
+			Arc::try_unwrap(db_conn).unwrap().into_transaction_log()
+		);
+		assert!(!transaction_log.contains("`lifted_at` IS NULL"));
+	}
+}
@@ -1,37 +1,258 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
 use sea_orm::{
-	ColumnTrait, DatabaseConnection, DbConn, DbErr, DeleteResult, EntityTrait, InsertResult,
-	QueryFilter
+	sea_query::Expr, ActiveValue, ColumnTrait, Condition, DatabaseConnection, DbConn, DbErr,
+	EntityTrait, InsertResult, Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+	QueryTrait, Select, TransactionTrait
 };
 
 use crate::adapter::mysql::{
 	level_request_repository::LevelRequestRepository,
-	model::{level_request, prelude::LevelRequest}
+	model::{
+		archived_level_request, archived_review, archived_review_revision, level_request,
+		moderator,
+		prelude::{
+			ArchivedLevelRequest, ArchivedReview, ArchivedReviewRevision, LevelRequest, Moderator,
+			Review, ReviewAssignment, ReviewRevision
+		},
+		review, review_assignment, review_revision, sea_orm_active_enums
+	},
+	mysql_review_assignment_repository::MySqlReviewAssignmentRepository
 };
 
-pub struct MySqlLevelRequestRepository<'a> {
-	db_conn: &'a DatabaseConnection
+pub struct MySqlLevelRequestRepository {
+	db_conn: Arc<DatabaseConnection>
 }
 
-impl<'a> LevelRequestRepository for MySqlLevelRequestRepository<'a> {
+impl LevelRequestRepository for MySqlLevelRequestRepository {
 	async fn create_record(
 		&self,
 		record: level_request::ActiveModel
 	) -> Result<InsertResult<level_request::ActiveModel>, DbErr> {
-		LevelRequest::insert(record).exec(self.db_conn).await
+		LevelRequest::insert(record)
+			.exec(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn get_record(
+		&self,
+		guild_id: u64,
+		level_id: u64
+	) -> Result<Option<level_request::Model>, DbErr> {
+		LevelRequest::find_by_id((guild_id.into(), level_id.into()))
+			.filter(level_request::Column::ArchivedAt.is_null())
+			.one(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn get_record_ignore_archived(
+		&self,
+		guild_id: u64,
+		level_id: u64
+	) -> Result<Option<level_request::Model>, DbErr> {
+		LevelRequest::find_by_id((guild_id.into(), level_id.into()))
+			.one(self.db_conn.as_ref())
+			.await
 	}
 
-	async fn get_record(&self, level_id: u64) -> Result<Option<level_request::Model>, DbErr> {
-		LevelRequest::find_by_id(level_id).one(self.db_conn).await
+	async fn replace_archived_record(
+		&self,
+		record: level_request::ActiveModel
+	) -> Result<InsertResult<level_request::ActiveModel>, DbErr> {
+		let guild_id = record.guild_id.clone().unwrap();
+		let level_id = record.level_id.clone().unwrap();
+		let txn = self.db_conn.begin().await?;
+
+		let archived_level_request = LevelRequest::find_by_id((guild_id, level_id))
+			.filter(level_request::Column::ArchivedAt.is_not_null())
+			.one(&txn)
+			.await?
+			.ok_or(DbErr::RecordNotFound(format!(
+				"Archived level request for {}",
+				u64::from(level_id)
+			)))?;
+		let moderator = Moderator::find_by_id((guild_id, level_id))
+			.one(&txn)
+			.await?;
+		let archived_level_request_id = ArchivedLevelRequest::insert(
+			Self::archived_level_request_storable(archived_level_request, moderator)
+		)
+		.exec(&txn)
+		.await?
+		.last_insert_id;
+
+		let reviews = Review::find()
+			.filter(review::Column::GuildId.eq(guild_id))
+			.filter(review::Column::LevelId.eq(level_id))
+			.all(&txn)
+			.await?;
+		for review in reviews {
+			let review_revisions = ReviewRevision::find()
+				.filter(review_revision::Column::GuildId.eq(guild_id))
+				.filter(review_revision::Column::LevelId.eq(level_id))
+				.filter(review_revision::Column::DiscordId.eq(review.discord_id))
+				.all(&txn)
+				.await?;
+			let archived_review_id = ArchivedReview::insert(archived_review::ActiveModel {
+				id: ActiveValue::NotSet,
+				archived_level_request_id: ActiveValue::Set(archived_level_request_id),
+				guild_id: ActiveValue::Set(review.guild_id),
+				level_id: ActiveValue::Set(review.level_id),
+				discord_id: ActiveValue::Set(review.discord_id),
+				message_id: ActiveValue::Set(review.message_id),
+				review_content: ActiveValue::Set(review.review_content),
+				rubric_scores: ActiveValue::Set(review.rubric_scores),
+				suggested_score: ActiveValue::Set(review.suggested_score),
+				suggested_rating: ActiveValue::Set(review.suggested_rating)
+			})
+			.exec(&txn)
+			.await?
+			.last_insert_id;
+			if !review_revisions.is_empty() {
+				ArchivedReviewRevision::insert_many(review_revisions.into_iter().map(
+					|review_revision| archived_review_revision::ActiveModel {
+						id: ActiveValue::NotSet,
+						archived_review_id: ActiveValue::Set(archived_review_id),
+						revision: ActiveValue::Set(review_revision.revision),
+						message_id: ActiveValue::Set(review_revision.message_id),
+						review_content: ActiveValue::Set(review_revision.review_content),
+						created_at: ActiveValue::Set(review_revision.created_at),
+						rubric_scores: ActiveValue::Set(review_revision.rubric_scores),
+						suggested_score: ActiveValue::Set(review_revision.suggested_score),
+						suggested_rating: ActiveValue::Set(review_revision.suggested_rating)
+					}
+				))
+				.exec(&txn)
+				.await?;
+			}
+		}
+
+		ReviewRevision::delete_many()
+			.filter(review_revision::Column::GuildId.eq(guild_id))
+			.filter(review_revision::Column::LevelId.eq(level_id))
+			.exec(&txn)
+			.await?;
+		Review::delete_many()
+			.filter(review::Column::GuildId.eq(guild_id))
+			.filter(review::Column::LevelId.eq(level_id))
+			.exec(&txn)
+			.await?;
+		Moderator::delete_many()
+			.filter(moderator::Column::GuildId.eq(guild_id))
+			.filter(moderator::Column::LevelId.eq(level_id))
+			.exec(&txn)
+			.await?;
+		ReviewAssignment::delete_many()
+			.filter(review_assignment::Column::GuildId.eq(guild_id))
+			.filter(review_assignment::Column::LevelId.eq(level_id))
+			.exec(&txn)
+			.await?;
+		LevelRequest::delete_by_id((guild_id, level_id))
+			.exec(&txn)
+			.await?;
+		let insert_result = LevelRequest::insert(record).exec(&txn).await?;
+
+		txn.commit().await?;
+		Ok(insert_result)
 	}
 
 	async fn get_record_filter_feedback(
 		&self,
+		guild_id: u64,
 		level_id: u64,
 		has_requested_feedback: bool
 	) -> Result<Option<level_request::Model>, DbErr> {
-		LevelRequest::find_by_id(level_id)
+		LevelRequest::find_by_id((guild_id.into(), level_id.into()))
 			.filter(level_request::Column::HasRequestedFeedback.eq(has_requested_feedback))
-			.one(self.db_conn)
+			.filter(level_request::Column::ArchivedAt.is_null())
+			.one(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn get_records(
+		&self,
+		guild_id: u64,
+		condition: Condition,
+		order: Order,
+		offset: u64,
+		limit: u64
+	) -> Result<Vec<level_request::Model>, DbErr> {
+		LevelRequest::find()
+			.filter(level_request::Column::GuildId.eq(guild_id))
+			.filter(condition)
+			.filter(level_request::Column::ArchivedAt.is_null())
+			.order_by(level_request::Column::Timestamp, order.clone())
+			.order_by(level_request::Column::LevelId, order)
+			.offset(offset)
+			.limit(limit)
+			.all(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn get_queued_records(&self, guild_id: u64) -> Result<Vec<level_request::Model>, DbErr> {
+		LevelRequest::find()
+			.filter(level_request::Column::GuildId.eq(guild_id))
+			.filter(level_request::Column::Status.eq(sea_orm_active_enums::RequestStatus::Pending))
+			.filter(level_request::Column::ArchivedAt.is_null())
+			.order_by(level_request::Column::Timestamp, Order::Asc)
+			.all(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn requeue_unassigned_records(&self, now: DateTime<Utc>) -> Result<u64, DbErr> {
+		self.requeue_unassigned(Condition::all(), now).await
+	}
+
+	async fn requeue_unassigned_record(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		now: DateTime<Utc>
+	) -> Result<u64, DbErr> {
+		self.requeue_unassigned(
+			Condition::all()
+				.add(level_request::Column::GuildId.eq(guild_id))
+				.add(level_request::Column::LevelId.eq(level_id)),
+			now
+		)
+		.await
+	}
+
+	async fn get_user_records_since(
+		&self,
+		guild_id: u64,
+		discord_id: u64,
+		since: DateTime<Utc>
+	) -> Result<Vec<level_request::Model>, DbErr> {
+		let mut level_requests = LevelRequest::find()
+			.filter(level_request::Column::GuildId.eq(guild_id))
+			.filter(level_request::Column::DiscordId.eq(discord_id))
+			.filter(level_request::Column::Timestamp.gte(since))
+			.order_by(level_request::Column::Timestamp, Order::Asc)
+			.all(self.db_conn.as_ref())
+			.await?;
+		let archived_level_requests = ArchivedLevelRequest::find()
+			.filter(archived_level_request::Column::GuildId.eq(guild_id))
+			.filter(archived_level_request::Column::DiscordId.eq(discord_id))
+			.filter(archived_level_request::Column::Timestamp.gte(since))
+			.all(self.db_conn.as_ref())
+			.await?;
+		level_requests.extend(
+			archived_level_requests
+				.into_iter()
+				.map(Self::unarchived_level_request_model)
+		);
+		level_requests.sort_by_key(|level_request| level_request.timestamp);
+		Ok(level_requests)
+	}
+
+	async fn count_records(&self, guild_id: u64, condition: Condition) -> Result<u64, DbErr> {
+		LevelRequest::find()
+			.filter(level_request::Column::GuildId.eq(guild_id))
+			.filter(condition)
+			.filter(level_request::Column::ArchivedAt.is_null())
+			.count(self.db_conn.as_ref())
 			.await
 	}
 
@@ -39,226 +260,517 @@ impl<'a> LevelRequestRepository for MySqlLevelRequestRepository<'a> {
 		&self,
 		record: level_request::ActiveModel
 	) -> Result<level_request::Model, DbErr> {
-		LevelRequest::update(record).exec(self.db_conn).await
+		LevelRequest::update(record)
+			.exec(self.db_conn.as_ref())
+			.await
 	}
+}
+
+impl MySqlLevelRequestRepository {
+	pub fn new(db_conn: Arc<DbConn>) -> Self { MySqlLevelRequestRepository { db_conn } }
 
-	async fn delete_record(
+	async fn requeue_unassigned(
 		&self,
-		record: level_request::ActiveModel
-	) -> Result<DeleteResult, DbErr> {
-		LevelRequest::delete(record).exec(self.db_conn).await
+		condition: Condition,
+		now: DateTime<Utc>
+	) -> Result<u64, DbErr> {
+		LevelRequest::update_many()
+			.col_expr(
+				level_request::Column::Status,
+				Expr::value(sea_orm_active_enums::RequestStatus::Pending)
+			)
+			.filter(condition)
+			.filter(level_request::Column::Status.eq(sea_orm_active_enums::RequestStatus::InReview))
+			.filter(level_request::Column::ArchivedAt.is_null())
+			.filter(Expr::exists(Self::request_assignments().into_query()))
+			.filter(
+				Expr::exists(
+					Self::request_assignments()
+						.filter(MySqlReviewAssignmentRepository::active_condition(now))
+						.into_query()
+				)
+				.not()
+			)
+			.exec(self.db_conn.as_ref())
+			.await
+			.map(|update_result| update_result.rows_affected)
 	}
-}
 
-impl<'a> MySqlLevelRequestRepository<'a> {
-	pub fn new(db_conn: &'a DbConn) -> Self { MySqlLevelRequestRepository { db_conn } }
+	/// Assignments of the level made since the current request for it, so
+	/// that ones left over from an earlier request do not count.
+	fn request_assignments() -> Select<ReviewAssignment> {
+		ReviewAssignment::find()
+			.select_only()
+			.column(review_assignment::Column::Id)
+			.filter(
+				Expr::col((ReviewAssignment, review_assignment::Column::GuildId))
+					.equals((LevelRequest, level_request::Column::GuildId))
+			)
+			.filter(
+				Expr::col((ReviewAssignment, review_assignment::Column::LevelId))
+					.equals((LevelRequest, level_request::Column::LevelId))
+			)
+			.filter(
+				Expr::col((ReviewAssignment, review_assignment::Column::AssignedAt))
+					.gte(Expr::col((LevelRequest, level_request::Column::Timestamp)))
+			)
+	}
+
+	fn archived_level_request_storable(
+		level_request: level_request::Model,
+		moderator: Option<moderator::Model>
+	) -> archived_level_request::ActiveModel {
+		archived_level_request::ActiveModel {
+			id: ActiveValue::NotSet,
+			guild_id: ActiveValue::Set(level_request.guild_id),
+			level_id: ActiveValue::Set(level_request.level_id),
+			discord_id: ActiveValue::Set(level_request.discord_id),
+			discord_message_id: ActiveValue::Set(level_request.discord_message_id),
+			name: ActiveValue::Set(level_request.name),
+			author: ActiveValue::Set(level_request.author),
+			request_rating: ActiveValue::Set(level_request.request_rating),
+			level_length: ActiveValue::Set(level_request.level_length),
+			you_tube_video_link: ActiveValue::Set(level_request.you_tube_video_link),
+			has_requested_feedback: ActiveValue::Set(level_request.has_requested_feedback),
+			notify: ActiveValue::Set(level_request.notify),
+			status: ActiveValue::Set(level_request.status),
+			timestamp: ActiveValue::Set(level_request.timestamp),
+			archived_at: ActiveValue::Set(level_request.archived_at.unwrap_or_else(Utc::now)),
+			description: ActiveValue::Set(level_request.description),
+			author_account_id: ActiveValue::Set(level_request.author_account_id),
+			author_player_id: ActiveValue::Set(level_request.author_player_id),
+			difficulty: ActiveValue::Set(level_request.difficulty),
+			stars: ActiveValue::Set(level_request.stars),
+			feature_state: ActiveValue::Set(level_request.feature_state),
+			downloads: ActiveValue::Set(level_request.downloads),
+			likes: ActiveValue::Set(level_request.likes),
+			game_version: ActiveValue::Set(level_request.game_version),
+			song_id: ActiveValue::Set(level_request.song_id),
+			song_name: ActiveValue::Set(level_request.song_name),
+			song_artist: ActiveValue::Set(level_request.song_artist),
+			is_custom_song: ActiveValue::Set(level_request.is_custom_song),
+			object_count: ActiveValue::Set(level_request.object_count),
+			coin_count: ActiveValue::Set(level_request.coin_count),
+			skipped_at: ActiveValue::Set(level_request.skipped_at),
+			moderator_score: ActiveValue::Set(
+				moderator.as_ref().map(|moderator| moderator.score.clone())
+			),
+			moderator_rating: ActiveValue::Set(
+				moderator.as_ref().map(|moderator| moderator.rating.clone())
+			),
+			moderator_sent_at: ActiveValue::Set(moderator.and_then(|moderator| moderator.sent_at))
+		}
+	}
+
+	pub(crate) fn unarchived_level_request_model(
+		archived_level_request: archived_level_request::Model
+	) -> level_request::Model {
+		level_request::Model {
+			guild_id: archived_level_request.guild_id,
+			level_id: archived_level_request.level_id,
+			discord_id: archived_level_request.discord_id,
+			discord_message_id: archived_level_request.discord_message_id,
+			name: archived_level_request.name,
+			author: archived_level_request.author,
+			request_rating: archived_level_request.request_rating,
+			level_length: archived_level_request.level_length,
+			you_tube_video_link: archived_level_request.you_tube_video_link,
+			has_requested_feedback: archived_level_request.has_requested_feedback,
+			notify: archived_level_request.notify,
+			status: archived_level_request.status,
+			timestamp: archived_level_request.timestamp,
+			archived_at: Some(archived_level_request.archived_at),
+			description: archived_level_request.description,
+			author_account_id: archived_level_request.author_account_id,
+			author_player_id: archived_level_request.author_player_id,
+			difficulty: archived_level_request.difficulty,
+			stars: archived_level_request.stars,
+			feature_state: archived_level_request.feature_state,
+			downloads: archived_level_request.downloads,
+			likes: archived_level_request.likes,
+			game_version: archived_level_request.game_version,
+			song_id: archived_level_request.song_id,
+			song_name: archived_level_request.song_name,
+			song_artist: archived_level_request.song_artist,
+			is_custom_song: archived_level_request.is_custom_song,
+			object_count: archived_level_request.object_count,
+			coin_count: archived_level_request.coin_count,
+			skipped_at: archived_level_request.skipped_at
+		}
+	}
 }
 
-// #[cfg(test)]
-// mod tests {
-// 	use rocket::tokio;
-// 	use sea_orm::{ActiveModelTrait, ActiveValue, DatabaseBackend, MockDatabase,
-// MockExecResult};
-//
-// 	use crate::adapter::mysql::{
-// 		level_request_repository::LevelRequestRepository,
-// 		model::{level_request, sea_orm_active_enums::RequestRating},
-// 		mysql_level_request_repository::MySqlLevelRequestRepository
-// 	};
-//
-// 	#[tokio::test]
-// 	async fn test_insert_level_record_should_insert_properly() {
-// 		let db = MockDatabase::new(DatabaseBackend::MySql)
-// 			.append_exec_results([MockExecResult {
-// 				last_insert_id: 1,
-// 				rows_affected: 1
-// 			}])
-// 			.into_connection();
-//
-// 		let level_request_storable = setup_helper_record(
-// 			99999999,
-// 			99999999,
-// 			"Level Name".to_string(),
-// 			Some("Level Description".to_string()),
-// 			"Level Creator".to_string(),
-// 			RequestRating::Two,
-// 			None
-// 		);
-//
-// 		let repository = MySqlLevelRequestRepository { db_conn: &db };
-//
-// 		match repository.create_record(level_request_storable).await {
-// 			Ok(result) => {
-// 				assert_eq!(result.last_insert_id, 99999999)
-// 			}
-// 			Err(_err) => {
-// 				assert!(false)
-// 			}
-// 		}
-// 	}
-//
-// 	#[tokio::test]
-// 	async fn test_update_level_record_should_update_properly() {
-// 		let initial_result = level_request::Model {
-// 			level_id: 99999999,
-// 			discord_id: 0,
-// 			discord_message_id: None,
-// 			discord_thread_id: None,
-// 			name: "Level Name".to_string(),
-// 			description: Some("Level Description".to_string()),
-// 			author: "Level Creator".to_string(),
-// 			request_rating: RequestRating::Two,
-// 			you_tube_video_link: "None".to_string()
-// 		};
-// 		let updated_result = level_request::Model {
-// 			level_id: 99999999,
-// 			discord_id: 0,
-// 			discord_message_id: None,
-// 			discord_thread_id: None,
-// 			name: "Level Name".to_string(),
-// 			description: Some("New Level Description".to_string()),
-// 			author: "New Level Creator".to_string(),
-// 			request_rating: RequestRating::Two,
-// 			you_tube_video_link: "None".to_string()
-// 		};
-//
-// 		let db = MockDatabase::new(DatabaseBackend::MySql)
-// 			.append_query_results([[initial_result], [updated_result.clone()]])
-// 			.append_exec_results([
-// 				MockExecResult {
-// 					last_insert_id: 99999999,
-// 					rows_affected: 1
-// 				},
-// 				MockExecResult {
-// 					last_insert_id: 99999999,
-// 					rows_affected: 1
-// 				}
-// 			])
-// 			.into_connection();
-//
-// 		let initial_record = setup_helper_record(
-// 			99999999,
-// 			99999999,
-// 			"Level Name".to_string(),
-// 			Some("Level Description".to_string()),
-// 			"Level Creator".to_string(),
-// 			RequestRating::Two,
-// 			None
-// 		);
-//
-// 		initial_record
-// 			.insert(&db)
-// 			.await
-// 			.expect("Failed initial insert");
-//
-// 		let level_request_storable = setup_helper_record(
-// 			99999999,
-// 			99999999,
-// 			"New Level Name".to_string(),
-// 			Some("New Level Description".to_string()),
-// 			"New Level Creator".to_string(),
-// 			RequestRating::Four,
-// 			None
-// 		);
-//
-// 		let repository = MySqlLevelRequestRepository { db_conn: &db };
-//
-// 		match repository.update_record(level_request_storable).await {
-// 			Ok(result) => {
-// 				assert_eq!(result, updated_result)
-// 			}
-// 			Err(err) => {
-// 				println!("{:?}", err);
-// 				assert!(false)
-// 			}
-// 		}
-// 	}
-//
-// 	#[tokio::test]
-// 	async fn test_delete_level_record_should_delete_properly() {
-// 		let initial_result = level_request::Model {
-// 			level_id: 99999999,
-// 			discord_id: 0,
-// 			discord_message_id: None,
-// 			discord_thread_id: None,
-// 			name: "Level Name".to_string(),
-// 			description: Some("Level Description".to_string()),
-// 			author: "Level Creator".to_string(),
-// 			request_rating: RequestRating::Two,
-// 			you_tube_video_link: None
-// 		};
-//
-// 		let db = MockDatabase::new(DatabaseBackend::MySql)
-// 			.append_query_results([[initial_result]])
-// 			.append_exec_results([
-// 				MockExecResult {
-// 					last_insert_id: 99999999,
-// 					rows_affected: 1
-// 				},
-// 				MockExecResult {
-// 					last_insert_id: 99999999,
-// 					rows_affected: 1
-// 				}
-// 			])
-// 			.into_connection();
-//
-// 		let initial_record = setup_helper_record(
-// 			99999999,
-// 			99999999,
-// 			"Level Name".to_string(),
-// 			Some("Level Description".to_string()),
-// 			"Level Creator".to_string(),
-// 			RequestRating::Two,
-// 			None
-// 		);
-//
-// 		initial_record
-// 			.insert(&db)
-// 			.await
-// 			.expect("Failed initial insert");
-//
-// 		let level_request_storable = setup_helper_record(
-// 			99999999,
-// 			99999999,
-// 			"Level Name".to_string(),
-// 			Some("Level Description".to_string()),
-// 			"Level Creator".to_string(),
-// 			RequestRating::Two,
-// 			None
-// 		);
-//
-// 		let repository = MySqlLevelRequestRepository { db_conn: &db };
-//
-// 		match repository.delete_record(level_request_storable).await {
-// 			Ok(result) => {
-// 				assert_eq!(result.rows_affected, 1)
-// 			}
-// 			Err(err) => {
-// 				println!("{:?}", err);
-// 				assert!(false)
-// 			}
-// 		}
-// 	}
-//
-// 	fn setup_helper_record(
-// 		id: u64,
-// 		discord_id: u64,
-// 		name: String,
-// 		description: Option<String>,
-// 		author: String,
-// 		request_rating: RequestRating,
-// 		you_tube_video_link: String
-// 	) -> level_request::ActiveModel {
-// 		level_request::ActiveModel {
-// 			level_id: ActiveValue::Set(id),
-// 			discord_id: ActiveValue::Set(discord_id),
-// 			discord_message_id: ActiveValue::Set(None),
-// 			discord_thread_id: ActiveValue::Set(None),
-// 			name: ActiveValue::Set(name),
-// 			description: ActiveValue::Set(description),
-// 			author: ActiveValue::Set(author),
-// 			request_rating: ActiveValue::Set(request_rating),
-// 			you_tube_video_link: ActiveValue::Set(you_tube_video_link)
-// 		}
-// 	}
-// }
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+
+	use chrono::{Duration, Utc};
+	use sea_orm::{
+		ActiveValue, Condition, DatabaseBackend, DbErr, IntoActiveModel, MockDatabase,
+		MockExecResult, Order
+	};
+
+	use crate::{
+		adapter::mysql::{
+			level_request_repository::LevelRequestRepository,
+			model::{
+				archived_level_request, level_request, moderator, review, review_revision,
+				sea_orm_active_enums
+			},
+			mysql_level_request_repository::MySqlLevelRequestRepository
+		},
+		domain::model::gd_level::RequestStatus,
+		rocket::common::testing::level_request_model
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_create_record_should_insert_properly() {
+		let db_conn = MockDatabase::new(DatabaseBackend::MySql)
+			.append_exec_results([MockExecResult {
+				last_insert_id: 0,
+				rows_affected: 1
+			}])
+			.into_connection();
+		let repository = MySqlLevelRequestRepository::new(Arc::new(db_conn));
+
+		let level_request_storable =
+			level_request_model(1, 99999999, 12345, RequestStatus::Pending).into_active_model();
+
+		assert_eq!(
+			repository
+				.create_record(level_request_storable)
+				.await
+				.unwrap()
+				.last_insert_id,
+			(1.into(), 99999999.into())
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_record_should_ignore_archived_requests() {
+		let level_request = level_request_model(1, 99999999, 12345, RequestStatus::Pending);
+		let db_conn = Arc::new(
+			MockDatabase::new(DatabaseBackend::MySql)
+				.append_query_results([vec![level_request.clone()]])
+				.into_connection()
+		);
+		let repository = MySqlLevelRequestRepository::new(db_conn.clone());
+
+		assert_eq!(
+			repository.get_record(1, 99999999).await.unwrap(),
+			Some(level_request)
+		);
+
+		drop(repository);
+		let transaction_log = Arc::try_unwrap(db_conn).unwrap().into_transaction_log();
+		assert!(format!("{:?}", transaction_log).contains("`archived_at` IS NULL"));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_records_should_return_page() {
+		let level_requests = vec![
+			level_request_model(1, 99999998, 12345, RequestStatus::Pending),
+			level_request_model(1, 99999999, 54321, RequestStatus::Reviewed),
+		];
+		let db_conn = Arc::new(
+			MockDatabase::new(DatabaseBackend::MySql)
+				.append_query_results([level_requests.clone()])
+				.into_connection()
+		);
+		let repository = MySqlLevelRequestRepository::new(db_conn.clone());
+
+		assert_eq!(
+			repository
+				.get_records(1, Condition::all(), Order::Asc, 0, 25)
+				.await
+				.unwrap(),
+			level_requests
+		);
+
+		drop(repository);
+		let transaction_log = Arc::try_unwrap(db_conn).unwrap().into_transaction_log();
+		assert!(format!("{:?}", transaction_log)
+			.contains("ORDER BY `level_request`.`timestamp` ASC, `level_request`.`level_id` ASC"));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_queued_records_should_only_return_pending_requests() {
+		let level_request = level_request_model(1, 99999999, 12345, RequestStatus::Pending);
+		let db_conn = Arc::new(
+			MockDatabase::new(DatabaseBackend::MySql)
+				.append_query_results([vec![level_request.clone()]])
+				.into_connection()
+		);
+		let repository = MySqlLevelRequestRepository::new(db_conn.clone());
+
+		assert_eq!(
+			repository.get_queued_records(1).await.unwrap(),
+			vec![level_request]
+		);
+
+		drop(repository);
+		let transaction_log = format!(
+			"{:?}",
+			Arc::try_unwrap(db_conn).unwrap().into_transaction_log()
+		);
+		assert!(transaction_log.contains("`status` = ?"));
+		assert!(transaction_log.contains("`archived_at` IS NULL"));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_requeue_unassigned_records_should_only_requeue_levels_without_active_assignment()
+	{
+		let db_conn = Arc::new(
+			MockDatabase::new(DatabaseBackend::MySql)
+				.append_exec_results([MockExecResult {
+					last_insert_id: 0,
+					rows_affected: 1
+				}])
+				.into_connection()
+		);
+		let repository = MySqlLevelRequestRepository::new(db_conn.clone());
+
+		assert_eq!(
+			repository
+				.requeue_unassigned_records(Utc::now())
+				.await
+				.unwrap(),
+			1
+		);
+
+		drop(repository);
+		let transaction_log = format!(
+			"{:?}",
+			Arc::try_unwrap(db_conn).unwrap().into_transaction_log()
+		);
+		assert!(transaction_log.contains("UPDATE `level_request` SET `status` = ?"));
+		assert!(transaction_log.contains("EXISTS(SELECT `review_assignment`.`id`"));
+		assert!(transaction_log.contains("NOT EXISTS(SELECT `review_assignment`.`id`"));
+		assert!(transaction_log
+			.contains("`review_assignment`.`assigned_at` >= `level_request`.`timestamp`"));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_user_records_since_should_include_archived_requests() {
+		let level_request = level_request_model(1, 99999999, 12345, RequestStatus::Pending);
+		let mut replaced_level_request =
+			level_request_model(1, 88888888, 12345, RequestStatus::Sent);
+		replaced_level_request.timestamp = level_request.timestamp - Duration::hours(1);
+		let db_conn = Arc::new(
+			MockDatabase::new(DatabaseBackend::MySql)
+				.append_query_results([vec![level_request.clone()]])
+				.append_query_results([vec![archived_level_request_model(
+					replaced_level_request.clone()
+				)]])
+				.into_connection()
+		);
+		let repository = MySqlLevelRequestRepository::new(db_conn.clone());
+
+		let level_requests = repository
+			.get_user_records_since(1, 12345, Utc::now() - Duration::days(1))
+			.await
+			.unwrap();
+
+		assert_eq!(level_requests.len(), 2);
+		assert_eq!(level_requests[0].level_id, replaced_level_request.level_id);
+		assert!(level_requests[0].archived_at.is_some());
+		assert_eq!(level_requests[1], level_request);
+
+		drop(repository);
+		let transaction_log = format!(
+			"{:?}",
+			Arc::try_unwrap(db_conn).unwrap().into_transaction_log()
+		);
+		assert!(transaction_log.contains("`discord_id` = ?"));
+		assert!(transaction_log.contains("`timestamp` >= ?"));
+		assert!(!transaction_log.contains("`archived_at` IS NULL"));
+		assert!(transaction_log.contains("FROM `archived_level_request`"));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_replace_archived_record_should_move_archived_request_in_one_transaction() {
+		let mut archived_level_request =
+			level_request_model(1, 99999999, 12345, RequestStatus::Sent);
+		archived_level_request.archived_at = Some(Utc::now());
+		let review = review::Model {
+			guild_id: 1.into(),
+			level_id: 99999999.into(),
+			discord_id: 54321.into(),
+			message_id: 11111.into(),
+			review_content: "Nice level".to_string(),
+			rubric_scores: None,
+			suggested_score: None,
+			suggested_rating: None
+		};
+		let review_revision = review_revision::Model {
+			id: 1,
+			guild_id: 1.into(),
+			level_id: 99999999.into(),
+			discord_id: 54321.into(),
+			revision: 1,
+			message_id: 11111.into(),
+			review_content: "Nice level".to_string(),
+			created_at: Utc::now(),
+			rubric_scores: None,
+			suggested_score: None,
+			suggested_rating: None
+		};
+		let exec_result = |last_insert_id| MockExecResult {
+			last_insert_id,
+			rows_affected: 1
+		};
+		let db_conn = Arc::new(
+			MockDatabase::new(DatabaseBackend::MySql)
+				.append_query_results([vec![archived_level_request]])
+				.append_query_results([vec![moderator::Model {
+					guild_id: 1.into(),
+					level_id: 99999999.into(),
+					score: sea_orm_active_enums::Score::Four,
+					rating: sea_orm_active_enums::Rating::Rate,
+					sent_at: None
+				}]])
+				.append_query_results([vec![review]])
+				.append_query_results([vec![review_revision]])
+				.append_exec_results([
+					exec_result(7),
+					exec_result(3),
+					exec_result(5),
+					exec_result(0),
+					exec_result(0),
+					exec_result(0),
+					exec_result(0),
+					exec_result(0),
+					exec_result(0)
+				])
+				.into_connection()
+		);
+		let repository = MySqlLevelRequestRepository::new(db_conn.clone());
+
+		let level_request_storable =
+			level_request_model(1, 99999999, 12345, RequestStatus::Pending).into_active_model();
+
+		assert_eq!(
+			repository
+				.replace_archived_record(level_request_storable)
+				.await
+				.unwrap()
+				.last_insert_id,
+			(1.into(), 99999999.into())
+		);
+
+		drop(repository);
+		let transaction_log = format!(
+			"{:?}",
+			Arc::try_unwrap(db_conn).unwrap().into_transaction_log()
+		);
+		let statement_positions: Vec<usize> = [
+			"BEGIN",
+			"INSERT INTO `archived_level_request`",
+			"INSERT INTO `archived_review`",
+			"INSERT INTO `archived_review_revision`",
+			"DELETE FROM `review_revision`",
+			"DELETE FROM `review`",
+			"DELETE FROM `moderator`",
+			"DELETE FROM `review_assignment`",
+			"DELETE FROM `level_request`",
+			"INSERT INTO `level_request`",
+			"COMMIT"
+		]
+		.iter()
+		.map(|statement| transaction_log.find(statement).unwrap())
+		.collect();
+		assert!(statement_positions.windows(2).all(|pair| pair[0] < pair[1]));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_update_record_should_update_properly() {
+		let mut updated_level_request =
+			level_request_model(1, 99999999, 12345, RequestStatus::Pending);
+		updated_level_request.you_tube_video_link = "https://youtu.be/dQw4w9WgXcQ".to_string();
+		let db_conn = MockDatabase::new(DatabaseBackend::MySql)
+			.append_query_results([vec![updated_level_request.clone()]])
+			.append_exec_results([MockExecResult {
+				last_insert_id: 0,
+				rows_affected: 1
+			}])
+			.into_connection();
+		let repository = MySqlLevelRequestRepository::new(Arc::new(db_conn));
+
+		let mut level_request_storable =
+			level_request_model(1, 99999999, 12345, RequestStatus::Pending).into_active_model();
+		level_request_storable.you_tube_video_link =
+			ActiveValue::Set("https://youtu.be/dQw4w9WgXcQ".to_string());
+
+		assert_eq!(
+			repository
+				.update_record(level_request_storable)
+				.await
+				.unwrap(),
+			updated_level_request
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_update_record_should_return_error_when_record_does_not_exist() {
+		let db_conn = MockDatabase::new(DatabaseBackend::MySql)
+			.append_exec_results([MockExecResult {
+				last_insert_id: 0,
+				rows_affected: 0
+			}])
+			.into_connection();
+		let repository = MySqlLevelRequestRepository::new(Arc::new(db_conn));
+
+		let mut level_request_storable =
+			level_request_model(1, 99999999, 12345, RequestStatus::Pending).into_active_model();
+		level_request_storable.notify = ActiveValue::Set(1);
+
+		assert_eq!(
+			repository
+				.update_record(level_request_storable)
+				.await
+				.unwrap_err(),
+			DbErr::RecordNotUpdated
+		);
+	}
+
+	fn archived_level_request_model(
+		level_request: level_request::Model
+	) -> archived_level_request::Model {
+		archived_level_request::Model {
+			id: 1,
+			guild_id: level_request.guild_id,
+			level_id: level_request.level_id,
+			discord_id: level_request.discord_id,
+			discord_message_id: level_request.discord_message_id,
+			name: level_request.name,
+			author: level_request.author,
+			request_rating: level_request.request_rating,
+			level_length: level_request.level_length,
+			you_tube_video_link: level_request.you_tube_video_link,
+			has_requested_feedback: level_request.has_requested_feedback,
+			notify: level_request.notify,
+			status: level_request.status,
+			timestamp: level_request.timestamp,
+			archived_at: Utc::now(),
+			description: level_request.description,
+			author_account_id: level_request.author_account_id,
+			author_player_id: level_request.author_player_id,
+			difficulty: level_request.difficulty,
+			stars: level_request.stars,
+			feature_state: level_request.feature_state,
+			downloads: level_request.downloads,
+			likes: level_request.likes,
+			game_version: level_request.game_version,
+			song_id: level_request.song_id,
+			song_name: level_request.song_name,
+			song_artist: level_request.song_artist,
+			is_custom_song: level_request.is_custom_song,
+			object_count: level_request.object_count,
+			coin_count: level_request.coin_count,
+			skipped_at: level_request.skipped_at,
+			moderator_score: None,
+			moderator_rating: None,
+			moderator_sent_at: None
+		}
+	}
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use sea_orm::{DatabaseConnection, DbConn, DbErr, EntityTrait, InsertResult};
+
+use crate::adapter::mysql::{
+	model::{
+		prelude::RequestConfig,
+		request_config::{ActiveModel, Model}
+	},
+	request_config_repository::RequestConfigRepository
+};
+
+pub struct MySqlRequestConfigRepository {
+	db_conn: Arc<DatabaseConnection>
+}
+
+impl RequestConfigRepository for MySqlRequestConfigRepository {
+	async fn create_record(&self, record: ActiveModel) -> Result<InsertResult<ActiveModel>, DbErr> {
+		RequestConfig::insert(record)
+			.exec(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn get_record(&self, guild_id: u64) -> Result<Option<Model>, DbErr> {
+		RequestConfig::find_by_id(guild_id)
+			.one(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn get_records(&self) -> Result<Vec<Model>, DbErr> {
+		RequestConfig::find().all(self.db_conn.as_ref()).await
+	}
+
+	async fn update_record(&self, record: ActiveModel) -> Result<Model, DbErr> {
+		RequestConfig::update(record)
+			.exec(self.db_conn.as_ref())
+			.await
+	}
+}
+
+impl MySqlRequestConfigRepository {
+	pub fn new(db_conn: Arc<DbConn>) -> Self { MySqlRequestConfigRepository { db_conn } }
+}
@@ -0,0 +1,268 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use sea_orm::{
+	ColumnTrait, Condition, DatabaseConnection, DbConn, DbErr, EntityTrait, InsertResult, Order,
+	QueryFilter, QueryOrder, QuerySelect, TransactionTrait
+};
+
+use crate::adapter::mysql::{
+	model::{
+		level_request,
+		prelude::{LevelRequest, ReviewAssignment},
+		review_assignment,
+		review_assignment::ActiveModel
+	},
+	review_assignment_repository::ReviewAssignmentRepository
+};
+
+pub struct MySqlReviewAssignmentRepository {
+	db_conn: Arc<DatabaseConnection>
+}
+
+impl ReviewAssignmentRepository for MySqlReviewAssignmentRepository {
+	async fn create_record_if_unassigned(
+		&self,
+		record: ActiveModel,
+		now: DateTime<Utc>
+	) -> Result<Option<InsertResult<ActiveModel>>, DbErr> {
+		let guild_id = record.guild_id.clone().unwrap();
+		let level_id = record.level_id.clone().unwrap();
+		let txn = self.db_conn.begin().await?;
+
+		LevelRequest::find_by_id((guild_id, level_id))
+			.lock_exclusive()
+			.one(&txn)
+			.await?;
+		let active_assignment = ReviewAssignment::find()
+			.filter(review_assignment::Column::GuildId.eq(guild_id))
+			.filter(review_assignment::Column::LevelId.eq(level_id))
+			.filter(Self::active_condition(now))
+			.one(&txn)
+			.await?;
+		if active_assignment.is_some() {
+			txn.rollback().await?;
+			return Ok(None);
+		}
+		let insert_result = ReviewAssignment::insert(record).exec(&txn).await?;
+
+		txn.commit().await?;
+		Ok(Some(insert_result))
+	}
+
+	async fn get_active_record(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		now: DateTime<Utc>
+	) -> Result<Option<review_assignment::Model>, DbErr> {
+		ReviewAssignment::find()
+			.filter(review_assignment::Column::GuildId.eq(guild_id))
+			.filter(review_assignment::Column::LevelId.eq(level_id))
+			.filter(Self::active_condition(now))
+			.order_by(review_assignment::Column::AssignedAt, Order::Desc)
+			.one(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn get_active_records(
+		&self,
+		guild_id: u64,
+		reviewer_discord_id: Option<u64>,
+		now: DateTime<Utc>
+	) -> Result<Vec<(review_assignment::Model, Option<level_request::Model>)>, DbErr> {
+		let mut select = ReviewAssignment::find()
+			.filter(review_assignment::Column::GuildId.eq(guild_id))
+			.filter(Self::active_condition(now));
+		if let Some(reviewer_discord_id) = reviewer_discord_id {
+			select = select.filter(review_assignment::Column::DiscordId.eq(reviewer_discord_id));
+		}
+
+		select
+			.order_by(review_assignment::Column::AssignedAt, Order::Asc)
+			.find_also_related(LevelRequest)
+			.all(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn get_latest_record(
+		&self,
+		guild_id: u64
+	) -> Result<Option<review_assignment::Model>, DbErr> {
+		ReviewAssignment::find()
+			.filter(review_assignment::Column::GuildId.eq(guild_id))
+			.order_by(review_assignment::Column::Id, Order::Desc)
+			.one(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn update_record(&self, record: ActiveModel) -> Result<review_assignment::Model, DbErr> {
+		ReviewAssignment::update(record)
+			.exec(self.db_conn.as_ref())
+			.await
+	}
+}
+
+impl MySqlReviewAssignmentRepository {
+	pub fn new(db_conn: Arc<DbConn>) -> Self { MySqlReviewAssignmentRepository { db_conn } }
+
+	/// Assignments that have not been completed or released, and have not
+	/// expired by `now`.
+	pub(crate) fn active_condition(now: DateTime<Utc>) -> Condition {
+		Condition::all()
+			.add(review_assignment::Column::CompletedAt.is_null())
+			.add(review_assignment::Column::ReleasedAt.is_null())
+			.add(review_assignment::Column::ExpiresAt.gt(now))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+
+	use chrono::Utc;
+	use sea_orm::{ActiveValue, DatabaseBackend, IntoActiveModel, MockDatabase, MockExecResult};
+
+	use crate::{
+		adapter::mysql::{
+			model::review_assignment,
+			mysql_review_assignment_repository::MySqlReviewAssignmentRepository,
+			review_assignment_repository::ReviewAssignmentRepository
+		},
+		domain::model::gd_level::RequestStatus,
+		rocket::common::testing::{level_request_model, review_assignment_model}
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_create_record_if_unassigned_should_lock_level_request_and_insert() {
+		let db_conn = Arc::new(
+			MockDatabase::new(DatabaseBackend::MySql)
+				.append_query_results([vec![level_request_model(
+					1,
+					99999999,
+					54321,
+					RequestStatus::Pending
+				)]])
+				.append_query_results([Vec::<review_assignment::Model>::new()])
+				.append_exec_results([MockExecResult {
+					last_insert_id: 7,
+					rows_affected: 1
+				}])
+				.into_connection()
+		);
+		let repository = MySqlReviewAssignmentRepository::new(db_conn.clone());
+
+		let mut review_assignment_storable =
+			review_assignment_model(1, 99999999, 12345).into_active_model();
+		review_assignment_storable.id = ActiveValue::NotSet;
+
+		assert_eq!(
+			repository
+				.create_record_if_unassigned(review_assignment_storable, Utc::now())
+				.await
+				.unwrap()
+				.unwrap()
+				.last_insert_id,
+			7
+		);
+
+		drop(repository);
+		let transaction_log = format!(
+			"{:?}",
+			Arc::try_unwrap(db_conn).unwrap().into_transaction_log()
+		);
+		assert!(transaction_log.contains("FOR UPDATE"));
+		assert!(transaction_log.contains("INSERT INTO `review_assignment`"));
+		assert!(transaction_log.contains("COMMIT"));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_create_record_if_unassigned_should_not_insert_when_level_is_assigned() {
+		let db_conn = Arc::new(
+			MockDatabase::new(DatabaseBackend::MySql)
+				.append_query_results([vec![level_request_model(
+					1,
+					99999999,
+					54321,
+					RequestStatus::InReview
+				)]])
+				.append_query_results([vec![review_assignment_model(1, 99999999, 12345)]])
+				.into_connection()
+		);
+		let repository = MySqlReviewAssignmentRepository::new(db_conn.clone());
+
+		let mut review_assignment_storable =
+			review_assignment_model(1, 99999999, 12345).into_active_model();
+		review_assignment_storable.id = ActiveValue::NotSet;
+
+		assert!(repository
+			.create_record_if_unassigned(review_assignment_storable, Utc::now())
+			.await
+			.unwrap()
+			.is_none());
+
+		drop(repository);
+		let transaction_log = format!(
+			"{:?}",
+			Arc::try_unwrap(db_conn).unwrap().into_transaction_log()
+		);
+		assert!(!transaction_log.contains("INSERT INTO `review_assignment`"));
+		assert!(transaction_log.contains("ROLLBACK"));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_active_record_should_ignore_ended_and_expired_assignments() {
+		let review_assignment = review_assignment_model(1, 99999999, 12345);
+		let db_conn = Arc::new(
+			MockDatabase::new(DatabaseBackend::MySql)
+				.append_query_results([vec![review_assignment.clone()]])
+				.into_connection()
+		);
+		let repository = MySqlReviewAssignmentRepository::new(db_conn.clone());
+
+		assert_eq!(
+			repository
+				.get_active_record(1, 99999999, Utc::now())
+				.await
+				.unwrap(),
+			Some(review_assignment)
+		);
+
+		drop(repository);
+		let transaction_log = format!(
+			"{:?}",
+			Arc::try_unwrap(db_conn).unwrap().into_transaction_log()
+		);
+		assert!(transaction_log.contains("`review_assignment`.`completed_at` IS NULL"));
+		assert!(transaction_log.contains("`review_assignment`.`released_at` IS NULL"));
+		assert!(transaction_log.contains("`review_assignment`.`expires_at` > ?"));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_active_records_should_include_level_requests() {
+		let review_assignment = review_assignment_model(1, 99999999, 12345);
+		let level_request = level_request_model(1, 99999999, 54321, RequestStatus::InReview);
+		let db_conn = Arc::new(
+			MockDatabase::new(DatabaseBackend::MySql)
+				.append_query_results([vec![(review_assignment.clone(), level_request.clone())]])
+				.into_connection()
+		);
+		let repository = MySqlReviewAssignmentRepository::new(db_conn.clone());
+
+		assert_eq!(
+			repository
+				.get_active_records(1, Some(12345), Utc::now())
+				.await
+				.unwrap(),
+			vec![(review_assignment, Some(level_request))]
+		);
+
+		drop(repository);
+		let transaction_log = format!(
+			"{:?}",
+			Arc::try_unwrap(db_conn).unwrap().into_transaction_log()
+		);
+		assert!(transaction_log.contains("LEFT JOIN `level_request`"));
+		assert!(transaction_log.contains("`review_assignment`.`discord_id` = ?"));
+	}
+}
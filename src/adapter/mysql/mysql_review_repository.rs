@@ -1,41 +1,181 @@
-use sea_orm::{DatabaseConnection, DbConn, DbErr, DeleteResult, EntityTrait, InsertResult};
+use std::sync::Arc;
+
+use sea_orm::{
+	ColumnTrait, Condition, DatabaseConnection, DbConn, DbErr, DeleteResult, EntityTrait,
+	InsertResult, JoinType, Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+	RelationTrait, Select
+};
 
 use crate::adapter::mysql::{
-	model::{prelude::Review, review},
+	model::{level_request, prelude::Review, review},
 	review_repository::ReviewRepository
 };
 
-pub struct MySqlReviewRepository<'a> {
-	db_conn: &'a DatabaseConnection
+pub struct MySqlReviewRepository {
+	db_conn: Arc<DatabaseConnection>
 }
 
-impl<'a> ReviewRepository for MySqlReviewRepository<'a> {
+impl ReviewRepository for MySqlReviewRepository {
 	async fn create_record(
 		&self,
 		record: review::ActiveModel
 	) -> Result<InsertResult<review::ActiveModel>, DbErr> {
-		Review::insert(record).exec(self.db_conn).await
+		Review::insert(record).exec(self.db_conn.as_ref()).await
 	}
 
 	async fn get_record(
 		&self,
+		guild_id: u64,
 		level_id: u64,
 		discord_id: u64
 	) -> Result<Option<review::Model>, DbErr> {
-		Review::find_by_id((level_id, discord_id))
-			.one(self.db_conn)
+		Review::find_by_id((guild_id.into(), level_id.into(), discord_id.into()))
+			.one(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn get_level_records(
+		&self,
+		guild_id: u64,
+		level_id: u64
+	) -> Result<Vec<review::Model>, DbErr> {
+		Review::find()
+			.filter(review::Column::GuildId.eq(guild_id))
+			.filter(review::Column::LevelId.eq(level_id))
+			.order_by(review::Column::DiscordId, Order::Asc)
+			.all(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn get_records(
+		&self,
+		guild_id: u64,
+		condition: Condition,
+		order: Order,
+		offset: u64,
+		limit: u64
+	) -> Result<Vec<review::Model>, DbErr> {
+		Self::find_with_level_request(guild_id, condition)
+			.order_by(level_request::Column::Timestamp, order.clone())
+			.order_by(review::Column::LevelId, order)
+			.order_by(review::Column::DiscordId, Order::Asc)
+			.offset(offset)
+			.limit(limit)
+			.all(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn count_records(&self, guild_id: u64, condition: Condition) -> Result<u64, DbErr> {
+		Self::find_with_level_request(guild_id, condition)
+			.count(self.db_conn.as_ref())
 			.await
 	}
 
 	async fn update_record(&self, record: review::ActiveModel) -> Result<review::Model, DbErr> {
-		Review::update(record).exec(self.db_conn).await
+		Review::update(record).exec(self.db_conn.as_ref()).await
 	}
 
 	async fn delete_record(&self, record: review::ActiveModel) -> Result<DeleteResult, DbErr> {
-		Review::delete(record).exec(self.db_conn).await
+		Review::delete(record).exec(self.db_conn.as_ref()).await
 	}
 }
 
-impl<'a> MySqlReviewRepository<'a> {
-	pub fn new(db_conn: &'a DbConn) -> Self { MySqlReviewRepository { db_conn } }
+impl MySqlReviewRepository {
+	pub fn new(db_conn: Arc<DbConn>) -> Self { MySqlReviewRepository { db_conn } }
+
+	fn find_with_level_request(guild_id: u64, condition: Condition) -> Select<Review> {
+		Review::find()
+			.join(JoinType::InnerJoin, review::Relation::LevelRequest.def())
+			.filter(review::Column::GuildId.eq(guild_id))
+			.filter(condition)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{collections::BTreeMap, sync::Arc};
+
+	use sea_orm::{DatabaseBackend, MockDatabase, Order, Value};
+
+	use crate::{
+		adapter::mysql::{
+			model::review, mysql_review_repository::MySqlReviewRepository,
+			review_repository::ReviewRepository
+		},
+		domain::model::review_query::LevelReviewFilter
+	};
+
+	fn review_model(level_id: u64, discord_id: u64) -> review::Model {
+		review::Model {
+			guild_id: 1.into(),
+			level_id: level_id.into(),
+			discord_id: discord_id.into(),
+			message_id: 11111.into(),
+			review_content: "Great level".to_string(),
+			rubric_scores: None,
+			suggested_score: None,
+			suggested_rating: None
+		}
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_records_should_search_review_content() {
+		let reviews = vec![review_model(99999998, 12345), review_model(99999999, 12345)];
+		let db_conn = Arc::new(
+			MockDatabase::new(DatabaseBackend::MySql)
+				.append_query_results([reviews.clone()])
+				.into_connection()
+		);
+		let repository = MySqlReviewRepository::new(db_conn.clone());
+		let filter = LevelReviewFilter {
+			reviewer_discord_id: Some(12345),
+			search: Some("great 100%".to_string()),
+			..LevelReviewFilter::default()
+		};
+
+		assert_eq!(
+			repository
+				.get_records(1, filter.into(), Order::Desc, 0, 25)
+				.await
+				.unwrap(),
+			reviews
+		);
+
+		drop(repository);
+		let transaction_log = format!(
+			"{:?}",
+			Arc::try_unwrap(db_conn).unwrap().into_transaction_log()
+		);
+		assert!(transaction_log.contains("INNER JOIN `level_request`"));
+		assert!(transaction_log.contains("`review`.`discord_id` = ?"));
+		assert!(transaction_log.contains("`review`.`review_content` LIKE ? ESCAPE"));
+		assert!(transaction_log.contains("%100\\\\%%"));
+		assert!(transaction_log.contains(
+			"ORDER BY `level_request`.`timestamp` DESC, `review`.`level_id` DESC, \
+			 `review`.`discord_id` ASC"
+		));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_count_records_should_filter_by_requester() {
+		let db_conn = Arc::new(
+			MockDatabase::new(DatabaseBackend::MySql)
+				.append_query_results([[BTreeMap::from([("num_items", Value::Int(Some(2)))])]])
+				.into_connection()
+		);
+		let repository = MySqlReviewRepository::new(db_conn.clone());
+		let filter = LevelReviewFilter {
+			requester_discord_id: Some(54321),
+			..LevelReviewFilter::default()
+		};
+
+		assert_eq!(repository.count_records(1, filter.into()).await.unwrap(), 2);
+
+		drop(repository);
+		let transaction_log = format!(
+			"{:?}",
+			Arc::try_unwrap(db_conn).unwrap().into_transaction_log()
+		);
+		assert!(transaction_log.contains("`level_request`.`discord_id` = ?"));
+	}
 }
@@ -0,0 +1,209 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use sea_orm::{
+	ColumnTrait, Condition, DatabaseConnection, DbConn, DbErr, EntityTrait, InsertResult, JoinType,
+	Order, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Select
+};
+
+use crate::adapter::mysql::{
+	model::{
+		level_request,
+		prelude::{LevelRequest, ReviewRevision},
+		review, review_revision,
+		review_revision::ActiveModel
+	},
+	review_revision_repository::ReviewRevisionRepository
+};
+
+pub struct MySqlReviewRevisionRepository {
+	db_conn: Arc<DatabaseConnection>
+}
+
+impl ReviewRevisionRepository for MySqlReviewRevisionRepository {
+	async fn create_record(&self, record: ActiveModel) -> Result<InsertResult<ActiveModel>, DbErr> {
+		ReviewRevision::insert(record)
+			.exec(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn get_records(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		discord_id: u64
+	) -> Result<Vec<review_revision::Model>, DbErr> {
+		Self::find_by_review(guild_id, level_id, discord_id)
+			.order_by(review_revision::Column::Revision, Order::Asc)
+			.all(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn get_first_records(
+		&self,
+		guild_id: u64,
+		discord_id: Option<u64>,
+		reviewed_from: Option<DateTime<Utc>>,
+		reviewed_to: Option<DateTime<Utc>>
+	) -> Result<Vec<(review_revision::Model, Option<level_request::Model>)>, DbErr> {
+		ReviewRevision::find()
+			.join(JoinType::InnerJoin, review_revision::Relation::Review.def())
+			.join(JoinType::InnerJoin, review::Relation::LevelRequest.def())
+			.filter(Self::first_revision_condition(
+				guild_id,
+				discord_id,
+				reviewed_from,
+				reviewed_to
+			))
+			.select_also(LevelRequest)
+			.all(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn get_latest_record(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		discord_id: u64
+	) -> Result<Option<review_revision::Model>, DbErr> {
+		Self::find_by_review(guild_id, level_id, discord_id)
+			.order_by(review_revision::Column::Revision, Order::Desc)
+			.one(self.db_conn.as_ref())
+			.await
+	}
+}
+
+impl MySqlReviewRevisionRepository {
+	pub fn new(db_conn: Arc<DbConn>) -> Self { MySqlReviewRevisionRepository { db_conn } }
+
+	fn first_revision_condition(
+		guild_id: u64,
+		discord_id: Option<u64>,
+		reviewed_from: Option<DateTime<Utc>>,
+		reviewed_to: Option<DateTime<Utc>>
+	) -> Condition {
+		Condition::all()
+			.add(review_revision::Column::GuildId.eq(guild_id))
+			.add(review_revision::Column::Revision.eq(1))
+			.add_option(
+				discord_id.map(|discord_id| review_revision::Column::DiscordId.eq(discord_id))
+			)
+			.add_option(
+				reviewed_from
+					.map(|reviewed_from| review_revision::Column::CreatedAt.gte(reviewed_from))
+			)
+			.add_option(
+				reviewed_to.map(|reviewed_to| review_revision::Column::CreatedAt.lte(reviewed_to))
+			)
+	}
+
+	fn find_by_review(guild_id: u64, level_id: u64, discord_id: u64) -> Select<ReviewRevision> {
+		ReviewRevision::find()
+			.filter(review_revision::Column::GuildId.eq(guild_id))
+			.filter(review_revision::Column::LevelId.eq(level_id))
+			.filter(review_revision::Column::DiscordId.eq(discord_id))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+
+	use chrono::{TimeZone, Utc};
+	use sea_orm::{DatabaseBackend, MockDatabase};
+
+	use crate::{
+		adapter::mysql::{
+			model::review_revision,
+			mysql_review_revision_repository::MySqlReviewRevisionRepository,
+			review_revision_repository::ReviewRevisionRepository
+		},
+		rocket::common::testing::review_revision_model
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_get_records_should_return_revisions_oldest_first() {
+		let review_revisions = vec![
+			review_revision_model(1, 99999999, 12345, 1, "Great level"),
+			review_revision_model(1, 99999999, 12345, 2, "Great level"),
+		];
+		let db_conn = Arc::new(
+			MockDatabase::new(DatabaseBackend::MySql)
+				.append_query_results([review_revisions.clone()])
+				.into_connection()
+		);
+		let repository = MySqlReviewRevisionRepository::new(db_conn.clone());
+
+		assert_eq!(
+			repository.get_records(1, 99999999, 12345).await.unwrap(),
+			review_revisions
+		);
+
+		drop(repository);
+		let transaction_log = format!(
+			"{:?}",
+			Arc::try_unwrap(db_conn).unwrap().into_transaction_log()
+		);
+		assert!(transaction_log.contains("`review_revision`.`discord_id` = ?"));
+		assert!(transaction_log.contains("ORDER BY `review_revision`.`revision` ASC"));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_first_records_should_join_level_request_and_filter_by_creation() {
+		let db_conn = Arc::new(
+			MockDatabase::new(DatabaseBackend::MySql)
+				.append_query_results([Vec::<review_revision::Model>::new()])
+				.into_connection()
+		);
+		let repository = MySqlReviewRevisionRepository::new(db_conn.clone());
+
+		assert!(repository
+			.get_first_records(
+				1,
+				Some(12345),
+				Some(Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap()),
+				None
+			)
+			.await
+			.unwrap()
+			.is_empty());
+
+		drop(repository);
+		let transaction_log = format!(
+			"{:?}",
+			Arc::try_unwrap(db_conn).unwrap().into_transaction_log()
+		);
+		assert!(transaction_log.contains("INNER JOIN `review`"));
+		assert!(transaction_log.contains("INNER JOIN `level_request`"));
+		assert!(transaction_log.contains("`review_revision`.`revision` = ?"));
+		assert!(transaction_log.contains("`review_revision`.`discord_id` = ?"));
+		assert!(transaction_log.contains("`review_revision`.`created_at` >= ?"));
+		assert!(!transaction_log.contains("`review_revision`.`created_at` <= ?"));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_latest_record_should_return_highest_revision() {
+		let review_revision = review_revision_model(1, 99999999, 12345, 2, "Great level");
+		let db_conn = Arc::new(
+			MockDatabase::new(DatabaseBackend::MySql)
+				.append_query_results([vec![review_revision.clone()]])
+				.into_connection()
+		);
+		let repository = MySqlReviewRevisionRepository::new(db_conn.clone());
+
+		assert_eq!(
+			repository
+				.get_latest_record(1, 99999999, 12345)
+				.await
+				.unwrap(),
+			Some(review_revision)
+		);
+
+		drop(repository);
+		let transaction_log = format!(
+			"{:?}",
+			Arc::try_unwrap(db_conn).unwrap().into_transaction_log()
+		);
+		assert!(transaction_log.contains("ORDER BY `review_revision`.`revision` DESC"));
+	}
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use sea_orm::{
+	ColumnTrait, DatabaseConnection, DbConn, DbErr, EntityTrait, InsertResult, Order, QueryFilter,
+	QueryOrder
+};
+
+use crate::adapter::mysql::{
+	model::{prelude::ReviewerActivity, reviewer_activity, reviewer_activity::ActiveModel},
+	reviewer_activity_repository::ReviewerActivityRepository
+};
+
+pub struct MySqlReviewerActivityRepository {
+	db_conn: Arc<DatabaseConnection>
+}
+
+impl ReviewerActivityRepository for MySqlReviewerActivityRepository {
+	async fn create_record(&self, record: ActiveModel) -> Result<InsertResult<ActiveModel>, DbErr> {
+		ReviewerActivity::insert(record)
+			.exec(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn get_records(
+		&self,
+		guild_id: u64,
+		discord_id: Option<u64>
+	) -> Result<Vec<reviewer_activity::Model>, DbErr> {
+		let mut select =
+			ReviewerActivity::find().filter(reviewer_activity::Column::GuildId.eq(guild_id));
+		if let Some(discord_id) = discord_id {
+			select = select.filter(reviewer_activity::Column::DiscordId.eq(discord_id));
+		}
+
+		select
+			.order_by(reviewer_activity::Column::ChangedAt, Order::Asc)
+			.order_by(reviewer_activity::Column::Id, Order::Asc)
+			.all(self.db_conn.as_ref())
+			.await
+	}
+}
+
+impl MySqlReviewerActivityRepository {
+	pub fn new(db_conn: Arc<DbConn>) -> Self { MySqlReviewerActivityRepository { db_conn } }
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+
+	use chrono::Utc;
+	use sea_orm::{DatabaseBackend, MockDatabase};
+
+	use crate::adapter::mysql::{
+		model::reviewer_activity,
+		mysql_reviewer_activity_repository::MySqlReviewerActivityRepository,
+		reviewer_activity_repository::ReviewerActivityRepository
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_get_records_should_return_reviewer_changes_oldest_first() {
+		let reviewer_activities = vec![
+			reviewer_activity::Model {
+				id: 1,
+				guild_id: 1.into(),
+				discord_id: 12345.into(),
+				active: 1,
+				changed_at: Utc::now()
+			},
+			reviewer_activity::Model {
+				id: 2,
+				guild_id: 1.into(),
+				discord_id: 12345.into(),
+				active: 0,
+				changed_at: Utc::now()
+			},
+		];
+		let db_conn = Arc::new(
+			MockDatabase::new(DatabaseBackend::MySql)
+				.append_query_results([reviewer_activities.clone()])
+				.into_connection()
+		);
+		let repository = MySqlReviewerActivityRepository::new(db_conn.clone());
+
+		assert_eq!(
+			repository.get_records(1, Some(12345)).await.unwrap(),
+			reviewer_activities
+		);
+
+		drop(repository);
+		let transaction_log = format!(
+			"{:?}",
+			Arc::try_unwrap(db_conn).unwrap().into_transaction_log()
+		);
+		assert!(transaction_log.contains("`reviewer_activity`.`discord_id` = ?"));
+		assert!(transaction_log.contains("ORDER BY `reviewer_activity`.`changed_at` ASC"));
+	}
+}
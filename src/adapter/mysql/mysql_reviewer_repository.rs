@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use sea_orm::{
 	ColumnTrait, DatabaseConnection, DbConn, DbErr, DeleteResult, EntityTrait, InsertResult,
 	QueryFilter
@@ -12,50 +14,71 @@ use crate::adapter::mysql::{
 	reviewer_repository::ReviewerRepository
 };
 
-pub struct MySqlReviewerRepository<'a> {
-	db_conn: &'a DatabaseConnection
+pub struct MySqlReviewerRepository {
+	db_conn: Arc<DatabaseConnection>
 }
 
-impl<'a> ReviewerRepository for MySqlReviewerRepository<'a> {
+impl ReviewerRepository for MySqlReviewerRepository {
 	async fn create_record(&self, record: ActiveModel) -> Result<InsertResult<ActiveModel>, DbErr> {
-		Reviewer::insert(record).exec(self.db_conn).await
+		Reviewer::insert(record).exec(self.db_conn.as_ref()).await
 	}
 
 	async fn get_record(
 		&self,
+		guild_id: u64,
 		reviewer_discord_id: u64,
 		is_active: Option<bool>
 	) -> Result<Option<Model>, DbErr> {
 		if let Some(active_toggle) = is_active {
-			Reviewer::find_by_id(reviewer_discord_id)
+			Reviewer::find_by_id((guild_id.into(), reviewer_discord_id.into()))
+				.filter(reviewer::Column::Active.eq(active_toggle))
+				.one(self.db_conn.as_ref())
+				.await
+		} else {
+			Reviewer::find_by_id((guild_id.into(), reviewer_discord_id.into()))
+				.one(self.db_conn.as_ref())
+				.await
+		}
+	}
+
+	async fn get_records(
+		&self,
+		guild_id: u64,
+		is_active: Option<bool>
+	) -> Result<Vec<Model>, DbErr> {
+		if let Some(active_toggle) = is_active {
+			Reviewer::find()
+				.filter(reviewer::Column::GuildId.eq(guild_id))
 				.filter(reviewer::Column::Active.eq(active_toggle))
-				.one(self.db_conn)
+				.all(self.db_conn.as_ref())
 				.await
 		} else {
-			Reviewer::find_by_id(reviewer_discord_id)
-				.one(self.db_conn)
+			Reviewer::find()
+				.filter(reviewer::Column::GuildId.eq(guild_id))
+				.all(self.db_conn.as_ref())
 				.await
 		}
 	}
 
 	async fn get_record_ignore_active(
 		&self,
+		guild_id: u64,
 		reviewer_discord_id: u64
 	) -> Result<Option<Model>, DbErr> {
-		Reviewer::find_by_id(reviewer_discord_id)
-			.one(self.db_conn)
+		Reviewer::find_by_id((guild_id.into(), reviewer_discord_id.into()))
+			.one(self.db_conn.as_ref())
 			.await
 	}
 
 	async fn update_record(&self, record: ActiveModel) -> Result<Model, DbErr> {
-		Reviewer::update(record).exec(self.db_conn).await
+		Reviewer::update(record).exec(self.db_conn.as_ref()).await
 	}
 
 	async fn delete_record(&self, record: ActiveModel) -> Result<DeleteResult, DbErr> {
-		Reviewer::delete(record).exec(self.db_conn).await
+		Reviewer::delete(record).exec(self.db_conn.as_ref()).await
 	}
 }
 
-impl<'a> MySqlReviewerRepository<'a> {
-	pub fn new(db_conn: &'a DbConn) -> Self { MySqlReviewerRepository { db_conn } }
+impl MySqlReviewerRepository {
+	pub fn new(db_conn: Arc<DbConn>) -> Self { MySqlReviewerRepository { db_conn } }
 }
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use sea_orm::{
+	ColumnTrait, DatabaseConnection, DbConn, DbErr, DeleteResult, EntityTrait, InsertResult,
+	QueryFilter
+};
+
+use crate::adapter::mysql::{
+	model::{
+		prelude::RevokedToken,
+		revoked_token,
+		revoked_token::{ActiveModel, Model}
+	},
+	revoked_token_repository::RevokedTokenRepository
+};
+
+pub struct MySqlRevokedTokenRepository {
+	db_conn: Arc<DatabaseConnection>
+}
+
+impl RevokedTokenRepository for MySqlRevokedTokenRepository {
+	async fn create_record(&self, record: ActiveModel) -> Result<InsertResult<ActiveModel>, DbErr> {
+		RevokedToken::insert(record)
+			.exec(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn get_record(&self, jti: &str) -> Result<Option<Model>, DbErr> {
+		RevokedToken::find_by_id(jti)
+			.one(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn delete_expired_records(&self, now: DateTime<Utc>) -> Result<DeleteResult, DbErr> {
+		RevokedToken::delete_many()
+			.filter(revoked_token::Column::ExpiresAt.lt(now))
+			.exec(self.db_conn.as_ref())
+			.await
+	}
+}
+
+impl MySqlRevokedTokenRepository {
+	pub fn new(db_conn: Arc<DbConn>) -> Self { MySqlRevokedTokenRepository { db_conn } }
+}
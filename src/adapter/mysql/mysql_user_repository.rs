@@ -1,32 +1,296 @@
-use sea_orm::{DatabaseConnection, DbConn, DbErr, DeleteResult, EntityTrait, InsertResult};
+use std::sync::Arc;
+
+use sea_orm::{
+	ColumnTrait, DatabaseConnection, DbConn, DbErr, DeleteResult, EntityTrait, InsertResult,
+	QueryFilter
+};
 
 use crate::adapter::mysql::{
 	model::{prelude::*, user::ActiveModel, *},
-	user_repository::UserRepository
+	mysql_level_request_repository::MySqlLevelRequestRepository,
+	user_repository::{ArchivedLevelRequestRecord, UserRepository}
 };
 
-pub struct MySqlUserRepository<'a> {
-	db_conn: &'a DatabaseConnection
+pub struct MySqlUserRepository {
+	db_conn: Arc<DatabaseConnection>
 }
 
-impl<'a> UserRepository for MySqlUserRepository<'a> {
+impl UserRepository for MySqlUserRepository {
 	async fn create_record(&self, record: ActiveModel) -> Result<InsertResult<ActiveModel>, DbErr> {
-		User::insert(record).exec(self.db_conn).await
+		User::insert(record).exec(self.db_conn.as_ref()).await
+	}
+
+	async fn get_record(
+		&self,
+		guild_id: u64,
+		discord_id: u64
+	) -> Result<Option<user::Model>, DbErr> {
+		User::find_by_id((guild_id.into(), discord_id.into()))
+			.one(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn get_level_request_records(
+		&self,
+		guild_id: u64,
+		discord_id: u64
+	) -> Result<Vec<(level_request::Model, Vec<review::Model>)>, DbErr> {
+		LevelRequest::find()
+			.filter(level_request::Column::GuildId.eq(guild_id))
+			.filter(level_request::Column::DiscordId.eq(discord_id))
+			.find_with_related(Review)
+			.all(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn get_archived_level_request_records(
+		&self,
+		guild_id: u64,
+		discord_id: u64
+	) -> Result<Vec<ArchivedLevelRequestRecord>, DbErr> {
+		let archived_level_requests = ArchivedLevelRequest::find()
+			.filter(archived_level_request::Column::GuildId.eq(guild_id))
+			.filter(archived_level_request::Column::DiscordId.eq(discord_id))
+			.find_with_related(ArchivedReview)
+			.all(self.db_conn.as_ref())
+			.await?;
+
+		Ok(archived_level_requests
+			.into_iter()
+			.map(|(archived_level_request, archived_reviews)| {
+				let moderator = match (
+					archived_level_request.moderator_score.clone(),
+					archived_level_request.moderator_rating.clone()
+				) {
+					(Some(score), Some(rating)) => Some(moderator::Model {
+						guild_id: archived_level_request.guild_id,
+						level_id: archived_level_request.level_id,
+						score,
+						rating,
+						sent_at: archived_level_request.moderator_sent_at
+					}),
+					_ => None
+				};
+				let reviews = archived_reviews
+					.into_iter()
+					.map(|archived_review| review::Model {
+						guild_id: archived_review.guild_id,
+						level_id: archived_review.level_id,
+						discord_id: archived_review.discord_id,
+						message_id: archived_review.message_id,
+						review_content: archived_review.review_content,
+						rubric_scores: archived_review.rubric_scores,
+						suggested_score: archived_review.suggested_score,
+						suggested_rating: archived_review.suggested_rating
+					})
+					.collect();
+				(
+					MySqlLevelRequestRepository::unarchived_level_request_model(
+						archived_level_request
+					),
+					reviews,
+					moderator
+				)
+			})
+			.collect())
 	}
 
-	async fn get_record(&self, discord_id: u64) -> Result<Option<user::Model>, DbErr> {
-		User::find_by_id(discord_id).one(self.db_conn).await
+	async fn get_moderator_records(
+		&self,
+		guild_id: u64,
+		discord_id: u64
+	) -> Result<Vec<moderator::Model>, DbErr> {
+		Moderator::find()
+			.inner_join(LevelRequest)
+			.filter(level_request::Column::GuildId.eq(guild_id))
+			.filter(level_request::Column::DiscordId.eq(discord_id))
+			.all(self.db_conn.as_ref())
+			.await
 	}
 
 	async fn update_record(&self, record: ActiveModel) -> Result<user::Model, DbErr> {
-		User::update(record).exec(self.db_conn).await
+		User::update(record).exec(self.db_conn.as_ref()).await
 	}
 
 	async fn delete_record(&self, record: ActiveModel) -> Result<DeleteResult, DbErr> {
-		User::delete(record).exec(self.db_conn).await
+		User::delete(record).exec(self.db_conn.as_ref()).await
 	}
 }
 
-impl<'a> MySqlUserRepository<'a> {
-	pub fn new(db_conn: &'a DbConn) -> Self { MySqlUserRepository { db_conn } }
+impl MySqlUserRepository {
+	pub fn new(db_conn: Arc<DbConn>) -> Self { MySqlUserRepository { db_conn } }
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+
+	use chrono::Utc;
+	use sea_orm::{DatabaseBackend, MockDatabase};
+
+	use crate::{
+		adapter::mysql::{
+			model::{
+				archived_level_request, archived_review, moderator, review, sea_orm_active_enums
+			},
+			mysql_user_repository::MySqlUserRepository,
+			user_repository::UserRepository
+		},
+		domain::model::gd_level::RequestStatus,
+		rocket::common::testing::level_request_model
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_get_level_request_records_should_group_reviews_by_request() {
+		let level_request = level_request_model(1, 99999999, 12345, RequestStatus::Reviewed);
+		let review = review::Model {
+			guild_id: 1.into(),
+			level_id: 99999999.into(),
+			discord_id: 54321.into(),
+			message_id: 11111.into(),
+			review_content: "Nice level".to_string(),
+			rubric_scores: None,
+			suggested_score: None,
+			suggested_rating: None
+		};
+		let db_conn = Arc::new(
+			MockDatabase::new(DatabaseBackend::MySql)
+				.append_query_results([vec![(level_request.clone(), review.clone())]])
+				.into_connection()
+		);
+		let repository = MySqlUserRepository::new(db_conn.clone());
+
+		assert_eq!(
+			repository
+				.get_level_request_records(1, 12345)
+				.await
+				.unwrap(),
+			vec![(level_request, vec![review])]
+		);
+
+		drop(repository);
+		let transaction_log = format!(
+			"{:?}",
+			Arc::try_unwrap(db_conn).unwrap().into_transaction_log()
+		);
+		assert!(transaction_log.contains("LEFT JOIN `review`"));
+		assert!(transaction_log.contains("`level_request`.`discord_id` = ?"));
+		assert!(!transaction_log.contains("`archived_at` IS NULL"));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_archived_level_request_records_should_keep_moderator_suggestion() {
+		let archived_level_request = archived_level_request_model();
+		let archived_review = archived_review::Model {
+			id: 1,
+			archived_level_request_id: 1,
+			guild_id: 1.into(),
+			level_id: 99999999.into(),
+			discord_id: 54321.into(),
+			message_id: 11111.into(),
+			review_content: "Nice level".to_string(),
+			rubric_scores: None,
+			suggested_score: None,
+			suggested_rating: None
+		};
+		let db_conn = MockDatabase::new(DatabaseBackend::MySql)
+			.append_query_results([vec![(
+				archived_level_request.clone(),
+				archived_review.clone()
+			)]])
+			.into_connection();
+		let repository = MySqlUserRepository::new(Arc::new(db_conn));
+
+		let archived_level_requests = repository
+			.get_archived_level_request_records(1, 12345)
+			.await
+			.unwrap();
+
+		assert_eq!(archived_level_requests.len(), 1);
+		let (level_request, reviews, moderator) = &archived_level_requests[0];
+		assert_eq!(
+			level_request.archived_at,
+			Some(archived_level_request.archived_at)
+		);
+		assert_eq!(
+			level_request.status,
+			sea_orm_active_enums::RequestStatus::Sent
+		);
+		assert_eq!(reviews.len(), 1);
+		assert_eq!(reviews[0].review_content, archived_review.review_content);
+		assert_eq!(
+			moderator.as_ref().unwrap().score,
+			sea_orm_active_enums::Score::Four
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_moderator_records_should_join_level_requests() {
+		let moderator = moderator::Model {
+			guild_id: 1.into(),
+			level_id: 99999999.into(),
+			score: sea_orm_active_enums::Score::Four,
+			rating: sea_orm_active_enums::Rating::Rate,
+			sent_at: None
+		};
+		let db_conn = Arc::new(
+			MockDatabase::new(DatabaseBackend::MySql)
+				.append_query_results([vec![moderator.clone()]])
+				.into_connection()
+		);
+		let repository = MySqlUserRepository::new(db_conn.clone());
+
+		assert_eq!(
+			repository.get_moderator_records(1, 12345).await.unwrap(),
+			vec![moderator]
+		);
+
+		drop(repository);
+		let transaction_log = format!(
+			"{:?}",
+			Arc::try_unwrap(db_conn).unwrap().into_transaction_log()
+		);
+		assert!(transaction_log.contains("INNER JOIN `level_request`"));
+		assert!(transaction_log.contains("`level_request`.`discord_id` = ?"));
+	}
+
+	fn archived_level_request_model() -> archived_level_request::Model {
+		let level_request = level_request_model(1, 99999999, 12345, RequestStatus::Sent);
+		archived_level_request::Model {
+			id: 1,
+			guild_id: level_request.guild_id,
+			level_id: level_request.level_id,
+			discord_id: level_request.discord_id,
+			discord_message_id: level_request.discord_message_id,
+			name: level_request.name,
+			author: level_request.author,
+			request_rating: level_request.request_rating,
+			level_length: level_request.level_length,
+			you_tube_video_link: level_request.you_tube_video_link,
+			has_requested_feedback: level_request.has_requested_feedback,
+			notify: level_request.notify,
+			status: level_request.status,
+			timestamp: level_request.timestamp,
+			archived_at: Utc::now(),
+			description: level_request.description,
+			author_account_id: level_request.author_account_id,
+			author_player_id: level_request.author_player_id,
+			difficulty: level_request.difficulty,
+			stars: level_request.stars,
+			feature_state: level_request.feature_state,
+			downloads: level_request.downloads,
+			likes: level_request.likes,
+			game_version: level_request.game_version,
+			song_id: level_request.song_id,
+			song_name: level_request.song_name,
+			song_artist: level_request.song_artist,
+			is_custom_song: level_request.is_custom_song,
+			object_count: level_request.object_count,
+			coin_count: level_request.coin_count,
+			skipped_at: level_request.skipped_at,
+			moderator_score: Some(sea_orm_active_enums::Score::Four),
+			moderator_rating: Some(sea_orm_active_enums::Rating::Rate),
+			moderator_sent_at: None
+		}
+	}
 }
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use sea_orm::{
+	ColumnTrait, DatabaseConnection, DbConn, DbErr, DeleteResult, EntityTrait, InsertResult,
+	QueryFilter
+};
+
+use crate::adapter::mysql::{
+	model::{
+		prelude::UserRole,
+		user_role,
+		user_role::{ActiveModel, Model}
+	},
+	user_role_repository::UserRoleRepository
+};
+
+pub struct MySqlUserRoleRepository {
+	db_conn: Arc<DatabaseConnection>
+}
+
+impl UserRoleRepository for MySqlUserRoleRepository {
+	async fn create_record(&self, record: ActiveModel) -> Result<InsertResult<ActiveModel>, DbErr> {
+		UserRole::insert(record).exec(self.db_conn.as_ref()).await
+	}
+
+	async fn get_records(&self, guild_id: u64, discord_id: u64) -> Result<Vec<Model>, DbErr> {
+		UserRole::find()
+			.filter(user_role::Column::GuildId.eq(guild_id))
+			.filter(user_role::Column::DiscordId.eq(discord_id))
+			.all(self.db_conn.as_ref())
+			.await
+	}
+
+	async fn delete_record(&self, record: ActiveModel) -> Result<DeleteResult, DbErr> {
+		UserRole::delete(record).exec(self.db_conn.as_ref()).await
+	}
+}
+
+impl MySqlUserRoleRepository {
+	pub fn new(db_conn: Arc<DbConn>) -> Self { MySqlUserRoleRepository { db_conn } }
+}
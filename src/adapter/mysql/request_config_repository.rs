@@ -0,0 +1,20 @@
+use sea_orm::{DbErr, InsertResult};
+
+use crate::adapter::mysql::model::request_config;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait RequestConfigRepository {
+	async fn create_record(
+		&self,
+		record: request_config::ActiveModel
+	) -> Result<InsertResult<request_config::ActiveModel>, DbErr>;
+
+	async fn get_record(&self, guild_id: u64) -> Result<Option<request_config::Model>, DbErr>;
+
+	async fn get_records(&self) -> Result<Vec<request_config::Model>, DbErr>;
+
+	async fn update_record(
+		&self,
+		record: request_config::ActiveModel
+	) -> Result<request_config::Model, DbErr>;
+}
@@ -0,0 +1,46 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{DbErr, InsertResult};
+
+use crate::adapter::mysql::model::{
+	level_request, review_assignment, review_assignment::ActiveModel
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait ReviewAssignmentRepository {
+	/// Inserts the assignment unless the level already has one that is active
+	/// at `now`, returning `None` then. The level request is locked while
+	/// checking so that concurrent assignments of a level cannot both succeed.
+	async fn create_record_if_unassigned(
+		&self,
+		record: ActiveModel,
+		now: DateTime<Utc>
+	) -> Result<Option<InsertResult<ActiveModel>>, DbErr>;
+
+	/// The assignment on the level that has neither been completed, released
+	/// nor expired by `now`.
+	async fn get_active_record(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		now: DateTime<Utc>
+	) -> Result<Option<review_assignment::Model>, DbErr>;
+
+	/// Every assignment in the guild that is active at `now`, oldest first,
+	/// with the level request it is for. Given `reviewer_discord_id`, only
+	/// that reviewer's assignments.
+	async fn get_active_records(
+		&self,
+		guild_id: u64,
+		reviewer_discord_id: Option<u64>,
+		now: DateTime<Utc>
+	) -> Result<Vec<(review_assignment::Model, Option<level_request::Model>)>, DbErr>;
+
+	/// The most recent assignment in the guild, whether or not it is still
+	/// active.
+	async fn get_latest_record(
+		&self,
+		guild_id: u64
+	) -> Result<Option<review_assignment::Model>, DbErr>;
+
+	async fn update_record(&self, record: ActiveModel) -> Result<review_assignment::Model, DbErr>;
+}
@@ -1,4 +1,4 @@
-use sea_orm::{DbErr, DeleteResult, InsertResult};
+use sea_orm::{Condition, DbErr, DeleteResult, InsertResult, Order};
 
 use crate::adapter::mysql::model::review;
 
@@ -11,10 +11,31 @@ pub trait ReviewRepository {
 
 	async fn get_record(
 		&self,
+		guild_id: u64,
 		level_id: u64,
 		discord_id: u64
 	) -> Result<Option<review::Model>, DbErr>;
 
+	/// Every reviewer's review of the level.
+	async fn get_level_records(
+		&self,
+		guild_id: u64,
+		level_id: u64
+	) -> Result<Vec<review::Model>, DbErr>;
+
+	/// Reviews in the guild matching `condition`, which may also filter on the
+	/// reviewed level request, ordered by when the level was requested.
+	async fn get_records(
+		&self,
+		guild_id: u64,
+		condition: Condition,
+		order: Order,
+		offset: u64,
+		limit: u64
+	) -> Result<Vec<review::Model>, DbErr>;
+
+	async fn count_records(&self, guild_id: u64, condition: Condition) -> Result<u64, DbErr>;
+
 	async fn update_record(&self, record: review::ActiveModel) -> Result<review::Model, DbErr>;
 
 	async fn delete_record(&self, record: review::ActiveModel) -> Result<DeleteResult, DbErr>;
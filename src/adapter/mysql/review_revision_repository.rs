@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{DbErr, InsertResult};
+
+use crate::adapter::mysql::model::{level_request, review_revision, review_revision::ActiveModel};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait ReviewRevisionRepository {
+	async fn create_record(&self, record: ActiveModel) -> Result<InsertResult<ActiveModel>, DbErr>;
+
+	/// Every revision of the reviewer's review of the level, oldest first.
+	async fn get_records(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		discord_id: u64
+	) -> Result<Vec<review_revision::Model>, DbErr>;
+
+	/// The first revision of each review, which is when it was written.
+	async fn get_first_records(
+		&self,
+		guild_id: u64,
+		discord_id: Option<u64>,
+		reviewed_from: Option<DateTime<Utc>>,
+		reviewed_to: Option<DateTime<Utc>>
+	) -> Result<Vec<(review_revision::Model, Option<level_request::Model>)>, DbErr>;
+
+	async fn get_latest_record(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		discord_id: u64
+	) -> Result<Option<review_revision::Model>, DbErr>;
+}
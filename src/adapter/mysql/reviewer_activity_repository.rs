@@ -0,0 +1,15 @@
+use sea_orm::{DbErr, InsertResult};
+
+use crate::adapter::mysql::model::{reviewer_activity, reviewer_activity::ActiveModel};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait ReviewerActivityRepository {
+	async fn create_record(&self, record: ActiveModel) -> Result<InsertResult<ActiveModel>, DbErr>;
+
+	/// Oldest first.
+	async fn get_records(
+		&self,
+		guild_id: u64,
+		discord_id: Option<u64>
+	) -> Result<Vec<reviewer_activity::Model>, DbErr>;
+}
@@ -11,12 +11,22 @@ pub trait ReviewerRepository {
 
 	async fn get_record(
 		&self,
+		guild_id: u64,
 		reviewer_discord_id: u64,
 		is_active: Option<bool>
 	) -> Result<Option<reviewer::Model>, DbErr>;
 
+	/// Every reviewer in the guild. Given `is_active`, only reviewers in that
+	/// state.
+	async fn get_records(
+		&self,
+		guild_id: u64,
+		is_active: Option<bool>
+	) -> Result<Vec<reviewer::Model>, DbErr>;
+
 	async fn get_record_ignore_active(
 		&self,
+		guild_id: u64,
 		reviewer_discord_id: u64
 	) -> Result<Option<reviewer::Model>, DbErr>;
 
@@ -0,0 +1,16 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{DbErr, DeleteResult, InsertResult};
+
+use crate::adapter::mysql::model::revoked_token;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait RevokedTokenRepository {
+	async fn create_record(
+		&self,
+		record: revoked_token::ActiveModel
+	) -> Result<InsertResult<revoked_token::ActiveModel>, DbErr>;
+
+	async fn get_record(&self, jti: &str) -> Result<Option<revoked_token::Model>, DbErr>;
+
+	async fn delete_expired_records(&self, now: DateTime<Utc>) -> Result<DeleteResult, DbErr>;
+}
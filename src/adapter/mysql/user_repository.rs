@@ -1,12 +1,47 @@
 use sea_orm::{DbErr, DeleteResult, InsertResult};
 
-use crate::adapter::mysql::model::{user, user::ActiveModel};
+use crate::adapter::mysql::model::{level_request, moderator, review, user, user::ActiveModel};
+
+/// An archived level request with its reviews and moderator suggestion.
+pub type ArchivedLevelRequestRecord = (
+	level_request::Model,
+	Vec<review::Model>,
+	Option<moderator::Model>
+);
 
 #[cfg_attr(test, mockall::automock)]
 pub trait UserRepository {
 	async fn create_record(&self, record: ActiveModel) -> Result<InsertResult<ActiveModel>, DbErr>;
 
-	async fn get_record(&self, discord_id: u64) -> Result<Option<user::Model>, DbErr>;
+	async fn get_record(
+		&self,
+		guild_id: u64,
+		discord_id: u64
+	) -> Result<Option<user::Model>, DbErr>;
+
+	/// Every level request the user has made, archived ones included, each
+	/// with the reviews it received.
+	async fn get_level_request_records(
+		&self,
+		guild_id: u64,
+		discord_id: u64
+	) -> Result<Vec<(level_request::Model, Vec<review::Model>)>, DbErr>;
+
+	/// Every level request of the user that was replaced by a new request for
+	/// the same level, as it was at the time, with the reviews it received and
+	/// the moderator suggestion it was sent with.
+	async fn get_archived_level_request_records(
+		&self,
+		guild_id: u64,
+		discord_id: u64
+	) -> Result<Vec<ArchivedLevelRequestRecord>, DbErr>;
+
+	/// The moderator suggestions the user's levels were sent with.
+	async fn get_moderator_records(
+		&self,
+		guild_id: u64,
+		discord_id: u64
+	) -> Result<Vec<moderator::Model>, DbErr>;
 
 	async fn update_record(&self, record: ActiveModel) -> Result<user::Model, DbErr>;
 
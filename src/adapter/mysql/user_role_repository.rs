@@ -0,0 +1,19 @@
+use sea_orm::{DbErr, DeleteResult, InsertResult};
+
+use crate::adapter::mysql::model::user_role;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait UserRoleRepository {
+	async fn create_record(
+		&self,
+		record: user_role::ActiveModel
+	) -> Result<InsertResult<user_role::ActiveModel>, DbErr>;
+
+	async fn get_records(
+		&self,
+		guild_id: u64,
+		discord_id: u64
+	) -> Result<Vec<user_role::Model>, DbErr>;
+
+	async fn delete_record(&self, record: user_role::ActiveModel) -> Result<DeleteResult, DbErr>;
+}
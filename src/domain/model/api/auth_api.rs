@@ -1,10 +1,11 @@
 use std::{
+	convert::Infallible,
 	error::Error,
-	fmt::{Display, Formatter}
+	fmt::{Display, Formatter},
+	ops::Deref
 };
 
-use chrono::Local;
-use jsonwebtoken::{decode, errors::Error as JsonWebTokenError, DecodingKey, Validation};
+use chrono::{DateTime, Local, Utc};
 use rocket::serde::{Deserialize, Serialize};
 use rocket_framework::{
 	http::{ContentType, Status},
@@ -13,72 +14,150 @@ use rocket_framework::{
 	serde::json::Json,
 	Request, Response
 };
+use serde::{ser::SerializeStruct, Serializer};
+use subtle::ConstantTimeEq;
 
 use crate::{
-	domain::model::{auth::claims::Claims, error::level_request_error::LevelRequestError},
+	domain::{
+		model::auth::{claims::TokenType, role::Role, token_pair::TokenPair},
+		service::role_service::RoleService
+	},
 	rocket::common::{
-		config::{auth_config::AUTH_CONFIG, client_config::CLIENT_CONFIG},
-		constants::TIMESTAMP_HEADER_NAME
+		app_state::AppState,
+		constants::{
+			ACCESS_TOKEN_HEADER_NAME, AUTHORIZATION_HEADER_NAME, DISCORD_APP_ID_HEADER_NAME,
+			DISCORD_USER_ID_HEADER_NAME, GUILD_ID_HEADER_NAME, REFRESH_TOKEN_HEADER_NAME,
+			TIMESTAMP_HEADER_NAME
+		}
 	}
 };
 
 #[derive(Deserialize)]
 pub struct AuthApiRequest {
 	pub discord_app_id: u64,
-	_access_token: String
+	pub guild_id: u64,
+	pub discord_user_id: u64
+}
+
+#[derive(Deserialize)]
+pub struct RefreshAuthApiRequest {
+	pub discord_app_id: u64,
+	pub refresh_token: String
 }
 
+/// An optional refresh token presented alongside an access token, e.g. so both
+/// can be revoked together.
+pub struct RefreshToken(pub Option<String>);
+
 #[derive(Serialize)]
 pub struct AuthApiResponse {
-	jwt: String
+	jwt: String,
+	refresh_jwt: String
 }
 
 #[derive(Deserialize)]
-pub struct Auth {}
+pub struct Auth {
+	pub discord_app_id: u64,
+	pub guild_id: u64,
+	pub discord_user_id: u64,
+	pub roles: Vec<Role>,
+	pub jti: String,
+	pub expires_at: DateTime<Utc>
+}
 
-#[derive(Debug, PartialEq)]
+/// Any member of the guild.
+pub struct RequesterAuth(pub Auth);
+
+/// An active reviewer in the guild.
+pub struct ReviewerAuth(pub Auth);
+
+/// A guild moderator, able to send levels and move requests through their
+/// lifecycle.
+pub struct ModeratorAuth(pub Auth);
+
+/// A guild admin, able to manage reviewers, roles and the request config.
+pub struct AdminAuth(pub Auth);
+
+#[derive(Clone, Debug, PartialEq)]
 pub enum AuthApiError {
+	MissingHeader(&'static str),
+	MalformedHeader(&'static str),
+	UnknownApplication,
+	InvalidAccessToken,
+	InvalidToken,
+	MissingRole(Role),
 	AuthError
 }
 
-impl AuthApiResponse {
-	pub fn new(jwt: String) -> Self { Self { jwt } }
+impl From<TokenPair> for AuthApiResponse {
+	fn from(value: TokenPair) -> Self {
+		Self {
+			jwt: value.access_token,
+			refresh_jwt: value.refresh_token
+		}
+	}
 }
 
 #[rocket::async_trait]
 impl<'r> FromRequest<'r> for AuthApiRequest {
-	type Error = LevelRequestError;
+	type Error = AuthApiError;
 
 	async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
-		let discord_app_id = request.headers().get_one("X-REQUESTX-DISCORD-APP-ID");
-		let access_token = request.headers().get_one("X-REQUESTX-ACCESS-TOKEN");
-		if discord_app_id.is_some() && access_token.is_some() {
-			if discord_app_id
-				.unwrap()
-				.parse::<u64>()
-				.unwrap()
-				.ne(&CLIENT_CONFIG.discord_app_id)
-			{
-				Outcome::Forward(Status::Unauthorized)
-			} else if access_token.unwrap().ne(&AUTH_CONFIG.access_token) {
-				Outcome::Forward(Status::Forbidden)
-			} else {
-				Outcome::Success(AuthApiRequest {
-					discord_app_id: discord_app_id.unwrap().parse::<u64>().unwrap(),
-					_access_token: access_token.unwrap().to_owned()
-				})
-			}
-		} else {
-			Outcome::Forward(Status::Unauthorized)
+		let authenticate = || -> Result<Self, AuthApiError> {
+			Ok(AuthApiRequest {
+				discord_app_id: authenticate_app(request)?,
+				guild_id: parse_header(request, GUILD_ID_HEADER_NAME)?,
+				discord_user_id: parse_header(request, DISCORD_USER_ID_HEADER_NAME)?
+			})
+		};
+
+		match authenticate() {
+			Ok(auth_api_request) => Outcome::Success(auth_api_request),
+			Err(auth_api_error) => auth_api_error.into_outcome(request)
+		}
+	}
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for RefreshAuthApiRequest {
+	type Error = AuthApiError;
+
+	async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+		let authenticate = || -> Result<Self, AuthApiError> {
+			Ok(RefreshAuthApiRequest {
+				discord_app_id: authenticate_app(request)?,
+				refresh_token: get_header(request, REFRESH_TOKEN_HEADER_NAME)?
+					.replace("Bearer ", "")
+			})
+		};
+
+		match authenticate() {
+			Ok(refresh_auth_api_request) => Outcome::Success(refresh_auth_api_request),
+			Err(auth_api_error) => auth_api_error.into_outcome(request)
 		}
 	}
 }
 
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for RefreshToken {
+	type Error = Infallible;
+
+	async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+		Outcome::Success(RefreshToken(
+			request
+				.headers()
+				.get_one(REFRESH_TOKEN_HEADER_NAME)
+				.map(|refresh_token| refresh_token.replace("Bearer ", ""))
+		))
+	}
+}
+
 impl<'r> Responder<'r, 'r> for AuthApiResponse {
 	fn respond_to(self, _request: &'r Request<'_>) -> rocket_framework::response::Result<'r> {
 		Response::build()
 			.status(Status::Created)
-			.raw_header("AUTHORIZATION", self.jwt)
+			.raw_header(AUTHORIZATION_HEADER_NAME, self.jwt)
+			.raw_header(REFRESH_TOKEN_HEADER_NAME, self.refresh_jwt)
 			.raw_header("X-TIMESTAMP", format!("{}", Local::now()))
 			.ok()
 	}
@@ -86,41 +165,216 @@ impl<'r> Responder<'r, 'r> for AuthApiResponse {
 
 #[rocket::async_trait]
 impl<'r> FromRequest<'r> for Auth {
-	type Error = ();
+	type Error = AuthApiError;
 
 	async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
-		let discord_app_id = request.headers().get_one("X-REQUESTX-DISCORD-APP-ID");
-		let jwt = request.headers().get_one("AUTHORIZATION");
-		if discord_app_id.is_some() && jwt.is_some() {
-			let mut validation = Validation::default();
-			validation.set_audience(&[discord_app_id.unwrap().to_string()]);
-
-			match decode::<Claims>(
-				&jwt.unwrap().replace("Bearer ", ""),
-				&DecodingKey::from_secret(&AUTH_CONFIG.secret_token.as_ref()),
-				&validation
-			) {
-				Ok(_token_claims) => Outcome::Success(Auth {}),
-				Err(_err) => {
-					info!("{:?}", _err);
-					Outcome::Forward(Status::Forbidden)
-				}
+		match Self::authenticate(request).await {
+			Ok(auth) => Outcome::Success(auth),
+			Err(auth_api_error) => auth_api_error.into_outcome(request)
+		}
+	}
+}
+
+impl Auth {
+	pub fn has_role(&self, role: Role) -> bool {
+		self.roles
+			.iter()
+			.any(|granted_role| granted_role.grants(role))
+	}
+
+	/// Whether the caller may act on behalf of `discord_user_id`. Admins may
+	/// act for anyone.
+	pub fn is_acting_as(&self, discord_user_id: u64) -> bool {
+		self.discord_user_id.eq(&discord_user_id) || self.has_role(Role::Admin)
+	}
+
+	async fn authenticate(request: &Request<'_>) -> Result<Self, AuthApiError> {
+		let discord_app_id = parse_header(request, DISCORD_APP_ID_HEADER_NAME)?;
+		let jwt = get_header(request, AUTHORIZATION_HEADER_NAME)?;
+		match get_app_state(request)?
+			.auth_service()
+			.decode_jwt(
+				&jwt.replace("Bearer ", ""),
+				discord_app_id,
+				TokenType::Access
+			)
+			.await
+		{
+			Ok(claims) => Ok(Auth {
+				discord_app_id,
+				guild_id: claims.guild_id,
+				discord_user_id: claims.sub,
+				expires_at: claims.expires_at(),
+				roles: claims.roles,
+				jti: claims.jti
+			}),
+			Err(decode_jwt_error) => {
+				info!("Rejected token: {}", decode_jwt_error);
+				Err(decode_jwt_error.into())
 			}
+		}
+	}
+
+	async fn authorize(request: &Request<'_>, role: Role) -> Outcome<Self, AuthApiError> {
+		match request.guard::<Auth>().await {
+			Outcome::Success(auth) => auth.require_role(request, role),
+			outcome => outcome
+		}
+	}
+
+	/// Roles can be taken away while an access token is still valid, so
+	/// privileged guards check the roles currently stored for the user instead
+	/// of the ones in the token.
+	async fn authorize_current_roles(
+		request: &Request<'_>,
+		role: Role
+	) -> Outcome<Self, AuthApiError> {
+		match request.guard::<Auth>().await {
+			Outcome::Success(mut auth) => match auth.get_current_roles(request).await {
+				Ok(roles) => {
+					auth.roles = roles;
+					auth.require_role(request, role)
+				}
+				Err(auth_api_error) => auth_api_error.into_outcome(request)
+			},
+			outcome => outcome
+		}
+	}
+
+	async fn get_current_roles(&self, request: &Request<'_>) -> Result<Vec<Role>, AuthApiError> {
+		get_app_state(request)?
+			.role_service()
+			.get_roles(self.guild_id, self.discord_user_id)
+			.await
+			.map_err(|get_roles_error| {
+				error!(
+					"Error getting roles of user {} in guild {}: {}",
+					self.discord_user_id, self.guild_id, get_roles_error
+				);
+				AuthApiError::AuthError
+			})
+	}
+
+	fn require_role(self, request: &Request<'_>, role: Role) -> Outcome<Self, AuthApiError> {
+		if self.has_role(role) {
+			Outcome::Success(self)
 		} else {
-			Outcome::Forward(Status::Unauthorized)
+			info!(
+				"User {} in guild {} is missing role {:?}",
+				self.discord_user_id, self.guild_id, role
+			);
+			AuthApiError::MissingRole(role).into_outcome(request)
 		}
 	}
 }
 
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for RequesterAuth {
+	type Error = AuthApiError;
+
+	async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+		Auth::authorize(request, Role::Requester)
+			.await
+			.map(RequesterAuth)
+	}
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for ReviewerAuth {
+	type Error = AuthApiError;
+
+	async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+		Auth::authorize(request, Role::Reviewer)
+			.await
+			.map(ReviewerAuth)
+	}
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for ModeratorAuth {
+	type Error = AuthApiError;
+
+	async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+		Auth::authorize_current_roles(request, Role::Moderator)
+			.await
+			.map(ModeratorAuth)
+	}
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for AdminAuth {
+	type Error = AuthApiError;
+
+	async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+		Auth::authorize_current_roles(request, Role::Admin)
+			.await
+			.map(AdminAuth)
+	}
+}
+
+impl Deref for RequesterAuth {
+	type Target = Auth;
+
+	fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+impl Deref for ReviewerAuth {
+	type Target = Auth;
+
+	fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+impl Deref for ModeratorAuth {
+	type Target = Auth;
+
+	fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+impl Deref for AdminAuth {
+	type Target = Auth;
+
+	fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+impl AuthApiError {
+	pub fn status(&self) -> Status {
+		match self {
+			AuthApiError::MissingHeader(_) => Status::Unauthorized,
+			AuthApiError::MalformedHeader(_) => Status::BadRequest,
+			AuthApiError::UnknownApplication => Status::Unauthorized,
+			AuthApiError::InvalidAccessToken => Status::Forbidden,
+			AuthApiError::InvalidToken => Status::Unauthorized,
+			AuthApiError::MissingRole(_) => Status::Forbidden,
+			AuthApiError::AuthError => Status::InternalServerError
+		}
+	}
+
+	/// Fails a request guard. Rocket does not hand guard errors to catchers, so
+	/// the error is also cached on the request for them to report.
+	fn into_outcome<T>(self, request: &Request<'_>) -> Outcome<T, AuthApiError> {
+		request.local_cache(|| Some(self.clone()));
+		Outcome::Error((self.status(), self))
+	}
+}
+
+impl Serialize for AuthApiError {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer
+	{
+		let mut state = serializer.serialize_struct("AuthApiError", 1)?;
+		state.serialize_field("message", &self.to_string())?;
+		state.end()
+	}
+}
+
 impl<'r> Responder<'r, 'r> for AuthApiError {
 	fn respond_to(self, request: &'r Request<'_>) -> rocket_framework::response::Result<'r> {
-		let json = Json(self.to_string());
-		let mut response = Response::build_from(json.respond_to(&request).unwrap());
-
-		response
+		let json = Json(&self);
+		Response::build_from(json.respond_to(&request).unwrap())
 			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
 			.header(ContentType::JSON)
-			.status(Status::InternalServerError)
+			.status(self.status())
 			.ok()
 	}
 }
@@ -128,15 +382,72 @@ impl<'r> Responder<'r, 'r> for AuthApiError {
 impl Display for AuthApiError {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		match self {
+			AuthApiError::MissingHeader(header_name) => {
+				write!(f, "Missing required header {}", header_name)
+			}
+			AuthApiError::MalformedHeader(header_name) => {
+				write!(f, "Header {} is malformed", header_name)
+			}
+			AuthApiError::UnknownApplication => {
+				write!(f, "Discord application is not recognized")
+			}
+			AuthApiError::InvalidAccessToken => {
+				write!(f, "Access token is invalid")
+			}
+			AuthApiError::InvalidToken => {
+				write!(f, "Token is invalid or has been revoked")
+			}
+			AuthApiError::MissingRole(role) => {
+				write!(f, "User does not have the {:?} role", role)
+			}
 			AuthApiError::AuthError => {
-				write!(f, "{{\"message\": \"Internal server error\"}}")
+				write!(f, "Internal server error")
 			}
 		}
 	}
 }
 
-impl From<JsonWebTokenError> for AuthApiError {
-	fn from(_value: JsonWebTokenError) -> Self { Self::AuthError }
+impl Error for AuthApiError {}
+
+fn get_header<'r>(
+	request: &'r Request<'_>,
+	header_name: &'static str
+) -> Result<&'r str, AuthApiError> {
+	request
+		.headers()
+		.get_one(header_name)
+		.ok_or(AuthApiError::MissingHeader(header_name))
 }
 
-impl Error for AuthApiError {}
+fn parse_header(request: &Request<'_>, header_name: &'static str) -> Result<u64, AuthApiError> {
+	get_header(request, header_name)?
+		.parse::<u64>()
+		.map_err(|_| AuthApiError::MalformedHeader(header_name))
+}
+
+/// Checks the Discord app ID and shared access token used to request tokens.
+/// The access token is compared in constant time.
+fn authenticate_app(request: &Request<'_>) -> Result<u64, AuthApiError> {
+	let app_config = get_app_state(request)?.app_config;
+	let discord_app_id = parse_header(request, DISCORD_APP_ID_HEADER_NAME)?;
+	let access_token = get_header(request, ACCESS_TOKEN_HEADER_NAME)?;
+
+	if discord_app_id.ne(&app_config.client_config.discord_app_id) {
+		Err(AuthApiError::UnknownApplication)
+	} else if !bool::from(
+		access_token
+			.as_bytes()
+			.ct_eq(app_config.auth_config.access_token.as_bytes())
+	) {
+		Err(AuthApiError::InvalidAccessToken)
+	} else {
+		Ok(discord_app_id)
+	}
+}
+
+fn get_app_state<'r>(request: &'r Request<'_>) -> Result<&'r AppState, AuthApiError> {
+	request.rocket().state::<AppState>().ok_or_else(|| {
+		error!("Application state is not managed by Rocket");
+		AuthApiError::AuthError
+	})
+}
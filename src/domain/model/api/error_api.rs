@@ -0,0 +1,35 @@
+use chrono::Local;
+use rocket_framework::{
+	http::{ContentType, Status},
+	response,
+	response::Responder,
+	serde::json::Json,
+	Request, Response
+};
+use serde_derive::Serialize;
+
+use crate::rocket::common::constants::TIMESTAMP_HEADER_NAME;
+
+/// Body returned by the catchers, matching the `message` field of the typed
+/// API errors.
+#[derive(Serialize)]
+pub struct ErrorApiResponse {
+	#[serde(skip)]
+	status: Status,
+	message: String
+}
+
+impl ErrorApiResponse {
+	pub fn new(status: Status, message: String) -> Self { Self { status, message } }
+}
+
+impl<'r> Responder<'r, 'r> for ErrorApiResponse {
+	fn respond_to(self, request: &'r Request<'_>) -> response::Result<'r> {
+		let json = Json(&self);
+		Response::build_from(json.respond_to(&request).unwrap())
+			.status(self.status)
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON)
+			.ok()
+	}
+}
@@ -0,0 +1,91 @@
+use std::{
+	error::Error,
+	fmt::{Debug, Display, Formatter}
+};
+
+use chrono::Local;
+use rocket::serde::Serialize;
+use rocket_framework::{
+	http::{ContentType, Status},
+	response,
+	response::Responder,
+	serde::json::Json,
+	Request, Response
+};
+use serde::{ser::SerializeStruct, Serializer};
+
+use crate::rocket::common::constants::TIMESTAMP_HEADER_NAME;
+
+#[derive(Debug, PartialEq)]
+pub enum LevelQueueApiResponseError {
+	QueueEmpty,
+	LevelRequestDoesNotExist,
+	LevelRequestNotQueued,
+	LevelRequestNotSkipped,
+	LevelQueueError
+}
+
+impl Serialize for LevelQueueApiResponseError {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer
+	{
+		let mut state = serializer.serialize_struct("LevelQueueApiResponseError", 1)?;
+		state.serialize_field("message", &self.to_string())?;
+		state.end()
+	}
+}
+
+impl<'r> Responder<'r, 'r> for LevelQueueApiResponseError {
+	fn respond_to(self, request: &'r Request<'_>) -> response::Result<'r> {
+		let json = Json(&self);
+		let mut response = Response::build_from(json.respond_to(request).unwrap());
+		response
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON);
+
+		match self {
+			LevelQueueApiResponseError::QueueEmpty => {
+				response.status(Status::NotFound);
+			}
+			LevelQueueApiResponseError::LevelRequestDoesNotExist => {
+				response.status(Status::NotFound);
+			}
+			LevelQueueApiResponseError::LevelRequestNotQueued => {
+				response.status(Status::Conflict);
+			}
+			LevelQueueApiResponseError::LevelRequestNotSkipped => {
+				response.status(Status::Conflict);
+			}
+			LevelQueueApiResponseError::LevelQueueError => {
+				response.status(Status::InternalServerError);
+			}
+		}
+
+		response.ok()
+	}
+}
+
+impl Display for LevelQueueApiResponseError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			LevelQueueApiResponseError::QueueEmpty => {
+				write!(f, "There are no pending level requests")
+			}
+			LevelQueueApiResponseError::LevelRequestDoesNotExist => {
+				write!(f, "Level request does not exist")
+			}
+			LevelQueueApiResponseError::LevelRequestNotQueued => {
+				write!(f, "Level request is no longer pending")
+			}
+			LevelQueueApiResponseError::LevelRequestNotSkipped => {
+				write!(f, "Level request has not been skipped")
+			}
+			LevelQueueApiResponseError::LevelQueueError => {
+				write!(f, "Internal server error")
+			}
+		}
+	}
+}
+
+impl Error for LevelQueueApiResponseError {}
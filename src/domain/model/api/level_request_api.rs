@@ -1,4 +1,5 @@
 use std::{
+	collections::HashMap,
 	error::Error,
 	fmt::{Debug, Display, Formatter}
 };
@@ -10,14 +11,23 @@ use rocket_framework::{
 	response,
 	response::Responder,
 	serde::json::Json,
-	Request, Response
+	FromForm, FromFormField, Request, Response
 };
 use serde::{ser::SerializeStruct, Serializer};
 use serde_derive::Deserialize;
 
 use crate::{
-	domain::model::{gd_level, gd_level::GDLevelRequest},
-	rocket::common::constants::TIMESTAMP_HEADER_NAME
+	domain::model::{
+		gd_level,
+		gd_level::{GDLevelRequest, LevelRequestSubmission, LevelRequestUpdate},
+		internal::api::internal_ban_api::BanTargetType,
+		level_queue::QueuedLevelRequest,
+		level_request_query,
+		level_request_query::{LevelRequestFilter, LevelRequestPage}
+	},
+	rocket::common::constants::{
+		DEFAULT_PAGE_SIZE, RETRY_AFTER_HEADER_NAME, TIMESTAMP_HEADER_NAME
+	}
 };
 
 #[derive(Serialize)]
@@ -28,11 +38,26 @@ pub struct GetLevelRequestApiResponse {
 	pub level_name: Option<String>,
 	pub level_author: Option<String>,
 	pub level_length: Option<LevelLength>,
+	pub level_description: Option<String>,
+	pub level_author_account_id: Option<u64>,
+	pub level_author_player_id: Option<u64>,
+	pub level_difficulty: Option<LevelDifficulty>,
+	pub level_stars: Option<u8>,
+	pub level_feature_state: Option<FeatureState>,
+	pub level_downloads: Option<u32>,
+	pub level_likes: Option<i32>,
+	pub level_game_version: Option<String>,
+	pub level_song: Option<LevelSong>,
+	pub level_object_count: Option<u32>,
+	pub level_coin_count: Option<u8>,
 	pub request_score: RequestRating,
 	pub youtube_video_link: String,
 	pub has_requested_feedback: bool,
 	pub notify: bool,
-	pub timestamp: DateTime<Utc>
+	pub status: RequestStatus,
+	pub timestamp: DateTime<Utc>,
+	/// 1 for the next level, `None` once the request is no longer pending.
+	pub queue_position: Option<u64>
 }
 
 impl From<GDLevelRequest> for GetLevelRequestApiResponse {
@@ -49,11 +74,27 @@ impl From<GDLevelRequest> for GetLevelRequestApiResponse {
 				level_name: Some(gd_level.name),
 				level_author: Some(gd_level.creator.name),
 				level_length: Some(gd_level.level_length.into()),
+				level_description: gd_level.description,
+				level_author_account_id: Some(gd_level.creator.account_id),
+				level_author_player_id: Some(gd_level.creator.player_id),
+				level_difficulty: Some(gd_level.difficulty.into()),
+				level_stars: Some(gd_level.stars),
+				level_feature_state: Some(gd_level.feature_state.into()),
+				level_downloads: Some(gd_level.downloads),
+				level_likes: Some(gd_level.likes),
+				level_game_version: gd_level
+					.game_version
+					.map(|game_version| game_version.to_string()),
+				level_song: gd_level.song.map(LevelSong::from),
+				level_object_count: gd_level.object_count,
+				level_coin_count: Some(gd_level.coin_count),
 				request_score: value.request_rating.into(),
 				youtube_video_link: value.youtube_video_link,
 				has_requested_feedback: value.has_requested_feedback,
 				notify: value.notify,
-				timestamp: value.timestamp
+				status: value.status.into(),
+				timestamp: value.timestamp,
+				queue_position: None
 			}
 		} else {
 			Self {
@@ -67,16 +108,44 @@ impl From<GDLevelRequest> for GetLevelRequestApiResponse {
 				level_name: None,
 				level_author: None,
 				level_length: None,
+				level_description: None,
+				level_author_account_id: None,
+				level_author_player_id: None,
+				level_difficulty: None,
+				level_stars: None,
+				level_feature_state: None,
+				level_downloads: None,
+				level_likes: None,
+				level_game_version: None,
+				level_song: None,
+				level_object_count: None,
+				level_coin_count: None,
 				request_score: value.request_rating.into(),
 				youtube_video_link: value.youtube_video_link,
 				has_requested_feedback: value.has_requested_feedback,
 				notify: value.notify,
-				timestamp: value.timestamp
+				status: value.status.into(),
+				timestamp: value.timestamp,
+				queue_position: None
 			}
 		}
 	}
 }
 
+impl From<QueuedLevelRequest> for GetLevelRequestApiResponse {
+	fn from(value: QueuedLevelRequest) -> Self {
+		GetLevelRequestApiResponse::from(value.level_request)
+			.with_queue_position(Some(value.position))
+	}
+}
+
+impl GetLevelRequestApiResponse {
+	pub fn with_queue_position(mut self, queue_position: Option<u64>) -> Self {
+		self.queue_position = queue_position;
+		self
+	}
+}
+
 impl<'r> Responder<'r, 'r> for GetLevelRequestApiResponse {
 	fn respond_to(self, request: &Request) -> response::Result<'r> {
 		let json = Json(self);
@@ -88,6 +157,102 @@ impl<'r> Responder<'r, 'r> for GetLevelRequestApiResponse {
 	}
 }
 
+#[derive(FromForm)]
+pub struct GetLevelRequestsApiRequest {
+	pub request_rating: Option<RequestRating>,
+	pub level_length: Option<LevelLength>,
+	pub has_requested_feedback: Option<bool>,
+	pub status: Option<RequestStatus>,
+	pub discord_id: Option<u64>,
+	pub from_timestamp: Option<String>,
+	pub to_timestamp: Option<String>,
+	pub sort: Option<SortOrder>,
+	pub page: Option<u64>,
+	pub page_size: Option<u64>
+}
+
+impl GetLevelRequestsApiRequest {
+	pub fn page(&self) -> u64 { self.page.unwrap_or(1) }
+
+	pub fn page_size(&self) -> u64 { self.page_size.unwrap_or(DEFAULT_PAGE_SIZE) }
+
+	pub fn sort_order(&self) -> level_request_query::SortOrder {
+		self.sort.map(|sort| sort.into()).unwrap_or_default()
+	}
+
+	fn parse_timestamp(
+		timestamp: &Option<String>
+	) -> Result<Option<DateTime<Utc>>, LevelRequestApiResponseError> {
+		match timestamp {
+			Some(timestamp) => DateTime::parse_from_rfc3339(timestamp)
+				.map(|parsed_timestamp| Some(parsed_timestamp.with_timezone(&Utc)))
+				.map_err(|_| LevelRequestApiResponseError::MalformedRequest),
+			None => Ok(None)
+		}
+	}
+}
+
+impl TryFrom<&GetLevelRequestsApiRequest> for LevelRequestFilter {
+	type Error = LevelRequestApiResponseError;
+
+	fn try_from(value: &GetLevelRequestsApiRequest) -> Result<Self, Self::Error> {
+		Ok(Self {
+			request_rating: value
+				.request_rating
+				.map(|request_rating| request_rating.into()),
+			level_length: value.level_length.map(|level_length| level_length.into()),
+			has_requested_feedback: value.has_requested_feedback,
+			status: value.status.map(|status| status.into()),
+			discord_user_id: value.discord_id,
+			from_timestamp: GetLevelRequestsApiRequest::parse_timestamp(&value.from_timestamp)?,
+			to_timestamp: GetLevelRequestsApiRequest::parse_timestamp(&value.to_timestamp)?
+		})
+	}
+}
+
+#[derive(Serialize)]
+pub struct GetLevelRequestsApiResponse {
+	pub level_requests: Vec<GetLevelRequestApiResponse>,
+	pub page: u64,
+	pub page_size: u64,
+	pub total_count: u64
+}
+
+impl From<LevelRequestPage> for GetLevelRequestsApiResponse {
+	fn from(value: LevelRequestPage) -> Self {
+		Self {
+			level_requests: value
+				.level_requests
+				.into_iter()
+				.map(GetLevelRequestApiResponse::from)
+				.collect(),
+			page: value.page,
+			page_size: value.page_size,
+			total_count: value.total_count
+		}
+	}
+}
+
+impl GetLevelRequestsApiResponse {
+	pub fn with_queue_positions(mut self, queue_positions: &HashMap<u64, u64>) -> Self {
+		for level_request in self.level_requests.iter_mut() {
+			level_request.queue_position = queue_positions.get(&level_request.level_id).copied();
+		}
+		self
+	}
+}
+
+impl<'r> Responder<'r, 'r> for GetLevelRequestsApiResponse {
+	fn respond_to(self, request: &Request) -> response::Result<'r> {
+		let json = Json(self);
+		Response::build_from(json.respond_to(&request).unwrap())
+			.status(Status::Ok)
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON)
+			.ok()
+	}
+}
+
 #[derive(Deserialize)]
 pub struct PostLevelRequestApiRequest<'a> {
 	pub level_id: u64,
@@ -105,10 +270,23 @@ pub struct PostLevelRequestApiResponse {
 	pub level_name: Option<String>,
 	pub level_author: Option<String>,
 	pub level_length: Option<LevelLength>,
+	pub level_description: Option<String>,
+	pub level_author_account_id: Option<u64>,
+	pub level_author_player_id: Option<u64>,
+	pub level_difficulty: Option<LevelDifficulty>,
+	pub level_stars: Option<u8>,
+	pub level_feature_state: Option<FeatureState>,
+	pub level_downloads: Option<u32>,
+	pub level_likes: Option<i32>,
+	pub level_game_version: Option<String>,
+	pub level_song: Option<LevelSong>,
+	pub level_object_count: Option<u32>,
+	pub level_coin_count: Option<u8>,
 	pub request_score: RequestRating,
 	pub youtube_video_link: String,
 	pub has_requested_feedback: bool,
-	pub notify: bool
+	pub notify: bool,
+	pub status: RequestStatus
 }
 
 #[derive(Deserialize)]
@@ -121,6 +299,32 @@ pub struct PatchLevelRequestApiRequest<'a> {
 	pub notify: Option<bool>
 }
 
+impl<'a> From<PostLevelRequestApiRequest<'a>> for LevelRequestSubmission {
+	fn from(value: PostLevelRequestApiRequest<'a>) -> Self {
+		Self {
+			youtube_video_link: value.youtube_video_link.to_string(),
+			request_rating: value.request_rating.into(),
+			has_requested_feedback: value.has_requested_feedback,
+			notify: value.notify
+		}
+	}
+}
+
+impl<'a> From<PatchLevelRequestApiRequest<'a>> for LevelRequestUpdate {
+	fn from(value: PatchLevelRequestApiRequest<'a>) -> Self {
+		Self {
+			youtube_video_link: value
+				.youtube_video_link
+				.map(|youtube_video_link| youtube_video_link.to_string()),
+			request_rating: value
+				.request_rating
+				.map(|request_rating| request_rating.into()),
+			has_requested_feedback: value.has_requested_feedback,
+			notify: value.notify
+		}
+	}
+}
+
 impl From<GDLevelRequest> for PostLevelRequestApiResponse {
 	fn from(value: GDLevelRequest) -> Self {
 		if let Some(gd_level) = value.gd_level {
@@ -130,10 +334,25 @@ impl From<GDLevelRequest> for PostLevelRequestApiResponse {
 				level_name: Some(gd_level.name),
 				level_author: Some(gd_level.creator.name),
 				level_length: Some(gd_level.level_length.into()),
+				level_description: gd_level.description,
+				level_author_account_id: Some(gd_level.creator.account_id),
+				level_author_player_id: Some(gd_level.creator.player_id),
+				level_difficulty: Some(gd_level.difficulty.into()),
+				level_stars: Some(gd_level.stars),
+				level_feature_state: Some(gd_level.feature_state.into()),
+				level_downloads: Some(gd_level.downloads),
+				level_likes: Some(gd_level.likes),
+				level_game_version: gd_level
+					.game_version
+					.map(|game_version| game_version.to_string()),
+				level_song: gd_level.song.map(LevelSong::from),
+				level_object_count: gd_level.object_count,
+				level_coin_count: Some(gd_level.coin_count),
 				request_score: value.request_rating.into(),
 				youtube_video_link: value.youtube_video_link,
 				has_requested_feedback: value.has_requested_feedback,
-				notify: value.notify
+				notify: value.notify,
+				status: value.status.into()
 			}
 		} else {
 			Self {
@@ -142,10 +361,23 @@ impl From<GDLevelRequest> for PostLevelRequestApiResponse {
 				level_name: None,
 				level_author: None,
 				level_length: None,
+				level_description: None,
+				level_author_account_id: None,
+				level_author_player_id: None,
+				level_difficulty: None,
+				level_stars: None,
+				level_feature_state: None,
+				level_downloads: None,
+				level_likes: None,
+				level_game_version: None,
+				level_song: None,
+				level_object_count: None,
+				level_coin_count: None,
 				request_score: value.request_rating.into(),
 				youtube_video_link: value.youtube_video_link,
 				has_requested_feedback: value.has_requested_feedback,
-				notify: value.notify
+				notify: value.notify,
+				status: value.status.into()
 			}
 		}
 	}
@@ -168,8 +400,13 @@ pub enum LevelRequestApiResponseError {
 	LevelRequestExists,
 	LevelRequestDoesNotExist,
 	UserOnCooldown(DateTime<Utc>, Duration),
+	RequestQuotaExceeded(RequestQuota, u32, Option<Duration>),
 	EditUnownedLevelRequest(u64, u64, u64),
+	ActingAsAnotherUser,
 	LevelRequestDisabled,
+	IllegalStatusTransition(RequestStatus, RequestStatus),
+	IneligibleLevel(IneligibilityReason),
+	Banned(BanTargetType, Option<DateTime<Utc>>, Option<String>),
 	LevelRequestError
 }
 
@@ -187,6 +424,26 @@ impl Serialize for LevelRequestApiResponseError {
 				state.serialize_field("last_request_time", last_request_time)?;
 				state.serialize_field("request_cooldown", &request_cooldown.num_minutes())?;
 			}
+			LevelRequestApiResponseError::RequestQuotaExceeded(quota, limit, retry_after) => {
+				state.serialize_field("quota", quota)?;
+				state.serialize_field("limit", limit)?;
+				state.serialize_field(
+					"retry_after",
+					&retry_after.map(|retry_after| retry_after.num_seconds())
+				)?;
+			}
+			LevelRequestApiResponseError::IllegalStatusTransition(current_status, status) => {
+				state.serialize_field("current_status", current_status)?;
+				state.serialize_field("requested_status", status)?;
+			}
+			LevelRequestApiResponseError::IneligibleLevel(reason) => {
+				state.serialize_field("reason", reason)?;
+			}
+			LevelRequestApiResponseError::Banned(target_type, expires_at, reason) => {
+				state.serialize_field("banned", target_type)?;
+				state.serialize_field("expires_at", expires_at)?;
+				state.serialize_field("reason", reason)?;
+			}
 			_ => {}
 		}
 
@@ -212,15 +469,40 @@ impl<'r> Responder<'r, 'r> for LevelRequestApiResponseError {
 			LevelRequestApiResponseError::LevelRequestDoesNotExist => {
 				response.status(Status::NotFound);
 			}
-			LevelRequestApiResponseError::UserOnCooldown(_, _) => {
+			LevelRequestApiResponseError::UserOnCooldown(last_request_time, request_cooldown) => {
+				let retry_after = last_request_time + request_cooldown - Utc::now();
+				response.status(Status::TooManyRequests).raw_header(
+					RETRY_AFTER_HEADER_NAME,
+					retry_after.num_seconds().max(0).to_string()
+				);
+			}
+			LevelRequestApiResponseError::RequestQuotaExceeded(_, _, retry_after) => {
 				response.status(Status::TooManyRequests);
+				if let Some(retry_after) = retry_after {
+					response.raw_header(
+						RETRY_AFTER_HEADER_NAME,
+						retry_after.num_seconds().to_string()
+					);
+				}
 			}
 			LevelRequestApiResponseError::EditUnownedLevelRequest(_, _, _) => {
 				response.status(Status::Forbidden);
 			}
+			LevelRequestApiResponseError::ActingAsAnotherUser => {
+				response.status(Status::Forbidden);
+			}
 			LevelRequestApiResponseError::LevelRequestDisabled => {
 				response.status(Status::ServiceUnavailable);
 			}
+			LevelRequestApiResponseError::IllegalStatusTransition(_, _) => {
+				response.status(Status::Conflict);
+			}
+			LevelRequestApiResponseError::IneligibleLevel(_) => {
+				response.status(Status::UnprocessableEntity);
+			}
+			LevelRequestApiResponseError::Banned(_, _, _) => {
+				response.status(Status::Forbidden);
+			}
 			LevelRequestApiResponseError::LevelRequestError => {
 				response.status(Status::InternalServerError);
 			}
@@ -245,12 +527,30 @@ impl Display for LevelRequestApiResponseError {
 			LevelRequestApiResponseError::UserOnCooldown(_, _) => {
 				write!(f, "User is on cooldown")
 			}
+			LevelRequestApiResponseError::RequestQuotaExceeded(_, _, _) => {
+				write!(f, "User has reached a request quota")
+			}
 			LevelRequestApiResponseError::EditUnownedLevelRequest(_, _, _) => {
 				write!(f, "User attempted to edit a request they do not own")
 			}
+			LevelRequestApiResponseError::ActingAsAnotherUser => {
+				write!(f, "User attempted to act on behalf of another user")
+			}
 			LevelRequestApiResponseError::LevelRequestDisabled => {
 				write!(f, "Level requests are disabled")
 			}
+			LevelRequestApiResponseError::IllegalStatusTransition(_, _) => {
+				write!(f, "Level request cannot move to the requested status")
+			}
+			LevelRequestApiResponseError::IneligibleLevel(_) => {
+				write!(f, "Level is not eligible for requests")
+			}
+			LevelRequestApiResponseError::Banned(BanTargetType::User, _, _) => {
+				write!(f, "User is banned from requesting levels")
+			}
+			LevelRequestApiResponseError::Banned(BanTargetType::Level, _, _) => {
+				write!(f, "Level is blocked from requests")
+			}
 			LevelRequestApiResponseError::LevelRequestError => {
 				write!(f, "Internal server error")
 			}
@@ -260,7 +560,7 @@ impl Display for LevelRequestApiResponseError {
 
 impl Error for LevelRequestApiResponseError {}
 
-#[derive(Deserialize, Serialize, Clone, Copy)]
+#[derive(Deserialize, Serialize, Clone, Copy, FromFormField)]
 pub enum RequestRating {
 	One,
 	Two,
@@ -291,7 +591,7 @@ impl Into<gd_level::RequestRating> for RequestRating {
 	}
 }
 
-#[derive(Deserialize, Serialize, Clone, Copy)]
+#[derive(Deserialize, Serialize, Clone, Copy, FromFormField)]
 pub enum LevelLength {
 	Tiny,
 	Short,
@@ -313,3 +613,97 @@ impl Into<gd_level::LevelLength> for LevelLength {
 		}
 	}
 }
+
+#[derive(Serialize, Clone, Copy)]
+pub enum LevelDifficulty {
+	NotAvailable,
+	Auto,
+	Easy,
+	Normal,
+	Hard,
+	Harder,
+	Insane,
+	EasyDemon,
+	MediumDemon,
+	HardDemon,
+	InsaneDemon,
+	ExtremeDemon
+}
+
+#[derive(Serialize, Clone, Copy)]
+pub enum FeatureState {
+	NotFeatured,
+	Featured,
+	Epic
+}
+
+#[derive(Serialize)]
+pub struct LevelSong {
+	pub song_id: u64,
+	pub name: String,
+	pub artist: String,
+	pub is_custom: bool
+}
+
+impl From<gd_level::LevelSong> for LevelSong {
+	fn from(value: gd_level::LevelSong) -> Self {
+		Self {
+			song_id: value.song_id,
+			name: value.name,
+			artist: value.artist,
+			is_custom: value.is_custom
+		}
+	}
+}
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+pub enum IneligibilityReason {
+	AlreadyRated,
+	AlreadyFeatured,
+	TooFewObjects,
+	OutdatedGameVersion,
+	DeniedCreator,
+	LevelInfoUnavailable
+}
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+pub enum RequestQuota {
+	OpenRequests,
+	DailyRequests
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, FromFormField)]
+pub enum RequestStatus {
+	Pending,
+	InReview,
+	Reviewed,
+	Sent,
+	Rejected
+}
+
+impl From<RequestStatus> for gd_level::RequestStatus {
+	fn from(value: RequestStatus) -> Self {
+		match value {
+			RequestStatus::Pending => gd_level::RequestStatus::Pending,
+			RequestStatus::InReview => gd_level::RequestStatus::InReview,
+			RequestStatus::Reviewed => gd_level::RequestStatus::Reviewed,
+			RequestStatus::Sent => gd_level::RequestStatus::Sent,
+			RequestStatus::Rejected => gd_level::RequestStatus::Rejected
+		}
+	}
+}
+
+#[derive(Clone, Copy, FromFormField)]
+pub enum SortOrder {
+	Asc,
+	Desc
+}
+
+impl From<SortOrder> for level_request_query::SortOrder {
+	fn from(value: SortOrder) -> Self {
+		match value {
+			SortOrder::Asc => level_request_query::SortOrder::Ascending,
+			SortOrder::Desc => level_request_query::SortOrder::Descending
+		}
+	}
+}
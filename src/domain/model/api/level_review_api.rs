@@ -3,24 +3,69 @@ use std::{
 	fmt::{Display, Formatter}
 };
 
-use chrono::Local;
+use chrono::{DateTime, Local, Utc};
 use rocket::serde::{Deserialize, Serialize};
 use rocket_framework::{
 	http::{ContentType, Status},
 	response,
 	response::Responder,
 	serde::json::Json,
-	Request, Response
+	FromForm, Request, Response
 };
 
-use crate::{domain::model::review::LevelReview, rocket::common::constants::TIMESTAMP_HEADER_NAME};
+use crate::{
+	domain::model::{
+		api::level_request_api::SortOrder,
+		internal::api::moderator_api::{SuggestedRating, SuggestedScore},
+		level_request_query,
+		review::{LevelReview, LevelReviewSubmission},
+		review_query::{LevelReviewFilter, LevelReviewPage},
+		review_revision,
+		review_revision::{ReviewDiffLine, ReviewRevision, ReviewRevisionDiff, RubricScoreChange},
+		review_rubric::{LevelRubricSummary, ReviewRubric, RubricCategorySummary, RubricScore}
+	},
+	rocket::common::{
+		config::review_rubric_config::ReviewRubricConfig,
+		constants::{DEFAULT_PAGE_SIZE, TIMESTAMP_HEADER_NAME}
+	}
+};
+
+#[derive(Deserialize, Serialize)]
+pub struct RubricScoreApi {
+	pub category: String,
+	pub score: u8,
+	pub comment: Option<String>
+}
+
+impl From<RubricScore> for RubricScoreApi {
+	fn from(value: RubricScore) -> Self {
+		Self {
+			category: value.category,
+			score: value.score,
+			comment: value.comment
+		}
+	}
+}
+
+impl From<RubricScoreApi> for RubricScore {
+	fn from(value: RubricScoreApi) -> Self {
+		RubricScore {
+			category: value.category,
+			score: value.score,
+			comment: value.comment
+		}
+	}
+}
 
 #[derive(Serialize)]
 pub struct GetLevelReviewApiRespnse {
 	pub level_id: u64,
 	pub reviewer_discord_id: u64,
 	pub discord_message_id: u64,
-	pub review_contents: String
+	pub review_contents: String,
+	pub scores: Vec<RubricScoreApi>,
+	pub suggested_score: Option<SuggestedScore>,
+	pub suggested_rating: Option<SuggestedRating>
 }
 
 impl From<LevelReview> for GetLevelReviewApiRespnse {
@@ -29,7 +74,21 @@ impl From<LevelReview> for GetLevelReviewApiRespnse {
 			level_id: value.level_id,
 			reviewer_discord_id: value.reviewer_discord_id,
 			discord_message_id: value.discord_message_id,
-			review_contents: value.review_contents
+			review_contents: value.review_contents,
+			scores: value
+				.rubric
+				.scores
+				.into_iter()
+				.map(RubricScoreApi::from)
+				.collect(),
+			suggested_score: value
+				.rubric
+				.suggested_score
+				.map(|suggested_score| suggested_score.into()),
+			suggested_rating: value
+				.rubric
+				.suggested_rating
+				.map(|suggested_rating| suggested_rating.into())
 		}
 	}
 }
@@ -45,12 +104,109 @@ impl<'r> Responder<'r, 'r> for GetLevelReviewApiRespnse {
 	}
 }
 
+#[derive(FromForm)]
+pub struct GetLevelReviewsApiRequest {
+	pub level_id: Option<u64>,
+	pub reviewer_discord_id: Option<u64>,
+	/// The user who requested the reviewed levels.
+	pub requester_discord_id: Option<u64>,
+	pub search: Option<String>,
+	pub sort: Option<SortOrder>,
+	pub page: Option<u64>,
+	pub page_size: Option<u64>
+}
+
+impl GetLevelReviewsApiRequest {
+	pub fn page(&self) -> u64 { self.page.unwrap_or(1) }
+
+	pub fn page_size(&self) -> u64 { self.page_size.unwrap_or(DEFAULT_PAGE_SIZE) }
+
+	pub fn sort_order(&self) -> level_request_query::SortOrder {
+		self.sort.map(|sort| sort.into()).unwrap_or_default()
+	}
+}
+
+impl From<&GetLevelReviewsApiRequest> for LevelReviewFilter {
+	fn from(value: &GetLevelReviewsApiRequest) -> Self {
+		Self {
+			level_id: value.level_id,
+			reviewer_discord_id: value.reviewer_discord_id,
+			requester_discord_id: value.requester_discord_id,
+			search: value
+				.search
+				.as_ref()
+				.filter(|search| !search.trim().is_empty())
+				.cloned()
+		}
+	}
+}
+
+#[derive(Serialize)]
+pub struct GetLevelReviewsApiResponse {
+	pub level_reviews: Vec<GetLevelReviewApiRespnse>,
+	pub page: u64,
+	pub page_size: u64,
+	pub total_count: u64
+}
+
+impl From<LevelReviewPage> for GetLevelReviewsApiResponse {
+	fn from(value: LevelReviewPage) -> Self {
+		Self {
+			level_reviews: value
+				.level_reviews
+				.into_iter()
+				.map(GetLevelReviewApiRespnse::from)
+				.collect(),
+			page: value.page,
+			page_size: value.page_size,
+			total_count: value.total_count
+		}
+	}
+}
+
+impl<'r> Responder<'r, 'r> for GetLevelReviewsApiResponse {
+	fn respond_to(self, request: &'r Request<'_>) -> response::Result<'r> {
+		let json = Json(self);
+		Response::build_from(json.respond_to(request).unwrap())
+			.status(Status::Ok)
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON)
+			.ok()
+	}
+}
+
 #[derive(Deserialize)]
 pub struct LevelReviewApiRequest<'a> {
 	pub level_id: u64,
 	pub reviewer_discord_id: u64,
 	pub discord_message_id: u64,
-	pub review_contents: Cow<'a, str>
+	pub review_contents: Cow<'a, str>,
+	#[serde(default)]
+	pub scores: Vec<RubricScoreApi>,
+	pub suggested_score: Option<SuggestedScore>,
+	pub suggested_rating: Option<SuggestedRating>
+}
+
+impl<'a> From<LevelReviewApiRequest<'a>> for LevelReviewSubmission {
+	fn from(value: LevelReviewApiRequest<'a>) -> Self {
+		Self {
+			discord_message_id: value.discord_message_id,
+			review_contents: value.review_contents.into_owned(),
+			rubric: ReviewRubric {
+				scores: value
+					.scores
+					.into_iter()
+					.map(|rubric_score| rubric_score.into())
+					.collect(),
+				suggested_score: value
+					.suggested_score
+					.map(|suggested_score| suggested_score.into()),
+				suggested_rating: value
+					.suggested_rating
+					.map(|suggested_rating| suggested_rating.into())
+			}
+		}
+	}
 }
 
 #[derive(Serialize)]
@@ -59,6 +215,9 @@ pub struct LevelReviewApiResponse {
 	pub reviewer_discord_id: u64,
 	pub discord_message_id: u64,
 	pub review_contents: String,
+	pub scores: Vec<RubricScoreApi>,
+	pub suggested_score: Option<SuggestedScore>,
+	pub suggested_rating: Option<SuggestedRating>,
 	pub is_update: bool
 }
 
@@ -69,6 +228,20 @@ impl From<LevelReview> for LevelReviewApiResponse {
 			reviewer_discord_id: value.reviewer_discord_id,
 			discord_message_id: value.discord_message_id,
 			review_contents: value.review_contents,
+			scores: value
+				.rubric
+				.scores
+				.into_iter()
+				.map(RubricScoreApi::from)
+				.collect(),
+			suggested_score: value
+				.rubric
+				.suggested_score
+				.map(|suggested_score| suggested_score.into()),
+			suggested_rating: value
+				.rubric
+				.suggested_rating
+				.map(|suggested_rating| suggested_rating.into()),
 			is_update: value.is_update
 		}
 	}
@@ -91,9 +264,289 @@ impl<'r> Responder<'r, 'r> for LevelReviewApiResponse {
 	}
 }
 
+#[derive(Serialize)]
+pub struct ReviewRevisionApiResponse {
+	pub revision: u32,
+	pub discord_message_id: u64,
+	pub review_contents: String,
+	pub scores: Vec<RubricScoreApi>,
+	pub suggested_score: Option<SuggestedScore>,
+	pub suggested_rating: Option<SuggestedRating>,
+	pub created_at: DateTime<Utc>
+}
+
+impl From<ReviewRevision> for ReviewRevisionApiResponse {
+	fn from(value: ReviewRevision) -> Self {
+		Self {
+			revision: value.revision,
+			discord_message_id: value.discord_message_id,
+			review_contents: value.review_contents,
+			scores: value
+				.rubric
+				.scores
+				.into_iter()
+				.map(RubricScoreApi::from)
+				.collect(),
+			suggested_score: value
+				.rubric
+				.suggested_score
+				.map(|suggested_score| suggested_score.into()),
+			suggested_rating: value
+				.rubric
+				.suggested_rating
+				.map(|suggested_rating| suggested_rating.into()),
+			created_at: value.created_at
+		}
+	}
+}
+
+#[derive(Serialize)]
+pub struct GetReviewRevisionsApiResponse {
+	pub level_id: u64,
+	pub reviewer_discord_id: u64,
+	/// Oldest first.
+	pub revisions: Vec<ReviewRevisionApiResponse>
+}
+
+impl GetReviewRevisionsApiResponse {
+	pub fn new(level_id: u64, reviewer_discord_id: u64, revisions: Vec<ReviewRevision>) -> Self {
+		Self {
+			level_id,
+			reviewer_discord_id,
+			revisions: revisions
+				.into_iter()
+				.map(ReviewRevisionApiResponse::from)
+				.collect()
+		}
+	}
+}
+
+impl<'r> Responder<'r, 'r> for GetReviewRevisionsApiResponse {
+	fn respond_to(self, request: &'r Request<'_>) -> response::Result<'r> {
+		let json = Json(self);
+		Response::build_from(json.respond_to(request).unwrap())
+			.status(Status::Ok)
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON)
+			.ok()
+	}
+}
+
+#[derive(Serialize)]
+pub enum ReviewLineChange {
+	Unchanged,
+	Added,
+	Removed
+}
+
+impl From<review_revision::ReviewLineChange> for ReviewLineChange {
+	fn from(value: review_revision::ReviewLineChange) -> Self {
+		match value {
+			review_revision::ReviewLineChange::Unchanged => ReviewLineChange::Unchanged,
+			review_revision::ReviewLineChange::Added => ReviewLineChange::Added,
+			review_revision::ReviewLineChange::Removed => ReviewLineChange::Removed
+		}
+	}
+}
+
+#[derive(Serialize)]
+pub struct ReviewDiffLineApiResponse {
+	pub change: ReviewLineChange,
+	pub line: String
+}
+
+impl From<ReviewDiffLine> for ReviewDiffLineApiResponse {
+	fn from(value: ReviewDiffLine) -> Self {
+		Self {
+			change: ReviewLineChange::from(value.change),
+			line: value.line
+		}
+	}
+}
+
+#[derive(Serialize)]
+pub struct RubricScoreChangeApiResponse {
+	pub category: String,
+	pub from: Option<RubricScoreApi>,
+	pub to: Option<RubricScoreApi>
+}
+
+impl From<RubricScoreChange> for RubricScoreChangeApiResponse {
+	fn from(value: RubricScoreChange) -> Self {
+		Self {
+			category: value.category,
+			from: value.from.map(RubricScoreApi::from),
+			to: value.to.map(RubricScoreApi::from)
+		}
+	}
+}
+
+#[derive(Serialize)]
+pub struct ReviewRevisionDiffApiResponse {
+	pub level_id: u64,
+	pub reviewer_discord_id: u64,
+	pub from: ReviewRevisionApiResponse,
+	pub to: ReviewRevisionApiResponse,
+	pub lines: Vec<ReviewDiffLineApiResponse>,
+	pub rubric_score_changes: Vec<RubricScoreChangeApiResponse>
+}
+
+impl From<ReviewRevisionDiff> for ReviewRevisionDiffApiResponse {
+	fn from(value: ReviewRevisionDiff) -> Self {
+		Self {
+			level_id: value.from.level_id,
+			reviewer_discord_id: value.from.reviewer_discord_id,
+			from: ReviewRevisionApiResponse::from(value.from),
+			to: ReviewRevisionApiResponse::from(value.to),
+			lines: value
+				.lines
+				.into_iter()
+				.map(ReviewDiffLineApiResponse::from)
+				.collect(),
+			rubric_score_changes: value
+				.rubric_score_changes
+				.into_iter()
+				.map(RubricScoreChangeApiResponse::from)
+				.collect()
+		}
+	}
+}
+
+impl<'r> Responder<'r, 'r> for ReviewRevisionDiffApiResponse {
+	fn respond_to(self, request: &'r Request<'_>) -> response::Result<'r> {
+		let json = Json(self);
+		Response::build_from(json.respond_to(request).unwrap())
+			.status(Status::Ok)
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON)
+			.ok()
+	}
+}
+
+#[derive(Serialize)]
+pub struct GetReviewRubricApiResponse {
+	pub categories: Vec<String>,
+	pub min_score: u8,
+	pub max_score: u8,
+	pub require_all_categories: bool
+}
+
+impl From<&ReviewRubricConfig> for GetReviewRubricApiResponse {
+	fn from(value: &ReviewRubricConfig) -> Self {
+		Self {
+			categories: value.categories.clone(),
+			min_score: value.min_score,
+			max_score: value.max_score,
+			require_all_categories: value.require_all_categories
+		}
+	}
+}
+
+impl<'r> Responder<'r, 'r> for GetReviewRubricApiResponse {
+	fn respond_to(self, request: &'r Request<'_>) -> response::Result<'r> {
+		let json = Json(self);
+		Response::build_from(json.respond_to(request).unwrap())
+			.status(Status::Ok)
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON)
+			.ok()
+	}
+}
+
+#[derive(Serialize)]
+pub struct RubricCategorySummaryApiResponse {
+	pub category: String,
+	pub score_count: u32,
+	pub average_score: Option<f64>,
+	pub min_score: Option<u8>,
+	pub max_score: Option<u8>
+}
+
+impl From<RubricCategorySummary> for RubricCategorySummaryApiResponse {
+	fn from(value: RubricCategorySummary) -> Self {
+		Self {
+			category: value.category,
+			score_count: value.score_count,
+			average_score: value.average_score,
+			min_score: value.min_score,
+			max_score: value.max_score
+		}
+	}
+}
+
+#[derive(Serialize)]
+pub struct SuggestedScoreCountApiResponse {
+	pub suggested_score: SuggestedScore,
+	pub count: u32
+}
+
+#[derive(Serialize)]
+pub struct SuggestedRatingCountApiResponse {
+	pub suggested_rating: SuggestedRating,
+	pub count: u32
+}
+
+#[derive(Serialize)]
+pub struct GetLevelRubricSummaryApiResponse {
+	pub level_id: u64,
+	pub review_count: u32,
+	pub categories: Vec<RubricCategorySummaryApiResponse>,
+	pub suggested_scores: Vec<SuggestedScoreCountApiResponse>,
+	pub suggested_ratings: Vec<SuggestedRatingCountApiResponse>
+}
+
+impl From<LevelRubricSummary> for GetLevelRubricSummaryApiResponse {
+	fn from(value: LevelRubricSummary) -> Self {
+		Self {
+			level_id: value.level_id,
+			review_count: value.review_count,
+			categories: value
+				.categories
+				.into_iter()
+				.map(RubricCategorySummaryApiResponse::from)
+				.collect(),
+			suggested_scores: value
+				.suggested_scores
+				.into_iter()
+				.map(|(suggested_score, count)| SuggestedScoreCountApiResponse {
+					suggested_score: suggested_score.into(),
+					count
+				})
+				.collect(),
+			suggested_ratings: value
+				.suggested_ratings
+				.into_iter()
+				.map(
+					|(suggested_rating, count)| SuggestedRatingCountApiResponse {
+						suggested_rating: suggested_rating.into(),
+						count
+					}
+				)
+				.collect()
+		}
+	}
+}
+
+impl<'r> Responder<'r, 'r> for GetLevelRubricSummaryApiResponse {
+	fn respond_to(self, request: &'r Request<'_>) -> response::Result<'r> {
+		let json = Json(self);
+		Response::build_from(json.respond_to(request).unwrap())
+			.status(Status::Ok)
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON)
+			.ok()
+	}
+}
+
 #[derive(Debug, PartialEq)]
 pub enum LevelReviewApiResponseError {
+	MalformedRequest,
+	InvalidRubric,
 	LevelRequestDoesNotExist,
+	LevelNotReviewable,
+	ActingAsAnotherUser,
+	AssignedToAnotherReviewer,
+	RevisionDoesNotExist,
 	LevelReviewError
 }
 
@@ -106,9 +559,27 @@ impl<'r> Responder<'r, 'r> for LevelReviewApiResponseError {
 			.header(ContentType::JSON);
 
 		match self {
+			LevelReviewApiResponseError::MalformedRequest => {
+				response.status(Status::BadRequest);
+			}
+			LevelReviewApiResponseError::InvalidRubric => {
+				response.status(Status::BadRequest);
+			}
 			LevelReviewApiResponseError::LevelRequestDoesNotExist => {
 				response.status(Status::NotFound);
 			}
+			LevelReviewApiResponseError::LevelNotReviewable => {
+				response.status(Status::Conflict);
+			}
+			LevelReviewApiResponseError::ActingAsAnotherUser => {
+				response.status(Status::Forbidden);
+			}
+			LevelReviewApiResponseError::AssignedToAnotherReviewer => {
+				response.status(Status::Conflict);
+			}
+			LevelReviewApiResponseError::RevisionDoesNotExist => {
+				response.status(Status::NotFound);
+			}
 			LevelReviewApiResponseError::LevelReviewError => {
 				response.status(Status::InternalServerError);
 			}
@@ -121,9 +592,39 @@ impl<'r> Responder<'r, 'r> for LevelReviewApiResponseError {
 impl Display for LevelReviewApiResponseError {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		match self {
+			LevelReviewApiResponseError::MalformedRequest => {
+				write!(f, "{{\"message\": \"Level review query was malformed\"}}")
+			}
+			LevelReviewApiResponseError::InvalidRubric => {
+				write!(
+					f,
+					"{{\"message\": \"The scores do not match the review rubric\"}}"
+				)
+			}
 			LevelReviewApiResponseError::LevelRequestDoesNotExist => {
 				write!(f, "{{\"message\": \"The level request does not exist\"}}")
 			}
+			LevelReviewApiResponseError::LevelNotReviewable => {
+				write!(
+					f,
+					"{{\"message\": \"The level request can no longer be reviewed\"}}"
+				)
+			}
+			LevelReviewApiResponseError::ActingAsAnotherUser => {
+				write!(
+					f,
+					"{{\"message\": \"User attempted to review on behalf of another user\"}}"
+				)
+			}
+			LevelReviewApiResponseError::AssignedToAnotherReviewer => {
+				write!(
+					f,
+					"{{\"message\": \"The level is assigned to another reviewer\"}}"
+				)
+			}
+			LevelReviewApiResponseError::RevisionDoesNotExist => {
+				write!(f, "{{\"message\": \"The review revision does not exist\"}}")
+			}
 			LevelReviewApiResponseError::LevelReviewError => {
 				write!(f, "{{\"message\": \"Internal server error\"}}")
 			}
@@ -1,5 +1,9 @@
 pub mod auth_api;
+pub mod error_api;
+pub mod level_queue_api;
 pub mod level_request_api;
 pub mod level_review_api;
+pub mod review_assignment_api;
 pub mod reviewer_api;
+pub mod role_api;
 pub mod user_api;
@@ -0,0 +1,213 @@
+use std::{
+	error::Error,
+	fmt::{Display, Formatter}
+};
+
+use chrono::{DateTime, Local, Utc};
+use rocket_framework::{
+	http::{ContentType, Status},
+	response,
+	response::Responder,
+	serde::json::Json,
+	Request, Response
+};
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+use serde_derive::Deserialize;
+
+use crate::{
+	domain::model::{
+		api::level_request_api::GetLevelRequestApiResponse,
+		review_assignment::{AssignedLevelRequest, ReviewAssignment}
+	},
+	rocket::common::constants::TIMESTAMP_HEADER_NAME
+};
+
+/// Leaving out the reviewer assigns the level by the configured assignment
+/// policy.
+#[derive(Deserialize)]
+pub struct AssignLevelApiRequest {
+	pub reviewer_discord_id: Option<u64>
+}
+
+#[derive(Serialize)]
+pub struct ReviewAssignmentApiResponse {
+	pub assignment_id: u64,
+	pub level_id: u64,
+	pub reviewer_discord_id: u64,
+	pub assigned_by: u64,
+	pub assigned_at: DateTime<Utc>,
+	pub expires_at: DateTime<Utc>,
+	pub completed_at: Option<DateTime<Utc>>,
+	pub released_at: Option<DateTime<Utc>>
+}
+
+impl From<ReviewAssignment> for ReviewAssignmentApiResponse {
+	fn from(value: ReviewAssignment) -> Self {
+		Self {
+			assignment_id: value.assignment_id,
+			level_id: value.level_id,
+			reviewer_discord_id: value.reviewer_discord_id,
+			assigned_by: value.assigned_by,
+			assigned_at: value.assigned_at,
+			expires_at: value.expires_at,
+			completed_at: value.completed_at,
+			released_at: value.released_at
+		}
+	}
+}
+
+impl<'r> Responder<'r, 'r> for ReviewAssignmentApiResponse {
+	fn respond_to(self, request: &'r Request<'_>) -> response::Result<'r> {
+		let json = Json(self);
+		Response::build_from(json.respond_to(request).unwrap())
+			.status(Status::Ok)
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON)
+			.ok()
+	}
+}
+
+#[derive(Serialize)]
+pub struct AssignedLevelApiResponse {
+	pub assignment: ReviewAssignmentApiResponse,
+	pub level_request: GetLevelRequestApiResponse
+}
+
+impl From<AssignedLevelRequest> for AssignedLevelApiResponse {
+	fn from(value: AssignedLevelRequest) -> Self {
+		Self {
+			assignment: ReviewAssignmentApiResponse::from(value.assignment),
+			level_request: GetLevelRequestApiResponse::from(value.level_request)
+		}
+	}
+}
+
+#[derive(Serialize)]
+pub struct GetAssignedLevelsApiResponse {
+	pub assigned_levels: Vec<AssignedLevelApiResponse>
+}
+
+impl From<Vec<AssignedLevelRequest>> for GetAssignedLevelsApiResponse {
+	fn from(value: Vec<AssignedLevelRequest>) -> Self {
+		Self {
+			assigned_levels: value
+				.into_iter()
+				.map(AssignedLevelApiResponse::from)
+				.collect()
+		}
+	}
+}
+
+impl<'r> Responder<'r, 'r> for GetAssignedLevelsApiResponse {
+	fn respond_to(self, request: &'r Request<'_>) -> response::Result<'r> {
+		let json = Json(self);
+		Response::build_from(json.respond_to(request).unwrap())
+			.status(Status::Ok)
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON)
+			.ok()
+	}
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ReviewAssignmentApiResponseError {
+	LevelRequestDoesNotExist,
+	LevelNotReviewable,
+	ReviewerDoesNotExist,
+	NoActiveReviewers,
+	AssignedToAnotherReviewer(u64),
+	AssignmentDoesNotExist,
+	ReviewAssignmentError
+}
+
+impl Serialize for ReviewAssignmentApiResponseError {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer
+	{
+		match self {
+			ReviewAssignmentApiResponseError::AssignedToAnotherReviewer(reviewer_discord_id) => {
+				let mut state =
+					serializer.serialize_struct("ReviewAssignmentApiResponseError", 2)?;
+				state.serialize_field("message", &self.to_string())?;
+				state.serialize_field("assigned_to", reviewer_discord_id)?;
+				state.end()
+			}
+			_ => {
+				let mut state =
+					serializer.serialize_struct("ReviewAssignmentApiResponseError", 1)?;
+				state.serialize_field("message", &self.to_string())?;
+				state.end()
+			}
+		}
+	}
+}
+
+impl<'r> Responder<'r, 'r> for ReviewAssignmentApiResponseError {
+	fn respond_to(self, request: &'r Request<'_>) -> response::Result<'r> {
+		let json = Json(&self);
+		let mut response = Response::build_from(json.respond_to(request).unwrap());
+		response
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON);
+
+		match self {
+			ReviewAssignmentApiResponseError::LevelRequestDoesNotExist => {
+				response.status(Status::NotFound);
+			}
+			ReviewAssignmentApiResponseError::LevelNotReviewable => {
+				response.status(Status::Conflict);
+			}
+			ReviewAssignmentApiResponseError::ReviewerDoesNotExist => {
+				response.status(Status::NotFound);
+			}
+			ReviewAssignmentApiResponseError::NoActiveReviewers => {
+				response.status(Status::Conflict);
+			}
+			ReviewAssignmentApiResponseError::AssignedToAnotherReviewer(_) => {
+				response.status(Status::Conflict);
+			}
+			ReviewAssignmentApiResponseError::AssignmentDoesNotExist => {
+				response.status(Status::NotFound);
+			}
+			ReviewAssignmentApiResponseError::ReviewAssignmentError => {
+				response.status(Status::InternalServerError);
+			}
+		}
+
+		response.ok()
+	}
+}
+
+impl Display for ReviewAssignmentApiResponseError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			ReviewAssignmentApiResponseError::LevelRequestDoesNotExist => {
+				write!(
+					f,
+					"The level request does not exist or feedback was not requested"
+				)
+			}
+			ReviewAssignmentApiResponseError::LevelNotReviewable => {
+				write!(f, "The level request can no longer be assigned")
+			}
+			ReviewAssignmentApiResponseError::ReviewerDoesNotExist => {
+				write!(f, "Reviewer does not exist or is not active")
+			}
+			ReviewAssignmentApiResponseError::NoActiveReviewers => {
+				write!(f, "There are no active reviewers to assign the level to")
+			}
+			ReviewAssignmentApiResponseError::AssignedToAnotherReviewer(_) => {
+				write!(f, "The level is assigned to another reviewer")
+			}
+			ReviewAssignmentApiResponseError::AssignmentDoesNotExist => {
+				write!(f, "The level is not assigned to anyone")
+			}
+			ReviewAssignmentApiResponseError::ReviewAssignmentError => {
+				write!(f, "Internal server error")
+			}
+		}
+	}
+}
+
+impl Error for ReviewAssignmentApiResponseError {}
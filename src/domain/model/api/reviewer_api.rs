@@ -3,16 +3,23 @@ use std::{
 	fmt::{Display, Formatter}
 };
 
-use chrono::Local;
+use chrono::{DateTime, Local, Utc};
 use rocket_framework::{
 	http::{ContentType, Status},
 	response::Responder,
 	serde::json::Json,
-	Request, Response
+	FromForm, FromFormField, Request, Response
 };
 use serde_derive::{Deserialize, Serialize};
 
-use crate::{domain::model::reviewer::Reviewer, rocket::common::constants::TIMESTAMP_HEADER_NAME};
+use crate::{
+	domain::model::{
+		reviewer::{Reviewer, ReviewerActivity},
+		reviewer_stats,
+		reviewer_stats::{PeriodReviewCount, ReviewerLeaderboardEntry, ReviewerStats, StatsRange}
+	},
+	rocket::common::constants::{DEFAULT_PAGE_SIZE, TIMESTAMP_HEADER_NAME}
+};
 
 #[derive(Serialize)]
 pub struct GetReviewerApiResponse {
@@ -45,9 +52,237 @@ impl<'r> Responder<'r, 'r> for GetReviewerApiResponse {
 	}
 }
 
+#[derive(FromForm)]
+pub struct GetReviewerStatsApiRequest {
+	pub period: Option<StatsPeriod>,
+	pub from_timestamp: Option<String>,
+	pub to_timestamp: Option<String>
+}
+
+impl GetReviewerStatsApiRequest {
+	pub fn period(&self) -> reviewer_stats::StatsPeriod {
+		self.period.map(|period| period.into()).unwrap_or_default()
+	}
+}
+
+impl TryFrom<&GetReviewerStatsApiRequest> for StatsRange {
+	type Error = ReviewerApiResponseError;
+
+	fn try_from(value: &GetReviewerStatsApiRequest) -> Result<Self, Self::Error> {
+		Ok(Self {
+			from_timestamp: parse_timestamp(&value.from_timestamp)?,
+			to_timestamp: parse_timestamp(&value.to_timestamp)?
+		})
+	}
+}
+
+#[derive(FromForm)]
+pub struct GetReviewerLeaderboardApiRequest {
+	pub sort: Option<ReviewerStatsMetric>,
+	pub period: Option<StatsPeriod>,
+	pub from_timestamp: Option<String>,
+	pub to_timestamp: Option<String>,
+	pub limit: Option<u64>
+}
+
+impl GetReviewerLeaderboardApiRequest {
+	pub fn metric(&self) -> reviewer_stats::ReviewerStatsMetric {
+		self.sort.map(|sort| sort.into()).unwrap_or_default()
+	}
+
+	pub fn period(&self) -> reviewer_stats::StatsPeriod {
+		self.period.map(|period| period.into()).unwrap_or_default()
+	}
+
+	pub fn limit(&self) -> u64 { self.limit.unwrap_or(DEFAULT_PAGE_SIZE) }
+}
+
+impl TryFrom<&GetReviewerLeaderboardApiRequest> for StatsRange {
+	type Error = ReviewerApiResponseError;
+
+	fn try_from(value: &GetReviewerLeaderboardApiRequest) -> Result<Self, Self::Error> {
+		Ok(Self {
+			from_timestamp: parse_timestamp(&value.from_timestamp)?,
+			to_timestamp: parse_timestamp(&value.to_timestamp)?
+		})
+	}
+}
+
+fn parse_timestamp(
+	timestamp: &Option<String>
+) -> Result<Option<DateTime<Utc>>, ReviewerApiResponseError> {
+	match timestamp {
+		Some(timestamp) => DateTime::parse_from_rfc3339(timestamp)
+			.map(|parsed_timestamp| Some(parsed_timestamp.with_timezone(&Utc)))
+			.map_err(|_| ReviewerApiResponseError::MalformedRequest),
+		None => Ok(None)
+	}
+}
+
+#[derive(Serialize)]
+pub struct PeriodReviewCountApiResponse {
+	pub period_start: DateTime<Utc>,
+	pub review_count: u32
+}
+
+impl From<PeriodReviewCount> for PeriodReviewCountApiResponse {
+	fn from(value: PeriodReviewCount) -> Self {
+		Self {
+			period_start: value.period_start,
+			review_count: value.review_count
+		}
+	}
+}
+
+#[derive(Serialize)]
+pub struct ReviewerActivityApiResponse {
+	pub is_active: bool,
+	pub changed_at: DateTime<Utc>
+}
+
+impl From<ReviewerActivity> for ReviewerActivityApiResponse {
+	fn from(value: ReviewerActivity) -> Self {
+		Self {
+			is_active: value.is_active,
+			changed_at: value.changed_at
+		}
+	}
+}
+
+#[derive(Serialize)]
+pub struct GetReviewerStatsApiResponse {
+	pub reviewer_discord_id: u64,
+	pub is_active: bool,
+	pub review_count: u32,
+	pub reviews_per_period: Vec<PeriodReviewCountApiResponse>,
+	pub average_turnaround_in_seconds: Option<i64>,
+	pub sent_level_count: u32,
+	pub activity: Vec<ReviewerActivityApiResponse>
+}
+
+impl From<ReviewerStats> for GetReviewerStatsApiResponse {
+	fn from(value: ReviewerStats) -> Self {
+		Self {
+			reviewer_discord_id: value.discord_id,
+			is_active: value.is_active,
+			review_count: value.review_count,
+			reviews_per_period: value
+				.reviews_per_period
+				.into_iter()
+				.map(PeriodReviewCountApiResponse::from)
+				.collect(),
+			average_turnaround_in_seconds: value
+				.average_turnaround
+				.map(|average_turnaround| average_turnaround.num_seconds()),
+			sent_level_count: value.sent_level_count,
+			activity: value
+				.activity
+				.into_iter()
+				.map(ReviewerActivityApiResponse::from)
+				.collect()
+		}
+	}
+}
+
+impl<'r> Responder<'r, 'r> for GetReviewerStatsApiResponse {
+	fn respond_to(self, request: &'r Request<'_>) -> rocket_framework::response::Result<'r> {
+		let json = Json(self);
+		Response::build_from(json.respond_to(request).unwrap())
+			.status(Status::Ok)
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON)
+			.ok()
+	}
+}
+
+#[derive(Serialize)]
+pub struct ReviewerLeaderboardEntryApiResponse {
+	pub rank: u32,
+	#[serde(flatten)]
+	pub reviewer_stats: GetReviewerStatsApiResponse
+}
+
+impl From<ReviewerLeaderboardEntry> for ReviewerLeaderboardEntryApiResponse {
+	fn from(value: ReviewerLeaderboardEntry) -> Self {
+		Self {
+			rank: value.rank,
+			reviewer_stats: GetReviewerStatsApiResponse::from(value.reviewer_stats)
+		}
+	}
+}
+
+#[derive(Serialize)]
+pub struct GetReviewerLeaderboardApiResponse {
+	pub leaderboard: Vec<ReviewerLeaderboardEntryApiResponse>
+}
+
+impl From<Vec<ReviewerLeaderboardEntry>> for GetReviewerLeaderboardApiResponse {
+	fn from(value: Vec<ReviewerLeaderboardEntry>) -> Self {
+		Self {
+			leaderboard: value
+				.into_iter()
+				.map(ReviewerLeaderboardEntryApiResponse::from)
+				.collect()
+		}
+	}
+}
+
+impl<'r> Responder<'r, 'r> for GetReviewerLeaderboardApiResponse {
+	fn respond_to(self, request: &'r Request<'_>) -> rocket_framework::response::Result<'r> {
+		let json = Json(self);
+		Response::build_from(json.respond_to(request).unwrap())
+			.status(Status::Ok)
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON)
+			.ok()
+	}
+}
+
+#[derive(Clone, Copy, FromFormField)]
+pub enum StatsPeriod {
+	Day,
+	Week,
+	Month
+}
+
+impl From<StatsPeriod> for reviewer_stats::StatsPeriod {
+	fn from(value: StatsPeriod) -> Self {
+		match value {
+			StatsPeriod::Day => reviewer_stats::StatsPeriod::Day,
+			StatsPeriod::Week => reviewer_stats::StatsPeriod::Week,
+			StatsPeriod::Month => reviewer_stats::StatsPeriod::Month
+		}
+	}
+}
+
+#[derive(Clone, Copy, FromFormField)]
+pub enum ReviewerStatsMetric {
+	#[field(value = "review_count")]
+	ReviewCount,
+	#[field(value = "average_turnaround")]
+	AverageTurnaround,
+	#[field(value = "sent_level_count")]
+	SentLevelCount
+}
+
+impl From<ReviewerStatsMetric> for reviewer_stats::ReviewerStatsMetric {
+	fn from(value: ReviewerStatsMetric) -> Self {
+		match value {
+			ReviewerStatsMetric::ReviewCount => reviewer_stats::ReviewerStatsMetric::ReviewCount,
+			ReviewerStatsMetric::AverageTurnaround => {
+				reviewer_stats::ReviewerStatsMetric::AverageTurnaround
+			}
+			ReviewerStatsMetric::SentLevelCount => {
+				reviewer_stats::ReviewerStatsMetric::SentLevelCount
+			}
+		}
+	}
+}
+
 #[derive(Debug, PartialEq)]
 pub enum ReviewerApiResponseError {
 	ReviewerDoesNotExist,
+	MalformedRequest,
 	ReviewerError
 }
 
@@ -62,6 +297,9 @@ impl<'r> Responder<'r, 'r> for ReviewerApiResponseError {
 			ReviewerApiResponseError::ReviewerDoesNotExist => {
 				response.status(Status::NotFound);
 			}
+			ReviewerApiResponseError::MalformedRequest => {
+				response.status(Status::BadRequest);
+			}
 			ReviewerApiResponseError::ReviewerError => {
 				response.status(Status::InternalServerError);
 			}
@@ -77,6 +315,12 @@ impl Display for ReviewerApiResponseError {
 			ReviewerApiResponseError::ReviewerDoesNotExist => {
 				write!(f, "{{\"message\": \"Reviewer does not exist\"}}")
 			}
+			ReviewerApiResponseError::MalformedRequest => {
+				write!(
+					f,
+					"{{\"message\": \"Reviewer statistics query was malformed\"}}"
+				)
+			}
 			ReviewerApiResponseError::ReviewerError => {
 				write!(f, "{{\"message\": \"Internal server error\"}}")
 			}
@@ -0,0 +1,129 @@
+use std::{
+	error::Error,
+	fmt::{Display, Formatter}
+};
+
+use chrono::Local;
+use rocket_framework::{
+	http::{ContentType, Status},
+	response::Responder,
+	serde::json::Json,
+	Request, Response
+};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{domain::model::auth::role, rocket::common::constants::TIMESTAMP_HEADER_NAME};
+
+#[derive(Serialize)]
+pub struct GetUserRolesApiResponse {
+	pub discord_id: u64,
+	pub roles: Vec<Role>
+}
+
+#[derive(Deserialize)]
+pub struct CreateUserRoleApiRequest {
+	pub discord_id: u64,
+	pub role: Role
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, FromFormField)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+	Requester,
+	Reviewer,
+	Moderator,
+	Admin
+}
+
+impl GetUserRolesApiResponse {
+	pub fn new(discord_id: u64, roles: Vec<role::Role>) -> Self {
+		Self {
+			discord_id,
+			roles: roles.into_iter().map(Role::from).collect()
+		}
+	}
+}
+
+impl<'r> Responder<'r, 'r> for GetUserRolesApiResponse {
+	fn respond_to(self, request: &'r Request<'_>) -> rocket_framework::response::Result<'r> {
+		let json = Json(self);
+		Response::build_from(json.respond_to(&request).unwrap())
+			.status(Status::Ok)
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON)
+			.ok()
+	}
+}
+
+impl From<Role> for role::Role {
+	fn from(value: Role) -> Self {
+		match value {
+			Role::Requester => role::Role::Requester,
+			Role::Reviewer => role::Role::Reviewer,
+			Role::Moderator => role::Role::Moderator,
+			Role::Admin => role::Role::Admin
+		}
+	}
+}
+
+impl From<role::Role> for Role {
+	fn from(value: role::Role) -> Self {
+		match value {
+			role::Role::Requester => Role::Requester,
+			role::Role::Reviewer => Role::Reviewer,
+			role::Role::Moderator => Role::Moderator,
+			role::Role::Admin => Role::Admin
+		}
+	}
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RoleApiResponseError {
+	UnassignableRole,
+	RoleDoesNotExist,
+	RoleError
+}
+
+impl<'r> Responder<'r, 'r> for RoleApiResponseError {
+	fn respond_to(self, request: &'r Request<'_>) -> rocket_framework::response::Result<'r> {
+		let json = Json(self.to_string());
+		let mut response = Response::build_from(json.respond_to(&request).unwrap());
+		response
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON);
+		match self {
+			RoleApiResponseError::UnassignableRole => {
+				response.status(Status::BadRequest);
+			}
+			RoleApiResponseError::RoleDoesNotExist => {
+				response.status(Status::NotFound);
+			}
+			RoleApiResponseError::RoleError => {
+				response.status(Status::InternalServerError);
+			}
+		}
+
+		response.ok()
+	}
+}
+
+impl Display for RoleApiResponseError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			RoleApiResponseError::UnassignableRole => {
+				write!(
+					f,
+					"{{\"message\": \"Only moderator and admin roles can be assigned\"}}"
+				)
+			}
+			RoleApiResponseError::RoleDoesNotExist => {
+				write!(f, "{{\"message\": \"User does not have the role\"}}")
+			}
+			RoleApiResponseError::RoleError => {
+				write!(f, "{{\"message\": \"Internal server error\"}}")
+			}
+		}
+	}
+}
+
+impl Error for RoleApiResponseError {}
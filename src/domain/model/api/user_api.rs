@@ -11,11 +11,18 @@ use rocket_framework::{
 	Request, Response
 };
 use serde::{ser::SerializeStruct, Serialize, Serializer};
+use serde_derive::Deserialize;
 
 use crate::{
-	domain::{
-		model::discord::user::DiscordUser,
-		service::internal::request_manager_service::RequestManagerService
+	domain::model::{
+		api::{level_request_api::RequestStatus, level_review_api::GetLevelReviewApiRespnse},
+		discord::user::DiscordUser,
+		internal::api::{
+			internal_ban_api::GetBanApiResponse,
+			moderator_api::{SuggestedRating, SuggestedScore}
+		},
+		request_limits::{RequestLimitOverrides, RequestLimits},
+		user_profile::{UserProfile, UserProfileLevelRequest}
 	},
 	rocket::common::constants::TIMESTAMP_HEADER_NAME
 };
@@ -23,7 +30,10 @@ use crate::{
 pub struct GetDiscordUserApiResponse {
 	pub discord_user_id: u64,
 	pub last_request_time: Option<DateTime<Utc>>,
-	pub request_cooldown: Duration
+	pub request_cooldown: Duration,
+	pub cooldown_exempt: bool,
+	pub max_open_requests: Option<u32>,
+	pub daily_request_quota: Option<u32>
 }
 
 impl Serialize for GetDiscordUserApiResponse {
@@ -31,21 +41,144 @@ impl Serialize for GetDiscordUserApiResponse {
 	where
 		S: Serializer
 	{
-		let mut state = serializer.serialize_struct("LevelRequestApiResponseError", 3)?;
+		let mut state = serializer.serialize_struct("LevelRequestApiResponseError", 6)?;
 		state.serialize_field("discord_user_id", &self.discord_user_id)?;
 		state.serialize_field("last_request_time", &self.last_request_time)?;
 		state.serialize_field("request_cooldown", &self.request_cooldown.num_minutes())?;
+		state.serialize_field("cooldown_exempt", &self.cooldown_exempt)?;
+		state.serialize_field("max_open_requests", &self.max_open_requests)?;
+		state.serialize_field("daily_request_quota", &self.daily_request_quota)?;
 
 		state.end()
 	}
 }
 
-impl From<DiscordUser> for GetDiscordUserApiResponse {
-	fn from(value: DiscordUser) -> Self {
+impl GetDiscordUserApiResponse {
+	/// Reports the limits that apply to the user, with their overrides applied.
+	pub fn new(discord_user: DiscordUser, request_limits: RequestLimits) -> Self {
 		Self {
-			discord_user_id: value.discord_user_id,
-			last_request_time: value.last_request_time,
-			request_cooldown: RequestManagerService {}.get_request_cooldown()
+			discord_user_id: discord_user.discord_user_id,
+			last_request_time: discord_user.last_request_time,
+			request_cooldown: request_limits.cooldown_duration,
+			cooldown_exempt: discord_user.request_limit_overrides.cooldown_exempt,
+			max_open_requests: request_limits.max_open_requests,
+			daily_request_quota: request_limits.daily_request_quota
+		}
+	}
+}
+
+#[derive(Serialize)]
+pub struct GetUserProfileApiResponse {
+	pub discord_user_id: u64,
+	pub last_request_time: Option<DateTime<Utc>>,
+	#[serde(rename = "request_cooldown")]
+	pub request_cooldown_in_minutes: i64,
+	pub cooldown_exempt: bool,
+	#[serde(rename = "cooldown_remaining")]
+	pub cooldown_remaining_in_seconds: i64,
+	pub open_requests: u32,
+	pub max_open_requests: Option<u32>,
+	pub daily_requests: u32,
+	pub daily_request_quota: Option<u32>,
+	pub ban: Option<GetBanApiResponse>,
+	pub level_requests: Vec<UserProfileLevelRequestApiResponse>
+}
+
+#[derive(Serialize)]
+pub struct UserProfileLevelRequestApiResponse {
+	pub level_id: u64,
+	pub level_name: Option<String>,
+	pub status: RequestStatus,
+	pub timestamp: DateTime<Utc>,
+	pub archived: bool,
+	pub reviewed: bool,
+	pub sent: bool,
+	pub reviews: Vec<GetLevelReviewApiRespnse>,
+	pub suggested_score: Option<SuggestedScore>,
+	pub suggested_rating: Option<SuggestedRating>
+}
+
+impl From<UserProfile> for GetUserProfileApiResponse {
+	fn from(value: UserProfile) -> Self {
+		Self {
+			discord_user_id: value.discord_user.discord_user_id,
+			last_request_time: value.discord_user.last_request_time,
+			request_cooldown_in_minutes: value.request_limits.cooldown_duration.num_minutes(),
+			cooldown_exempt: value.discord_user.request_limit_overrides.cooldown_exempt,
+			cooldown_remaining_in_seconds: value.cooldown_remaining.num_seconds(),
+			open_requests: value.open_requests,
+			max_open_requests: value.request_limits.max_open_requests,
+			daily_requests: value.daily_requests,
+			daily_request_quota: value.request_limits.daily_request_quota,
+			ban: value.ban.map(GetBanApiResponse::from),
+			level_requests: value
+				.level_requests
+				.into_iter()
+				.map(UserProfileLevelRequestApiResponse::from)
+				.collect()
+		}
+	}
+}
+
+impl From<UserProfileLevelRequest> for UserProfileLevelRequestApiResponse {
+	fn from(value: UserProfileLevelRequest) -> Self {
+		Self {
+			reviewed: value.is_reviewed(),
+			sent: value.is_sent(),
+			level_id: value.level_request.level_id,
+			level_name: value.level_request.gd_level.map(|gd_level| gd_level.name),
+			status: value.level_request.status.into(),
+			timestamp: value.level_request.timestamp,
+			archived: value.archived,
+			reviews: value
+				.reviews
+				.into_iter()
+				.map(GetLevelReviewApiRespnse::from)
+				.collect(),
+			suggested_score: value
+				.moderator
+				.map(|moderator| moderator.suggested_score.into()),
+			suggested_rating: value
+				.moderator
+				.map(|moderator| moderator.suggested_rating.into())
+		}
+	}
+}
+
+impl<'r> Responder<'r, 'r> for GetUserProfileApiResponse {
+	fn respond_to(self, request: &'r Request<'_>) -> rocket_framework::response::Result<'r> {
+		let json = Json(self);
+		Response::build_from(json.respond_to(request).unwrap())
+			.status(Status::Ok)
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON)
+			.ok()
+	}
+}
+
+/// Replaces the user's request limit overrides. Limits left out fall back to
+/// the guild's.
+#[derive(Deserialize)]
+pub struct UpdateRequestLimitsApiRequest {
+	#[serde(rename = "cooldown_duration")]
+	pub cooldown_duration_in_minutes: Option<u64>,
+	#[serde(default)]
+	pub cooldown_exempt: bool,
+	pub max_open_requests: Option<u32>,
+	pub daily_request_quota: Option<u32>
+}
+
+impl From<UpdateRequestLimitsApiRequest> for RequestLimitOverrides {
+	fn from(value: UpdateRequestLimitsApiRequest) -> Self {
+		RequestLimitOverrides {
+			cooldown_duration: value.cooldown_duration_in_minutes.map(
+				|cooldown_duration_in_minutes| {
+					Duration::minutes(cooldown_duration_in_minutes as i64)
+				}
+			),
+			cooldown_exempt: value.cooldown_exempt,
+			max_open_requests: value.max_open_requests,
+			daily_request_quota: value.daily_request_quota
 		}
 	}
 }
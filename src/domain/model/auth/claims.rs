@@ -1,20 +1,77 @@
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde_derive::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{domain::model::auth::role::Role, rocket::common::config::auth_config::AUTH_CONFIG};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
-	aud: u64,
+	/// Serialized as a string, which `jsonwebtoken` requires to validate the
+	/// audience.
+	aud: String,
 	iat: i64,
-	exp: i64
+	exp: i64,
+	pub jti: String,
+	pub sub: u64,
+	pub guild_id: u64,
+	pub roles: Vec<Role>,
+	pub token_type: TokenType
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenType {
+	Access,
+	Refresh
 }
 
 impl Claims {
-	pub fn new(aud: u64) -> Self {
+	pub fn new_access_token(aud: u64, guild_id: u64, sub: u64, roles: Vec<Role>) -> Self {
+		Self::new(
+			aud,
+			guild_id,
+			sub,
+			roles,
+			TokenType::Access,
+			Duration::minutes(AUTH_CONFIG.access_token_duration_in_minutes as i64)
+		)
+	}
+
+	/// Refresh tokens carry no roles; they are looked up again when the token
+	/// is exchanged.
+	pub fn new_refresh_token(aud: u64, guild_id: u64, sub: u64) -> Self {
+		Self::new(
+			aud,
+			guild_id,
+			sub,
+			vec![],
+			TokenType::Refresh,
+			Duration::minutes(AUTH_CONFIG.refresh_token_duration_in_minutes as i64)
+		)
+	}
+
+	pub fn expires_at(&self) -> DateTime<Utc> {
+		DateTime::from_timestamp(self.exp, 0).unwrap_or_else(Utc::now)
+	}
+
+	fn new(
+		aud: u64,
+		guild_id: u64,
+		sub: u64,
+		roles: Vec<Role>,
+		token_type: TokenType,
+		duration: Duration
+	) -> Self {
 		let now = Utc::now();
 		Self {
-			aud,
+			aud: aud.to_string(),
 			iat: now.timestamp(),
-			exp: (now + Duration::days(7)).timestamp()
+			exp: (now + duration).timestamp(),
+			jti: Uuid::new_v4().to_string(),
+			sub,
+			guild_id,
+			roles,
+			token_type
 		}
 	}
 }
@@ -1 +1,5 @@
 pub mod claims;
+pub mod role;
+pub mod signing_key;
+pub mod token_pair;
+pub mod user_role;
@@ -0,0 +1,26 @@
+use serde_derive::{Deserialize, Serialize};
+
+use crate::adapter::mysql::model::sea_orm_active_enums;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+	Requester,
+	Reviewer,
+	Moderator,
+	Admin
+}
+
+impl Role {
+	/// Admins may act in any role.
+	pub fn grants(&self, role: Role) -> bool { self.eq(&role) || self.eq(&Role::Admin) }
+}
+
+impl From<sea_orm_active_enums::Role> for Role {
+	fn from(value: sea_orm_active_enums::Role) -> Self {
+		match value {
+			sea_orm_active_enums::Role::Moderator => Role::Moderator,
+			sea_orm_active_enums::Role::Admin => Role::Admin
+		}
+	}
+}
@@ -0,0 +1,147 @@
+use std::{collections::HashMap, fs};
+
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+
+use crate::{
+	domain::model::error::auth_error::AuthError,
+	rocket::common::config::auth_config::{
+		AuthConfig, SigningAlgorithm, SigningKeyConfig, DEFAULT_SIGNING_KID
+	}
+};
+
+pub struct SigningKey {
+	pub kid: String,
+	pub algorithm: Algorithm,
+	pub encoding_key: Option<EncodingKey>,
+	pub decoding_key: DecodingKey
+}
+
+/// Every key a token may be verified with, keyed by `kid`, plus the key new
+/// tokens are signed with.
+pub struct SigningKeys {
+	signing_kid: String,
+	signing_keys: HashMap<String, SigningKey>
+}
+
+impl SigningKeys {
+	pub fn from_auth_config(auth_config: &AuthConfig) -> Result<Self, AuthError> {
+		let mut signing_keys = HashMap::new();
+		if !auth_config.secret_token.is_empty() {
+			signing_keys.insert(
+				DEFAULT_SIGNING_KID.to_string(),
+				SigningKey {
+					kid: DEFAULT_SIGNING_KID.to_string(),
+					algorithm: Algorithm::HS256,
+					encoding_key: Some(EncodingKey::from_secret(
+						auth_config.secret_token.as_bytes()
+					)),
+					decoding_key: DecodingKey::from_secret(auth_config.secret_token.as_bytes())
+				}
+			);
+		}
+		for signing_key_config in &auth_config.signing_keys {
+			let signing_key = SigningKey::try_from(signing_key_config)?;
+			if signing_keys
+				.insert(signing_key.kid.clone(), signing_key)
+				.is_some()
+			{
+				return Err(AuthError::SigningKeyError(format!(
+					"Duplicate signing key {}",
+					signing_key_config.kid
+				)));
+			}
+		}
+
+		match signing_keys.get(&auth_config.signing_kid) {
+			Some(signing_key) if signing_key.encoding_key.is_some() => Ok(Self {
+				signing_kid: auth_config.signing_kid.clone(),
+				signing_keys
+			}),
+			Some(_) => Err(AuthError::SigningKeyError(format!(
+				"Signing key {} has no private key",
+				auth_config.signing_kid
+			))),
+			None => Err(AuthError::UnknownSigningKey(Some(
+				auth_config.signing_kid.clone()
+			)))
+		}
+	}
+
+	pub fn signing_key(&self) -> &SigningKey { &self.signing_keys[&self.signing_kid] }
+
+	pub fn get(&self, kid: &str) -> Option<&SigningKey> { self.signing_keys.get(kid) }
+}
+
+impl TryFrom<&SigningKeyConfig> for SigningKey {
+	type Error = AuthError;
+
+	fn try_from(value: &SigningKeyConfig) -> Result<Self, Self::Error> {
+		match value.algorithm {
+			SigningAlgorithm::Hs256 => match &value.secret {
+				Some(secret) if !secret.is_empty() => Ok(SigningKey {
+					kid: value.kid.clone(),
+					algorithm: Algorithm::HS256,
+					encoding_key: Some(EncodingKey::from_secret(secret.as_bytes())),
+					decoding_key: DecodingKey::from_secret(secret.as_bytes())
+				}),
+				_ => Err(AuthError::SigningKeyError(format!(
+					"Signing key {} requires a secret",
+					value.kid
+				)))
+			},
+			SigningAlgorithm::Rs256 | SigningAlgorithm::EdDsa => {
+				let public_key = read_pem(&value.kid, value.public_key_path.as_ref())?;
+				let private_key = match &value.private_key_path {
+					Some(private_key_path) => Some(read_pem(&value.kid, Some(private_key_path))?),
+					None => None
+				};
+
+				let (algorithm, encoding_key, decoding_key) = match value.algorithm {
+					SigningAlgorithm::Rs256 => (
+						Algorithm::RS256,
+						private_key
+							.map(|private_key| EncodingKey::from_rsa_pem(&private_key))
+							.transpose(),
+						DecodingKey::from_rsa_pem(&public_key)
+					),
+					_ => (
+						Algorithm::EdDSA,
+						private_key
+							.map(|private_key| EncodingKey::from_ed_pem(&private_key))
+							.transpose(),
+						DecodingKey::from_ed_pem(&public_key)
+					)
+				};
+
+				Ok(SigningKey {
+					kid: value.kid.clone(),
+					algorithm,
+					encoding_key: encoding_key.map_err(|err| {
+						AuthError::SigningKeyError(format!(
+							"Invalid private key for {}: {}",
+							value.kid, err
+						))
+					})?,
+					decoding_key: decoding_key.map_err(|err| {
+						AuthError::SigningKeyError(format!(
+							"Invalid public key for {}: {}",
+							value.kid, err
+						))
+					})?
+				})
+			}
+		}
+	}
+}
+
+fn read_pem(kid: &str, path: Option<&String>) -> Result<Vec<u8>, AuthError> {
+	match path {
+		Some(path) => fs::read(path).map_err(|err| {
+			AuthError::SigningKeyError(format!("Unable to read key {} for {}: {}", path, kid, err))
+		}),
+		None => Err(AuthError::SigningKeyError(format!(
+			"Signing key {} requires a public_key_path",
+			kid
+		)))
+	}
+}
@@ -0,0 +1,44 @@
+use sea_orm::ActiveValue;
+
+use crate::{
+	adapter::mysql::model::{sea_orm_active_enums, user_role},
+	domain::model::{auth::role::Role, error::role_error::RoleError}
+};
+
+/// A role granted to a guild member through the `user_role` table. Requesters
+/// and reviewers are derived from guild membership and the `reviewer` table
+/// instead, so only moderators and admins can be stored.
+#[derive(Copy, Clone, Debug)]
+pub struct UserRole {
+	pub guild_id: u64,
+	pub discord_id: u64,
+	pub role: Role
+}
+
+impl TryFrom<UserRole> for user_role::ActiveModel {
+	type Error = RoleError;
+
+	fn try_from(value: UserRole) -> Result<Self, Self::Error> {
+		let role = match value.role {
+			Role::Moderator => sea_orm_active_enums::Role::Moderator,
+			Role::Admin => sea_orm_active_enums::Role::Admin,
+			unassignable_role => return Err(RoleError::UnassignableRole(unassignable_role))
+		};
+
+		Ok(user_role::ActiveModel {
+			guild_id: ActiveValue::Set(value.guild_id.into()),
+			discord_id: ActiveValue::Set(value.discord_id.into()),
+			role: ActiveValue::Set(role)
+		})
+	}
+}
+
+impl From<user_role::Model> for UserRole {
+	fn from(value: user_role::Model) -> Self {
+		Self {
+			guild_id: value.guild_id.into(),
+			discord_id: value.discord_id.into(),
+			role: value.role.into()
+		}
+	}
+}
@@ -0,0 +1,106 @@
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue;
+
+use crate::{
+	adapter::mysql::model::{request_ban, sea_orm_active_enums::BanTargetType},
+	domain::model::internal::api::internal_ban_api
+};
+
+/// What a ban keeps from being requested.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BanTarget {
+	/// A Discord user, who may not request any level.
+	User(u64),
+	/// A level, which nobody may request.
+	Level(u64)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ban {
+	pub ban_id: u64,
+	pub guild_id: u64,
+	pub target: BanTarget,
+	pub reason: Option<String>,
+	/// Discord user that issued the ban.
+	pub issued_by: u64,
+	pub created_at: DateTime<Utc>,
+	/// A ban without an expiry lasts until it is lifted.
+	pub expires_at: Option<DateTime<Utc>>,
+	pub lifted_at: Option<DateTime<Utc>>,
+	pub lifted_by: Option<u64>
+}
+
+impl Ban {
+	pub fn is_active(&self, now: &DateTime<Utc>) -> bool {
+		if self.lifted_at.is_some() {
+			return false;
+		}
+
+		match self.expires_at {
+			Some(expires_at) => expires_at.gt(now),
+			None => true
+		}
+	}
+}
+
+impl BanTarget {
+	pub fn target_id(&self) -> u64 {
+		match self {
+			BanTarget::User(discord_user_id) => *discord_user_id,
+			BanTarget::Level(level_id) => *level_id
+		}
+	}
+
+	pub fn target_type(&self) -> BanTargetType {
+		match self {
+			BanTarget::User(_) => BanTargetType::User,
+			BanTarget::Level(_) => BanTargetType::Level
+		}
+	}
+}
+
+impl From<BanTarget> for internal_ban_api::BanTargetType {
+	fn from(value: BanTarget) -> Self {
+		match value {
+			BanTarget::User(_) => internal_ban_api::BanTargetType::User,
+			BanTarget::Level(_) => internal_ban_api::BanTargetType::Level
+		}
+	}
+}
+
+impl From<Ban> for request_ban::ActiveModel {
+	fn from(value: Ban) -> Self {
+		request_ban::ActiveModel {
+			id: ActiveValue::Set(value.ban_id as i32),
+			guild_id: ActiveValue::Set(value.guild_id.into()),
+			target_type: ActiveValue::Set(value.target.target_type()),
+			target_id: ActiveValue::Set(value.target.target_id().into()),
+			reason: ActiveValue::Set(value.reason),
+			issued_by: ActiveValue::Set(value.issued_by.into()),
+			created_at: ActiveValue::Set(value.created_at),
+			expires_at: ActiveValue::Set(value.expires_at),
+			lifted_at: ActiveValue::Set(value.lifted_at),
+			lifted_by: ActiveValue::Set(value.lifted_by.map(|lifted_by| lifted_by.into()))
+		}
+	}
+}
+
+impl From<request_ban::Model> for Ban {
+	fn from(value: request_ban::Model) -> Self {
+		let target_id: u64 = value.target_id.into();
+		Self {
+			ban_id: value.id as u64,
+			guild_id: value.guild_id.into(),
+			target: match value.target_type {
+				BanTargetType::User => BanTarget::User(target_id),
+				BanTargetType::Level => BanTarget::Level(target_id)
+			},
+			reason: value.reason,
+			issued_by: value.issued_by.into(),
+			created_at: value.created_at,
+			expires_at: value.expires_at,
+			lifted_at: value.lifted_at,
+			lifted_by: value.lifted_by.map(|lifted_by| lifted_by.into())
+		}
+	}
+}
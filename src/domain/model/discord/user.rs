@@ -1,23 +1,39 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use sea_orm::ActiveValue;
 
-use crate::adapter::mysql::model::{user, user::Model};
+use crate::{
+	adapter::mysql::model::{user, user::Model},
+	domain::model::request_limits::RequestLimitOverrides
+};
 
 #[derive(Debug, Clone, Copy)]
 pub struct DiscordUser {
+	pub guild_id: u64,
 	pub discord_user_id: u64,
-	pub last_request_time: Option<DateTime<Utc>>
+	pub last_request_time: Option<DateTime<Utc>>,
+	pub request_limit_overrides: RequestLimitOverrides
 }
 
 impl Into<user::ActiveModel> for DiscordUser {
 	fn into(self) -> user::ActiveModel {
 		user::ActiveModel {
-			discord_id: ActiveValue::Set(self.discord_user_id),
+			guild_id: ActiveValue::Set(self.guild_id.into()),
+			discord_id: ActiveValue::Set(self.discord_user_id.into()),
 			timestamp: ActiveValue::Set(if let Some(last_request_time) = self.last_request_time {
 				Some(last_request_time)
 			} else {
 				None
-			})
+			}),
+			cooldown_duration: ActiveValue::Set(
+				self.request_limit_overrides
+					.cooldown_duration
+					.map(|cooldown_duration| cooldown_duration.num_minutes() as i32)
+			),
+			cooldown_exempt: ActiveValue::Set(i8::from(
+				self.request_limit_overrides.cooldown_exempt
+			)),
+			max_open_requests: ActiveValue::Set(self.request_limit_overrides.max_open_requests),
+			daily_request_quota: ActiveValue::Set(self.request_limit_overrides.daily_request_quota)
 		}
 	}
 }
@@ -25,8 +41,17 @@ impl Into<user::ActiveModel> for DiscordUser {
 impl From<Model> for DiscordUser {
 	fn from(value: Model) -> Self {
 		Self {
-			discord_user_id: value.discord_id,
-			last_request_time: value.timestamp
+			guild_id: value.guild_id.into(),
+			discord_user_id: value.discord_id.into(),
+			last_request_time: value.timestamp,
+			request_limit_overrides: RequestLimitOverrides {
+				cooldown_duration: value
+					.cooldown_duration
+					.map(|cooldown_duration| Duration::minutes(cooldown_duration as i64)),
+				cooldown_exempt: value.cooldown_exempt != 0,
+				max_open_requests: value.max_open_requests,
+				daily_request_quota: value.daily_request_quota
+			}
 		}
 	}
 }
@@ -0,0 +1,70 @@
+use std::{
+	error::Error,
+	fmt::{Display, Formatter}
+};
+
+use jsonwebtoken::errors::Error as JsonWebTokenError;
+use sea_orm::DbErr;
+
+use crate::domain::model::{
+	api::auth_api::AuthApiError, auth::claims::TokenType, error::role_error::RoleError
+};
+
+#[derive(Debug, PartialEq)]
+pub enum AuthError {
+	SigningKeyError(String),
+	UnknownSigningKey(Option<String>),
+	InvalidToken(JsonWebTokenError),
+	UnexpectedTokenType(TokenType),
+	TokenRevoked,
+	DatabaseError(DbErr),
+	RoleError(RoleError)
+}
+
+impl Display for AuthError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			AuthError::SigningKeyError(message) => {
+				write!(f, "Unable to load signing keys: {}", message)
+			}
+			AuthError::UnknownSigningKey(kid) => {
+				write!(f, "Signing key {:?} is not configured", kid)
+			}
+			AuthError::InvalidToken(jwt_error) => {
+				write!(f, "Token is invalid: {}", jwt_error)
+			}
+			AuthError::UnexpectedTokenType(token_type) => {
+				write!(f, "Token is a {:?} token", token_type)
+			}
+			AuthError::TokenRevoked => {
+				write!(f, "Token has been revoked")
+			}
+			AuthError::DatabaseError(db_err) => {
+				write!(
+					f,
+					"Unable to check token revocation due to database error: {}",
+					db_err
+				)
+			}
+			AuthError::RoleError(role_error) => {
+				write!(f, "Unable to resolve roles: {}", role_error)
+			}
+		}
+	}
+}
+
+impl From<AuthError> for AuthApiError {
+	fn from(value: AuthError) -> Self {
+		match value {
+			AuthError::UnknownSigningKey(_)
+			| AuthError::InvalidToken(_)
+			| AuthError::UnexpectedTokenType(_)
+			| AuthError::TokenRevoked => AuthApiError::InvalidToken,
+			AuthError::SigningKeyError(_)
+			| AuthError::DatabaseError(_)
+			| AuthError::RoleError(_) => AuthApiError::AuthError
+		}
+	}
+}
+
+impl Error for AuthError {}
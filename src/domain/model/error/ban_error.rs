@@ -0,0 +1,54 @@
+use std::{
+	error::Error,
+	fmt::{Display, Formatter}
+};
+
+use sea_orm::DbErr;
+
+use crate::domain::model::{ban::BanTarget, internal::api::internal_ban_api::BanApiResponseError};
+
+#[derive(Debug, PartialEq)]
+pub enum BanError {
+	DatabaseError(DbErr),
+	MalformedBan,
+	/// The target and the id of the ban already on it.
+	AlreadyBanned(BanTarget, u64),
+	BanDoesNotExist,
+	BanNotActive(u64)
+}
+
+impl Display for BanError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			BanError::DatabaseError(db_err) => {
+				write!(f, "Unable to manage bans due to database error: {}", db_err)
+			}
+			BanError::MalformedBan => {
+				write!(f, "Ban is malformed")
+			}
+			BanError::AlreadyBanned(target, ban_id) => {
+				write!(f, "{:?} is already banned by ban {}", target, ban_id)
+			}
+			BanError::BanDoesNotExist => {
+				write!(f, "Ban does not exist")
+			}
+			BanError::BanNotActive(ban_id) => {
+				write!(f, "Ban {} has already been lifted or has expired", ban_id)
+			}
+		}
+	}
+}
+
+impl Error for BanError {}
+
+impl From<BanError> for BanApiResponseError {
+	fn from(value: BanError) -> Self {
+		match value {
+			BanError::DatabaseError(_) => BanApiResponseError::BanError,
+			BanError::MalformedBan => BanApiResponseError::MalformedBan,
+			BanError::AlreadyBanned(_, _) => BanApiResponseError::AlreadyBanned,
+			BanError::BanDoesNotExist => BanApiResponseError::BanDoesNotExist,
+			BanError::BanNotActive(_) => BanApiResponseError::BanNotActive
+		}
+	}
+}
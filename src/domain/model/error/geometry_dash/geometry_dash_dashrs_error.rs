@@ -1,27 +1,33 @@
 use std::{
 	error::Error,
 	fmt,
-	fmt::{Debug, Display, Formatter}
+	fmt::{Debug, Display, Formatter},
+	sync::Arc
 };
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum GeometryDashDashrsError {
-	HttpError(reqwest::Error),
+	HttpError(Arc<reqwest::Error>),
 	DashrsError(String),
-	LevelNotFoundError(u64)
+	LevelNotFoundError(u64),
+	CircuitOpenError
 }
 
 impl Display for GeometryDashDashrsError {
 	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
 		match self {
-			GeometryDashDashrsError::HttpError(reqwest_err) => {
-				write!(
+			GeometryDashDashrsError::HttpError(reqwest_err) => match reqwest_err.url() {
+				Some(url) => write!(
 					f,
 					"Unable to make call to url {} with error: {}",
-					reqwest_err.url().unwrap(),
+					url, reqwest_err
+				),
+				None => write!(
+					f,
+					"Unable to make call to Geometry Dash servers with error: {}",
 					reqwest_err
 				)
-			}
+			},
 			GeometryDashDashrsError::DashrsError(dashrs_err) => {
 				write!(
 					f,
@@ -32,6 +38,9 @@ impl Display for GeometryDashDashrsError {
 			GeometryDashDashrsError::LevelNotFoundError(level_id) => {
 				write!(f, "Unable to find level with level ID: {}", level_id)
 			}
+			GeometryDashDashrsError::CircuitOpenError => {
+				write!(f, "Geometry Dash servers are unavailable, skipped the call")
+			}
 		}
 	}
 }
@@ -44,6 +53,7 @@ impl PartialEq for GeometryDashDashrsError {
 			(Self::HttpError(_), Self::HttpError(_)) => true,
 			(Self::DashrsError(s1), Self::DashrsError(s2)) => s1 == s2,
 			(Self::LevelNotFoundError(n1), Self::LevelNotFoundError(n2)) => n1 == n2,
+			(Self::CircuitOpenError, Self::CircuitOpenError) => true,
 			_ => false
 		}
 	}
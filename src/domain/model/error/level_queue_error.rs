@@ -0,0 +1,78 @@
+use std::{
+	error::Error,
+	fmt::{Display, Formatter}
+};
+
+use sea_orm::DbErr;
+
+use crate::domain::model::api::{
+	level_queue_api::LevelQueueApiResponseError, level_request_api::LevelRequestApiResponseError
+};
+
+#[derive(Debug, PartialEq)]
+pub enum LevelQueueError {
+	DatabaseError(DbErr),
+	QueueEmpty,
+	LevelRequestDoesNotExist,
+	LevelRequestNotQueued(u64),
+	LevelRequestNotSkipped(u64)
+}
+
+impl Display for LevelQueueError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			LevelQueueError::DatabaseError(db_err) => {
+				write!(
+					f,
+					"Unable to read level queue due to database error: {}",
+					db_err
+				)
+			}
+			LevelQueueError::QueueEmpty => {
+				write!(f, "There are no pending level requests")
+			}
+			LevelQueueError::LevelRequestDoesNotExist => {
+				write!(f, "Level request does not exist")
+			}
+			LevelQueueError::LevelRequestNotQueued(level_id) => {
+				write!(f, "Level request {} is no longer pending", level_id)
+			}
+			LevelQueueError::LevelRequestNotSkipped(level_id) => {
+				write!(f, "Level request {} has not been skipped", level_id)
+			}
+		}
+	}
+}
+
+impl Error for LevelQueueError {}
+
+impl From<LevelQueueError> for LevelQueueApiResponseError {
+	fn from(value: LevelQueueError) -> Self {
+		match value {
+			LevelQueueError::DatabaseError(_) => LevelQueueApiResponseError::LevelQueueError,
+			LevelQueueError::QueueEmpty => LevelQueueApiResponseError::QueueEmpty,
+			LevelQueueError::LevelRequestDoesNotExist => {
+				LevelQueueApiResponseError::LevelRequestDoesNotExist
+			}
+			LevelQueueError::LevelRequestNotQueued(_) => {
+				LevelQueueApiResponseError::LevelRequestNotQueued
+			}
+			LevelQueueError::LevelRequestNotSkipped(_) => {
+				LevelQueueApiResponseError::LevelRequestNotSkipped
+			}
+		}
+	}
+}
+
+/// Queue positions are looked up alongside level requests, where only a
+/// database error is expected.
+impl From<LevelQueueError> for LevelRequestApiResponseError {
+	fn from(value: LevelQueueError) -> Self {
+		match value {
+			LevelQueueError::LevelRequestDoesNotExist => {
+				LevelRequestApiResponseError::LevelRequestDoesNotExist
+			}
+			_ => LevelRequestApiResponseError::LevelRequestError
+		}
+	}
+}
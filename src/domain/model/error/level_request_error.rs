@@ -9,7 +9,11 @@ use sea_orm::DbErr;
 
 use crate::domain::model::{
 	api::level_request_api::LevelRequestApiResponseError,
-	error::geometry_dash::geometry_dash_dashrs_error::GeometryDashDashrsError
+	ban::{Ban, BanTarget},
+	error::geometry_dash::geometry_dash_dashrs_error::GeometryDashDashrsError,
+	gd_level::RequestStatus,
+	level_eligibility::IneligibilityReason,
+	request_limits::RequestQuota
 };
 
 #[derive(Debug, PartialEq)]
@@ -19,9 +23,15 @@ pub enum LevelRequestError {
 	LevelRequestExists,
 	LevelRequestDoesNotExist,
 	UserOnCooldown(DateTime<Utc>, Duration),
+	/// The quota, its limit and how long until the user may request again, if
+	/// that only depends on time passing.
+	RequestQuotaExceeded(RequestQuota, u32, Option<Duration>),
 	EditUnownedLevelRequest(u64, u64, u64),
 	LevelRequestsDisabled,
-	GeometryDashClientError(u64, GeometryDashDashrsError)
+	IllegalStatusTransition(RequestStatus, RequestStatus),
+	GeometryDashClientError(u64, GeometryDashDashrsError),
+	IneligibleLevel(u64, IneligibilityReason),
+	Banned(Ban)
 }
 
 impl Display for LevelRequestError {
@@ -49,6 +59,9 @@ impl Display for LevelRequestError {
 			LevelRequestError::UserOnCooldown(_last_request_time, _request_cooldown) => {
 				write!(f, "The user is still on cooldown")
 			}
+			LevelRequestError::RequestQuotaExceeded(quota, limit, _retry_after) => {
+				write!(f, "The user has reached their limit of {} {}", limit, quota)
+			}
 			LevelRequestError::EditUnownedLevelRequest(
 				_level_id,
 				_discord_user_id,
@@ -59,6 +72,13 @@ impl Display for LevelRequestError {
 			LevelRequestError::LevelRequestsDisabled => {
 				write!(f, "Level requests are disabled")
 			}
+			LevelRequestError::IllegalStatusTransition(current_status, status) => {
+				write!(
+					f,
+					"Unable to move level request from {:?} to {:?}",
+					current_status, status
+				)
+			}
 			LevelRequestError::GeometryDashClientError(level_id, client_error) => {
 				write!(
 					f,
@@ -66,6 +86,25 @@ impl Display for LevelRequestError {
 					level_id, client_error
 				)
 			}
+			LevelRequestError::IneligibleLevel(level_id, reason) => {
+				write!(f, "Level {} cannot be requested: {}", level_id, reason)
+			}
+			LevelRequestError::Banned(ban) => match ban.target {
+				BanTarget::User(discord_user_id) => {
+					write!(
+						f,
+						"User {} is banned from requesting levels by ban {}",
+						discord_user_id, ban.ban_id
+					)
+				}
+				BanTarget::Level(level_id) => {
+					write!(
+						f,
+						"Level {} is blocked from requests by ban {}",
+						level_id, ban.ban_id
+					)
+				}
+			}
 		}
 	}
 }
@@ -86,6 +125,9 @@ impl Into<LevelRequestApiResponseError> for LevelRequestError {
 			LevelRequestError::UserOnCooldown(last_request_time, request_cooldown) => {
 				LevelRequestApiResponseError::UserOnCooldown(last_request_time, request_cooldown)
 			}
+			LevelRequestError::RequestQuotaExceeded(quota, limit, retry_after) => {
+				LevelRequestApiResponseError::RequestQuotaExceeded(quota.into(), limit, retry_after)
+			}
 			LevelRequestError::EditUnownedLevelRequest(
 				level_id,
 				discord_user_id,
@@ -98,9 +140,21 @@ impl Into<LevelRequestApiResponseError> for LevelRequestError {
 			LevelRequestError::LevelRequestsDisabled => {
 				LevelRequestApiResponseError::LevelRequestDisabled
 			}
+			LevelRequestError::IllegalStatusTransition(current_status, status) => {
+				LevelRequestApiResponseError::IllegalStatusTransition(
+					current_status.into(),
+					status.into()
+				)
+			}
 			LevelRequestError::GeometryDashClientError(_, _) => {
 				LevelRequestApiResponseError::LevelRequestError
 			}
+			LevelRequestError::IneligibleLevel(_, reason) => {
+				LevelRequestApiResponseError::IneligibleLevel(reason.into())
+			}
+			LevelRequestError::Banned(ban) => {
+				LevelRequestApiResponseError::Banned(ban.target.into(), ban.expires_at, ban.reason)
+			}
 		}
 	}
 }
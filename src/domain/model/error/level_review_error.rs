@@ -5,17 +5,36 @@ use std::{
 
 use sea_orm::DbErr;
 
-use crate::domain::model::api::level_review_api::LevelReviewApiResponseError;
+use crate::domain::model::{
+	api::level_review_api::LevelReviewApiResponseError, gd_level::RequestStatus
+};
 
 #[derive(Debug, PartialEq)]
 pub enum LevelReviewError {
+	MalformedRequest,
+	/// A score outside the configured rubric.
+	InvalidRubric,
 	DatabaseError(DbErr),
-	LevelRequestDoesNotExist
+	LevelRequestDoesNotExist,
+	/// Rejected levels can no longer be reviewed.
+	LevelNotReviewable(RequestStatus),
+	/// The reviewer the level is assigned to.
+	AssignedToAnotherReviewer(u64),
+	RevisionDoesNotExist(u32)
 }
 
 impl Display for LevelReviewError {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		match self {
+			LevelReviewError::MalformedRequest => {
+				write!(f, "Level review query is malformed")
+			}
+			LevelReviewError::InvalidRubric => {
+				write!(
+					f,
+					"Unable to submit review: Rubric scores do not match the configured rubric"
+				)
+			}
 			LevelReviewError::DatabaseError(db_err) => {
 				write!(
 					f,
@@ -29,6 +48,27 @@ impl Display for LevelReviewError {
 					"Unable to create level request: Level request does not exist"
 				)
 			}
+			LevelReviewError::LevelNotReviewable(status) => {
+				write!(
+					f,
+					"Unable to submit review: Level request is {:?} and can no longer be reviewed",
+					status
+				)
+			}
+			LevelReviewError::AssignedToAnotherReviewer(reviewer_discord_id) => {
+				write!(
+					f,
+					"Unable to submit review: Level is assigned to reviewer {}",
+					reviewer_discord_id
+				)
+			}
+			LevelReviewError::RevisionDoesNotExist(revision) => {
+				write!(
+					f,
+					"Unable to get review revision: Revision {} does not exist",
+					revision
+				)
+			}
 		}
 	}
 }
@@ -38,10 +78,21 @@ impl Error for LevelReviewError {}
 impl Into<LevelReviewApiResponseError> for LevelReviewError {
 	fn into(self) -> LevelReviewApiResponseError {
 		match self {
+			LevelReviewError::MalformedRequest => LevelReviewApiResponseError::MalformedRequest,
+			LevelReviewError::InvalidRubric => LevelReviewApiResponseError::InvalidRubric,
 			LevelReviewError::DatabaseError(_) => LevelReviewApiResponseError::LevelReviewError,
 			LevelReviewError::LevelRequestDoesNotExist => {
 				LevelReviewApiResponseError::LevelRequestDoesNotExist
 			}
+			LevelReviewError::LevelNotReviewable(_) => {
+				LevelReviewApiResponseError::LevelNotReviewable
+			}
+			LevelReviewError::AssignedToAnotherReviewer(_) => {
+				LevelReviewApiResponseError::AssignedToAnotherReviewer
+			}
+			LevelReviewError::RevisionDoesNotExist(_) => {
+				LevelReviewApiResponseError::RevisionDoesNotExist
+			}
 		}
 	}
 }
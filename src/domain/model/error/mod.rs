@@ -1,6 +1,12 @@
+pub mod auth_error;
+pub mod ban_error;
 pub mod discord;
 pub mod geometry_dash;
+pub mod level_queue_error;
 pub mod level_request_error;
 pub mod level_review_error;
 pub mod moderator_error;
+pub mod request_manager_error;
+pub mod review_assignment_error;
 pub mod reviewer_error;
+pub mod role_error;
@@ -0,0 +1,46 @@
+use std::{
+	error::Error,
+	fmt::{Display, Formatter}
+};
+
+use sea_orm::DbErr;
+
+use crate::domain::model::internal::api::internal_request_manager_api::RequestConfigApiResponseError;
+
+#[derive(Debug, PartialEq)]
+pub enum RequestManagerError {
+	MalformedRequest,
+	DatabaseError(DbErr)
+}
+
+impl Display for RequestManagerError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			RequestManagerError::MalformedRequest => {
+				write!(f, "Request config was malformed")
+			}
+			RequestManagerError::DatabaseError(db_err) => {
+				write!(
+					f,
+					"Unable to access request config due to database error: {}",
+					db_err
+				)
+			}
+		}
+	}
+}
+
+impl From<RequestManagerError> for RequestConfigApiResponseError {
+	fn from(value: RequestManagerError) -> Self {
+		match value {
+			RequestManagerError::MalformedRequest => {
+				RequestConfigApiResponseError::MalformedRequest
+			}
+			RequestManagerError::DatabaseError(_) => {
+				RequestConfigApiResponseError::RequestConfigError
+			}
+		}
+	}
+}
+
+impl Error for RequestManagerError {}
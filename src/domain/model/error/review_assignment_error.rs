@@ -0,0 +1,89 @@
+use std::{
+	error::Error,
+	fmt::{Display, Formatter}
+};
+
+use sea_orm::DbErr;
+
+use crate::domain::model::{
+	api::review_assignment_api::ReviewAssignmentApiResponseError, gd_level::RequestStatus
+};
+
+#[derive(Debug, PartialEq)]
+pub enum ReviewAssignmentError {
+	DatabaseError(DbErr),
+	LevelRequestDoesNotExist,
+	/// Only pending and in review levels can be assigned.
+	LevelNotReviewable(RequestStatus),
+	ReviewerDoesNotExist,
+	NoActiveReviewers,
+	/// The reviewer the level is assigned to.
+	AssignedToAnotherReviewer(u64),
+	AssignmentDoesNotExist
+}
+
+impl Display for ReviewAssignmentError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			ReviewAssignmentError::DatabaseError(db_err) => {
+				write!(
+					f,
+					"Unable to manage review assignments due to database error: {}",
+					db_err
+				)
+			}
+			ReviewAssignmentError::LevelRequestDoesNotExist => {
+				write!(f, "Level request does not exist")
+			}
+			ReviewAssignmentError::LevelNotReviewable(status) => {
+				write!(
+					f,
+					"Level request is {:?} and can no longer be assigned",
+					status
+				)
+			}
+			ReviewAssignmentError::ReviewerDoesNotExist => {
+				write!(f, "Reviewer does not exist")
+			}
+			ReviewAssignmentError::NoActiveReviewers => {
+				write!(f, "There are no active reviewers to assign the level to")
+			}
+			ReviewAssignmentError::AssignedToAnotherReviewer(reviewer_discord_id) => {
+				write!(f, "Level is assigned to reviewer {}", reviewer_discord_id)
+			}
+			ReviewAssignmentError::AssignmentDoesNotExist => {
+				write!(f, "Level is not assigned to anyone")
+			}
+		}
+	}
+}
+
+impl Error for ReviewAssignmentError {}
+
+impl From<ReviewAssignmentError> for ReviewAssignmentApiResponseError {
+	fn from(value: ReviewAssignmentError) -> Self {
+		match value {
+			ReviewAssignmentError::DatabaseError(_) => {
+				ReviewAssignmentApiResponseError::ReviewAssignmentError
+			}
+			ReviewAssignmentError::LevelRequestDoesNotExist => {
+				ReviewAssignmentApiResponseError::LevelRequestDoesNotExist
+			}
+			ReviewAssignmentError::LevelNotReviewable(_) => {
+				ReviewAssignmentApiResponseError::LevelNotReviewable
+			}
+			ReviewAssignmentError::ReviewerDoesNotExist => {
+				ReviewAssignmentApiResponseError::ReviewerDoesNotExist
+			}
+			ReviewAssignmentError::NoActiveReviewers => {
+				ReviewAssignmentApiResponseError::NoActiveReviewers
+			}
+			ReviewAssignmentError::AssignedToAnotherReviewer(reviewer_discord_id) => {
+				ReviewAssignmentApiResponseError::AssignedToAnotherReviewer(reviewer_discord_id)
+			}
+			ReviewAssignmentError::AssignmentDoesNotExist => {
+				ReviewAssignmentApiResponseError::AssignmentDoesNotExist
+			}
+		}
+	}
+}
@@ -10,7 +10,8 @@ use crate::domain::model::api::reviewer_api::ReviewerApiResponseError;
 #[derive(Debug, PartialEq)]
 pub enum ReviewerError {
 	DatabaseError(DbErr),
-	ReviewerDoesNotExist
+	ReviewerDoesNotExist,
+	MalformedRequest
 }
 
 impl Display for ReviewerError {
@@ -26,6 +27,9 @@ impl Display for ReviewerError {
 			ReviewerError::ReviewerDoesNotExist => {
 				write!(f, "Unable to get reviewer: Reviewer does not exist")
 			}
+			ReviewerError::MalformedRequest => {
+				write!(f, "Unable to get reviewer statistics: Malformed request")
+			}
 		}
 	}
 }
@@ -34,7 +38,8 @@ impl Into<ReviewerApiResponseError> for ReviewerError {
 	fn into(self) -> ReviewerApiResponseError {
 		match self {
 			ReviewerError::DatabaseError(_) => ReviewerApiResponseError::ReviewerError,
-			ReviewerError::ReviewerDoesNotExist => ReviewerApiResponseError::ReviewerDoesNotExist
+			ReviewerError::ReviewerDoesNotExist => ReviewerApiResponseError::ReviewerDoesNotExist,
+			ReviewerError::MalformedRequest => ReviewerApiResponseError::MalformedRequest
 		}
 	}
 }
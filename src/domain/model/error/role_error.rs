@@ -0,0 +1,51 @@
+use std::{
+	error::Error,
+	fmt::{Display, Formatter}
+};
+
+use sea_orm::DbErr;
+
+use crate::domain::model::{api::role_api::RoleApiResponseError, auth::role::Role};
+
+#[derive(Debug, PartialEq)]
+pub enum RoleError {
+	DatabaseError(DbErr),
+	UnassignableRole(Role),
+	RoleDoesNotExist
+}
+
+impl Display for RoleError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			RoleError::DatabaseError(db_err) => {
+				write!(
+					f,
+					"Unable to access roles due to database error: {}",
+					db_err
+				)
+			}
+			RoleError::UnassignableRole(role) => {
+				write!(
+					f,
+					"Unable to assign role: {:?} cannot be assigned directly",
+					role
+				)
+			}
+			RoleError::RoleDoesNotExist => {
+				write!(f, "Unable to remove role: User does not have the role")
+			}
+		}
+	}
+}
+
+impl From<RoleError> for RoleApiResponseError {
+	fn from(value: RoleError) -> Self {
+		match value {
+			RoleError::DatabaseError(_) => RoleApiResponseError::RoleError,
+			RoleError::UnassignableRole(_) => RoleApiResponseError::UnassignableRole,
+			RoleError::RoleDoesNotExist => RoleApiResponseError::RoleDoesNotExist
+		}
+	}
+}
+
+impl Error for RoleError {}
@@ -1,6 +1,15 @@
+use std::fmt::{Display, Formatter};
+
 use chrono::Utc;
-use dash_rs::model::level::{online_level::ListedLevel, LevelLength as DashrsLevelLength};
+use dash_rs::model::{
+	level::{
+		online_level::ListedLevel, DemonRating, Featured, LevelLength as DashrsLevelLength,
+		LevelRating
+	},
+	GameVersion as DashrsGameVersion
+};
 use sea_orm::ActiveValue;
+use serde_derive::Deserialize;
 
 use crate::{
 	adapter::mysql::model::{level_request, level_request::Model, sea_orm_active_enums},
@@ -9,6 +18,7 @@ use crate::{
 
 #[derive(Clone, Debug)]
 pub struct GDLevelRequest {
+	pub guild_id: u64,
 	pub gd_level: Option<GDLevel>,
 	pub level_id: u64,
 	pub discord_user_id: u64,
@@ -17,14 +27,45 @@ pub struct GDLevelRequest {
 	pub youtube_video_link: String,
 	pub has_requested_feedback: bool,
 	pub notify: bool,
-	pub timestamp: chrono::DateTime<Utc>
+	pub status: RequestStatus,
+	pub timestamp: chrono::DateTime<Utc>,
+	/// Skipped requests wait at the back of the queue until they are requeued.
+	pub skipped_at: Option<chrono::DateTime<Utc>>
+}
+
+/// What the requester fills in when requesting a level.
+#[derive(Clone, Debug)]
+pub struct LevelRequestSubmission {
+	pub youtube_video_link: String,
+	pub request_rating: RequestRating,
+	pub has_requested_feedback: bool,
+	pub notify: bool
+}
+
+/// Fields left as `None` keep their current value.
+#[derive(Clone, Debug, Default)]
+pub struct LevelRequestUpdate {
+	pub youtube_video_link: Option<String>,
+	pub request_rating: Option<RequestRating>,
+	pub has_requested_feedback: Option<bool>,
+	pub notify: Option<bool>
 }
 
 #[derive(Clone, Debug)]
 pub struct GDLevel {
 	pub name: String,
+	pub description: Option<String>,
 	pub creator: LevelCreator,
-	pub level_length: LevelLength
+	pub level_length: LevelLength,
+	pub difficulty: LevelDifficulty,
+	pub stars: u8,
+	pub feature_state: FeatureState,
+	pub downloads: u32,
+	pub likes: i32,
+	pub game_version: Option<GameVersion>,
+	pub song: Option<LevelSong>,
+	pub object_count: Option<u32>,
+	pub coin_count: u8
 }
 
 #[derive(Clone, Debug)]
@@ -34,15 +75,51 @@ pub struct LevelCreator {
 	pub player_id: u64
 }
 
+/// Written as `major.minor`, e.g. `2.1`, in the database, API responses and
+/// config.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(try_from = "String")]
+pub struct GameVersion {
+	pub major: u8,
+	pub minor: u8
+}
+
+impl TryFrom<String> for GameVersion {
+	type Error = String;
+
+	fn try_from(value: String) -> Result<Self, Self::Error> {
+		value
+			.split_once('.')
+			.and_then(|(major, minor)| Some((major.parse().ok()?, minor.parse().ok()?)))
+			.map(|(major, minor)| GameVersion { major, minor })
+			.ok_or_else(|| format!("{} is not a valid game version", value))
+	}
+}
+
+impl Display for GameVersion {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}.{}", self.major, self.minor)
+	}
+}
+
+#[derive(Clone, Debug)]
+pub struct LevelSong {
+	pub song_id: u64,
+	pub name: String,
+	pub artist: String,
+	pub is_custom: bool
+}
+
 impl Into<level_request::ActiveModel> for GDLevelRequest {
 	fn into(self) -> level_request::ActiveModel {
 		if let Some(gd_level) = self.gd_level {
 			level_request::ActiveModel {
-				level_id: ActiveValue::Set(self.level_id),
-				discord_id: ActiveValue::set(self.discord_user_id),
+				guild_id: ActiveValue::Set(self.guild_id.into()),
+				level_id: ActiveValue::Set(self.level_id.into()),
+				discord_id: ActiveValue::set(self.discord_user_id.into()),
 				discord_message_id: ActiveValue::Set(
 					if let Some(discord_message) = self.discord_message_data {
-						Some(discord_message.message_id)
+						Some(discord_message.message_id.into())
 					} else {
 						None
 					}
@@ -50,19 +127,44 @@ impl Into<level_request::ActiveModel> for GDLevelRequest {
 				name: ActiveValue::Set(Some(gd_level.name)),
 				level_length: ActiveValue::Set(Some(gd_level.level_length.into())),
 				author: ActiveValue::Set(Some(gd_level.creator.name)),
+				description: ActiveValue::Set(gd_level.description),
+				author_account_id: ActiveValue::Set(Some(gd_level.creator.account_id.into())),
+				author_player_id: ActiveValue::Set(Some(gd_level.creator.player_id.into())),
+				difficulty: ActiveValue::Set(Some(gd_level.difficulty.into())),
+				stars: ActiveValue::Set(Some(gd_level.stars)),
+				feature_state: ActiveValue::Set(Some(gd_level.feature_state.into())),
+				downloads: ActiveValue::Set(Some(gd_level.downloads)),
+				likes: ActiveValue::Set(Some(gd_level.likes)),
+				game_version: ActiveValue::Set(
+					gd_level
+						.game_version
+						.map(|game_version| game_version.to_string())
+				),
+				song_id: ActiveValue::Set(gd_level.song.as_ref().map(|song| song.song_id.into())),
+				song_name: ActiveValue::Set(gd_level.song.as_ref().map(|song| song.name.clone())),
+				song_artist: ActiveValue::Set(
+					gd_level.song.as_ref().map(|song| song.artist.clone())
+				),
+				is_custom_song: ActiveValue::Set(gd_level.song.map(|song| song.is_custom.into())),
+				object_count: ActiveValue::Set(gd_level.object_count),
+				coin_count: ActiveValue::Set(Some(gd_level.coin_count)),
 				request_rating: ActiveValue::Set(self.request_rating.into()),
 				you_tube_video_link: ActiveValue::Set(self.youtube_video_link),
 				has_requested_feedback: ActiveValue::Set(self.has_requested_feedback.into()),
 				notify: ActiveValue::Set(self.notify.into()),
-				timestamp: ActiveValue::Set(self.timestamp)
+				status: ActiveValue::Set(self.status.into()),
+				timestamp: ActiveValue::Set(self.timestamp),
+				archived_at: ActiveValue::Set(None),
+				skipped_at: ActiveValue::Set(self.skipped_at)
 			}
 		} else {
 			level_request::ActiveModel {
-				level_id: ActiveValue::Set(self.level_id),
-				discord_id: ActiveValue::set(self.discord_user_id),
+				guild_id: ActiveValue::Set(self.guild_id.into()),
+				level_id: ActiveValue::Set(self.level_id.into()),
+				discord_id: ActiveValue::set(self.discord_user_id.into()),
 				discord_message_id: ActiveValue::Set(
 					if let Some(discord_message) = self.discord_message_data {
-						Some(discord_message.message_id)
+						Some(discord_message.message_id.into())
 					} else {
 						None
 					}
@@ -70,11 +172,29 @@ impl Into<level_request::ActiveModel> for GDLevelRequest {
 				name: ActiveValue::Set(None),
 				level_length: ActiveValue::Set(None),
 				author: ActiveValue::Set(None),
+				description: ActiveValue::Set(None),
+				author_account_id: ActiveValue::Set(None),
+				author_player_id: ActiveValue::Set(None),
+				difficulty: ActiveValue::Set(None),
+				stars: ActiveValue::Set(None),
+				feature_state: ActiveValue::Set(None),
+				downloads: ActiveValue::Set(None),
+				likes: ActiveValue::Set(None),
+				game_version: ActiveValue::Set(None),
+				song_id: ActiveValue::Set(None),
+				song_name: ActiveValue::Set(None),
+				song_artist: ActiveValue::Set(None),
+				is_custom_song: ActiveValue::Set(None),
+				object_count: ActiveValue::Set(None),
+				coin_count: ActiveValue::Set(None),
 				request_rating: ActiveValue::Set(self.request_rating.into()),
 				you_tube_video_link: ActiveValue::Set(self.youtube_video_link),
 				has_requested_feedback: ActiveValue::Set(self.has_requested_feedback.into()),
 				notify: ActiveValue::Set(self.notify.into()),
-				timestamp: ActiveValue::Set(self.timestamp)
+				status: ActiveValue::Set(self.status.into()),
+				timestamp: ActiveValue::Set(self.timestamp),
+				archived_at: ActiveValue::Set(None),
+				skipped_at: ActiveValue::Set(self.skipped_at)
 			}
 		}
 	}
@@ -83,25 +203,62 @@ impl Into<level_request::ActiveModel> for GDLevelRequest {
 impl From<Model> for GDLevelRequest {
 	fn from(value: Model) -> Self {
 		Self {
+			guild_id: value.guild_id.into(),
 			gd_level: if let (Some(name), Some(author), Some(level_length)) =
 				(value.name, value.author, value.level_length)
 			{
+				// Requests made before the metadata columns existed only have
+				// the name, author and length, the rest is filled in on update
 				Some(GDLevel {
 					name,
+					description: value.description,
 					creator: LevelCreator {
 						name: author,
-						player_id: 0,
-						account_id: 0
+						player_id: value.author_player_id.map(u64::from).unwrap_or_default(),
+						account_id: value.author_account_id.map(u64::from).unwrap_or_default()
+					},
+					level_length: level_length.into(),
+					difficulty: value
+						.difficulty
+						.map(LevelDifficulty::from)
+						.unwrap_or(LevelDifficulty::NotAvailable),
+					stars: value.stars.unwrap_or_default(),
+					feature_state: value
+						.feature_state
+						.map(FeatureState::from)
+						.unwrap_or(FeatureState::NotFeatured),
+					downloads: value.downloads.unwrap_or_default(),
+					likes: value.likes.unwrap_or_default(),
+					game_version: value
+						.game_version
+						.and_then(|game_version| GameVersion::try_from(game_version).ok()),
+					song: if let (Some(song_id), Some(name), Some(artist), Some(is_custom_song)) = (
+						value.song_id,
+						value.song_name,
+						value.song_artist,
+						value.is_custom_song
+					) {
+						Some(LevelSong {
+							song_id: song_id.into(),
+							name,
+							artist,
+							is_custom: is_custom_song != 0
+						})
+					} else {
+						None
 					},
-					level_length: level_length.into()
+					object_count: value.object_count,
+					coin_count: value.coin_count.unwrap_or_default()
 				})
 			} else {
 				None
 			},
-			level_id: value.level_id,
-			discord_user_id: value.discord_id,
+			level_id: value.level_id.into(),
+			discord_user_id: value.discord_id.into(),
 			discord_message_data: if let Some(message_id) = value.discord_message_id {
-				Some(DiscordMessage { message_id })
+				Some(DiscordMessage {
+					message_id: message_id.into()
+				})
 			} else {
 				None
 			},
@@ -113,7 +270,9 @@ impl From<Model> for GDLevelRequest {
 				false
 			},
 			notify: if value.notify != 0 { true } else { false },
-			timestamp: value.timestamp
+			status: RequestStatus::from(value.status),
+			timestamp: value.timestamp,
+			skipped_at: value.skipped_at
 		}
 	}
 }
@@ -122,17 +281,56 @@ impl From<&ListedLevel<'_>> for GDLevel {
 	fn from(listed_level: &ListedLevel) -> Self {
 		GDLevel {
 			name: listed_level.name.to_string(),
-			creator: LevelCreator {
-				name: listed_level.creator.as_ref().unwrap().name.to_string(),
-				account_id: listed_level
-					.creator
-					.as_ref()
-					.unwrap()
-					.account_id
-					.unwrap_or_default(),
-				player_id: listed_level.creator.as_ref().unwrap().user_id
+			description: listed_level
+				.description
+				.clone()
+				.and_then(|description| description.into_processed().ok())
+				.map(|description| description.0.into_owned()),
+			creator: match &listed_level.creator {
+				Some(creator) => LevelCreator {
+					name: creator.name.to_string(),
+					account_id: creator.account_id.unwrap_or_default(),
+					player_id: creator.user_id
+				},
+				None => LevelCreator {
+					name: String::from("-"),
+					account_id: 0,
+					player_id: 0
+				}
+			},
+			level_length: LevelLength::from(listed_level.length),
+			difficulty: LevelDifficulty::from(listed_level.difficulty),
+			stars: listed_level.stars,
+			feature_state: if listed_level.is_epic {
+				FeatureState::Epic
+			} else if let Featured::Featured(_) = listed_level.featured {
+				FeatureState::Featured
+			} else {
+				FeatureState::NotFeatured
+			},
+			downloads: listed_level.downloads,
+			likes: listed_level.likes,
+			game_version: match listed_level.gd_version {
+				DashrsGameVersion::Version { major, minor } => Some(GameVersion { major, minor }),
+				DashrsGameVersion::Unknown => None
+			},
+			song: match (&listed_level.custom_song, &listed_level.main_song) {
+				(Some(custom_song), _) => Some(LevelSong {
+					song_id: custom_song.song_id,
+					name: custom_song.name.to_string(),
+					artist: custom_song.artist.to_string(),
+					is_custom: true
+				}),
+				(None, Some(main_song)) => Some(LevelSong {
+					song_id: main_song.main_song_id.into(),
+					name: main_song.name.to_string(),
+					artist: main_song.artist.to_string(),
+					is_custom: false
+				}),
+				(None, None) => None
 			},
-			level_length: LevelLength::from(listed_level.length)
+			object_count: listed_level.object_amount,
+			coin_count: listed_level.coin_amount
 		}
 	}
 }
@@ -200,7 +398,140 @@ impl Into<api::level_request_api::LevelLength> for LevelLength {
 	}
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LevelDifficulty {
+	NotAvailable,
+	Auto,
+	Easy,
+	Normal,
+	Hard,
+	Harder,
+	Insane,
+	EasyDemon,
+	MediumDemon,
+	HardDemon,
+	InsaneDemon,
+	ExtremeDemon
+}
+
+impl From<sea_orm_active_enums::LevelDifficulty> for LevelDifficulty {
+	fn from(value: sea_orm_active_enums::LevelDifficulty) -> Self {
+		match value {
+			sea_orm_active_enums::LevelDifficulty::NotAvailable => Self::NotAvailable,
+			sea_orm_active_enums::LevelDifficulty::Auto => Self::Auto,
+			sea_orm_active_enums::LevelDifficulty::Easy => Self::Easy,
+			sea_orm_active_enums::LevelDifficulty::Normal => Self::Normal,
+			sea_orm_active_enums::LevelDifficulty::Hard => Self::Hard,
+			sea_orm_active_enums::LevelDifficulty::Harder => Self::Harder,
+			sea_orm_active_enums::LevelDifficulty::Insane => Self::Insane,
+			sea_orm_active_enums::LevelDifficulty::EasyDemon => Self::EasyDemon,
+			sea_orm_active_enums::LevelDifficulty::MediumDemon => Self::MediumDemon,
+			sea_orm_active_enums::LevelDifficulty::HardDemon => Self::HardDemon,
+			sea_orm_active_enums::LevelDifficulty::InsaneDemon => Self::InsaneDemon,
+			sea_orm_active_enums::LevelDifficulty::ExtremeDemon => Self::ExtremeDemon
+		}
+	}
+}
+
+impl From<LevelDifficulty> for sea_orm_active_enums::LevelDifficulty {
+	fn from(value: LevelDifficulty) -> Self {
+		match value {
+			LevelDifficulty::NotAvailable => sea_orm_active_enums::LevelDifficulty::NotAvailable,
+			LevelDifficulty::Auto => sea_orm_active_enums::LevelDifficulty::Auto,
+			LevelDifficulty::Easy => sea_orm_active_enums::LevelDifficulty::Easy,
+			LevelDifficulty::Normal => sea_orm_active_enums::LevelDifficulty::Normal,
+			LevelDifficulty::Hard => sea_orm_active_enums::LevelDifficulty::Hard,
+			LevelDifficulty::Harder => sea_orm_active_enums::LevelDifficulty::Harder,
+			LevelDifficulty::Insane => sea_orm_active_enums::LevelDifficulty::Insane,
+			LevelDifficulty::EasyDemon => sea_orm_active_enums::LevelDifficulty::EasyDemon,
+			LevelDifficulty::MediumDemon => sea_orm_active_enums::LevelDifficulty::MediumDemon,
+			LevelDifficulty::HardDemon => sea_orm_active_enums::LevelDifficulty::HardDemon,
+			LevelDifficulty::InsaneDemon => sea_orm_active_enums::LevelDifficulty::InsaneDemon,
+			LevelDifficulty::ExtremeDemon => sea_orm_active_enums::LevelDifficulty::ExtremeDemon
+		}
+	}
+}
+
+/// Geometry Dash shows demons of an unknown rating as hard demons and anything
+/// else it cannot rate as N/A.
+impl From<LevelRating> for LevelDifficulty {
+	fn from(value: LevelRating) -> Self {
+		match value {
+			LevelRating::Unknown(_) => Self::NotAvailable,
+			LevelRating::NotAvailable => Self::NotAvailable,
+			LevelRating::Auto => Self::Auto,
+			LevelRating::Easy => Self::Easy,
+			LevelRating::Normal => Self::Normal,
+			LevelRating::Hard => Self::Hard,
+			LevelRating::Harder => Self::Harder,
+			LevelRating::Insane => Self::Insane,
+			LevelRating::Demon(DemonRating::Unknown(_)) => Self::HardDemon,
+			LevelRating::Demon(DemonRating::Easy) => Self::EasyDemon,
+			LevelRating::Demon(DemonRating::Medium) => Self::MediumDemon,
+			LevelRating::Demon(DemonRating::Hard) => Self::HardDemon,
+			LevelRating::Demon(DemonRating::Insane) => Self::InsaneDemon,
+			LevelRating::Demon(DemonRating::Extreme) => Self::ExtremeDemon
+		}
+	}
+}
+
+impl From<LevelDifficulty> for api::level_request_api::LevelDifficulty {
+	fn from(value: LevelDifficulty) -> Self {
+		match value {
+			LevelDifficulty::NotAvailable => api::level_request_api::LevelDifficulty::NotAvailable,
+			LevelDifficulty::Auto => api::level_request_api::LevelDifficulty::Auto,
+			LevelDifficulty::Easy => api::level_request_api::LevelDifficulty::Easy,
+			LevelDifficulty::Normal => api::level_request_api::LevelDifficulty::Normal,
+			LevelDifficulty::Hard => api::level_request_api::LevelDifficulty::Hard,
+			LevelDifficulty::Harder => api::level_request_api::LevelDifficulty::Harder,
+			LevelDifficulty::Insane => api::level_request_api::LevelDifficulty::Insane,
+			LevelDifficulty::EasyDemon => api::level_request_api::LevelDifficulty::EasyDemon,
+			LevelDifficulty::MediumDemon => api::level_request_api::LevelDifficulty::MediumDemon,
+			LevelDifficulty::HardDemon => api::level_request_api::LevelDifficulty::HardDemon,
+			LevelDifficulty::InsaneDemon => api::level_request_api::LevelDifficulty::InsaneDemon,
+			LevelDifficulty::ExtremeDemon => api::level_request_api::LevelDifficulty::ExtremeDemon
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FeatureState {
+	NotFeatured,
+	Featured,
+	Epic
+}
+
+impl From<sea_orm_active_enums::FeatureState> for FeatureState {
+	fn from(value: sea_orm_active_enums::FeatureState) -> Self {
+		match value {
+			sea_orm_active_enums::FeatureState::NotFeatured => Self::NotFeatured,
+			sea_orm_active_enums::FeatureState::Featured => Self::Featured,
+			sea_orm_active_enums::FeatureState::Epic => Self::Epic
+		}
+	}
+}
+
+impl From<FeatureState> for sea_orm_active_enums::FeatureState {
+	fn from(value: FeatureState) -> Self {
+		match value {
+			FeatureState::NotFeatured => sea_orm_active_enums::FeatureState::NotFeatured,
+			FeatureState::Featured => sea_orm_active_enums::FeatureState::Featured,
+			FeatureState::Epic => sea_orm_active_enums::FeatureState::Epic
+		}
+	}
+}
+
+impl From<FeatureState> for api::level_request_api::FeatureState {
+	fn from(value: FeatureState) -> Self {
+		match value {
+			FeatureState::NotFeatured => api::level_request_api::FeatureState::NotFeatured,
+			FeatureState::Featured => api::level_request_api::FeatureState::Featured,
+			FeatureState::Epic => api::level_request_api::FeatureState::Epic
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum RequestRating {
 	One,
 	Two,
@@ -264,3 +595,95 @@ impl Into<api::level_request_api::RequestRating> for RequestRating {
 		}
 	}
 }
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RequestStatus {
+	Pending,
+	InReview,
+	Reviewed,
+	Sent,
+	Rejected
+}
+
+impl RequestStatus {
+	pub fn next(&self) -> Option<RequestStatus> {
+		match self {
+			RequestStatus::Pending => Some(RequestStatus::InReview),
+			RequestStatus::InReview => Some(RequestStatus::Reviewed),
+			RequestStatus::Reviewed => Some(RequestStatus::Sent),
+			RequestStatus::Sent | RequestStatus::Rejected => None
+		}
+	}
+
+	/// Pending -> InReview -> Reviewed -> Sent one step at a time, or any ->
+	/// Rejected.
+	pub fn can_transition_to(&self, status: RequestStatus) -> bool {
+		match status {
+			RequestStatus::Rejected => *self != RequestStatus::Rejected,
+			_ => self.next() == Some(status)
+		}
+	}
+
+	/// The statuses to move through, one legal transition at a time, to get
+	/// forward to `status`. Empty when already at or past `status`, `None`
+	/// when it cannot be reached by moving forward, as from Rejected.
+	pub fn advance_steps_to(&self, status: RequestStatus) -> Option<Vec<RequestStatus>> {
+		let following_statuses = self.following_statuses();
+		if let Some(index) = following_statuses
+			.iter()
+			.position(|following_status| *following_status == status)
+		{
+			return Some(following_statuses[..=index].to_vec());
+		}
+		if *self == status || status.following_statuses().contains(self) {
+			return Some(Vec::new());
+		}
+		None
+	}
+
+	fn following_statuses(&self) -> Vec<RequestStatus> {
+		let mut following_statuses = Vec::new();
+		let mut current_status = *self;
+		while let Some(next_status) = current_status.next() {
+			following_statuses.push(next_status);
+			current_status = next_status;
+		}
+		following_statuses
+	}
+}
+
+impl From<sea_orm_active_enums::RequestStatus> for RequestStatus {
+	fn from(value: sea_orm_active_enums::RequestStatus) -> Self {
+		match value {
+			sea_orm_active_enums::RequestStatus::Pending => Self::Pending,
+			sea_orm_active_enums::RequestStatus::InReview => Self::InReview,
+			sea_orm_active_enums::RequestStatus::Reviewed => Self::Reviewed,
+			sea_orm_active_enums::RequestStatus::Sent => Self::Sent,
+			sea_orm_active_enums::RequestStatus::Rejected => Self::Rejected
+		}
+	}
+}
+
+impl From<RequestStatus> for sea_orm_active_enums::RequestStatus {
+	fn from(value: RequestStatus) -> Self {
+		match value {
+			RequestStatus::Pending => sea_orm_active_enums::RequestStatus::Pending,
+			RequestStatus::InReview => sea_orm_active_enums::RequestStatus::InReview,
+			RequestStatus::Reviewed => sea_orm_active_enums::RequestStatus::Reviewed,
+			RequestStatus::Sent => sea_orm_active_enums::RequestStatus::Sent,
+			RequestStatus::Rejected => sea_orm_active_enums::RequestStatus::Rejected
+		}
+	}
+}
+
+impl From<RequestStatus> for api::level_request_api::RequestStatus {
+	fn from(value: RequestStatus) -> Self {
+		match value {
+			RequestStatus::Pending => api::level_request_api::RequestStatus::Pending,
+			RequestStatus::InReview => api::level_request_api::RequestStatus::InReview,
+			RequestStatus::Reviewed => api::level_request_api::RequestStatus::Reviewed,
+			RequestStatus::Sent => api::level_request_api::RequestStatus::Sent,
+			RequestStatus::Rejected => api::level_request_api::RequestStatus::Rejected
+		}
+	}
+}
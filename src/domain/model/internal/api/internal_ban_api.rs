@@ -0,0 +1,182 @@
+use std::{
+	error::Error,
+	fmt::{Display, Formatter}
+};
+
+use chrono::{DateTime, Local, Utc};
+use rocket_framework::{
+	http::{ContentType, Status},
+	response,
+	response::Responder,
+	serde::json::Json,
+	Request, Response
+};
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+use serde_derive::Deserialize;
+
+use crate::{
+	domain::model::{ban, ban::Ban},
+	rocket::common::constants::TIMESTAMP_HEADER_NAME
+};
+
+#[derive(Deserialize)]
+pub struct CreateBanApiRequest {
+	pub target_type: BanTargetType,
+	/// Discord user id for a user ban, level id for a level ban.
+	pub target_id: u64,
+	pub reason: Option<String>,
+	pub expires_at: Option<DateTime<Utc>>
+}
+
+impl CreateBanApiRequest {
+	pub fn target(&self) -> ban::BanTarget {
+		match self.target_type {
+			BanTargetType::User => ban::BanTarget::User(self.target_id),
+			BanTargetType::Level => ban::BanTarget::Level(self.target_id)
+		}
+	}
+}
+
+#[derive(Serialize)]
+pub struct GetBanApiResponse {
+	pub ban_id: u64,
+	pub target_type: BanTargetType,
+	pub target_id: u64,
+	pub reason: Option<String>,
+	pub issued_by: u64,
+	pub created_at: DateTime<Utc>,
+	pub expires_at: Option<DateTime<Utc>>,
+	pub lifted_at: Option<DateTime<Utc>>,
+	pub lifted_by: Option<u64>
+}
+
+impl From<Ban> for GetBanApiResponse {
+	fn from(value: Ban) -> Self {
+		Self {
+			ban_id: value.ban_id,
+			target_type: value.target.into(),
+			target_id: value.target.target_id(),
+			reason: value.reason,
+			issued_by: value.issued_by,
+			created_at: value.created_at,
+			expires_at: value.expires_at,
+			lifted_at: value.lifted_at,
+			lifted_by: value.lifted_by
+		}
+	}
+}
+
+impl<'r> Responder<'r, 'r> for GetBanApiResponse {
+	fn respond_to(self, request: &'r Request<'_>) -> response::Result<'r> {
+		let json = Json(self);
+		Response::build_from(json.respond_to(request).unwrap())
+			.status(Status::Ok)
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON)
+			.ok()
+	}
+}
+
+#[derive(Serialize)]
+pub struct GetBansApiResponse {
+	pub bans: Vec<GetBanApiResponse>
+}
+
+impl From<Vec<Ban>> for GetBansApiResponse {
+	fn from(value: Vec<Ban>) -> Self {
+		Self {
+			bans: value.into_iter().map(GetBanApiResponse::from).collect()
+		}
+	}
+}
+
+impl<'r> Responder<'r, 'r> for GetBansApiResponse {
+	fn respond_to(self, request: &'r Request<'_>) -> response::Result<'r> {
+		let json = Json(self);
+		Response::build_from(json.respond_to(request).unwrap())
+			.status(Status::Ok)
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON)
+			.ok()
+	}
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq)]
+pub enum BanTargetType {
+	User,
+	Level
+}
+
+#[derive(Debug, PartialEq)]
+pub enum BanApiResponseError {
+	MalformedBan,
+	AlreadyBanned,
+	BanDoesNotExist,
+	BanNotActive,
+	BanError
+}
+
+impl Serialize for BanApiResponseError {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer
+	{
+		let mut state = serializer.serialize_struct("BanApiResponseError", 1)?;
+		state.serialize_field("message", &self.to_string())?;
+		state.end()
+	}
+}
+
+impl<'r> Responder<'r, 'r> for BanApiResponseError {
+	fn respond_to(self, request: &'r Request<'_>) -> response::Result<'r> {
+		let json = Json(&self);
+		let mut response = Response::build_from(json.respond_to(request).unwrap());
+		response
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON);
+
+		match self {
+			BanApiResponseError::MalformedBan => {
+				response.status(Status::BadRequest);
+			}
+			BanApiResponseError::AlreadyBanned => {
+				response.status(Status::Conflict);
+			}
+			BanApiResponseError::BanDoesNotExist => {
+				response.status(Status::NotFound);
+			}
+			BanApiResponseError::BanNotActive => {
+				response.status(Status::Conflict);
+			}
+			BanApiResponseError::BanError => {
+				response.status(Status::InternalServerError);
+			}
+		}
+
+		response.ok()
+	}
+}
+
+impl Display for BanApiResponseError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			BanApiResponseError::MalformedBan => {
+				write!(f, "Ban is malformed")
+			}
+			BanApiResponseError::AlreadyBanned => {
+				write!(f, "Target is already banned")
+			}
+			BanApiResponseError::BanDoesNotExist => {
+				write!(f, "Ban does not exist")
+			}
+			BanApiResponseError::BanNotActive => {
+				write!(f, "Ban has already been lifted or has expired")
+			}
+			BanApiResponseError::BanError => {
+				write!(f, "Internal server error")
+			}
+		}
+	}
+}
+
+impl Error for BanApiResponseError {}
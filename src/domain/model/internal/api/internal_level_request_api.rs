@@ -6,7 +6,10 @@ use rocket_framework::{
 };
 use serde_derive::{Deserialize, Serialize};
 
-use crate::rocket::common::constants::TIMESTAMP_HEADER_NAME;
+use crate::{
+	domain::model::api::level_request_api::RequestStatus,
+	rocket::common::constants::TIMESTAMP_HEADER_NAME
+};
 
 #[derive(Deserialize)]
 pub struct InternalUpdateLevelRequestMessageIdApiRequest {
@@ -14,6 +17,11 @@ pub struct InternalUpdateLevelRequestMessageIdApiRequest {
 	pub discord_message_id: u64
 }
 
+#[derive(Deserialize)]
+pub struct InternalUpdateLevelRequestStatusApiRequest {
+	pub status: RequestStatus
+}
+
 #[derive(Serialize)]
 pub struct InternalUpdateLevelRequestDiscordDataApiResponse {}
 
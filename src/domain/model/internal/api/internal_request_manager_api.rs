@@ -1,24 +1,78 @@
+use std::{
+	error::Error,
+	fmt::{Display, Formatter}
+};
+
 use chrono::Local;
 use rocket_framework::{
 	http::{ContentType, Status},
 	response::Responder,
+	serde::json::Json,
 	Request, Response
 };
 use serde_derive::{Deserialize, Serialize};
 
-use crate::rocket::common::constants::TIMESTAMP_HEADER_NAME;
+use crate::{
+	domain::model::{
+		level_queue,
+		request_config::{RequestConfig, RequestConfigUpdate}
+	},
+	rocket::common::constants::TIMESTAMP_HEADER_NAME
+};
 
 #[derive(Deserialize)]
 pub struct InternalUpdateRequestConfigApiRequest {
 	#[serde(rename = "duration")]
 	pub duration_in_minutes: Option<u64>,
 	pub enable_requests: Option<bool>,
-	pub enable_gd_requests: Option<bool>
+	pub enable_gd_requests: Option<bool>,
+	pub queue_policy: Option<QueuePolicy>,
+	/// 0 for no limit.
+	pub max_open_requests: Option<u32>,
+	/// 0 for no limit.
+	pub daily_request_quota: Option<u32>
+}
+
+impl From<InternalUpdateRequestConfigApiRequest> for RequestConfigUpdate {
+	fn from(value: InternalUpdateRequestConfigApiRequest) -> Self {
+		Self {
+			cooldown_duration_in_minutes: value.duration_in_minutes,
+			enable_requests: value.enable_requests,
+			enable_gd_requests: value.enable_gd_requests,
+			queue_policy: value.queue_policy.map(|queue_policy| queue_policy.into()),
+			max_open_requests: value.max_open_requests,
+			daily_request_quota: value.daily_request_quota
+		}
+	}
 }
 
 #[derive(Serialize)]
 pub struct InternalUpdateRequestConfigApiResponse {}
 
+#[derive(Serialize)]
+pub struct InternalGetRequestConfigApiResponse {
+	#[serde(rename = "duration")]
+	pub duration_in_minutes: u64,
+	pub enable_requests: bool,
+	pub enable_gd_requests: bool,
+	pub queue_policy: QueuePolicy,
+	pub max_open_requests: u32,
+	pub daily_request_quota: u32
+}
+
+impl From<RequestConfig> for InternalGetRequestConfigApiResponse {
+	fn from(value: RequestConfig) -> Self {
+		Self {
+			duration_in_minutes: value.cooldown_duration.num_minutes() as u64,
+			enable_requests: value.enable_requests,
+			enable_gd_requests: value.enable_gd_requests,
+			queue_policy: value.queue_policy.into(),
+			max_open_requests: value.max_open_requests,
+			daily_request_quota: value.daily_request_quota
+		}
+	}
+}
+
 impl<'r> Responder<'r, 'r> for InternalUpdateRequestConfigApiResponse {
 	fn respond_to(self, _request: &'r Request<'_>) -> rocket_framework::response::Result<'r> {
 		Response::build()
@@ -28,3 +82,74 @@ impl<'r> Responder<'r, 'r> for InternalUpdateRequestConfigApiResponse {
 			.ok()
 	}
 }
+
+impl<'r> Responder<'r, 'r> for InternalGetRequestConfigApiResponse {
+	fn respond_to(self, request: &'r Request<'_>) -> rocket_framework::response::Result<'r> {
+		let json = Json(self);
+		Response::build_from(json.respond_to(&request).unwrap())
+			.status(Status::Ok)
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON)
+			.ok()
+	}
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RequestConfigApiResponseError {
+	MalformedRequest,
+	RequestConfigError
+}
+
+impl<'r> Responder<'r, 'r> for RequestConfigApiResponseError {
+	fn respond_to(self, request: &'r Request<'_>) -> rocket_framework::response::Result<'r> {
+		let json = Json(self.to_string());
+		let mut response = Response::build_from(json.respond_to(&request).unwrap());
+		response
+			.raw_header(TIMESTAMP_HEADER_NAME, format!("{}", Local::now()))
+			.header(ContentType::JSON);
+		match self {
+			RequestConfigApiResponseError::MalformedRequest => {
+				response.status(Status::BadRequest);
+			}
+			RequestConfigApiResponseError::RequestConfigError => {
+				response.status(Status::InternalServerError);
+			}
+		}
+
+		response.ok()
+	}
+}
+
+impl Display for RequestConfigApiResponseError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			RequestConfigApiResponseError::MalformedRequest => {
+				write!(f, "{{\"message\": \"Request config was malformed\"}}")
+			}
+			RequestConfigApiResponseError::RequestConfigError => {
+				write!(f, "{{\"message\": \"Internal server error\"}}")
+			}
+		}
+	}
+}
+
+impl Error for RequestConfigApiResponseError {}
+
+#[derive(Deserialize, Serialize, Clone, Copy)]
+pub enum QueuePolicy {
+	Fifo,
+	RequestRating,
+	FeedbackFirst,
+	RoundRobin
+}
+
+impl From<QueuePolicy> for level_queue::QueuePolicy {
+	fn from(value: QueuePolicy) -> Self {
+		match value {
+			QueuePolicy::Fifo => level_queue::QueuePolicy::Fifo,
+			QueuePolicy::RequestRating => level_queue::QueuePolicy::RequestRating,
+			QueuePolicy::FeedbackFirst => level_queue::QueuePolicy::FeedbackFirst,
+			QueuePolicy::RoundRobin => level_queue::QueuePolicy::RoundRobin
+		}
+	}
+}
@@ -1,3 +1,4 @@
+pub mod internal_ban_api;
 pub mod internal_level_request_api;
 pub mod internal_level_review_api;
 pub mod internal_request_manager_api;
@@ -0,0 +1,60 @@
+use std::fmt::{Display, Formatter};
+
+use crate::domain::model::api;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IneligibilityReason {
+	AlreadyRated,
+	AlreadyFeatured,
+	TooFewObjects,
+	OutdatedGameVersion,
+	DeniedCreator,
+	LevelInfoUnavailable
+}
+
+impl Display for IneligibilityReason {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			IneligibilityReason::AlreadyRated => write!(f, "Level has already been rated"),
+			IneligibilityReason::AlreadyFeatured => write!(f, "Level has already been featured"),
+			IneligibilityReason::TooFewObjects => write!(f, "Level has too few objects"),
+			IneligibilityReason::OutdatedGameVersion => {
+				write!(f, "Level was made for an outdated game version")
+			}
+			IneligibilityReason::DeniedCreator => {
+				write!(f, "Levels by this creator cannot be requested")
+			}
+			IneligibilityReason::LevelInfoUnavailable => {
+				write!(
+					f,
+					"Level info is unavailable, so the level cannot be checked"
+				)
+			}
+		}
+	}
+}
+
+impl From<IneligibilityReason> for api::level_request_api::IneligibilityReason {
+	fn from(value: IneligibilityReason) -> Self {
+		match value {
+			IneligibilityReason::AlreadyRated => {
+				api::level_request_api::IneligibilityReason::AlreadyRated
+			}
+			IneligibilityReason::AlreadyFeatured => {
+				api::level_request_api::IneligibilityReason::AlreadyFeatured
+			}
+			IneligibilityReason::TooFewObjects => {
+				api::level_request_api::IneligibilityReason::TooFewObjects
+			}
+			IneligibilityReason::OutdatedGameVersion => {
+				api::level_request_api::IneligibilityReason::OutdatedGameVersion
+			}
+			IneligibilityReason::DeniedCreator => {
+				api::level_request_api::IneligibilityReason::DeniedCreator
+			}
+			IneligibilityReason::LevelInfoUnavailable => {
+				api::level_request_api::IneligibilityReason::LevelInfoUnavailable
+			}
+		}
+	}
+}
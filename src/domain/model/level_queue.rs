@@ -0,0 +1,109 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use serde_derive::Deserialize;
+
+use crate::{
+	adapter::mysql::model::sea_orm_active_enums,
+	domain::model::{gd_level::GDLevelRequest, internal::api::internal_request_manager_api}
+};
+
+/// How a guild's pending level requests are ordered. Every policy falls back to
+/// request time, oldest first, between requests it considers equal.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueuePolicy {
+	#[default]
+	Fifo,
+	/// Higher requested ratings first.
+	RequestRating,
+	/// Requests that asked for feedback first.
+	FeedbackFirst,
+	/// One request per requester at a time, so a single requester cannot fill
+	/// the top of the queue.
+	RoundRobin
+}
+
+impl QueuePolicy {
+	/// Orders pending level requests with the next level first. Skipped
+	/// requests always wait at the back, in the order they were skipped.
+	pub fn order(&self, level_requests: Vec<GDLevelRequest>) -> Vec<GDLevelRequest> {
+		let (mut queued_level_requests, mut skipped_level_requests): (Vec<_>, Vec<_>) =
+			level_requests
+				.into_iter()
+				.partition(|level_request| level_request.skipped_at.is_none());
+
+		// Sorts below are stable, so requests they consider equal stay in
+		// request order
+		queued_level_requests.sort_by_key(|level_request| level_request.timestamp);
+		match self {
+			QueuePolicy::Fifo => {}
+			QueuePolicy::RequestRating => queued_level_requests
+				.sort_by_key(|level_request| Reverse(level_request.request_rating)),
+			QueuePolicy::FeedbackFirst => queued_level_requests
+				.sort_by_key(|level_request| !level_request.has_requested_feedback),
+			QueuePolicy::RoundRobin => {
+				let mut requests_per_user: HashMap<u64, usize> = HashMap::new();
+				let mut level_requests_by_round: Vec<(usize, GDLevelRequest)> =
+					queued_level_requests
+						.into_iter()
+						.map(|level_request| {
+							let round = requests_per_user
+								.entry(level_request.discord_user_id)
+								.or_default();
+							*round += 1;
+							(*round, level_request)
+						})
+						.collect();
+				level_requests_by_round.sort_by_key(|(round, _)| *round);
+				queued_level_requests = level_requests_by_round
+					.into_iter()
+					.map(|(_, level_request)| level_request)
+					.collect();
+			}
+		}
+
+		skipped_level_requests.sort_by_key(|level_request| level_request.skipped_at);
+		queued_level_requests.append(&mut skipped_level_requests);
+		queued_level_requests
+	}
+}
+
+impl From<sea_orm_active_enums::QueuePolicy> for QueuePolicy {
+	fn from(value: sea_orm_active_enums::QueuePolicy) -> Self {
+		match value {
+			sea_orm_active_enums::QueuePolicy::Fifo => Self::Fifo,
+			sea_orm_active_enums::QueuePolicy::RequestRating => Self::RequestRating,
+			sea_orm_active_enums::QueuePolicy::FeedbackFirst => Self::FeedbackFirst,
+			sea_orm_active_enums::QueuePolicy::RoundRobin => Self::RoundRobin
+		}
+	}
+}
+
+impl From<QueuePolicy> for sea_orm_active_enums::QueuePolicy {
+	fn from(value: QueuePolicy) -> Self {
+		match value {
+			QueuePolicy::Fifo => sea_orm_active_enums::QueuePolicy::Fifo,
+			QueuePolicy::RequestRating => sea_orm_active_enums::QueuePolicy::RequestRating,
+			QueuePolicy::FeedbackFirst => sea_orm_active_enums::QueuePolicy::FeedbackFirst,
+			QueuePolicy::RoundRobin => sea_orm_active_enums::QueuePolicy::RoundRobin
+		}
+	}
+}
+
+impl From<QueuePolicy> for internal_request_manager_api::QueuePolicy {
+	fn from(value: QueuePolicy) -> Self {
+		match value {
+			QueuePolicy::Fifo => internal_request_manager_api::QueuePolicy::Fifo,
+			QueuePolicy::RequestRating => internal_request_manager_api::QueuePolicy::RequestRating,
+			QueuePolicy::FeedbackFirst => internal_request_manager_api::QueuePolicy::FeedbackFirst,
+			QueuePolicy::RoundRobin => internal_request_manager_api::QueuePolicy::RoundRobin
+		}
+	}
+}
+
+#[derive(Clone, Debug)]
+pub struct QueuedLevelRequest {
+	pub level_request: GDLevelRequest,
+	/// 1 for the next level.
+	pub position: u64
+}
@@ -0,0 +1,89 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{ColumnTrait, Condition, Order};
+
+use crate::{
+	adapter::mysql::model::{level_request, sea_orm_active_enums},
+	domain::model::gd_level::{GDLevelRequest, LevelLength, RequestRating, RequestStatus}
+};
+
+#[derive(Clone, Debug, Default)]
+pub struct LevelRequestFilter {
+	pub request_rating: Option<RequestRating>,
+	pub level_length: Option<LevelLength>,
+	pub has_requested_feedback: Option<bool>,
+	pub status: Option<RequestStatus>,
+	pub discord_user_id: Option<u64>,
+	pub from_timestamp: Option<DateTime<Utc>>,
+	pub to_timestamp: Option<DateTime<Utc>>
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SortOrder {
+	#[default]
+	Ascending,
+	Descending
+}
+
+#[derive(Clone, Debug)]
+pub struct LevelRequestPage {
+	pub level_requests: Vec<GDLevelRequest>,
+	pub page: u64,
+	pub page_size: u64,
+	pub total_count: u64
+}
+
+impl LevelRequestFilter {
+	pub fn is_valid_timestamp_range(&self) -> bool {
+		if let (Some(from_timestamp), Some(to_timestamp)) = (self.from_timestamp, self.to_timestamp)
+		{
+			from_timestamp.le(&to_timestamp)
+		} else {
+			true
+		}
+	}
+}
+
+impl From<LevelRequestFilter> for Condition {
+	fn from(value: LevelRequestFilter) -> Self {
+		Condition::all()
+			.add_option(value.request_rating.map(|request_rating| {
+				level_request::Column::RequestRating
+					.eq::<sea_orm_active_enums::RequestRating>(request_rating.into())
+			}))
+			.add_option(value.level_length.map(|level_length| {
+				level_request::Column::LevelLength
+					.eq::<sea_orm_active_enums::LevelLength>(level_length.into())
+			}))
+			.add_option(value.has_requested_feedback.map(|has_requested_feedback| {
+				level_request::Column::HasRequestedFeedback.eq(i8::from(has_requested_feedback))
+			}))
+			.add_option(value.status.map(|status| {
+				level_request::Column::Status
+					.eq::<sea_orm_active_enums::RequestStatus>(status.into())
+			}))
+			.add_option(
+				value
+					.discord_user_id
+					.map(|discord_user_id| level_request::Column::DiscordId.eq(discord_user_id))
+			)
+			.add_option(
+				value
+					.from_timestamp
+					.map(|from_timestamp| level_request::Column::Timestamp.gte(from_timestamp))
+			)
+			.add_option(
+				value
+					.to_timestamp
+					.map(|to_timestamp| level_request::Column::Timestamp.lte(to_timestamp))
+			)
+	}
+}
+
+impl From<SortOrder> for Order {
+	fn from(value: SortOrder) -> Self {
+		match value {
+			SortOrder::Ascending => Order::Asc,
+			SortOrder::Descending => Order::Desc
+		}
+	}
+}
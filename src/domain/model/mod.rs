@@ -1,9 +1,21 @@
 pub mod api;
 pub mod auth;
+pub mod ban;
 pub mod discord;
 pub mod error;
 pub mod gd_level;
 pub mod internal;
+pub mod level_eligibility;
+pub mod level_queue;
+pub mod level_request_query;
 pub mod moderator;
+pub mod request_config;
+pub mod request_limits;
 pub mod review;
+pub mod review_assignment;
+pub mod review_query;
+pub mod review_revision;
+pub mod review_rubric;
 pub mod reviewer;
+pub mod reviewer_stats;
+pub mod user_profile;
@@ -1,15 +1,22 @@
+use chrono::{DateTime, Utc};
 use dash_rs::request::moderator::{SuggestedFeatureScore, SuggestedStars};
 use sea_orm::ActiveValue;
 
-use crate::adapter::mysql::model::{
-	moderator, moderator::Model, sea_orm_active_enums, sea_orm_active_enums::Score
+use crate::{
+	adapter::mysql::model::{
+		moderator, moderator::Model, sea_orm_active_enums, sea_orm_active_enums::Score
+	},
+	domain::model::internal::api::moderator_api
 };
 
 #[derive(Clone, Copy, Debug)]
 pub struct Moderator {
+	pub guild_id: u64,
 	pub level_id: u64,
 	pub suggested_score: SuggestedScore,
-	pub suggested_rating: SuggestedRating
+	pub suggested_rating: SuggestedRating,
+	/// `None` for levels sent before send times were recorded.
+	pub sent_at: Option<DateTime<Utc>>
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -40,9 +47,11 @@ pub enum SuggestedScore {
 impl Into<moderator::ActiveModel> for Moderator {
 	fn into(self) -> moderator::ActiveModel {
 		moderator::ActiveModel {
-			level_id: ActiveValue::Set(self.level_id),
+			guild_id: ActiveValue::Set(self.guild_id.into()),
+			level_id: ActiveValue::Set(self.level_id.into()),
 			score: ActiveValue::Set(self.suggested_score.into()),
-			rating: ActiveValue::Set(self.suggested_rating.into())
+			rating: ActiveValue::Set(self.suggested_rating.into()),
+			sent_at: ActiveValue::Set(self.sent_at)
 		}
 	}
 }
@@ -50,9 +59,11 @@ impl Into<moderator::ActiveModel> for Moderator {
 impl From<moderator::Model> for Moderator {
 	fn from(value: Model) -> Self {
 		Self {
-			level_id: value.level_id,
+			guild_id: value.guild_id.into(),
+			level_id: value.level_id.into(),
 			suggested_score: SuggestedScore::from(value.score),
-			suggested_rating: SuggestedRating::from(value.rating)
+			suggested_rating: SuggestedRating::from(value.rating),
+			sent_at: value.sent_at
 		}
 	}
 }
@@ -148,3 +159,34 @@ impl Into<SuggestedFeatureScore> for SuggestedRating {
 		}
 	}
 }
+
+impl From<SuggestedScore> for moderator_api::SuggestedScore {
+	fn from(value: SuggestedScore) -> Self {
+		match value {
+			SuggestedScore::NoRate => moderator_api::SuggestedScore::NoRate,
+			SuggestedScore::Rated => moderator_api::SuggestedScore::Rated,
+			SuggestedScore::One => moderator_api::SuggestedScore::One,
+			SuggestedScore::Two => moderator_api::SuggestedScore::Two,
+			SuggestedScore::Three => moderator_api::SuggestedScore::Three,
+			SuggestedScore::Four => moderator_api::SuggestedScore::Four,
+			SuggestedScore::Five => moderator_api::SuggestedScore::Five,
+			SuggestedScore::Six => moderator_api::SuggestedScore::Six,
+			SuggestedScore::Seven => moderator_api::SuggestedScore::Seven,
+			SuggestedScore::Eight => moderator_api::SuggestedScore::Eight,
+			SuggestedScore::Nine => moderator_api::SuggestedScore::Nine,
+			SuggestedScore::Ten => moderator_api::SuggestedScore::Ten
+		}
+	}
+}
+
+impl From<SuggestedRating> for moderator_api::SuggestedRating {
+	fn from(value: SuggestedRating) -> Self {
+		match value {
+			SuggestedRating::Rate => moderator_api::SuggestedRating::Rate,
+			SuggestedRating::Feature => moderator_api::SuggestedRating::Feature,
+			SuggestedRating::Epic => moderator_api::SuggestedRating::Epic,
+			SuggestedRating::Legendary => moderator_api::SuggestedRating::Legendary,
+			SuggestedRating::Mythic => moderator_api::SuggestedRating::Mythic
+		}
+	}
+}
@@ -0,0 +1,73 @@
+use chrono::Duration;
+use sea_orm::ActiveValue;
+
+use crate::{
+	adapter::mysql::model::request_config, domain::model::level_queue::QueuePolicy,
+	rocket::common::config::client_config::ClientConfig
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RequestConfig {
+	pub guild_id: u64,
+	pub cooldown_duration: Duration,
+	pub enable_requests: bool,
+	pub enable_gd_requests: bool,
+	pub queue_policy: QueuePolicy,
+	/// 0 for no limit.
+	pub max_open_requests: u32,
+	/// 0 for no limit.
+	pub daily_request_quota: u32
+}
+
+/// Fields left as `None` keep their current value.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RequestConfigUpdate {
+	pub cooldown_duration_in_minutes: Option<u64>,
+	pub enable_requests: Option<bool>,
+	pub enable_gd_requests: Option<bool>,
+	pub queue_policy: Option<QueuePolicy>,
+	pub max_open_requests: Option<u32>,
+	pub daily_request_quota: Option<u32>
+}
+
+impl RequestConfig {
+	pub fn from_client_config(guild_id: u64, client_config: &ClientConfig) -> Self {
+		Self {
+			guild_id,
+			cooldown_duration: Duration::minutes(client_config.cooldown_duration as i64),
+			enable_requests: client_config.enable_requests,
+			enable_gd_requests: client_config.enable_gd_requests,
+			queue_policy: client_config.queue_policy,
+			max_open_requests: client_config.max_open_requests,
+			daily_request_quota: client_config.daily_request_quota
+		}
+	}
+}
+
+impl From<RequestConfig> for request_config::ActiveModel {
+	fn from(value: RequestConfig) -> Self {
+		request_config::ActiveModel {
+			guild_id: ActiveValue::Set(value.guild_id.into()),
+			cooldown_duration: ActiveValue::Set(value.cooldown_duration.num_minutes() as i32),
+			enable_requests: ActiveValue::Set(i8::from(value.enable_requests)),
+			enable_gd_requests: ActiveValue::Set(i8::from(value.enable_gd_requests)),
+			queue_policy: ActiveValue::Set(value.queue_policy.into()),
+			max_open_requests: ActiveValue::Set(value.max_open_requests),
+			daily_request_quota: ActiveValue::Set(value.daily_request_quota)
+		}
+	}
+}
+
+impl From<request_config::Model> for RequestConfig {
+	fn from(value: request_config::Model) -> Self {
+		Self {
+			guild_id: value.guild_id.into(),
+			cooldown_duration: Duration::minutes(value.cooldown_duration as i64),
+			enable_requests: value.enable_requests != 0,
+			enable_gd_requests: value.enable_gd_requests != 0,
+			queue_policy: QueuePolicy::from(value.queue_policy),
+			max_open_requests: value.max_open_requests,
+			daily_request_quota: value.daily_request_quota
+		}
+	}
+}
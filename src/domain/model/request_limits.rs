@@ -0,0 +1,89 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::Duration;
+
+use crate::domain::model::{api, request_config::RequestConfig};
+
+/// Request limits set for a single user, such as a shorter cooldown for
+/// supporters. Limits left unset fall back to the guild's.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RequestLimitOverrides {
+	pub cooldown_duration: Option<Duration>,
+	/// Lets the user request again without waiting out any cooldown.
+	pub cooldown_exempt: bool,
+	/// 0 lifts the limit for the user.
+	pub max_open_requests: Option<u32>,
+	/// 0 lifts the limit for the user.
+	pub daily_request_quota: Option<u32>
+}
+
+/// The limits a user's next request is checked against once their overrides
+/// have been applied to the guild's limits.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RequestLimits {
+	pub cooldown_duration: Duration,
+	/// Most requests the user may have pending or in review, if limited.
+	pub max_open_requests: Option<u32>,
+	/// Most requests the user may make in a rolling day, if limited.
+	pub daily_request_quota: Option<u32>
+}
+
+impl RequestLimits {
+	pub fn new(request_config: &RequestConfig, overrides: &RequestLimitOverrides) -> Self {
+		let cooldown_duration = if overrides.cooldown_exempt {
+			Duration::zero()
+		} else {
+			overrides
+				.cooldown_duration
+				.unwrap_or(request_config.cooldown_duration)
+		};
+
+		Self {
+			cooldown_duration,
+			max_open_requests: Self::limit(
+				overrides
+					.max_open_requests
+					.unwrap_or(request_config.max_open_requests)
+			),
+			daily_request_quota: Self::limit(
+				overrides
+					.daily_request_quota
+					.unwrap_or(request_config.daily_request_quota)
+			)
+		}
+	}
+
+	fn limit(limit: u32) -> Option<u32> {
+		if limit == 0 {
+			None
+		} else {
+			Some(limit)
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RequestQuota {
+	/// Requests that are pending or in review.
+	OpenRequests,
+	/// Requests made in the last 24 hours, including archived ones.
+	DailyRequests
+}
+
+impl Display for RequestQuota {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			RequestQuota::OpenRequests => write!(f, "open requests"),
+			RequestQuota::DailyRequests => write!(f, "daily requests")
+		}
+	}
+}
+
+impl From<RequestQuota> for api::level_request_api::RequestQuota {
+	fn from(value: RequestQuota) -> Self {
+		match value {
+			RequestQuota::OpenRequests => api::level_request_api::RequestQuota::OpenRequests,
+			RequestQuota::DailyRequests => api::level_request_api::RequestQuota::DailyRequests
+		}
+	}
+}
@@ -1,23 +1,41 @@
 use sea_orm::ActiveValue;
 
-use crate::adapter::mysql::model::{review, review::Model};
+use crate::{
+	adapter::mysql::model::{review, review::Model},
+	domain::model::review_rubric::ReviewRubric
+};
 
 #[derive(Debug, Clone)]
 pub struct LevelReview {
+	pub guild_id: u64,
 	pub reviewer_discord_id: u64,
 	pub discord_message_id: u64,
 	pub level_id: u64,
 	pub review_contents: String,
+	pub rubric: ReviewRubric,
 	pub is_update: bool
 }
 
+/// What the reviewer fills in when reviewing a level.
+#[derive(Debug, Clone)]
+pub struct LevelReviewSubmission {
+	pub discord_message_id: u64,
+	pub review_contents: String,
+	pub rubric: ReviewRubric
+}
+
 impl Into<review::ActiveModel> for LevelReview {
 	fn into(self) -> review::ActiveModel {
+		let (rubric_scores, suggested_score, suggested_rating) = self.rubric.into_columns();
 		review::ActiveModel {
-			level_id: ActiveValue::Set(self.level_id),
-			discord_id: ActiveValue::Set(self.reviewer_discord_id),
-			message_id: ActiveValue::Set(self.discord_message_id),
-			review_content: ActiveValue::Set(self.review_contents)
+			guild_id: ActiveValue::Set(self.guild_id.into()),
+			level_id: ActiveValue::Set(self.level_id.into()),
+			discord_id: ActiveValue::Set(self.reviewer_discord_id.into()),
+			message_id: ActiveValue::Set(self.discord_message_id.into()),
+			review_content: ActiveValue::Set(self.review_contents),
+			rubric_scores: ActiveValue::Set(rubric_scores),
+			suggested_score: ActiveValue::Set(suggested_score),
+			suggested_rating: ActiveValue::Set(suggested_rating)
 		}
 	}
 }
@@ -25,10 +43,16 @@ impl Into<review::ActiveModel> for LevelReview {
 impl From<Model> for LevelReview {
 	fn from(value: Model) -> Self {
 		Self {
-			reviewer_discord_id: value.discord_id,
-			discord_message_id: value.message_id,
-			level_id: value.level_id,
+			guild_id: value.guild_id.into(),
+			reviewer_discord_id: value.discord_id.into(),
+			discord_message_id: value.message_id.into(),
+			level_id: value.level_id.into(),
 			review_contents: value.review_content,
+			rubric: ReviewRubric::from_columns(
+				value.rubric_scores,
+				value.suggested_score,
+				value.suggested_rating
+			),
 			is_update: false
 		}
 	}
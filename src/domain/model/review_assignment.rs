@@ -0,0 +1,110 @@
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue;
+use serde_derive::Deserialize;
+
+use crate::{adapter::mysql::model::review_assignment, domain::model::gd_level::GDLevelRequest};
+
+/// How a level is assigned when no reviewer is picked for it. Both policies
+/// only consider active reviewers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AssignmentPolicy {
+	/// Reviewers take turns in order of their Discord ids.
+	#[default]
+	RoundRobin,
+	/// The reviewer with the fewest active assignments, taking turns between
+	/// equally loaded reviewers.
+	LeastLoaded
+}
+
+impl AssignmentPolicy {
+	/// Picks the reviewer to assign the next level to, or `None` without any
+	/// active reviewers.
+	pub fn pick_reviewer(
+		&self,
+		mut reviewer_discord_ids: Vec<u64>,
+		active_assignments: &[ReviewAssignment],
+		last_assigned_reviewer: Option<u64>
+	) -> Option<u64> {
+		// Turns start after whoever was assigned last
+		reviewer_discord_ids.sort();
+		if let Some(last_assigned_reviewer) = last_assigned_reviewer {
+			let next_turn = reviewer_discord_ids
+				.iter()
+				.position(|reviewer_discord_id| *reviewer_discord_id > last_assigned_reviewer)
+				.unwrap_or(reviewer_discord_ids.len());
+			reviewer_discord_ids.rotate_left(next_turn);
+		}
+
+		match self {
+			AssignmentPolicy::RoundRobin => reviewer_discord_ids.first().copied(),
+			AssignmentPolicy::LeastLoaded => {
+				reviewer_discord_ids
+					.into_iter()
+					.min_by_key(|reviewer_discord_id| {
+						active_assignments
+							.iter()
+							.filter(|assignment| {
+								assignment.reviewer_discord_id == *reviewer_discord_id
+							})
+							.count()
+					})
+			}
+		}
+	}
+}
+
+/// A level handed to a single reviewer so nobody else reviews it meanwhile.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReviewAssignment {
+	pub assignment_id: u64,
+	pub guild_id: u64,
+	pub level_id: u64,
+	pub reviewer_discord_id: u64,
+	/// The reviewer themselves for a claim.
+	pub assigned_by: u64,
+	pub assigned_at: DateTime<Utc>,
+	/// Stale assignments lapse so the level can be picked up again.
+	pub expires_at: DateTime<Utc>,
+	/// Set once the reviewer has reviewed the level.
+	pub completed_at: Option<DateTime<Utc>>,
+	pub released_at: Option<DateTime<Utc>>
+}
+
+#[derive(Clone, Debug)]
+pub struct AssignedLevelRequest {
+	pub assignment: ReviewAssignment,
+	pub level_request: GDLevelRequest
+}
+
+impl From<ReviewAssignment> for review_assignment::ActiveModel {
+	fn from(value: ReviewAssignment) -> Self {
+		review_assignment::ActiveModel {
+			id: ActiveValue::Set(value.assignment_id as i32),
+			guild_id: ActiveValue::Set(value.guild_id.into()),
+			level_id: ActiveValue::Set(value.level_id.into()),
+			discord_id: ActiveValue::Set(value.reviewer_discord_id.into()),
+			assigned_by: ActiveValue::Set(value.assigned_by.into()),
+			assigned_at: ActiveValue::Set(value.assigned_at),
+			expires_at: ActiveValue::Set(value.expires_at),
+			completed_at: ActiveValue::Set(value.completed_at),
+			released_at: ActiveValue::Set(value.released_at)
+		}
+	}
+}
+
+impl From<review_assignment::Model> for ReviewAssignment {
+	fn from(value: review_assignment::Model) -> Self {
+		Self {
+			assignment_id: value.id as u64,
+			guild_id: value.guild_id.into(),
+			level_id: value.level_id.into(),
+			reviewer_discord_id: value.discord_id.into(),
+			assigned_by: value.assigned_by.into(),
+			assigned_at: value.assigned_at,
+			expires_at: value.expires_at,
+			completed_at: value.completed_at,
+			released_at: value.released_at
+		}
+	}
+}
@@ -0,0 +1,62 @@
+use sea_orm::{sea_query::LikeExpr, ColumnTrait, Condition};
+
+use crate::{
+	adapter::mysql::model::{level_request, review},
+	domain::model::review::LevelReview
+};
+
+#[derive(Clone, Debug, Default)]
+pub struct LevelReviewFilter {
+	pub level_id: Option<u64>,
+	pub reviewer_discord_id: Option<u64>,
+	/// The user who requested the reviewed level.
+	pub requester_discord_id: Option<u64>,
+	/// Only reviews containing every word of the search.
+	pub search: Option<String>
+}
+
+#[derive(Clone, Debug)]
+pub struct LevelReviewPage {
+	pub level_reviews: Vec<LevelReview>,
+	pub page: u64,
+	pub page_size: u64,
+	pub total_count: u64
+}
+
+impl LevelReviewFilter {
+	fn search_condition(search: String) -> Condition {
+		search
+			.split_whitespace()
+			.fold(Condition::all(), |condition, word| {
+				let escaped_word = word
+					.replace('\\', "\\\\")
+					.replace('%', "\\%")
+					.replace('_', "\\_");
+				condition.add(
+					review::Column::ReviewContent
+						.into_expr()
+						.like(LikeExpr::new(format!("%{}%", escaped_word)).escape('\\'))
+				)
+			})
+	}
+}
+
+impl From<LevelReviewFilter> for Condition {
+	fn from(value: LevelReviewFilter) -> Self {
+		Condition::all()
+			.add_option(
+				value
+					.level_id
+					.map(|level_id| review::Column::LevelId.eq(level_id))
+			)
+			.add_option(
+				value
+					.reviewer_discord_id
+					.map(|reviewer_discord_id| review::Column::DiscordId.eq(reviewer_discord_id))
+			)
+			.add_option(value.requester_discord_id.map(|requester_discord_id| {
+				level_request::Column::DiscordId.eq(requester_discord_id)
+			}))
+			.add_option(value.search.map(LevelReviewFilter::search_condition))
+	}
+}
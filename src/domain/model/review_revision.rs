@@ -0,0 +1,147 @@
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue;
+use similar::{ChangeTag, TextDiff};
+
+use crate::{
+	adapter::mysql::model::review_revision,
+	domain::model::review_rubric::{ReviewRubric, RubricScore}
+};
+
+/// One version of a review. Editing a review appends a revision rather than
+/// replacing the earlier text.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReviewRevision {
+	pub guild_id: u64,
+	pub level_id: u64,
+	pub reviewer_discord_id: u64,
+	/// Numbered from 1 per review.
+	pub revision: u32,
+	/// The message the revision was submitted with.
+	pub discord_message_id: u64,
+	pub review_contents: String,
+	/// The rubric as submitted alongside the review contents.
+	pub rubric: ReviewRubric,
+	pub created_at: DateTime<Utc>
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReviewLineChange {
+	Unchanged,
+	Added,
+	Removed
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReviewDiffLine {
+	pub change: ReviewLineChange,
+	pub line: String
+}
+
+/// A rubric category whose score or comment differs between two revisions.
+/// `None` when the category was not scored in that revision.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RubricScoreChange {
+	pub category: String,
+	pub from: Option<RubricScore>,
+	pub to: Option<RubricScore>
+}
+
+/// A line by line comparison of two revisions of the same review, along with
+/// the rubric categories that changed.
+#[derive(Clone, Debug)]
+pub struct ReviewRevisionDiff {
+	pub from: ReviewRevision,
+	pub to: ReviewRevision,
+	pub lines: Vec<ReviewDiffLine>,
+	/// In the order the categories were first scored.
+	pub rubric_score_changes: Vec<RubricScoreChange>
+}
+
+impl ReviewRevisionDiff {
+	pub fn new(from: ReviewRevision, to: ReviewRevision) -> Self {
+		let lines = TextDiff::from_lines(&from.review_contents, &to.review_contents)
+			.iter_all_changes()
+			.map(|change| ReviewDiffLine {
+				change: match change.tag() {
+					ChangeTag::Equal => ReviewLineChange::Unchanged,
+					ChangeTag::Insert => ReviewLineChange::Added,
+					ChangeTag::Delete => ReviewLineChange::Removed
+				},
+				line: change.value().trim_end_matches(['\r', '\n']).to_string()
+			})
+			.collect();
+		let rubric_score_changes = Self::diff_rubric_scores(&from.rubric, &to.rubric);
+
+		Self {
+			from,
+			to,
+			lines,
+			rubric_score_changes
+		}
+	}
+
+	fn diff_rubric_scores(from: &ReviewRubric, to: &ReviewRubric) -> Vec<RubricScoreChange> {
+		let find_score = |rubric: &ReviewRubric, category: &str| {
+			rubric
+				.scores
+				.iter()
+				.find(|rubric_score| rubric_score.category == category)
+				.cloned()
+		};
+
+		let mut categories: Vec<&str> = Vec::new();
+		for rubric_score in from.scores.iter().chain(to.scores.iter()) {
+			if !categories.contains(&rubric_score.category.as_str()) {
+				categories.push(&rubric_score.category);
+			}
+		}
+
+		categories
+			.into_iter()
+			.map(|category| RubricScoreChange {
+				category: category.to_string(),
+				from: find_score(from, category),
+				to: find_score(to, category)
+			})
+			.filter(|rubric_score_change| rubric_score_change.from != rubric_score_change.to)
+			.collect()
+	}
+}
+
+impl From<ReviewRevision> for review_revision::ActiveModel {
+	fn from(value: ReviewRevision) -> Self {
+		let (rubric_scores, suggested_score, suggested_rating) = value.rubric.into_columns();
+		review_revision::ActiveModel {
+			id: ActiveValue::NotSet,
+			guild_id: ActiveValue::Set(value.guild_id.into()),
+			level_id: ActiveValue::Set(value.level_id.into()),
+			discord_id: ActiveValue::Set(value.reviewer_discord_id.into()),
+			revision: ActiveValue::Set(value.revision),
+			message_id: ActiveValue::Set(value.discord_message_id.into()),
+			review_content: ActiveValue::Set(value.review_contents),
+			created_at: ActiveValue::Set(value.created_at),
+			rubric_scores: ActiveValue::Set(rubric_scores),
+			suggested_score: ActiveValue::Set(suggested_score),
+			suggested_rating: ActiveValue::Set(suggested_rating)
+		}
+	}
+}
+
+impl From<review_revision::Model> for ReviewRevision {
+	fn from(value: review_revision::Model) -> Self {
+		Self {
+			guild_id: value.guild_id.into(),
+			level_id: value.level_id.into(),
+			reviewer_discord_id: value.discord_id.into(),
+			revision: value.revision,
+			discord_message_id: value.message_id.into(),
+			review_contents: value.review_content,
+			rubric: ReviewRubric::from_columns(
+				value.rubric_scores,
+				value.suggested_score,
+				value.suggested_rating
+			),
+			created_at: value.created_at
+		}
+	}
+}
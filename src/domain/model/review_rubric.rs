@@ -0,0 +1,148 @@
+use sea_orm::JsonValue;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+	adapter::mysql::model::sea_orm_active_enums::{Rating, Score},
+	domain::model::{
+		moderator::{SuggestedRating, SuggestedScore},
+		review::LevelReview
+	}
+};
+
+/// A reviewer's score in one of the configured rubric categories.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RubricScore {
+	pub category: String,
+	pub score: u8,
+	pub comment: Option<String>
+}
+
+/// The structured part of a review, alongside its free text.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReviewRubric {
+	pub scores: Vec<RubricScore>,
+	pub suggested_score: Option<SuggestedScore>,
+	pub suggested_rating: Option<SuggestedRating>
+}
+
+impl ReviewRubric {
+	/// Reviews and their revisions store the rubric in the same columns, with
+	/// the scores as JSON.
+	pub fn from_columns(
+		rubric_scores: Option<JsonValue>,
+		suggested_score: Option<Score>,
+		suggested_rating: Option<Rating>
+	) -> Self {
+		Self {
+			scores: rubric_scores
+				.and_then(|rubric_scores| {
+					serde_json::from_value::<Vec<RubricScore>>(rubric_scores).ok()
+				})
+				.unwrap_or_default(),
+			suggested_score: suggested_score.map(SuggestedScore::from),
+			suggested_rating: suggested_rating.map(SuggestedRating::from)
+		}
+	}
+
+	pub fn into_columns(self) -> (Option<JsonValue>, Option<Score>, Option<Rating>) {
+		(
+			if self.scores.is_empty() {
+				None
+			} else {
+				serde_json::to_value(self.scores).ok()
+			},
+			self.suggested_score
+				.map(|suggested_score| suggested_score.into()),
+			self.suggested_rating
+				.map(|suggested_rating| suggested_rating.into())
+		)
+	}
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RubricCategorySummary {
+	pub category: String,
+	/// How many reviewers scored the category.
+	pub score_count: u32,
+	/// `None` when nobody scored the category.
+	pub average_score: Option<f64>,
+	pub min_score: Option<u8>,
+	pub max_score: Option<u8>
+}
+
+/// Every reviewer's rubric for a level, combined.
+#[derive(Clone, Debug)]
+pub struct LevelRubricSummary {
+	pub level_id: u64,
+	pub review_count: u32,
+	/// In the order of the configured categories.
+	pub categories: Vec<RubricCategorySummary>,
+	/// How many reviewers suggested each star score, most suggested first.
+	pub suggested_scores: Vec<(SuggestedScore, u32)>,
+	/// How many reviewers suggested each rating, most suggested first.
+	pub suggested_ratings: Vec<(SuggestedRating, u32)>
+}
+
+impl LevelRubricSummary {
+	pub fn new(level_id: u64, categories: &[String], level_reviews: &[LevelReview]) -> Self {
+		let categories = categories
+			.iter()
+			.map(|category| {
+				let scores: Vec<u8> = level_reviews
+					.iter()
+					.flat_map(|level_review| level_review.rubric.scores.iter())
+					.filter(|rubric_score| rubric_score.category == *category)
+					.map(|rubric_score| rubric_score.score)
+					.collect();
+				let average_score = if scores.is_empty() {
+					None
+				} else {
+					Some(
+						scores.iter().map(|score| f64::from(*score)).sum::<f64>()
+							/ scores.len() as f64
+					)
+				};
+
+				RubricCategorySummary {
+					category: category.clone(),
+					score_count: scores.len() as u32,
+					average_score,
+					min_score: scores.iter().min().copied(),
+					max_score: scores.iter().max().copied()
+				}
+			})
+			.collect();
+
+		Self {
+			level_id,
+			review_count: level_reviews.len() as u32,
+			categories,
+			suggested_scores: Self::tally(
+				level_reviews
+					.iter()
+					.filter_map(|level_review| level_review.rubric.suggested_score)
+			),
+			suggested_ratings: Self::tally(
+				level_reviews
+					.iter()
+					.filter_map(|level_review| level_review.rubric.suggested_rating)
+			)
+		}
+	}
+
+	/// Counts each suggestion, keeping the first suggested ahead on ties.
+	fn tally<T: Copy + PartialEq>(suggestions: impl Iterator<Item = T>) -> Vec<(T, u32)> {
+		let mut tally: Vec<(T, u32)> = Vec::new();
+		for suggestion in suggestions {
+			match tally
+				.iter_mut()
+				.find(|(tallied_suggestion, _)| *tallied_suggestion == suggestion)
+			{
+				Some((_, count)) => *count += 1,
+				None => tally.push((suggestion, 1))
+			}
+		}
+		tally.sort_by(|(_, count), (_, other_count)| other_count.cmp(count));
+		tally
+	}
+}
@@ -1,9 +1,11 @@
+use chrono::{DateTime, Utc};
 use sea_orm::ActiveValue;
 
-use crate::adapter::mysql::model::reviewer;
+use crate::adapter::mysql::model::{reviewer, reviewer_activity};
 
 #[derive(Copy, Clone, Debug)]
 pub struct Reviewer {
+	pub guild_id: u64,
 	pub discord_id: u64,
 	pub is_active: bool
 }
@@ -11,7 +13,8 @@ pub struct Reviewer {
 impl Into<reviewer::ActiveModel> for Reviewer {
 	fn into(self) -> reviewer::ActiveModel {
 		reviewer::ActiveModel {
-			discord_id: ActiveValue::Set(self.discord_id),
+			guild_id: ActiveValue::Set(self.guild_id.into()),
+			discord_id: ActiveValue::Set(self.discord_id.into()),
 			active: ActiveValue::Set(i8::from(self.is_active))
 		}
 	}
@@ -20,8 +23,40 @@ impl Into<reviewer::ActiveModel> for Reviewer {
 impl From<reviewer::Model> for Reviewer {
 	fn from(value: reviewer::Model) -> Self {
 		Self {
-			discord_id: value.discord_id,
+			guild_id: value.guild_id.into(),
+			discord_id: value.discord_id.into(),
 			is_active: if value.active != 0 { true } else { false }
 		}
 	}
 }
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ReviewerActivity {
+	pub guild_id: u64,
+	pub discord_id: u64,
+	pub is_active: bool,
+	pub changed_at: DateTime<Utc>
+}
+
+impl From<ReviewerActivity> for reviewer_activity::ActiveModel {
+	fn from(value: ReviewerActivity) -> Self {
+		reviewer_activity::ActiveModel {
+			id: ActiveValue::NotSet,
+			guild_id: ActiveValue::Set(value.guild_id.into()),
+			discord_id: ActiveValue::Set(value.discord_id.into()),
+			active: ActiveValue::Set(i8::from(value.is_active)),
+			changed_at: ActiveValue::Set(value.changed_at)
+		}
+	}
+}
+
+impl From<reviewer_activity::Model> for ReviewerActivity {
+	fn from(value: reviewer_activity::Model) -> Self {
+		Self {
+			guild_id: value.guild_id.into(),
+			discord_id: value.discord_id.into(),
+			is_active: value.active != 0,
+			changed_at: value.changed_at
+		}
+	}
+}
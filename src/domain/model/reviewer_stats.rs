@@ -0,0 +1,209 @@
+use std::{cmp::Ordering, collections::BTreeMap};
+
+use chrono::{DateTime, Datelike, Duration, NaiveTime, Utc};
+
+use crate::domain::model::reviewer::{Reviewer, ReviewerActivity};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum StatsPeriod {
+	Day,
+	/// Weeks start on Monday.
+	Week,
+	#[default]
+	Month
+}
+
+impl StatsPeriod {
+	pub fn start_of(&self, timestamp: &DateTime<Utc>) -> DateTime<Utc> {
+		let date = timestamp.date_naive();
+		let period_start = match self {
+			StatsPeriod::Day => date,
+			StatsPeriod::Week => {
+				date - Duration::days(date.weekday().num_days_from_monday() as i64)
+			}
+			StatsPeriod::Month => date - Duration::days(date.day0() as i64)
+		};
+		period_start.and_time(NaiveTime::MIN).and_utc()
+	}
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct StatsRange {
+	pub from_timestamp: Option<DateTime<Utc>>,
+	pub to_timestamp: Option<DateTime<Utc>>
+}
+
+impl StatsRange {
+	pub fn is_valid(&self) -> bool {
+		if let (Some(from_timestamp), Some(to_timestamp)) = (self.from_timestamp, self.to_timestamp)
+		{
+			from_timestamp.le(&to_timestamp)
+		} else {
+			true
+		}
+	}
+
+	pub fn contains(&self, timestamp: &DateTime<Utc>) -> bool {
+		self.from_timestamp
+			.map_or(true, |from_timestamp| from_timestamp.le(timestamp))
+			&& self
+				.to_timestamp
+				.map_or(true, |to_timestamp| to_timestamp.ge(timestamp))
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReviewedLevel {
+	pub level_id: u64,
+	pub reviewer_discord_id: u64,
+	pub requested_at: DateTime<Utc>,
+	/// When the first revision was written, later edits do not count.
+	pub reviewed_at: DateTime<Utc>,
+	pub is_sent: bool
+}
+
+impl ReviewedLevel {
+	pub fn turnaround(&self) -> Duration {
+		(self.reviewed_at - self.requested_at).max(Duration::zero())
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PeriodReviewCount {
+	pub period_start: DateTime<Utc>,
+	pub review_count: u32
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReviewerStats {
+	pub discord_id: u64,
+	pub is_active: bool,
+	pub review_count: u32,
+	pub reviews_per_period: Vec<PeriodReviewCount>,
+	pub average_turnaround: Option<Duration>,
+	pub sent_level_count: u32,
+	pub activity: Vec<ReviewerActivity>
+}
+
+impl ReviewerStats {
+	pub fn new(
+		reviewer: Reviewer,
+		reviewed_levels: &[ReviewedLevel],
+		activity: Vec<ReviewerActivity>,
+		range: &StatsRange,
+		period: StatsPeriod
+	) -> Self {
+		let reviewed_levels: Vec<&ReviewedLevel> = reviewed_levels
+			.iter()
+			.filter(|reviewed_level| {
+				reviewed_level.reviewer_discord_id == reviewer.discord_id
+					&& range.contains(&reviewed_level.reviewed_at)
+			})
+			.collect();
+
+		let mut reviews_per_period: BTreeMap<DateTime<Utc>, u32> = BTreeMap::new();
+		for reviewed_level in reviewed_levels.iter() {
+			*reviews_per_period
+				.entry(period.start_of(&reviewed_level.reviewed_at))
+				.or_default() += 1;
+		}
+		let average_turnaround = match reviewed_levels.len() {
+			0 => None,
+			review_count => {
+				let total_turnaround_in_seconds: i64 = reviewed_levels
+					.iter()
+					.map(|reviewed_level| reviewed_level.turnaround().num_seconds())
+					.sum();
+				Some(Duration::seconds(
+					total_turnaround_in_seconds / review_count as i64
+				))
+			}
+		};
+
+		Self {
+			discord_id: reviewer.discord_id,
+			is_active: reviewer.is_active,
+			review_count: reviewed_levels.len() as u32,
+			reviews_per_period: reviews_per_period
+				.into_iter()
+				.map(|(period_start, review_count)| PeriodReviewCount {
+					period_start,
+					review_count
+				})
+				.collect(),
+			average_turnaround,
+			sent_level_count: reviewed_levels
+				.iter()
+				.filter(|reviewed_level| reviewed_level.is_sent)
+				.count() as u32,
+			activity
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ReviewerStatsMetric {
+	#[default]
+	ReviewCount,
+	AverageTurnaround,
+	SentLevelCount
+}
+
+impl ReviewerStatsMetric {
+	/// Reviewers tied on the metric share a rank.
+	pub fn rank(
+		&self,
+		reviewer_stats: Vec<ReviewerStats>,
+		limit: usize
+	) -> Vec<ReviewerLeaderboardEntry> {
+		let mut reviewer_stats: Vec<ReviewerStats> = reviewer_stats
+			.into_iter()
+			.filter(|reviewer_stats| reviewer_stats.review_count > 0)
+			.collect();
+		reviewer_stats.sort_by(|reviewer_stats, other_reviewer_stats| {
+			self.compare(reviewer_stats, other_reviewer_stats).then(
+				reviewer_stats
+					.discord_id
+					.cmp(&other_reviewer_stats.discord_id)
+			)
+		});
+
+		let mut leaderboard: Vec<ReviewerLeaderboardEntry> = Vec::new();
+		for (position, reviewer_stats) in reviewer_stats.into_iter().take(limit).enumerate() {
+			let rank = match leaderboard.last() {
+				Some(previous_entry)
+					if self.compare(&previous_entry.reviewer_stats, &reviewer_stats)
+						== Ordering::Equal =>
+				{
+					previous_entry.rank
+				}
+				_ => position as u32 + 1
+			};
+			leaderboard.push(ReviewerLeaderboardEntry {
+				rank,
+				reviewer_stats
+			});
+		}
+		leaderboard
+	}
+
+	fn compare(&self, reviewer_stats: &ReviewerStats, other: &ReviewerStats) -> Ordering {
+		match self {
+			ReviewerStatsMetric::ReviewCount => {
+				other.review_count.cmp(&reviewer_stats.review_count)
+			}
+			ReviewerStatsMetric::AverageTurnaround => reviewer_stats
+				.average_turnaround
+				.cmp(&other.average_turnaround),
+			ReviewerStatsMetric::SentLevelCount => {
+				other.sent_level_count.cmp(&reviewer_stats.sent_level_count)
+			}
+		}
+	}
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReviewerLeaderboardEntry {
+	pub rank: u32,
+	pub reviewer_stats: ReviewerStats
+}
@@ -0,0 +1,96 @@
+use std::cmp::Reverse;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::model::{
+	ban::Ban,
+	discord::user::DiscordUser,
+	gd_level::{GDLevelRequest, RequestStatus},
+	moderator::Moderator,
+	request_limits::RequestLimits,
+	review::LevelReview
+};
+
+/// A user's requests in a guild along with what currently limits them from
+/// making the next one.
+#[derive(Clone, Debug)]
+pub struct UserProfile {
+	pub discord_user: DiscordUser,
+	pub request_limits: RequestLimits,
+	/// Zero once the user may request again.
+	pub cooldown_remaining: Duration,
+	/// Requests that are pending or in review, counted against
+	/// `max_open_requests`.
+	pub open_requests: u32,
+	/// Requests made in the last 24 hours, counted against
+	/// `daily_request_quota`.
+	pub daily_requests: u32,
+	/// The ban keeping the user from requesting, if any.
+	pub ban: Option<Ban>,
+	/// Newest first.
+	pub level_requests: Vec<UserProfileLevelRequest>
+}
+
+#[derive(Clone, Debug)]
+pub struct UserProfileLevelRequest {
+	pub level_request: GDLevelRequest,
+	pub archived: bool,
+	pub reviews: Vec<LevelReview>,
+	/// What the level was suggested for when it was sent, if it was.
+	pub moderator: Option<Moderator>
+}
+
+impl UserProfileLevelRequest {
+	pub fn is_reviewed(&self) -> bool { !self.reviews.is_empty() }
+
+	pub fn is_sent(&self) -> bool {
+		self.moderator.is_some() || self.level_request.status == RequestStatus::Sent
+	}
+}
+
+impl UserProfile {
+	pub fn new(
+		discord_user: DiscordUser,
+		request_limits: RequestLimits,
+		ban: Option<Ban>,
+		mut level_requests: Vec<UserProfileLevelRequest>,
+		now: &DateTime<Utc>
+	) -> Self {
+		level_requests.sort_by_key(|level_request| Reverse(level_request.level_request.timestamp));
+
+		let cooldown_remaining = match discord_user.last_request_time {
+			Some(last_request_time) => {
+				(last_request_time + request_limits.cooldown_duration - *now).max(Duration::zero())
+			}
+			None => Duration::zero()
+		};
+		// Archived requests still count towards the daily quota, but are no
+		// longer open
+		let open_requests = level_requests
+			.iter()
+			.filter(|level_request| {
+				!level_request.archived
+					&& matches!(
+						level_request.level_request.status,
+						RequestStatus::Pending | RequestStatus::InReview
+					)
+			})
+			.count() as u32;
+		let daily_requests = level_requests
+			.iter()
+			.filter(|level_request| {
+				level_request.level_request.timestamp >= *now - Duration::days(1)
+			})
+			.count() as u32;
+
+		Self {
+			discord_user,
+			request_limits,
+			cooldown_remaining,
+			open_requests,
+			daily_requests,
+			ban,
+			level_requests
+		}
+	}
+}
@@ -1,22 +1,172 @@
-use jsonwebtoken::{encode, EncodingKey, Header};
+use chrono::{DateTime, Utc};
+use jsonwebtoken::{decode, decode_header, encode, Header, Validation};
+use sea_orm::ActiveValue;
 
 use crate::{
-	domain::model::auth::claims::Claims, rocket::common::config::auth_config::AUTH_CONFIG
+	adapter::mysql::{
+		model::revoked_token::ActiveModel, revoked_token_repository::RevokedTokenRepository
+	},
+	domain::{
+		model::{
+			auth::{
+				claims::{Claims, TokenType},
+				signing_key::SigningKeys,
+				token_pair::TokenPair
+			},
+			error::auth_error::AuthError
+		},
+		service::role_service::RoleService
+	},
+	rocket::common::config::auth_config::DEFAULT_SIGNING_KID
 };
 
-pub struct AuthService {
-	claims: Claims
+pub struct AuthService<'a, T: RevokedTokenRepository> {
+	revoked_token_repository: &'a T,
+	signing_keys: &'a SigningKeys
 }
 
-impl AuthService {
-	pub fn new(claims: Claims) -> Self { Self { claims } }
+impl<'a, T: RevokedTokenRepository> AuthService<'a, T> {
+	pub fn new(revoked_token_repository: &'a T, signing_keys: &'a SigningKeys) -> Self {
+		Self {
+			revoked_token_repository,
+			signing_keys
+		}
+	}
 
-	pub fn generate_jwt(&self) -> jsonwebtoken::errors::Result<String> {
+	pub async fn generate_jwt<R: RoleService>(
+		&self,
+		role_service: &R,
+		discord_app_id: u64,
+		guild_id: u64,
+		discord_user_id: u64
+	) -> Result<TokenPair, AuthError> {
 		info!("Generating new JWT");
-		encode(
-			&Header::default(),
-			&self.claims,
-			&EncodingKey::from_secret(&AUTH_CONFIG.secret_token.as_ref())
-		)
+		let roles = role_service
+			.get_roles(guild_id, discord_user_id)
+			.await
+			.map_err(AuthError::RoleError)?;
+
+		Ok(TokenPair {
+			access_token: self.encode_jwt(&Claims::new_access_token(
+				discord_app_id,
+				guild_id,
+				discord_user_id,
+				roles
+			))?,
+			refresh_token: self.encode_jwt(&Claims::new_refresh_token(
+				discord_app_id,
+				guild_id,
+				discord_user_id
+			))?
+		})
+	}
+
+	/// Exchanges a refresh token for a new token pair. The refresh token is
+	/// single use and is revoked once exchanged.
+	pub async fn refresh_jwt<R: RoleService>(
+		&self,
+		role_service: &R,
+		discord_app_id: u64,
+		refresh_token: &str
+	) -> Result<TokenPair, AuthError> {
+		let claims = self
+			.decode_jwt(refresh_token, discord_app_id, TokenType::Refresh)
+			.await?;
+		self.revoke_jwt(claims.guild_id, &claims.jti, claims.expires_at())
+			.await?;
+
+		self.generate_jwt(role_service, discord_app_id, claims.guild_id, claims.sub)
+			.await
+	}
+
+	pub async fn decode_jwt(
+		&self,
+		jwt: &str,
+		discord_app_id: u64,
+		token_type: TokenType
+	) -> Result<Claims, AuthError> {
+		let header = decode_header(jwt).map_err(AuthError::InvalidToken)?;
+		let signing_key = self
+			.signing_keys
+			.get(header.kid.as_deref().unwrap_or(DEFAULT_SIGNING_KID))
+			.ok_or_else(|| AuthError::UnknownSigningKey(header.kid.clone()))?;
+
+		let mut validation = Validation::new(signing_key.algorithm);
+		validation.set_audience(&[discord_app_id.to_string()]);
+		let claims = decode::<Claims>(jwt, &signing_key.decoding_key, &validation)
+			.map_err(AuthError::InvalidToken)?
+			.claims;
+
+		if claims.token_type.ne(&token_type) {
+			return Err(AuthError::UnexpectedTokenType(claims.token_type));
+		}
+
+		match self.revoked_token_repository.get_record(&claims.jti).await {
+			Ok(Some(_)) => {
+				warn!("Token {} has been revoked", claims.jti);
+				Err(AuthError::TokenRevoked)
+			}
+			Ok(None) => Ok(claims),
+			Err(get_revoked_token_error) => {
+				error!(
+					"Error checking revocation of token {}: {}",
+					claims.jti, get_revoked_token_error
+				);
+				Err(AuthError::DatabaseError(get_revoked_token_error))
+			}
+		}
+	}
+
+	/// Adds a token to the revocation list until it expires. Expired entries
+	/// are pruned on each revocation since they can no longer pass validation
+	/// anyway.
+	pub async fn revoke_jwt(
+		&self,
+		guild_id: u64,
+		jti: &str,
+		expires_at: DateTime<Utc>
+	) -> Result<(), AuthError> {
+		if let Err(delete_expired_error) = self
+			.revoked_token_repository
+			.delete_expired_records(Utc::now())
+			.await
+		{
+			warn!(
+				"Unable to prune expired revoked tokens: {}",
+				delete_expired_error
+			);
+		}
+
+		let revoked_token = ActiveModel {
+			jti: ActiveValue::Set(jti.to_string()),
+			guild_id: ActiveValue::Set(guild_id.into()),
+			expires_at: ActiveValue::Set(expires_at)
+		};
+		if let Err(revoke_error) = self
+			.revoked_token_repository
+			.create_record(revoked_token)
+			.await
+		{
+			error!("Error revoking token {}: {}", jti, revoke_error);
+			return Err(AuthError::DatabaseError(revoke_error));
+		}
+
+		info!("Revoked token {}", jti);
+		Ok(())
+	}
+
+	fn encode_jwt(&self, claims: &Claims) -> Result<String, AuthError> {
+		let signing_key = self.signing_keys.signing_key();
+		let encoding_key = signing_key.encoding_key.as_ref().ok_or_else(|| {
+			AuthError::SigningKeyError(format!(
+				"Signing key {} has no private key",
+				signing_key.kid
+			))
+		})?;
+
+		let mut header = Header::new(signing_key.algorithm);
+		header.kid = Some(signing_key.kid.clone());
+		encode(&header, claims, encoding_key)
+			.map_err(|err| AuthError::SigningKeyError(err.to_string()))
 	}
 }
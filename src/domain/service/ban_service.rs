@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::model::{
+	ban::{Ban, BanTarget},
+	error::ban_error::BanError
+};
+
+pub trait BanService {
+	async fn ban(
+		&self,
+		guild_id: u64,
+		target: BanTarget,
+		reason: Option<String>,
+		issued_by: u64,
+		expires_at: Option<DateTime<Utc>>
+	) -> Result<Ban, BanError>;
+
+	/// Bans in the guild, newest first. Lifted and expired bans are only
+	/// included when asked for.
+	async fn get_bans(&self, guild_id: u64, include_inactive: bool) -> Result<Vec<Ban>, BanError>;
+
+	async fn lift_ban(&self, guild_id: u64, ban_id: u64, lifted_by: u64) -> Result<Ban, BanError>;
+}
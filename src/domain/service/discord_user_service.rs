@@ -1,18 +1,44 @@
+use chrono::Utc;
+
 use crate::{
-	adapter::mysql::user_repository::UserRepository,
+	adapter::mysql::{
+		ban_repository::BanRepository, model::sea_orm_active_enums::BanTargetType,
+		user_repository::UserRepository
+	},
 	domain::{
-		model::{discord::user::DiscordUser, error::discord::discord_error::DiscordError},
-		service::user_service::UserService
+		model::{
+			ban::Ban,
+			discord::user::DiscordUser,
+			error::discord::discord_error::DiscordError,
+			gd_level::GDLevelRequest,
+			moderator::Moderator,
+			request_limits::{RequestLimitOverrides, RequestLimits},
+			review::LevelReview,
+			user_profile::{UserProfile, UserProfileLevelRequest}
+		},
+		service::{
+			internal::request_manager_service::RequestManagerService, user_service::UserService
+		}
 	}
 };
 
-pub struct DiscordUserService<'a, U: UserRepository> {
-	user_repository: &'a U
+pub struct DiscordUserService<'a, U: UserRepository, B: BanRepository> {
+	user_repository: &'a U,
+	ban_repository: &'a B,
+	request_manager: &'a RequestManagerService
 }
 
-impl<'a, U: UserRepository> UserService for DiscordUserService<'a, U> {
-	async fn get_user(&self, discord_user_id: u64) -> Result<DiscordUser, DiscordError> {
-		match self.user_repository.get_record(discord_user_id).await {
+impl<'a, U: UserRepository, B: BanRepository> UserService for DiscordUserService<'a, U, B> {
+	async fn get_user(
+		&self,
+		guild_id: u64,
+		discord_user_id: u64
+	) -> Result<DiscordUser, DiscordError> {
+		match self
+			.user_repository
+			.get_record(guild_id, discord_user_id)
+			.await
+		{
 			Ok(Some(discord_user)) => Ok(DiscordUser::from(discord_user)),
 			Ok(None) => {
 				warn!("Discord user with ID {} does not exist", discord_user_id);
@@ -24,8 +50,511 @@ impl<'a, U: UserRepository> UserService for DiscordUserService<'a, U> {
 			}
 		}
 	}
+
+	async fn get_user_profile(
+		&self,
+		guild_id: u64,
+		discord_user_id: u64
+	) -> Result<UserProfile, DiscordError> {
+		let discord_user = self.get_user(guild_id, discord_user_id).await?;
+		let now = Utc::now();
+
+		let level_requests = match self
+			.user_repository
+			.get_level_request_records(guild_id, discord_user_id)
+			.await
+		{
+			Ok(level_requests) => level_requests,
+			Err(db_err) => {
+				error!(
+					"Error getting level requests of user {}: {}",
+					discord_user_id, db_err
+				);
+				return Err(DiscordError::DatabaseError(db_err));
+			}
+		};
+		let archived_level_requests = match self
+			.user_repository
+			.get_archived_level_request_records(guild_id, discord_user_id)
+			.await
+		{
+			Ok(archived_level_requests) => archived_level_requests,
+			Err(db_err) => {
+				error!(
+					"Error getting archived level requests of user {}: {}",
+					discord_user_id, db_err
+				);
+				return Err(DiscordError::DatabaseError(db_err));
+			}
+		};
+		let mut moderators = match self
+			.user_repository
+			.get_moderator_records(guild_id, discord_user_id)
+			.await
+		{
+			Ok(moderators) => moderators,
+			Err(db_err) => {
+				error!(
+					"Error getting sent levels of user {}: {}",
+					discord_user_id, db_err
+				);
+				return Err(DiscordError::DatabaseError(db_err));
+			}
+		};
+		let ban = match self
+			.ban_repository
+			.get_active_record(guild_id, BanTargetType::User, discord_user_id, now)
+			.await
+		{
+			Ok(ban) => ban.map(Ban::from),
+			Err(db_err) => {
+				error!(
+					"Error getting active ban on user {}: {}",
+					discord_user_id, db_err
+				);
+				return Err(DiscordError::DatabaseError(db_err));
+			}
+		};
+
+		let level_requests = level_requests
+			.into_iter()
+			.map(|(level_request, reviews)| {
+				let moderator = moderators
+					.iter()
+					.position(|moderator| moderator.level_id == level_request.level_id)
+					.map(|index| moderators.swap_remove(index));
+				(level_request, reviews, moderator)
+			})
+			.chain(archived_level_requests)
+			.map(
+				|(level_request, reviews, moderator)| UserProfileLevelRequest {
+					archived: level_request.archived_at.is_some(),
+					level_request: GDLevelRequest::from(level_request),
+					reviews: reviews.into_iter().map(LevelReview::from).collect(),
+					moderator: moderator.map(Moderator::from)
+				}
+			)
+			.collect();
+
+		Ok(UserProfile::new(
+			discord_user,
+			RequestLimits::new(
+				&self.request_manager.get_request_config(guild_id),
+				&discord_user.request_limit_overrides
+			),
+			ban,
+			level_requests,
+			&now
+		))
+	}
+
+	async fn update_request_limit_overrides(
+		&self,
+		guild_id: u64,
+		discord_user_id: u64,
+		request_limit_overrides: RequestLimitOverrides
+	) -> Result<DiscordUser, DiscordError> {
+		let save_discord_user_result = match self
+			.user_repository
+			.get_record(guild_id, discord_user_id)
+			.await
+		{
+			Ok(Some(discord_user)) => {
+				let discord_user = DiscordUser {
+					request_limit_overrides,
+					..DiscordUser::from(discord_user)
+				};
+				self.user_repository
+					.update_record(discord_user.into())
+					.await
+					.map(|_| discord_user)
+			}
+			Ok(None) => {
+				let discord_user = DiscordUser {
+					guild_id,
+					discord_user_id,
+					last_request_time: None,
+					request_limit_overrides
+				};
+				self.user_repository
+					.create_record(discord_user.into())
+					.await
+					.map(|_| discord_user)
+			}
+			Err(db_err) => Err(db_err)
+		};
+
+		match save_discord_user_result {
+			Ok(discord_user) => {
+				info!(
+					"Request limits for user {} set to {:?}",
+					discord_user_id, request_limit_overrides
+				);
+				Ok(discord_user)
+			}
+			Err(db_err) => {
+				error!(
+					"Error saving request limits for user {}: {}",
+					discord_user_id, db_err
+				);
+				Err(DiscordError::DatabaseError(db_err))
+			}
+		}
+	}
+}
+
+impl<'a, U: UserRepository, B: BanRepository> DiscordUserService<'a, U, B> {
+	pub fn new(
+		user_repository: &'a U,
+		ban_repository: &'a B,
+		request_manager: &'a RequestManagerService
+	) -> Self {
+		DiscordUserService {
+			user_repository,
+			ban_repository,
+			request_manager
+		}
+	}
 }
 
-impl<'a, U: UserRepository> DiscordUserService<'a, U> {
-	pub fn new(user_repository: &'a U) -> Self { DiscordUserService { user_repository } }
+#[cfg(test)]
+mod tests {
+	use chrono::{Duration, Utc};
+	use sea_orm::{ActiveValue, DbErr, InsertResult};
+
+	use crate::{
+		adapter::mysql::{
+			ban_repository::MockBanRepository,
+			model::{moderator, request_ban, review, sea_orm_active_enums, user},
+			user_repository::MockUserRepository
+		},
+		domain::{
+			model::{
+				error::discord::discord_error::DiscordError, gd_level::RequestStatus,
+				moderator::SuggestedScore, request_limits::RequestLimitOverrides
+			},
+			service::{
+				discord_user_service::DiscordUserService,
+				internal::request_manager_service::RequestManagerService,
+				user_service::UserService
+			}
+		},
+		rocket::common::testing::level_request_model
+	};
+
+	fn supporter_overrides() -> RequestLimitOverrides {
+		RequestLimitOverrides {
+			cooldown_duration: Some(Duration::minutes(5)),
+			cooldown_exempt: false,
+			max_open_requests: Some(0),
+			daily_request_quota: None
+		}
+	}
+
+	fn user_model(guild_id: u64, last_request_time: chrono::DateTime<Utc>) -> user::Model {
+		user::Model {
+			guild_id: guild_id.into(),
+			discord_id: 12345.into(),
+			timestamp: Some(last_request_time),
+			cooldown_duration: Some(5),
+			cooldown_exempt: 0,
+			max_open_requests: None,
+			daily_request_quota: None
+		}
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_user_profile_should_aggregate_requests() {
+		let mut mock_user_repository = MockUserRepository::new();
+		let mut mock_ban_repository = MockBanRepository::new();
+		let now = Utc::now();
+
+		mock_user_repository
+			.expect_get_record()
+			.returning(move |guild_id, _| Ok(Some(user_model(guild_id, now))));
+		mock_user_repository
+			.expect_get_level_request_records()
+			.returning(move |guild_id, discord_id| {
+				let mut sent_level_request =
+					level_request_model(guild_id, 11111111, discord_id, RequestStatus::Sent);
+				sent_level_request.timestamp = now - Duration::days(2);
+				sent_level_request.archived_at = Some(now - Duration::days(1));
+				let review = review::Model {
+					guild_id: guild_id.into(),
+					level_id: 22222222.into(),
+					discord_id: 54321.into(),
+					message_id: 33333.into(),
+					review_content: "Nice level".to_string(),
+					rubric_scores: None,
+					suggested_score: None,
+					suggested_rating: None
+				};
+				Ok(vec![
+					(sent_level_request, vec![]),
+					(
+						level_request_model(guild_id, 22222222, discord_id, RequestStatus::Pending),
+						vec![review]
+					),
+				])
+			});
+		mock_user_repository
+			.expect_get_archived_level_request_records()
+			.returning(move |guild_id, discord_id| {
+				let mut replaced_level_request =
+					level_request_model(guild_id, 22222222, discord_id, RequestStatus::Sent);
+				replaced_level_request.timestamp = now - Duration::days(4);
+				replaced_level_request.archived_at = Some(now - Duration::days(3));
+				Ok(vec![(
+					replaced_level_request,
+					vec![],
+					Some(moderator::Model {
+						guild_id: guild_id.into(),
+						level_id: 22222222.into(),
+						score: sea_orm_active_enums::Score::Two,
+						rating: sea_orm_active_enums::Rating::Rate,
+						sent_at: None
+					})
+				)])
+			});
+		mock_user_repository
+			.expect_get_moderator_records()
+			.returning(|guild_id, _| {
+				Ok(vec![moderator::Model {
+					guild_id: guild_id.into(),
+					level_id: 11111111.into(),
+					score: sea_orm_active_enums::Score::Four,
+					rating: sea_orm_active_enums::Rating::Rate,
+					sent_at: None
+				}])
+			});
+		mock_ban_repository
+			.expect_get_active_record()
+			.returning(|_, _, _, _| Ok(None));
+
+		let request_manager = RequestManagerService {};
+		let service = DiscordUserService::new(
+			&mock_user_repository,
+			&mock_ban_repository,
+			&request_manager
+		);
+
+		let user_profile = service.get_user_profile(8101, 12345).await.unwrap();
+
+		assert_eq!(user_profile.level_requests.len(), 3);
+		let pending_level_request = &user_profile.level_requests[0];
+		assert_eq!(pending_level_request.level_request.level_id, 22222222);
+		assert!(pending_level_request.is_reviewed());
+		assert!(!pending_level_request.is_sent());
+		let sent_level_request = &user_profile.level_requests[1];
+		assert!(sent_level_request.archived);
+		assert!(sent_level_request.is_sent());
+		assert_eq!(
+			sent_level_request.moderator.unwrap().suggested_score,
+			SuggestedScore::Four
+		);
+		let replaced_level_request = &user_profile.level_requests[2];
+		assert_eq!(replaced_level_request.level_request.level_id, 22222222);
+		assert!(replaced_level_request.archived);
+		assert_eq!(
+			replaced_level_request.moderator.unwrap().suggested_score,
+			SuggestedScore::Two
+		);
+		assert_eq!(user_profile.open_requests, 1);
+		assert_eq!(user_profile.daily_requests, 1);
+		assert!(user_profile.cooldown_remaining > Duration::minutes(4));
+		assert!(user_profile.ban.is_none());
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_user_profile_should_include_active_ban() {
+		let mut mock_user_repository = MockUserRepository::new();
+		let mut mock_ban_repository = MockBanRepository::new();
+
+		mock_user_repository
+			.expect_get_record()
+			.returning(|guild_id, _| Ok(Some(user_model(guild_id, Utc::now()))));
+		mock_user_repository
+			.expect_get_level_request_records()
+			.returning(|_, _| Ok(vec![]));
+		mock_user_repository
+			.expect_get_archived_level_request_records()
+			.returning(|_, _| Ok(vec![]));
+		mock_user_repository
+			.expect_get_moderator_records()
+			.returning(|_, _| Ok(vec![]));
+		mock_ban_repository
+			.expect_get_active_record()
+			.withf(|_, target_type, target_id, _| {
+				*target_type == sea_orm_active_enums::BanTargetType::User && *target_id == 12345
+			})
+			.returning(|guild_id, target_type, target_id, _| {
+				Ok(Some(request_ban::Model {
+					id: 1,
+					guild_id: guild_id.into(),
+					target_type,
+					target_id: target_id.into(),
+					reason: Some("Spamming requests".to_string()),
+					issued_by: 0.into(),
+					created_at: Utc::now(),
+					expires_at: None,
+					lifted_at: None,
+					lifted_by: None
+				}))
+			});
+
+		let request_manager = RequestManagerService {};
+		let service = DiscordUserService::new(
+			&mock_user_repository,
+			&mock_ban_repository,
+			&request_manager
+		);
+
+		let user_profile = service.get_user_profile(8102, 12345).await.unwrap();
+
+		assert_eq!(user_profile.ban.unwrap().ban_id, 1);
+		assert_eq!(user_profile.open_requests, 0);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_user_profile_should_return_user_does_not_exist() {
+		let mut mock_user_repository = MockUserRepository::new();
+
+		mock_user_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_user_repository
+			.expect_get_level_request_records()
+			.never();
+
+		let mock_ban_repository = MockBanRepository::new();
+		let request_manager = RequestManagerService {};
+		let service = DiscordUserService::new(
+			&mock_user_repository,
+			&mock_ban_repository,
+			&request_manager
+		);
+
+		assert!(matches!(
+			service.get_user_profile(8103, 12345).await,
+			Err(DiscordError::UserDoesNotExist)
+		));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_update_request_limit_overrides_should_keep_last_request_time() {
+		let mut mock_user_repository = MockUserRepository::new();
+		let last_request_time = Utc::now();
+
+		mock_user_repository
+			.expect_get_record()
+			.returning(move |guild_id, discord_id| {
+				Ok(Some(user::Model {
+					guild_id: guild_id.into(),
+					discord_id: discord_id.into(),
+					timestamp: Some(last_request_time),
+					cooldown_duration: None,
+					cooldown_exempt: 1,
+					max_open_requests: None,
+					daily_request_quota: None
+				}))
+			});
+		mock_user_repository
+			.expect_update_record()
+			.withf(move |record| {
+				record.timestamp == ActiveValue::Set(Some(last_request_time))
+					&& record.cooldown_duration == ActiveValue::Set(Some(5))
+					&& record.cooldown_exempt == ActiveValue::Set(0)
+					&& record.max_open_requests == ActiveValue::Set(Some(0))
+			})
+			.times(1)
+			.returning(move |_| {
+				Ok(user::Model {
+					guild_id: 8001.into(),
+					discord_id: 12345.into(),
+					timestamp: Some(last_request_time),
+					cooldown_duration: Some(5),
+					cooldown_exempt: 0,
+					max_open_requests: Some(0),
+					daily_request_quota: None
+				})
+			});
+		mock_user_repository.expect_create_record().never();
+
+		let mock_ban_repository = MockBanRepository::new();
+		let request_manager = RequestManagerService {};
+		let service = DiscordUserService::new(
+			&mock_user_repository,
+			&mock_ban_repository,
+			&request_manager
+		);
+
+		let discord_user = service
+			.update_request_limit_overrides(8001, 12345, supporter_overrides())
+			.await
+			.unwrap();
+
+		assert_eq!(discord_user.last_request_time, Some(last_request_time));
+		assert_eq!(discord_user.request_limit_overrides, supporter_overrides());
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_update_request_limit_overrides_should_create_missing_user() {
+		let mut mock_user_repository = MockUserRepository::new();
+
+		mock_user_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_user_repository
+			.expect_create_record()
+			.withf(|record| {
+				record.timestamp == ActiveValue::Set(None)
+					&& record.cooldown_duration == ActiveValue::Set(Some(5))
+			})
+			.times(1)
+			.returning(|_| {
+				Ok(InsertResult {
+					last_insert_id: (8002.into(), 12345.into())
+				})
+			});
+
+		let mock_ban_repository = MockBanRepository::new();
+		let request_manager = RequestManagerService {};
+		let service = DiscordUserService::new(
+			&mock_user_repository,
+			&mock_ban_repository,
+			&request_manager
+		);
+
+		let discord_user = service
+			.update_request_limit_overrides(8002, 12345, supporter_overrides())
+			.await
+			.unwrap();
+
+		assert_eq!(discord_user.last_request_time, None);
+		assert_eq!(discord_user.request_limit_overrides, supporter_overrides());
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_update_request_limit_overrides_should_return_database_error() {
+		let mut mock_user_repository = MockUserRepository::new();
+
+		mock_user_repository
+			.expect_get_record()
+			.returning(|_, _| Err(DbErr::Custom("Database is unavailable".to_string())));
+
+		let mock_ban_repository = MockBanRepository::new();
+		let request_manager = RequestManagerService {};
+		let service = DiscordUserService::new(
+			&mock_user_repository,
+			&mock_ban_repository,
+			&request_manager
+		);
+
+		assert!(matches!(
+			service
+				.update_request_limit_overrides(8003, 12345, supporter_overrides())
+				.await,
+			Err(DiscordError::DatabaseError(_))
+		));
+	}
 }
@@ -1 +1,2 @@
+pub mod request_config_service;
 pub mod request_manager_service;
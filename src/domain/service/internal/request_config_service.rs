@@ -0,0 +1,224 @@
+use std::time::Duration;
+
+use rocket_framework::tokio::time::{interval, MissedTickBehavior};
+
+use crate::{
+	adapter::mysql::request_config_repository::RequestConfigRepository,
+	domain::{
+		model::{
+			error::request_manager_error::RequestManagerError,
+			request_config::{RequestConfig, RequestConfigUpdate}
+		},
+		service::internal::request_manager_service::RequestManagerService
+	},
+	rocket::common::config::client_config::CLIENT_CONFIG
+};
+
+pub struct RequestConfigService<'a, R: RequestConfigRepository> {
+	request_config_repository: &'a R,
+	request_manager: &'a RequestManagerService
+}
+
+impl<'a, R: RequestConfigRepository> RequestConfigService<'a, R> {
+	pub fn new(
+		request_config_repository: &'a R,
+		request_manager: &'a RequestManagerService
+	) -> Self {
+		RequestConfigService {
+			request_config_repository,
+			request_manager
+		}
+	}
+
+	/// Primes the in-memory cache used by `RequestManagerService` with every
+	/// persisted guild config. Guilds without a row fall back to
+	/// `ClientConfig` until they are first written.
+	pub async fn load_request_configs(&self) -> Result<(), RequestManagerError> {
+		match self.request_config_repository.get_records().await {
+			Ok(request_configs) => {
+				for request_config in request_configs {
+					self.request_manager
+						.set_request_config(RequestConfig::from(request_config));
+				}
+				Ok(())
+			}
+			Err(get_request_configs_error) => {
+				error!(
+					"Error getting request configs: {}",
+					get_request_configs_error
+				);
+				Err(RequestManagerError::DatabaseError(
+					get_request_configs_error
+				))
+			}
+		}
+	}
+
+	/// Reloads the cache every `reload_interval` so that changes made through
+	/// other instances of the app are picked up. Never returns.
+	pub async fn reload_request_configs(&self, reload_interval: Duration) {
+		let mut reload_interval = interval(reload_interval);
+		reload_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+		// The first tick completes right away, and the configs were just loaded
+		reload_interval.tick().await;
+		loop {
+			reload_interval.tick().await;
+			debug!("Reloading request configs");
+			// Errors are logged, the next tick tries again
+			let _ = self.load_request_configs().await;
+		}
+	}
+
+	pub fn get_request_config(&self, guild_id: u64) -> RequestConfig {
+		self.request_manager.get_request_config(guild_id)
+	}
+
+	pub async fn update_request_config(
+		&self,
+		guild_id: u64,
+		request_config_update: RequestConfigUpdate
+	) -> Result<RequestConfig, RequestManagerError> {
+		let cooldown_duration = match request_config_update
+			.cooldown_duration_in_minutes
+			.map(i32::try_from)
+			.transpose()
+		{
+			Ok(cooldown_duration) => cooldown_duration,
+			Err(_) => {
+				warn!(
+					"Cooldown duration {:?} for guild {} is out of range",
+					request_config_update.cooldown_duration_in_minutes, guild_id
+				);
+				return Err(RequestManagerError::MalformedRequest);
+			}
+		};
+		let existing_request_config =
+			match self.request_config_repository.get_record(guild_id).await {
+				Ok(existing_request_config) => existing_request_config,
+				Err(get_request_config_error) => {
+					error!(
+						"Error getting request config for guild {}: {}",
+						guild_id, get_request_config_error
+					);
+					return Err(RequestManagerError::DatabaseError(get_request_config_error));
+				}
+			};
+		let is_new = existing_request_config.is_none();
+		let mut request_config = match existing_request_config {
+			Some(existing_request_config) => RequestConfig::from(existing_request_config),
+			None => RequestConfig::from_client_config(guild_id, *CLIENT_CONFIG)
+		};
+
+		if let Some(cooldown_duration) = cooldown_duration {
+			request_config.cooldown_duration = chrono::Duration::minutes(cooldown_duration as i64);
+		}
+		if let Some(enable_requests) = request_config_update.enable_requests {
+			request_config.enable_requests = enable_requests;
+		}
+		if let Some(enable_gd_requests) = request_config_update.enable_gd_requests {
+			request_config.enable_gd_requests = enable_gd_requests;
+		}
+		if let Some(queue_policy) = request_config_update.queue_policy {
+			request_config.queue_policy = queue_policy;
+		}
+		if let Some(max_open_requests) = request_config_update.max_open_requests {
+			request_config.max_open_requests = max_open_requests;
+		}
+		if let Some(daily_request_quota) = request_config_update.daily_request_quota {
+			request_config.daily_request_quota = daily_request_quota;
+		}
+
+		let save_result = if is_new {
+			info!("Creating request config for guild {}", guild_id);
+			self.request_config_repository
+				.create_record(request_config.clone().into())
+				.await
+				.map(|_| request_config)
+		} else {
+			self.request_config_repository
+				.update_record(request_config.into())
+				.await
+				.map(RequestConfig::from)
+		};
+		match save_result {
+			Ok(request_config) => {
+				self.request_manager
+					.set_request_config(request_config.clone());
+				Ok(request_config)
+			}
+			Err(save_request_config_error) => {
+				error!(
+					"Error saving request config for guild {}: {}",
+					guild_id, save_request_config_error
+				);
+				Err(RequestManagerError::DatabaseError(
+					save_request_config_error
+				))
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use mockall::predicate::eq;
+	use sea_orm::InsertResult;
+
+	use crate::{
+		adapter::mysql::request_config_repository::MockRequestConfigRepository,
+		domain::{
+			model::request_config::{RequestConfig, RequestConfigUpdate},
+			service::internal::{
+				request_config_service::RequestConfigService,
+				request_manager_service::RequestManagerService
+			}
+		},
+		rocket::common::config::client_config::CLIENT_CONFIG
+	};
+
+	#[test]
+	fn test_get_request_config_should_not_touch_database() {
+		let mock_request_config_repository = MockRequestConfigRepository::new();
+		let request_manager = RequestManagerService {};
+		let service = RequestConfigService::new(&mock_request_config_repository, &request_manager);
+
+		assert_eq!(
+			service.get_request_config(1101),
+			RequestConfig::from_client_config(1101, *CLIENT_CONFIG)
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_update_request_config_should_create_missing_config() {
+		let mut mock_request_config_repository = MockRequestConfigRepository::new();
+		mock_request_config_repository
+			.expect_get_record()
+			.with(eq(1102))
+			.returning(|_| Ok(None));
+		mock_request_config_repository
+			.expect_create_record()
+			.withf(|record| record.enable_requests.clone().unwrap() == 0)
+			.times(1)
+			.returning(|_| {
+				Ok(InsertResult {
+					last_insert_id: 1102.into()
+				})
+			});
+		let request_manager = RequestManagerService {};
+		let service = RequestConfigService::new(&mock_request_config_repository, &request_manager);
+
+		let request_config = service
+			.update_request_config(
+				1102,
+				RequestConfigUpdate {
+					enable_requests: Some(false),
+					..RequestConfigUpdate::default()
+				}
+			)
+			.await
+			.unwrap();
+
+		assert!(!request_config.enable_requests);
+		assert_eq!(service.get_request_config(1102), request_config);
+	}
+}
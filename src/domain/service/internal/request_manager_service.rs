@@ -1,42 +1,108 @@
-use chrono::Duration;
-
-use crate::rocket::common::config::client_config::{
-	COOLDOWN_DURATION, ENABLE_GD_REQUESTS, ENABLE_REQUESTS
+use crate::{
+	domain::model::{level_queue::QueuePolicy, request_config::RequestConfig},
+	rocket::common::config::client_config::{CLIENT_CONFIG, REQUEST_CONFIGS}
 };
 
 pub struct RequestManagerService {}
 
 impl RequestManagerService {
-	pub fn set_request_cooldown(&self, duration_in_minutes: u64) {
-		let mut guard = COOLDOWN_DURATION.lock().unwrap();
-		*guard = Duration::minutes(duration_in_minutes as i64);
-		info!("Request cooldown set to {}", duration_in_minutes)
+	pub fn set_request_config(&self, request_config: RequestConfig) {
+		let mut guard = REQUEST_CONFIGS.lock().unwrap();
+		info!(
+			"Request config for guild {} set to {:?}",
+			request_config.guild_id, request_config
+		);
+		guard.insert(request_config.guild_id, request_config);
 	}
 
-	pub fn get_request_cooldown(&self) -> Duration {
-		let guard = COOLDOWN_DURATION.lock().unwrap();
-		*guard
+	pub fn get_request_config(&self, guild_id: u64) -> RequestConfig {
+		let guard = REQUEST_CONFIGS.lock().unwrap();
+		match guard.get(&guild_id) {
+			Some(request_config) => request_config.clone(),
+			None => RequestConfig::from_client_config(guild_id, *CLIENT_CONFIG)
+		}
 	}
 
-	pub fn set_enable_request(&self, enable_requests: bool) {
-		let mut guard = ENABLE_REQUESTS.lock().unwrap();
-		*guard = enable_requests;
-		info!("Enable requests toggled to {}", enable_requests)
+	pub fn get_enable_request(&self, guild_id: u64) -> bool {
+		self.get_request_config(guild_id).enable_requests
 	}
 
-	pub fn get_enable_request(&self) -> bool {
-		let guard = ENABLE_REQUESTS.lock().unwrap();
-		*guard
+	pub fn get_enable_gd_request(&self, guild_id: u64) -> bool {
+		self.get_request_config(guild_id).enable_gd_requests
 	}
 
-	pub fn set_enable_gd_request(&self, enable_gd_requests: bool) {
-		let mut guard = ENABLE_GD_REQUESTS.lock().unwrap();
-		*guard = enable_gd_requests;
-		info!("Enable GD requests toggled to {}", enable_gd_requests)
+	pub fn get_queue_policy(&self, guild_id: u64) -> QueuePolicy {
+		self.get_request_config(guild_id).queue_policy
 	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::Duration;
+
+	use crate::{
+		domain::{
+			model::{level_queue::QueuePolicy, request_config::RequestConfig},
+			service::internal::request_manager_service::RequestManagerService
+		},
+		rocket::common::config::client_config::CLIENT_CONFIG
+	};
+
+	#[test]
+	fn test_get_request_config_should_fall_back_to_client_config() {
+		let request_manager = RequestManagerService {};
+
+		assert_eq!(
+			request_manager.get_request_config(1001),
+			RequestConfig::from_client_config(1001, *CLIENT_CONFIG)
+		);
+	}
+
+	#[test]
+	fn test_set_request_config_should_only_apply_to_its_guild() {
+		let request_manager = RequestManagerService {};
+		request_manager.set_request_config(RequestConfig {
+			guild_id: 1002,
+			cooldown_duration: Duration::minutes(30),
+			enable_requests: false,
+			enable_gd_requests: true,
+			queue_policy: QueuePolicy::RoundRobin,
+			max_open_requests: 0,
+			daily_request_quota: 0
+		});
+
+		assert_eq!(
+			request_manager.get_request_config(1002).cooldown_duration,
+			Duration::minutes(30)
+		);
+		assert!(!request_manager.get_enable_request(1002));
+		assert!(request_manager.get_enable_gd_request(1002));
+		assert_eq!(
+			request_manager.get_queue_policy(1002),
+			QueuePolicy::RoundRobin
+		);
+		assert_eq!(
+			request_manager.get_enable_request(1003),
+			CLIENT_CONFIG.enable_requests
+		);
+	}
+
+	#[test]
+	fn test_set_request_config_should_replace_previous_config() {
+		let request_manager = RequestManagerService {};
+		let mut request_config = RequestConfig {
+			guild_id: 1004,
+			cooldown_duration: Duration::minutes(5),
+			enable_requests: true,
+			enable_gd_requests: false,
+			queue_policy: QueuePolicy::Fifo,
+			max_open_requests: 0,
+			daily_request_quota: 0
+		};
+		request_manager.set_request_config(request_config.clone());
+		request_config.cooldown_duration = Duration::minutes(10);
+		request_manager.set_request_config(request_config.clone());
 
-	pub fn get_enable_gd_request(&self) -> bool {
-		let guard = ENABLE_GD_REQUESTS.lock().unwrap();
-		*guard
+		assert_eq!(request_manager.get_request_config(1004), request_config);
 	}
 }
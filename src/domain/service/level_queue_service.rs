@@ -0,0 +1,724 @@
+use std::{collections::HashMap, time::Duration};
+
+use chrono::{DateTime, Utc};
+use rocket_framework::tokio::time::{interval, MissedTickBehavior};
+use sea_orm::ActiveValue;
+
+use crate::{
+	adapter::mysql::{
+		level_request_repository::LevelRequestRepository, model::level_request::ActiveModel
+	},
+	domain::{
+		model::{
+			error::level_queue_error::LevelQueueError,
+			gd_level::{GDLevelRequest, RequestStatus},
+			level_queue::QueuedLevelRequest
+		},
+		service::{
+			internal::request_manager_service::RequestManagerService, queue_service::QueueService
+		}
+	}
+};
+
+pub struct LevelQueueService<'a, L: LevelRequestRepository> {
+	level_request_repository: &'a L,
+	request_manager: &'a RequestManagerService
+}
+
+impl<'a, L: LevelRequestRepository> QueueService for LevelQueueService<'a, L> {
+	async fn get_next_level_request(
+		&self,
+		guild_id: u64
+	) -> Result<QueuedLevelRequest, LevelQueueError> {
+		match self.get_queue(guild_id).await?.into_iter().next() {
+			Some(level_request) => Ok(QueuedLevelRequest {
+				level_request,
+				position: 1
+			}),
+			None => {
+				debug!("Level queue for guild {} is empty", guild_id);
+				Err(LevelQueueError::QueueEmpty)
+			}
+		}
+	}
+
+	async fn get_queue_positions(
+		&self,
+		guild_id: u64
+	) -> Result<HashMap<u64, u64>, LevelQueueError> {
+		Ok(self
+			.get_queue(guild_id)
+			.await?
+			.into_iter()
+			.zip(1..)
+			.map(|(level_request, position)| (level_request.level_id, position))
+			.collect())
+	}
+
+	async fn skip_level_request(
+		&self,
+		guild_id: u64,
+		level_id: u64
+	) -> Result<QueuedLevelRequest, LevelQueueError> {
+		self.set_skipped_at(guild_id, level_id, Some(Utc::now()))
+			.await?;
+		info!("Level request {} skipped", level_id);
+		self.get_queued_level_request(guild_id, level_id).await
+	}
+
+	async fn requeue_level_request(
+		&self,
+		guild_id: u64,
+		level_id: u64
+	) -> Result<QueuedLevelRequest, LevelQueueError> {
+		self.set_skipped_at(guild_id, level_id, None).await?;
+		info!("Level request {} requeued", level_id);
+		self.get_queued_level_request(guild_id, level_id).await
+	}
+}
+
+impl<'a, L: LevelRequestRepository> LevelQueueService<'a, L> {
+	pub fn new(
+		level_request_repository: &'a L,
+		request_manager: &'a RequestManagerService
+	) -> Self {
+		LevelQueueService {
+			level_request_repository,
+			request_manager
+		}
+	}
+
+	/// Returns levels whose review assignment ended without a review to the
+	/// queue every `requeue_interval`. Never returns.
+	pub async fn requeue_unassigned_level_requests(&self, requeue_interval: Duration) {
+		let mut requeue_interval = interval(requeue_interval);
+		requeue_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+		loop {
+			requeue_interval.tick().await;
+			match self
+				.level_request_repository
+				.requeue_unassigned_records(Utc::now())
+				.await
+			{
+				Ok(0) => {}
+				Ok(requeued_count) => {
+					info!("Returned {} level requests to the queue", requeued_count);
+				}
+				// The next tick tries again
+				Err(db_err) => {
+					error!(
+						"Error returning unassigned level requests to the queue: {}",
+						db_err
+					);
+				}
+			}
+		}
+	}
+
+	/// Pending level requests ordered by the guild's queue policy.
+	async fn get_queue(&self, guild_id: u64) -> Result<Vec<GDLevelRequest>, LevelQueueError> {
+		match self
+			.level_request_repository
+			.get_queued_records(guild_id)
+			.await
+		{
+			Ok(level_requests) => Ok(self.request_manager.get_queue_policy(guild_id).order(
+				level_requests
+					.into_iter()
+					.map(GDLevelRequest::from)
+					.collect()
+			)),
+			Err(db_err) => {
+				error!(
+					"Error getting level queue for guild {} from database: {}",
+					guild_id, db_err
+				);
+				Err(LevelQueueError::DatabaseError(db_err))
+			}
+		}
+	}
+
+	async fn get_queued_level_request(
+		&self,
+		guild_id: u64,
+		level_id: u64
+	) -> Result<QueuedLevelRequest, LevelQueueError> {
+		self.get_queue(guild_id)
+			.await?
+			.into_iter()
+			.zip(1..)
+			.find(|(level_request, _)| level_request.level_id == level_id)
+			.map(|(level_request, position)| QueuedLevelRequest {
+				level_request,
+				position
+			})
+			.ok_or(LevelQueueError::LevelRequestNotQueued(level_id))
+	}
+
+	async fn set_skipped_at(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		skipped_at: Option<DateTime<Utc>>
+	) -> Result<(), LevelQueueError> {
+		let level_request = match self
+			.level_request_repository
+			.get_record(guild_id, level_id)
+			.await
+		{
+			Ok(Some(level_request)) => GDLevelRequest::from(level_request),
+			Ok(None) => {
+				warn!("Level request with ID {} does not exist", level_id);
+				return Err(LevelQueueError::LevelRequestDoesNotExist);
+			}
+			Err(db_err) => {
+				error!(
+					"Error getting level request {} from database: {}",
+					level_id, db_err
+				);
+				return Err(LevelQueueError::DatabaseError(db_err));
+			}
+		};
+		if level_request.status != RequestStatus::Pending {
+			warn!(
+				"Level request {} is {:?} and no longer in the queue",
+				level_id, level_request.status
+			);
+			return Err(LevelQueueError::LevelRequestNotQueued(level_id));
+		}
+		if skipped_at.is_none() && level_request.skipped_at.is_none() {
+			warn!("Level request {} has not been skipped", level_id);
+			return Err(LevelQueueError::LevelRequestNotSkipped(level_id));
+		}
+
+		let mut update_level_request_storable: ActiveModel = level_request.into();
+		update_level_request_storable.skipped_at = ActiveValue::Set(skipped_at);
+		if let Err(db_err) = self
+			.level_request_repository
+			.update_record(update_level_request_storable)
+			.await
+		{
+			error!(
+				"Unable to update queue state of level request {}: {}",
+				level_id, db_err
+			);
+			return Err(LevelQueueError::DatabaseError(db_err));
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::{Duration, Utc};
+	use sea_orm::DbErr;
+
+	use crate::{
+		adapter::mysql::{
+			level_request_repository::MockLevelRequestRepository,
+			model::{level_request, sea_orm_active_enums}
+		},
+		domain::{
+			model::{
+				error::level_queue_error::LevelQueueError, gd_level::RequestStatus,
+				level_queue::QueuePolicy, request_config::RequestConfig
+			},
+			service::{
+				internal::request_manager_service::RequestManagerService,
+				level_queue_service::LevelQueueService, queue_service::QueueService
+			}
+		},
+		rocket::common::{config::client_config::CLIENT_CONFIG, testing::level_request_model}
+	};
+
+	fn queued_level_request_model(
+		guild_id: u64,
+		level_id: u64,
+		discord_id: u64,
+		request_rating: sea_orm_active_enums::RequestRating,
+		has_requested_feedback: bool,
+		minutes_ago: i64
+	) -> level_request::Model {
+		let mut level_request =
+			level_request_model(guild_id, level_id, discord_id, RequestStatus::Pending);
+		level_request.request_rating = request_rating;
+		level_request.has_requested_feedback = i8::from(has_requested_feedback);
+		level_request.timestamp = Utc::now() - Duration::minutes(minutes_ago);
+		level_request
+	}
+
+	fn set_queue_policy(
+		request_manager: &RequestManagerService,
+		guild_id: u64,
+		queue_policy: QueuePolicy
+	) {
+		let mut request_config = RequestConfig::from_client_config(guild_id, *CLIENT_CONFIG);
+		request_config.queue_policy = queue_policy;
+		request_manager.set_request_config(request_config);
+	}
+
+	async fn queue_order(
+		guild_id: u64,
+		queue_policy: QueuePolicy,
+		level_requests: Vec<level_request::Model>
+	) -> Vec<u64> {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		mock_level_request_repository
+			.expect_get_queued_records()
+			.returning(move |_| Ok(level_requests.clone()));
+
+		let request_manager = RequestManagerService {};
+		set_queue_policy(&request_manager, guild_id, queue_policy);
+		let service = LevelQueueService::new(&mock_level_request_repository, &request_manager);
+
+		let mut queue_positions: Vec<(u64, u64)> = service
+			.get_queue_positions(guild_id)
+			.await
+			.unwrap()
+			.into_iter()
+			.collect();
+		queue_positions.sort_by_key(|(_, position)| *position);
+		queue_positions
+			.into_iter()
+			.map(|(level_id, _)| level_id)
+			.collect()
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_queue_positions_should_order_by_request_time_for_fifo() {
+		let level_requests = vec![
+			queued_level_request_model(
+				7001,
+				1,
+				10,
+				sea_orm_active_enums::RequestRating::Two,
+				false,
+				5
+			),
+			queued_level_request_model(
+				7001,
+				2,
+				10,
+				sea_orm_active_enums::RequestRating::Two,
+				false,
+				15
+			),
+			queued_level_request_model(
+				7001,
+				3,
+				20,
+				sea_orm_active_enums::RequestRating::Two,
+				false,
+				10
+			),
+		];
+
+		assert_eq!(
+			queue_order(7001, QueuePolicy::Fifo, level_requests).await,
+			vec![2, 3, 1]
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_queue_positions_should_order_higher_ratings_first() {
+		let level_requests = vec![
+			queued_level_request_model(
+				7002,
+				1,
+				10,
+				sea_orm_active_enums::RequestRating::Two,
+				false,
+				30
+			),
+			queued_level_request_model(
+				7002,
+				2,
+				20,
+				sea_orm_active_enums::RequestRating::Ten,
+				false,
+				10
+			),
+			queued_level_request_model(
+				7002,
+				3,
+				30,
+				sea_orm_active_enums::RequestRating::Ten,
+				false,
+				20
+			),
+			queued_level_request_model(
+				7002,
+				4,
+				40,
+				sea_orm_active_enums::RequestRating::Five,
+				false,
+				40
+			),
+		];
+
+		assert_eq!(
+			queue_order(7002, QueuePolicy::RequestRating, level_requests).await,
+			vec![3, 2, 4, 1]
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_queue_positions_should_order_feedback_requests_first() {
+		let level_requests = vec![
+			queued_level_request_model(
+				7003,
+				1,
+				10,
+				sea_orm_active_enums::RequestRating::Two,
+				false,
+				30
+			),
+			queued_level_request_model(
+				7003,
+				2,
+				20,
+				sea_orm_active_enums::RequestRating::Two,
+				true,
+				10
+			),
+			queued_level_request_model(
+				7003,
+				3,
+				30,
+				sea_orm_active_enums::RequestRating::Two,
+				true,
+				20
+			),
+		];
+
+		assert_eq!(
+			queue_order(7003, QueuePolicy::FeedbackFirst, level_requests).await,
+			vec![3, 2, 1]
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_queue_positions_should_alternate_requesters_for_round_robin() {
+		let level_requests = vec![
+			queued_level_request_model(
+				7004,
+				1,
+				10,
+				sea_orm_active_enums::RequestRating::Two,
+				false,
+				50
+			),
+			queued_level_request_model(
+				7004,
+				2,
+				10,
+				sea_orm_active_enums::RequestRating::Two,
+				false,
+				40
+			),
+			queued_level_request_model(
+				7004,
+				3,
+				10,
+				sea_orm_active_enums::RequestRating::Two,
+				false,
+				30
+			),
+			queued_level_request_model(
+				7004,
+				4,
+				20,
+				sea_orm_active_enums::RequestRating::Two,
+				false,
+				20
+			),
+			queued_level_request_model(
+				7004,
+				5,
+				30,
+				sea_orm_active_enums::RequestRating::Two,
+				false,
+				10
+			),
+		];
+
+		assert_eq!(
+			queue_order(7004, QueuePolicy::RoundRobin, level_requests).await,
+			vec![1, 4, 5, 2, 3]
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_queue_positions_should_put_skipped_requests_last() {
+		let mut first_skipped_level_request = queued_level_request_model(
+			7005,
+			1,
+			10,
+			sea_orm_active_enums::RequestRating::Ten,
+			true,
+			50
+		);
+		first_skipped_level_request.skipped_at = Some(Utc::now() - Duration::minutes(5));
+		let mut second_skipped_level_request = queued_level_request_model(
+			7005,
+			2,
+			20,
+			sea_orm_active_enums::RequestRating::Ten,
+			true,
+			40
+		);
+		second_skipped_level_request.skipped_at = Some(Utc::now() - Duration::minutes(10));
+		let level_requests = vec![
+			first_skipped_level_request,
+			second_skipped_level_request,
+			queued_level_request_model(
+				7005,
+				3,
+				30,
+				sea_orm_active_enums::RequestRating::One,
+				false,
+				10
+			),
+		];
+
+		assert_eq!(
+			queue_order(7005, QueuePolicy::RequestRating, level_requests).await,
+			vec![3, 2, 1]
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_next_level_request_should_return_first_in_queue() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		mock_level_request_repository
+			.expect_get_queued_records()
+			.returning(|guild_id| {
+				Ok(vec![
+					queued_level_request_model(
+						guild_id,
+						1,
+						10,
+						sea_orm_active_enums::RequestRating::Two,
+						false,
+						10
+					),
+					queued_level_request_model(
+						guild_id,
+						2,
+						20,
+						sea_orm_active_enums::RequestRating::Two,
+						false,
+						20
+					),
+				])
+			});
+
+		let request_manager = RequestManagerService {};
+		let service = LevelQueueService::new(&mock_level_request_repository, &request_manager);
+
+		let queued_level_request = service.get_next_level_request(7006).await.unwrap();
+
+		assert_eq!(queued_level_request.level_request.level_id, 2);
+		assert_eq!(queued_level_request.position, 1);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_next_level_request_should_return_error_when_queue_is_empty() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		mock_level_request_repository
+			.expect_get_queued_records()
+			.returning(|_| Ok(vec![]));
+
+		let request_manager = RequestManagerService {};
+		let service = LevelQueueService::new(&mock_level_request_repository, &request_manager);
+
+		assert_eq!(
+			service.get_next_level_request(7007).await.unwrap_err(),
+			LevelQueueError::QueueEmpty
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_next_level_request_should_return_error_when_database_fails() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		mock_level_request_repository
+			.expect_get_queued_records()
+			.returning(|_| Err(DbErr::Custom(String::from("database unavailable"))));
+
+		let request_manager = RequestManagerService {};
+		let service = LevelQueueService::new(&mock_level_request_repository, &request_manager);
+
+		assert!(matches!(
+			service.get_next_level_request(7008).await,
+			Err(LevelQueueError::DatabaseError(_))
+		));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_skip_level_request_should_move_request_to_back_of_queue() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id| {
+				Ok(Some(level_request_model(
+					guild_id,
+					level_id,
+					10,
+					RequestStatus::Pending
+				)))
+			});
+		mock_level_request_repository
+			.expect_update_record()
+			.withf(|level_request| level_request.skipped_at.clone().unwrap().is_some())
+			.times(1)
+			.returning(|level_request| {
+				Ok(level_request::Model {
+					skipped_at: level_request.skipped_at.unwrap(),
+					..level_request_model(7009, 1, 10, RequestStatus::Pending)
+				})
+			});
+		mock_level_request_repository
+			.expect_get_queued_records()
+			.returning(|guild_id| {
+				let mut skipped_level_request = queued_level_request_model(
+					guild_id,
+					1,
+					10,
+					sea_orm_active_enums::RequestRating::Two,
+					false,
+					20
+				);
+				skipped_level_request.skipped_at = Some(Utc::now());
+				Ok(vec![
+					skipped_level_request,
+					queued_level_request_model(
+						guild_id,
+						2,
+						20,
+						sea_orm_active_enums::RequestRating::Two,
+						false,
+						10
+					),
+				])
+			});
+
+		let request_manager = RequestManagerService {};
+		let service = LevelQueueService::new(&mock_level_request_repository, &request_manager);
+
+		let queued_level_request = service.skip_level_request(7009, 1).await.unwrap();
+
+		assert_eq!(queued_level_request.level_request.level_id, 1);
+		assert_eq!(queued_level_request.position, 2);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_skip_level_request_should_return_error_when_request_is_not_pending() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id| {
+				Ok(Some(level_request_model(
+					guild_id,
+					level_id,
+					10,
+					RequestStatus::InReview
+				)))
+			});
+		mock_level_request_repository.expect_update_record().never();
+
+		let request_manager = RequestManagerService {};
+		let service = LevelQueueService::new(&mock_level_request_repository, &request_manager);
+
+		assert_eq!(
+			service.skip_level_request(7010, 1).await.unwrap_err(),
+			LevelQueueError::LevelRequestNotQueued(1)
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_skip_level_request_should_return_error_when_request_does_not_exist() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+
+		let request_manager = RequestManagerService {};
+		let service = LevelQueueService::new(&mock_level_request_repository, &request_manager);
+
+		assert_eq!(
+			service.skip_level_request(7011, 1).await.unwrap_err(),
+			LevelQueueError::LevelRequestDoesNotExist
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_requeue_level_request_should_clear_skip() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id| {
+				let mut level_request =
+					level_request_model(guild_id, level_id, 10, RequestStatus::Pending);
+				level_request.skipped_at = Some(Utc::now());
+				Ok(Some(level_request))
+			});
+		mock_level_request_repository
+			.expect_update_record()
+			.withf(|level_request| level_request.skipped_at.clone().unwrap().is_none())
+			.times(1)
+			.returning(|_| Ok(level_request_model(7012, 1, 10, RequestStatus::Pending)));
+		mock_level_request_repository
+			.expect_get_queued_records()
+			.returning(|guild_id| {
+				Ok(vec![
+					queued_level_request_model(
+						guild_id,
+						1,
+						10,
+						sea_orm_active_enums::RequestRating::Two,
+						false,
+						20
+					),
+					queued_level_request_model(
+						guild_id,
+						2,
+						20,
+						sea_orm_active_enums::RequestRating::Two,
+						false,
+						10
+					),
+				])
+			});
+
+		let request_manager = RequestManagerService {};
+		let service = LevelQueueService::new(&mock_level_request_repository, &request_manager);
+
+		let queued_level_request = service.requeue_level_request(7012, 1).await.unwrap();
+
+		assert_eq!(queued_level_request.position, 1);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_requeue_level_request_should_return_error_when_request_was_not_skipped() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id| {
+				Ok(Some(level_request_model(
+					guild_id,
+					level_id,
+					10,
+					RequestStatus::Pending
+				)))
+			});
+		mock_level_request_repository.expect_update_record().never();
+
+		let request_manager = RequestManagerService {};
+		let service = LevelQueueService::new(&mock_level_request_repository, &request_manager);
+
+		assert_eq!(
+			service.requeue_level_request(7013, 1).await.unwrap_err(),
+			LevelQueueError::LevelRequestNotSkipped(1)
+		);
+	}
+}
@@ -1,56 +1,74 @@
-use chrono::{DateTime, Utc};
-use sea_orm::{ActiveValue, IntoActiveModel};
+use chrono::{DateTime, Duration, Utc};
+use sea_orm::{ActiveValue, ColumnTrait, Condition, IntoActiveModel};
 
 use crate::{
 	adapter::{
 		geometry_dash::geometry_dash_client::GeometryDashClient,
 		mysql::{
+			ban_repository::BanRepository,
 			level_request_repository::LevelRequestRepository,
-			model::{level_request::ActiveModel, user::Model},
+			model::{level_request, level_request::ActiveModel, sea_orm_active_enums},
 			user_repository::UserRepository
 		}
 	},
 	domain::{
 		model::{
+			ban::{Ban, BanTarget},
 			discord::user::DiscordUser,
-			error::level_request_error::LevelRequestError,
-			gd_level::{GDLevelRequest, RequestRating}
+			error::{
+				geometry_dash::geometry_dash_dashrs_error::GeometryDashDashrsError,
+				level_request_error::LevelRequestError
+			},
+			gd_level::{
+				GDLevel, GDLevelRequest, LevelRequestSubmission, LevelRequestUpdate, RequestStatus
+			},
+			level_request_query::{LevelRequestFilter, LevelRequestPage, SortOrder},
+			request_limits::{RequestLimitOverrides, RequestLimits, RequestQuota}
 		},
 		service::{
 			internal::request_manager_service::RequestManagerService,
 			request_service::RequestService
 		}
 	},
-	rocket::common::{config::client_config::CLIENT_CONFIG, constants::YOUTUBE_LINK_REGEX}
+	rocket::common::{
+		config::level_eligibility_config::LevelEligibilityConfig,
+		constants::{MAX_PAGE_SIZE, YOUTUBE_LINK_REGEX}
+	}
 };
 
 pub struct LevelRequestService<
 	'a,
 	L: LevelRequestRepository,
 	U: UserRepository,
-	G: GeometryDashClient
+	G: GeometryDashClient,
+	B: BanRepository
 > {
 	level_request_repository: &'a L,
 	user_repository: &'a U,
 	gd_client: &'a G,
-	request_manager: &'a RequestManagerService
+	ban_repository: &'a B,
+	request_manager: &'a RequestManagerService,
+	level_eligibility_config: &'a LevelEligibilityConfig
 }
 
-impl<'a, R: LevelRequestRepository, U: UserRepository, G: GeometryDashClient> RequestService
-	for LevelRequestService<'a, R, U, G>
+impl<'a, R: LevelRequestRepository, U: UserRepository, G: GeometryDashClient, B: BanRepository>
+	RequestService for LevelRequestService<'a, R, U, G, B>
 {
 	async fn get_level_request(
 		&self,
+		guild_id: u64,
 		level_id: u64,
 		has_requested_feedback: Option<bool>
 	) -> Result<GDLevelRequest, LevelRequestError> {
 		let get_level_request_result =
 			if let Some(has_requested_feedback_toggle) = has_requested_feedback {
 				self.level_request_repository
-					.get_record_filter_feedback(level_id, has_requested_feedback_toggle)
+					.get_record_filter_feedback(guild_id, level_id, has_requested_feedback_toggle)
 					.await
 			} else {
-				self.level_request_repository.get_record(level_id).await
+				self.level_request_repository
+					.get_record(guild_id, level_id)
+					.await
 			};
 
 		match get_level_request_result {
@@ -69,16 +87,78 @@ impl<'a, R: LevelRequestRepository, U: UserRepository, G: GeometryDashClient> Re
 		}
 	}
 
+	async fn get_level_requests(
+		&self,
+		guild_id: u64,
+		filter: LevelRequestFilter,
+		sort_order: SortOrder,
+		page: u64,
+		page_size: u64
+	) -> Result<LevelRequestPage, LevelRequestError> {
+		if page == 0 || page_size == 0 || page_size > MAX_PAGE_SIZE {
+			warn!("Invalid page {} with page size {}", page, page_size);
+			return Err(LevelRequestError::MalformedRequest);
+		}
+		let offset = match (page - 1).checked_mul(page_size) {
+			Some(offset) => offset,
+			None => {
+				warn!("Page {} with page size {} is out of range", page, page_size);
+				return Err(LevelRequestError::MalformedRequest);
+			}
+		};
+		if !filter.is_valid_timestamp_range() {
+			warn!("Invalid timestamp range: {:?}", filter);
+			return Err(LevelRequestError::MalformedRequest);
+		}
+
+		let condition: Condition = filter.into();
+		let total_count = self
+			.level_request_repository
+			.count_records(guild_id, condition.clone())
+			.await
+			.map_err(|db_err| {
+				error!(
+					"Error counting level request records from database: {}",
+					db_err
+				);
+				LevelRequestError::DatabaseError(db_err)
+			})?;
+
+		self.level_request_repository
+			.get_records(guild_id, condition, sort_order.into(), offset, page_size)
+			.await
+			.map(|level_requests| LevelRequestPage {
+				level_requests: level_requests
+					.into_iter()
+					.map(GDLevelRequest::from)
+					.collect(),
+				page,
+				page_size,
+				total_count
+			})
+			.map_err(|db_err| {
+				error!(
+					"Error getting level request records from database: {}",
+					db_err
+				);
+				LevelRequestError::DatabaseError(db_err)
+			})
+	}
+
 	async fn make_level_request(
 		&self,
+		guild_id: u64,
 		level_id: u64,
-		youtube_video_link: String,
 		discord_user_id: u64,
-		request_rating: RequestRating,
-		has_requested_feedback: bool,
-		notify: bool
+		level_request_submission: LevelRequestSubmission
 	) -> Result<GDLevelRequest, LevelRequestError> {
-		if !self.request_manager.get_enable_request() {
+		let LevelRequestSubmission {
+			youtube_video_link,
+			request_rating,
+			has_requested_feedback,
+			notify
+		} = level_request_submission;
+		if !self.request_manager.get_enable_request(guild_id) {
 			return Err(LevelRequestError::LevelRequestsDisabled);
 		}
 		if !Self::is_valid_youtube_link(&youtube_video_link) {
@@ -87,60 +167,81 @@ impl<'a, R: LevelRequestRepository, U: UserRepository, G: GeometryDashClient> Re
 		}
 		let now = Utc::now();
 
-		if let Ok(_existing_level_request) = self.get_level_request(level_id, None).await {
+		for ban_target in [BanTarget::User(discord_user_id), BanTarget::Level(level_id)] {
+			if let Some(ban) = self.get_active_ban(guild_id, ban_target, &now).await? {
+				warn!(
+					"{:?} is banned from requests by ban {}",
+					ban_target, ban.ban_id
+				);
+				return Err(LevelRequestError::Banned(ban));
+			}
+		}
+
+		if let Ok(_existing_level_request) = self.get_level_request(guild_id, level_id, None).await
+		{
 			warn!("Level requests with ID: {} already exists", level_id);
 			return Err(LevelRequestError::LevelRequestExists);
 		}
 
-		let gd_level_request: GDLevelRequest;
-		if self.request_manager.get_enable_gd_request() {
-			let gd_level = self
-				.gd_client
-				.get_gd_level_info(level_id)
-				.await
-				.map_err(|err| {
-					error!("Error getting level info for level {}", level_id);
-					LevelRequestError::GeometryDashClientError(level_id, err)
-				})?;
-
-			gd_level_request = GDLevelRequest {
-				gd_level: Some(gd_level),
-				level_id,
-				discord_user_id,
-				discord_message_data: None,
-				request_rating,
-				youtube_video_link,
-				has_requested_feedback,
-				notify,
-				timestamp: now
-			};
-		} else {
-			gd_level_request = GDLevelRequest {
-				gd_level: None,
-				level_id,
-				discord_user_id,
-				discord_message_data: None,
-				request_rating,
-				youtube_video_link,
-				has_requested_feedback,
-				notify,
-				timestamp: now
-			};
+		let gd_level = self.get_gd_level(guild_id, level_id).await?;
+		if let Err(reason) = self.level_eligibility_config.check_level(gd_level.as_ref()) {
+			warn!(
+				"Level {} is not eligible for requests: {}",
+				level_id, reason
+			);
+			return Err(LevelRequestError::IneligibleLevel(level_id, reason));
 		}
 
-		match self.user_repository.get_record(discord_user_id).await {
-			Ok(Some(user)) => {
-				if self.is_user_on_cooldown(&user, &now) {
-					warn!(
-						"User {} attempted to request while on cooldown",
-						discord_user_id
-					);
-					return Err(LevelRequestError::UserOnCooldown(
-						user.timestamp.unwrap(),
-						self.request_manager.get_request_cooldown()
-					));
-				}
+		let gd_level_request = GDLevelRequest {
+			guild_id,
+			gd_level,
+			level_id,
+			discord_user_id,
+			discord_message_data: None,
+			request_rating,
+			youtube_video_link,
+			has_requested_feedback,
+			notify,
+			status: RequestStatus::Pending,
+			timestamp: now,
+			skipped_at: None
+		};
 
+		let user = match self
+			.user_repository
+			.get_record(guild_id, discord_user_id)
+			.await
+		{
+			Ok(user) => user,
+			Err(err) => {
+				error!(
+					"Error getting Discord user: {} record from database: {}",
+					discord_user_id, err
+				);
+				return Err(LevelRequestError::DatabaseError(err));
+			}
+		};
+
+		let request_limit_overrides = if let Some(user) = &user {
+			DiscordUser::from(user.clone()).request_limit_overrides
+		} else {
+			RequestLimitOverrides::default()
+		};
+		let request_limits = RequestLimits::new(
+			&self.request_manager.get_request_config(guild_id),
+			&request_limit_overrides
+		);
+		self.check_request_limits(
+			guild_id,
+			discord_user_id,
+			user.as_ref().and_then(|user| user.timestamp),
+			&request_limits,
+			&now
+		)
+		.await?;
+
+		match user {
+			Some(user) => {
 				let mut update_discord_user_last_request_time_storable = user.into_active_model();
 				update_discord_user_last_request_time_storable.timestamp =
 					ActiveValue::Set(Some(now));
@@ -156,10 +257,12 @@ impl<'a, R: LevelRequestRepository, U: UserRepository, G: GeometryDashClient> Re
 					return Err(LevelRequestError::DatabaseError(db_err));
 				}
 			}
-			Ok(None) => {
+			None => {
 				let user_storable = DiscordUser {
+					guild_id,
 					discord_user_id,
-					last_request_time: Some(now)
+					last_request_time: Some(now),
+					request_limit_overrides
 				}
 					.into();
 
@@ -173,21 +276,29 @@ impl<'a, R: LevelRequestRepository, U: UserRepository, G: GeometryDashClient> Re
 					return Err(LevelRequestError::DatabaseError(user_insert_error));
 				}
 			}
-			Err(err) => {
-				error!(
-					"Error getting Discord user: {} record from database: {}",
-					discord_user_id, err
-				);
-				return Err(LevelRequestError::DatabaseError(err));
-			}
 		};
 
 		let level_request_storable = gd_level_request.clone().into();
-		if let Err(level_insert_error) = self
+		let save_level_request_result = match self
 			.level_request_repository
-			.create_record(level_request_storable)
+			.get_record_ignore_archived(guild_id, level_id)
 			.await
 		{
+			Ok(Some(_archived_level_request)) => {
+				info!("Archiving the previous level request for {}", level_id);
+				self.level_request_repository
+					.replace_archived_record(level_request_storable)
+					.await
+					.map(|_| ())
+			}
+			Ok(None) => self
+				.level_request_repository
+				.create_record(level_request_storable)
+				.await
+				.map(|_| ()),
+			Err(db_err) => Err(db_err)
+		};
+		if let Err(level_insert_error) = save_level_request_result {
 			error!(
 				"Unable to save level request for {} to database: {}",
 				level_id, level_insert_error
@@ -200,13 +311,18 @@ impl<'a, R: LevelRequestRepository, U: UserRepository, G: GeometryDashClient> Re
 
 	async fn update_level_request(
 		&self,
+		guild_id: u64,
 		level_id: u64,
 		discord_user_id: u64,
-		youtube_video_link: Option<String>,
-		request_rating: Option<RequestRating>,
-		has_requested_feedback: Option<bool>,
-		notify: Option<bool>
+		is_admin: bool,
+		level_request_update: LevelRequestUpdate
 	) -> Result<GDLevelRequest, LevelRequestError> {
+		let LevelRequestUpdate {
+			youtube_video_link,
+			request_rating,
+			has_requested_feedback,
+			notify
+		} = level_request_update;
 		if youtube_video_link.is_none()
 			&& request_rating.is_none()
 			&& has_requested_feedback.is_none()
@@ -222,14 +338,12 @@ impl<'a, R: LevelRequestRepository, U: UserRepository, G: GeometryDashClient> Re
 			return Err(LevelRequestError::MalformedRequest);
 		}
 
-		match self.get_level_request(level_id, None).await {
+		match self.get_level_request(guild_id, level_id, None).await {
 			Err(get_existing_level_request_error) => {
 				return Err(get_existing_level_request_error);
 			}
-			Ok(existing_level_request) => {
-				if !discord_user_id.eq(&CLIENT_CONFIG.discord_bot_admin_id)
-					&& !discord_user_id.eq(&existing_level_request.discord_user_id)
-				{
+			Ok(mut existing_level_request) => {
+				if !is_admin && !discord_user_id.eq(&existing_level_request.discord_user_id) {
 					return Err(LevelRequestError::EditUnownedLevelRequest(
 						existing_level_request.level_id,
 						existing_level_request.discord_user_id,
@@ -237,6 +351,10 @@ impl<'a, R: LevelRequestRepository, U: UserRepository, G: GeometryDashClient> Re
 					));
 				}
 
+				if let Some(gd_level) = self.get_gd_level(guild_id, level_id).await? {
+					existing_level_request.gd_level = Some(gd_level);
+				}
+
 				let mut update_level_request_storable: ActiveModel = existing_level_request.into();
 
 				if youtube_video_link.is_some() {
@@ -256,23 +374,6 @@ impl<'a, R: LevelRequestRepository, U: UserRepository, G: GeometryDashClient> Re
 						ActiveValue::Set(i8::from(notify.unwrap()));
 				}
 
-				if self.request_manager.get_enable_gd_request() {
-					let gd_level =
-						self.gd_client
-							.get_gd_level_info(level_id)
-							.await
-							.map_err(|err| {
-								error!("Error getting level info for level {}", level_id);
-								LevelRequestError::GeometryDashClientError(level_id, err)
-							})?;
-
-					update_level_request_storable.name = ActiveValue::Set(Some(gd_level.name));
-					update_level_request_storable.author =
-						ActiveValue::Set(Some(gd_level.creator.name));
-					update_level_request_storable.level_length =
-						ActiveValue::Set(Some(gd_level.level_length.into()));
-				}
-
 				self.level_request_repository
 					.update_record(update_level_request_storable)
 					.await
@@ -290,19 +391,114 @@ impl<'a, R: LevelRequestRepository, U: UserRepository, G: GeometryDashClient> Re
 		}
 	}
 
-	async fn delete_level_request(
+	async fn update_level_request_status(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		status: RequestStatus
+	) -> Result<GDLevelRequest, LevelRequestError> {
+		let existing_level_request = self.get_level_request(guild_id, level_id, None).await?;
+		if !existing_level_request.status.can_transition_to(status) {
+			warn!(
+				"Illegal status transition for level request {} from {:?} to {:?}",
+				level_id, existing_level_request.status, status
+			);
+			return Err(LevelRequestError::IllegalStatusTransition(
+				existing_level_request.status,
+				status
+			));
+		}
+
+		self.save_level_request_status(existing_level_request, status)
+			.await
+	}
+
+	async fn advance_level_request_status(
 		&self,
+		guild_id: u64,
+		level_id: u64,
+		status: RequestStatus
+	) -> Result<GDLevelRequest, LevelRequestError> {
+		let existing_level_request = self.get_level_request(guild_id, level_id, None).await?;
+		let advance_steps = match existing_level_request.status.advance_steps_to(status) {
+			Some(advance_steps) => advance_steps,
+			None => {
+				warn!(
+					"Level request {} cannot advance from {:?} to {:?}",
+					level_id, existing_level_request.status, status
+				);
+				return Err(LevelRequestError::IllegalStatusTransition(
+					existing_level_request.status,
+					status
+				));
+			}
+		};
+		if advance_steps.is_empty() {
+			debug!(
+				"Level request {} is already past {:?}, leaving status as {:?}",
+				level_id, status, existing_level_request.status
+			);
+			return Ok(existing_level_request);
+		}
+
+		let mut level_request = existing_level_request;
+		for advance_step in advance_steps {
+			level_request = self
+				.save_level_request_status(level_request, advance_step)
+				.await?;
+		}
+		Ok(level_request)
+	}
+
+	async fn return_level_request_to_queue(
+		&self,
+		guild_id: u64,
 		level_id: u64
+	) -> Result<bool, LevelRequestError> {
+		match self
+			.level_request_repository
+			.requeue_unassigned_record(guild_id, level_id, Utc::now())
+			.await
+		{
+			Ok(requeued_count) => Ok(requeued_count > 0),
+			Err(db_err) => {
+				error!(
+					"Error returning level request {} to the queue: {}",
+					level_id, db_err
+				);
+				Err(LevelRequestError::DatabaseError(db_err))
+			}
+		}
+	}
+
+	async fn delete_level_request(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		discord_user_id: u64,
+		is_admin: bool
 	) -> Result<GDLevelRequest, LevelRequestError> {
-		match self.get_level_request(level_id, None).await {
+		match self.get_level_request(guild_id, level_id, None).await {
 			Ok(existing_level_request) => {
+				if !is_admin && !discord_user_id.eq(&existing_level_request.discord_user_id) {
+					return Err(LevelRequestError::EditUnownedLevelRequest(
+						existing_level_request.level_id,
+						existing_level_request.discord_user_id,
+						discord_user_id
+					));
+				}
+
+				let mut archive_level_request_storable: ActiveModel =
+					existing_level_request.clone().into();
+				archive_level_request_storable.archived_at = ActiveValue::Set(Some(Utc::now()));
+
 				if let Err(delete_level_request_error) = self
 					.level_request_repository
-					.delete_record(existing_level_request.clone().into())
+					.update_record(archive_level_request_storable)
 					.await
 				{
 					error!(
-						"Unable to delete level request for {} from database: {}",
+						"Unable to archive level request for {} in database: {}",
 						level_id, delete_level_request_error
 					);
 					return Err(LevelRequestError::DatabaseError(delete_level_request_error));
@@ -316,14 +512,15 @@ impl<'a, R: LevelRequestRepository, U: UserRepository, G: GeometryDashClient> Re
 
 	async fn update_level_request_message_id(
 		&self,
+		guild_id: u64,
 		level_id: u64,
 		discord_message_id: u64
 	) -> Result<(), LevelRequestError> {
-		match self.get_level_request(level_id, None).await {
+		match self.get_level_request(guild_id, level_id, None).await {
 			Ok(level_request) => {
 				let mut update_level_request_storable: ActiveModel = level_request.into();
 				update_level_request_storable.discord_message_id =
-					ActiveValue::Set(Some(discord_message_id));
+					ActiveValue::Set(Some(discord_message_id.into()));
 
 				if let Err(db_err) = self
 					.level_request_repository
@@ -348,15 +545,77 @@ impl<'a, R: LevelRequestRepository, U: UserRepository, G: GeometryDashClient> Re
 	}
 }
 
-impl<'a, R: LevelRequestRepository, U: UserRepository, G: GeometryDashClient>
-	LevelRequestService<'a, R, U, G>
+impl<'a, R: LevelRequestRepository, U: UserRepository, G: GeometryDashClient, B: BanRepository>
+	LevelRequestService<'a, R, U, G, B>
 {
-	pub fn new(level_request_repository: &'a R, user_repository: &'a U, gd_client: &'a G) -> Self {
+	pub fn new(
+		level_request_repository: &'a R,
+		user_repository: &'a U,
+		gd_client: &'a G,
+		ban_repository: &'a B,
+		request_manager: &'a RequestManagerService,
+		level_eligibility_config: &'a LevelEligibilityConfig
+	) -> Self {
 		LevelRequestService {
 			level_request_repository,
 			user_repository,
 			gd_client,
-			request_manager: &RequestManagerService {}
+			ban_repository,
+			request_manager,
+			level_eligibility_config
+		}
+	}
+
+	async fn save_level_request_status(
+		&self,
+		level_request: GDLevelRequest,
+		status: RequestStatus
+	) -> Result<GDLevelRequest, LevelRequestError> {
+		let level_id = level_request.level_id;
+		let mut update_level_request_storable: ActiveModel = level_request.into();
+		update_level_request_storable.status = ActiveValue::Set(status.into());
+
+		self.level_request_repository
+			.update_record(update_level_request_storable)
+			.await
+			.map(|updated_level_request| {
+				info!("Level request {} moved to {:?}", level_id, status);
+				GDLevelRequest::from(updated_level_request)
+			})
+			.map_err(|db_err| {
+				error!(
+					"Unable to update status of level request {}: {}",
+					level_id, db_err
+				);
+				LevelRequestError::DatabaseError(db_err)
+			})
+	}
+
+	/// Looks up level info if Geometry Dash requests are enabled for the guild.
+	/// While Geometry Dash is unavailable the request is saved without it
+	/// instead.
+	async fn get_gd_level(
+		&self,
+		guild_id: u64,
+		level_id: u64
+	) -> Result<Option<GDLevel>, LevelRequestError> {
+		if !self.request_manager.get_enable_gd_request(guild_id) {
+			return Ok(None);
+		}
+
+		match self.gd_client.get_gd_level_info(level_id).await {
+			Ok(gd_level) => Ok(Some(gd_level)),
+			Err(GeometryDashDashrsError::CircuitOpenError) => {
+				warn!(
+					"Geometry Dash is unavailable, saving level request {} without level info",
+					level_id
+				);
+				Ok(None)
+			}
+			Err(err) => {
+				error!("Error getting level info for level {}", level_id);
+				Err(LevelRequestError::GeometryDashClientError(level_id, err))
+			}
 		}
 	}
 
@@ -370,170 +629,1536 @@ impl<'a, R: LevelRequestRepository, U: UserRepository, G: GeometryDashClient>
 		regex.is_match(youtube_link)
 	}
 
-	fn is_user_on_cooldown(&self, discord_user: &Model, now: &DateTime<Utc>) -> bool {
-		if let Some(discord_user_last_request_time) = discord_user.timestamp {
-			return (discord_user_last_request_time + self.request_manager.get_request_cooldown())
-				.ge(now);
-		} else {
-			false
+	async fn get_active_ban(
+		&self,
+		guild_id: u64,
+		ban_target: BanTarget,
+		now: &DateTime<Utc>
+	) -> Result<Option<Ban>, LevelRequestError> {
+		self.ban_repository
+			.get_active_record(
+				guild_id,
+				ban_target.target_type(),
+				ban_target.target_id(),
+				*now
+			)
+			.await
+			.map(|ban| ban.map(Ban::from))
+			.map_err(|db_err| {
+				error!("Error getting active ban on {:?}: {}", ban_target, db_err);
+				LevelRequestError::DatabaseError(db_err)
+			})
+	}
+
+	/// Checks the user's cooldown and request quotas before they make a new
+	/// request.
+	async fn check_request_limits(
+		&self,
+		guild_id: u64,
+		discord_user_id: u64,
+		last_request_time: Option<DateTime<Utc>>,
+		request_limits: &RequestLimits,
+		now: &DateTime<Utc>
+	) -> Result<(), LevelRequestError> {
+		if let Some(last_request_time) = last_request_time {
+			if !request_limits.cooldown_duration.is_zero()
+				&& (last_request_time + request_limits.cooldown_duration).ge(now)
+			{
+				warn!(
+					"User {} attempted to request while on cooldown",
+					discord_user_id
+				);
+				return Err(LevelRequestError::UserOnCooldown(
+					last_request_time,
+					request_limits.cooldown_duration
+				));
+			}
 		}
+
+		if let Some(max_open_requests) = request_limits.max_open_requests {
+			let open_requests_condition = Condition::all()
+				.add(level_request::Column::DiscordId.eq(discord_user_id))
+				.add(level_request::Column::Status.is_in([
+					sea_orm_active_enums::RequestStatus::Pending,
+					sea_orm_active_enums::RequestStatus::InReview
+				]));
+			match self
+				.level_request_repository
+				.count_records(guild_id, open_requests_condition)
+				.await
+			{
+				Ok(open_requests) if open_requests >= max_open_requests as u64 => {
+					warn!(
+						"User {} attempted to request with {} open requests",
+						discord_user_id, open_requests
+					);
+					return Err(LevelRequestError::RequestQuotaExceeded(
+						RequestQuota::OpenRequests,
+						max_open_requests,
+						None
+					));
+				}
+				Ok(_) => {}
+				Err(db_err) => {
+					error!(
+						"Error counting open level requests for user {}: {}",
+						discord_user_id, db_err
+					);
+					return Err(LevelRequestError::DatabaseError(db_err));
+				}
+			}
+		}
+
+		if let Some(daily_request_quota) = request_limits.daily_request_quota {
+			match self
+				.level_request_repository
+				.get_user_records_since(guild_id, discord_user_id, *now - Duration::days(1))
+				.await
+			{
+				Ok(level_requests) if level_requests.len() >= daily_request_quota as usize => {
+					// The user may request again once enough of their oldest
+					// requests have left the window to bring them under the quota
+					let oldest_counted_request =
+						&level_requests[level_requests.len() - daily_request_quota as usize];
+					warn!(
+						"User {} attempted to request past their daily quota",
+						discord_user_id
+					);
+					return Err(LevelRequestError::RequestQuotaExceeded(
+						RequestQuota::DailyRequests,
+						daily_request_quota,
+						Some(oldest_counted_request.timestamp + Duration::days(1) - *now)
+					));
+				}
+				Ok(_) => {}
+				Err(db_err) => {
+					error!(
+						"Error getting daily level requests for user {}: {}",
+						discord_user_id, db_err
+					);
+					return Err(LevelRequestError::DatabaseError(db_err));
+				}
+			}
+		}
+
+		Ok(())
 	}
 }
 
-// #[cfg(test)]
-// mod tests {
-// 	use rocket::tokio;
-// 	use sea_orm::InsertResult;
-// 	use tokio_test::assert_ok;
-//
-// 	use crate::{
-// 		adapter::{
-// 			geometry_dash::geometry_dash_client::MockGeometryDashClient,
-// 			mysql::{
-// 				level_request_repository::MockLevelRequestRepository,
-// model::level_request::Model, 				user_repository::MockUserRepository
-// 			}
-// 		},
-// 		domain::{
-// 			model::{
-// 				discord::{message::DiscordMessage, user::DiscordUser},
-// 				error::level_request_error::LevelRequestError::LevelRequestExists,
-// 				gd_level::{GDLevel, GDLevelRequest},
-// 				level_creator::LevelCreator,
-// 				request_rating::RequestRating
-// 			},
-// 			service::{
-// 				level_request_service::LevelRequestService, request_service::RequestService
-// 			}
-// 		}
-// 	};
-//
-// 	#[tokio::test]
-// 	async fn test_request_service_should_return_ok() {
-// 		let mut mock_level_request_repository = MockLevelRequestRepository::new();
-// 		let mut mock_user_repository = MockUserRepository::new();
-// 		let mut mock_gd_client = MockGeometryDashClient::new();
-//
-// 		mock_level_request_repository
-// 			.expect_get_record()
-// 			.return_once(move |_| Ok(None));
-// 		mock_level_request_repository
-// 			.expect_create_record()
-// 			.return_once(move |_| {
-// 				Ok(InsertResult {
-// 					last_insert_id: 99999999
-// 				})
-// 			});
-//
-// 		mock_gd_client
-// 			.expect_get_gd_level_info()
-// 			.return_once(move |_| {
-// 				Ok(GDLevel {
-// 					level_id: 99999999,
-// 					name: "Level Name".to_string(),
-// 					creator: LevelCreator {
-// 						name: "Level Creator".to_string(),
-// 						account_id: 1234,
-// 						player_id: 5678
-// 					},
-// 					description: Some("Level Descritpion".to_string())
-// 				})
-// 			});
-//
-// 		let service = LevelRequestService {
-// 			level_request_repository: mock_level_request_repository,
-// 			user_repository: mock_user_repository,
-// 			gd_client: mock_gd_client
-// 		};
-//
-// 		assert_ok!(
-// 			service
-// 				.make_level_request(99999999, "LINK".to_string(), 99999999,
-// RequestRating::Two) 				.await
-// 		);
-// 	}
-//
-// 	#[tokio::test]
-// 	async fn test_request_service_should_return_error_when_request_already_exists() {
-// 		let mut mock_level_request_repository = MockLevelRequestRepository::new();
-// 		let mut mock_user_repository = MockUserRepository::new();
-// 		let mut mock_gd_client = MockGeometryDashClient::new();
-//
-// 		mock_level_request_repository
-// 			.expect_get_record()
-// 			.return_once(move |_| {
-// 				Ok(Some(Model {
-// 					level_id: 99999999,
-// 					discord_id: 99999999,
-// 					discord_message_id: Some(476936521364123),
-// 					discord_thread_id: None,
-// 					name: "Level Name".to_string(),
-// 					description: Some("Level Description".to_string()),
-// 					author: "Creator Name".to_string(),
-// 					request_rating: RequestRating::Two.into(),
-// 					you_tube_video_link: "LINK".to_string()
-// 				}))
-// 			});
-//
-// 		mock_gd_client
-// 			.expect_get_gd_level_info()
-// 			.return_once(move |_| {
-// 				Ok(GDLevel {
-// 					level_id: 99999999,
-// 					name: "Level Name".to_string(),
-// 					creator: LevelCreator {
-// 						name: "Level Creator".to_string(),
-// 						account_id: 1234,
-// 						player_id: 5678
-// 					},
-// 					description: Some("Level Descritpion".to_string())
-// 				})
-// 			});
-//
-// 		let service = LevelRequestService {
-// 			level_request_repository: mock_level_request_repository,
-// 			user_repository: mock_user_repository,
-// 			gd_client: mock_gd_client
-// 		};
-//
-// 		assert_eq!(
-// 			service
-// 				.make_level_request(99999999, "LINK".to_string(), 99999999,
-// RequestRating::Two) 				.await,
-// 			Err(LevelRequestExists)
-// 		)
-// 	}
-//
-// 	fn setup_level_helper(
-// 		level_id: u64,
-// 		discord_id: u64,
-// 		discord_message_id: u64,
-// 		name: String,
-// 		creator_name: String,
-// 		account_id: u64,
-// 		player_id: u64,
-// 		description: Option<String>,
-// 		request_rating: RequestRating,
-// 		youtube_video_link: String
-// 	) -> GDLevelRequest {
-// 		GDLevelRequest {
-// 			gd_level: GDLevel {
-// 				level_id,
-// 				name,
-// 				creator: LevelCreator {
-// 					name: creator_name,
-// 					account_id,
-// 					player_id
-// 				},
-// 				description
-// 			},
-// 			discord_user_data: DiscordUser { discord_user_id: discord_id },
-// 			discord_message_data: Some(DiscordMessage {
-// 				message_id: discord_message_id,
-// 				thread_id: None,
-// 			}),
-// 			request_rating,
-// 			youtube_video_link
-// 		}
-// 	}
-// }
+#[cfg(test)]
+mod tests {
+	use chrono::{Duration, Utc};
+	use mockall::Sequence;
+	use sea_orm::{ActiveValue, DbErr, InsertResult};
+
+	use crate::{
+		adapter::{
+			geometry_dash::geometry_dash_client::MockGeometryDashClient,
+			mysql::{
+				ban_repository::MockBanRepository,
+				level_request_repository::MockLevelRequestRepository,
+				model::{request_ban, sea_orm_active_enums, user},
+				user_repository::MockUserRepository
+			}
+		},
+		domain::{
+			model::{
+				ban::BanTarget,
+				error::{
+					geometry_dash::geometry_dash_dashrs_error::GeometryDashDashrsError,
+					level_request_error::LevelRequestError
+				},
+				gd_level::{
+					FeatureState, GDLevel, LevelCreator, LevelDifficulty, LevelLength,
+					LevelRequestSubmission, LevelRequestUpdate, RequestRating, RequestStatus
+				},
+				level_eligibility::IneligibilityReason,
+				level_queue::QueuePolicy,
+				level_request_query::{LevelRequestFilter, SortOrder},
+				request_config::RequestConfig,
+				request_limits::RequestQuota
+			},
+			service::{
+				internal::request_manager_service::RequestManagerService,
+				level_request_service::LevelRequestService, request_service::RequestService
+			}
+		},
+		rocket::common::{
+			config::level_eligibility_config::LevelEligibilityConfig,
+			testing::{level_request_model, YOUTUBE_VIDEO_LINK}
+		}
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_make_level_request_should_save_request_and_user() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mut mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_level_request_repository
+			.expect_get_record_ignore_archived()
+			.returning(|_, _| Ok(None));
+		mock_level_request_repository
+			.expect_create_record()
+			.withf(|record| {
+				record.status == ActiveValue::Set(sea_orm_active_enums::RequestStatus::Pending)
+					&& record.archived_at == ActiveValue::Set(None)
+			})
+			.times(1)
+			.returning(|_| {
+				Ok(InsertResult {
+					last_insert_id: (2001.into(), 99999999.into())
+				})
+			});
+		mock_user_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_user_repository
+			.expect_create_record()
+			.times(1)
+			.returning(|_| {
+				Ok(InsertResult {
+					last_insert_id: (2001.into(), 12345.into())
+				})
+			});
+
+		let request_manager = RequestManagerService {};
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		let level_request = service
+			.make_level_request(
+				2001,
+				99999999,
+				12345,
+				LevelRequestSubmission {
+					has_requested_feedback: true,
+					..level_request_submission()
+				}
+			)
+			.await
+			.unwrap();
+
+		assert_eq!(level_request.level_id, 99999999);
+		assert_eq!(level_request.discord_user_id, 12345);
+		assert_eq!(level_request.status, RequestStatus::Pending);
+		assert!(level_request.gd_level.is_none());
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_make_level_request_should_return_error_when_request_already_exists() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id| {
+				Ok(Some(level_request_model(
+					guild_id,
+					level_id,
+					12345,
+					RequestStatus::Pending
+				)))
+			});
+
+		let request_manager = RequestManagerService {};
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		assert_eq!(
+			service
+				.make_level_request(2002, 99999999, 12345, level_request_submission())
+				.await
+				.unwrap_err(),
+			LevelRequestError::LevelRequestExists
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_make_level_request_should_return_error_when_youtube_link_is_malformed() {
+		let mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		let request_manager = RequestManagerService {};
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		assert_eq!(
+			service
+				.make_level_request(
+					2003,
+					99999999,
+					12345,
+					LevelRequestSubmission {
+						youtube_video_link: "https://example.com/watch?v=dQw4w9WgXcQ".to_string(),
+						..level_request_submission()
+					}
+				)
+				.await
+				.unwrap_err(),
+			LevelRequestError::MalformedRequest
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_make_level_request_should_return_error_when_requests_are_disabled() {
+		let mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		let request_manager = RequestManagerService {};
+		request_manager.set_request_config(RequestConfig {
+			guild_id: 2004,
+			cooldown_duration: Duration::zero(),
+			enable_requests: false,
+			enable_gd_requests: false,
+			queue_policy: QueuePolicy::Fifo,
+			max_open_requests: 0,
+			daily_request_quota: 0
+		});
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		assert_eq!(
+			service
+				.make_level_request(2004, 99999999, 12345, level_request_submission())
+				.await
+				.unwrap_err(),
+			LevelRequestError::LevelRequestsDisabled
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_make_level_request_should_return_error_when_user_is_on_cooldown() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mut mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_user_repository
+			.expect_get_record()
+			.returning(|guild_id, discord_id| {
+				Ok(Some(user::Model {
+					guild_id: guild_id.into(),
+					discord_id: discord_id.into(),
+					timestamp: Some(Utc::now() - Duration::minutes(5)),
+					cooldown_duration: None,
+					cooldown_exempt: 0,
+					max_open_requests: None,
+					daily_request_quota: None
+				}))
+			});
+
+		let request_manager = RequestManagerService {};
+		request_manager.set_request_config(RequestConfig {
+			guild_id: 2005,
+			cooldown_duration: Duration::minutes(60),
+			enable_requests: true,
+			enable_gd_requests: false,
+			queue_policy: QueuePolicy::Fifo,
+			max_open_requests: 0,
+			daily_request_quota: 0
+		});
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		assert!(matches!(
+			service
+				.make_level_request(
+					2005,
+					99999999,
+					12345,
+					level_request_submission()
+				)
+				.await,
+			Err(LevelRequestError::UserOnCooldown(_, cooldown_duration))
+				if cooldown_duration == Duration::minutes(60)
+		));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_make_level_request_should_return_error_when_level_is_banned() {
+		let mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+		let mut mock_ban_repository = MockBanRepository::new();
+
+		mock_ban_repository.expect_get_active_record().returning(
+			|guild_id, target_type, target_id, _| match target_type {
+				sea_orm_active_enums::BanTargetType::User => Ok(None),
+				sea_orm_active_enums::BanTargetType::Level => Ok(Some(request_ban::Model {
+					id: 1,
+					guild_id: guild_id.into(),
+					target_type,
+					target_id: target_id.into(),
+					reason: Some("Stolen level".to_string()),
+					issued_by: 0.into(),
+					created_at: Utc::now(),
+					expires_at: None,
+					lifted_at: None,
+					lifted_by: None
+				}))
+			}
+		);
+
+		let request_manager = RequestManagerService {};
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		assert!(matches!(
+			service
+				.make_level_request(
+					2023,
+					99999999,
+					12345,
+					level_request_submission()
+				)
+				.await,
+			Err(LevelRequestError::Banned(ban))
+				if ban.target == BanTarget::Level(99999999)
+		));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_make_level_request_should_use_user_cooldown_override() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mut mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_user_repository
+			.expect_get_record()
+			.returning(|guild_id, discord_id| {
+				Ok(Some(user::Model {
+					guild_id: guild_id.into(),
+					discord_id: discord_id.into(),
+					timestamp: Some(Utc::now() - Duration::minutes(5)),
+					cooldown_duration: Some(10),
+					cooldown_exempt: 0,
+					max_open_requests: None,
+					daily_request_quota: None
+				}))
+			});
+
+		let request_manager = RequestManagerService {};
+		request_manager.set_request_config(RequestConfig {
+			guild_id: 2019,
+			cooldown_duration: Duration::minutes(60),
+			enable_requests: true,
+			enable_gd_requests: false,
+			queue_policy: QueuePolicy::Fifo,
+			max_open_requests: 0,
+			daily_request_quota: 0
+		});
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		assert!(matches!(
+			service
+				.make_level_request(
+					2019,
+					99999999,
+					12345,
+					level_request_submission()
+				)
+				.await,
+			Err(LevelRequestError::UserOnCooldown(_, cooldown_duration))
+				if cooldown_duration == Duration::minutes(10)
+		));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_make_level_request_should_let_cooldown_exempt_user_request() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mut mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_level_request_repository
+			.expect_get_record_ignore_archived()
+			.returning(|_, _| Ok(None));
+		mock_level_request_repository
+			.expect_create_record()
+			.times(1)
+			.returning(|_| {
+				Ok(InsertResult {
+					last_insert_id: (2020.into(), 99999999.into())
+				})
+			});
+		mock_user_repository
+			.expect_get_record()
+			.returning(|guild_id, discord_id| {
+				Ok(Some(user::Model {
+					guild_id: guild_id.into(),
+					discord_id: discord_id.into(),
+					timestamp: Some(Utc::now()),
+					cooldown_duration: None,
+					cooldown_exempt: 1,
+					max_open_requests: None,
+					daily_request_quota: None
+				}))
+			});
+		mock_user_repository
+			.expect_update_record()
+			.withf(|record| record.cooldown_exempt == ActiveValue::Unchanged(1))
+			.times(1)
+			.returning(|record| {
+				Ok(user::Model {
+					guild_id: record.guild_id.clone().unwrap(),
+					discord_id: record.discord_id.clone().unwrap(),
+					timestamp: record.timestamp.clone().unwrap(),
+					cooldown_duration: None,
+					cooldown_exempt: 1,
+					max_open_requests: None,
+					daily_request_quota: None
+				})
+			});
+
+		let request_manager = RequestManagerService {};
+		request_manager.set_request_config(RequestConfig {
+			guild_id: 2020,
+			cooldown_duration: Duration::minutes(60),
+			enable_requests: true,
+			enable_gd_requests: false,
+			queue_policy: QueuePolicy::Fifo,
+			max_open_requests: 0,
+			daily_request_quota: 0
+		});
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		assert!(service
+			.make_level_request(2020, 99999999, 12345, level_request_submission())
+			.await
+			.is_ok());
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_make_level_request_should_return_error_when_user_has_too_many_open_requests() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mut mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_level_request_repository
+			.expect_count_records()
+			.times(1)
+			.returning(|_, _| Ok(2));
+		mock_level_request_repository.expect_create_record().never();
+		mock_user_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_user_repository.expect_create_record().never();
+
+		let request_manager = RequestManagerService {};
+		request_manager.set_request_config(RequestConfig {
+			guild_id: 2021,
+			cooldown_duration: Duration::zero(),
+			enable_requests: true,
+			enable_gd_requests: false,
+			queue_policy: QueuePolicy::Fifo,
+			max_open_requests: 2,
+			daily_request_quota: 0
+		});
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		assert_eq!(
+			service
+				.make_level_request(2021, 99999999, 12345, level_request_submission())
+				.await
+				.unwrap_err(),
+			LevelRequestError::RequestQuotaExceeded(RequestQuota::OpenRequests, 2, None)
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_make_level_request_should_return_time_until_daily_quota_frees_up() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mut mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_level_request_repository
+			.expect_get_user_records_since()
+			.times(1)
+			.returning(|guild_id, discord_id, _| {
+				Ok([23, 20, 1]
+					.into_iter()
+					.map(|hours_ago| {
+						let mut level_request = level_request_model(
+							guild_id,
+							99999990 + hours_ago,
+							discord_id,
+							RequestStatus::Sent
+						);
+						level_request.timestamp = Utc::now() - Duration::hours(hours_ago as i64);
+						level_request
+					})
+					.collect())
+			});
+		mock_user_repository
+			.expect_get_record()
+			.returning(|guild_id, discord_id| {
+				Ok(Some(user::Model {
+					guild_id: guild_id.into(),
+					discord_id: discord_id.into(),
+					timestamp: Some(Utc::now() - Duration::hours(1)),
+					cooldown_duration: None,
+					cooldown_exempt: 0,
+					max_open_requests: None,
+					daily_request_quota: Some(2)
+				}))
+			});
+
+		let request_manager = RequestManagerService {};
+		request_manager.set_request_config(RequestConfig {
+			guild_id: 2022,
+			cooldown_duration: Duration::zero(),
+			enable_requests: true,
+			enable_gd_requests: false,
+			queue_policy: QueuePolicy::Fifo,
+			max_open_requests: 0,
+			daily_request_quota: 5
+		});
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		// The request from 20 hours ago has to leave the window first
+		assert!(matches!(
+			service
+				.make_level_request(
+					2022,
+					99999999,
+					12345,
+					level_request_submission()
+				)
+				.await,
+			Err(LevelRequestError::RequestQuotaExceeded(RequestQuota::DailyRequests, 2, Some(retry_after)))
+				if (retry_after - Duration::hours(4)).num_minutes() == 0
+		));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_make_level_request_should_replace_archived_request() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mut mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_level_request_repository
+			.expect_get_record_ignore_archived()
+			.returning(|guild_id, level_id| {
+				let mut archived_level_request =
+					level_request_model(guild_id, level_id, 12345, RequestStatus::Sent);
+				archived_level_request.archived_at = Some(Utc::now());
+				Ok(Some(archived_level_request))
+			});
+		mock_level_request_repository.expect_update_record().never();
+		mock_level_request_repository.expect_create_record().never();
+		mock_level_request_repository
+			.expect_replace_archived_record()
+			.withf(|record| {
+				record.status == ActiveValue::Set(sea_orm_active_enums::RequestStatus::Pending)
+					&& record.archived_at == ActiveValue::Set(None)
+			})
+			.times(1)
+			.returning(|_| {
+				Ok(InsertResult {
+					last_insert_id: (2006.into(), 99999999.into())
+				})
+			});
+		mock_user_repository
+			.expect_get_record()
+			.returning(|guild_id, discord_id| {
+				Ok(Some(user::Model {
+					guild_id: guild_id.into(),
+					discord_id: discord_id.into(),
+					timestamp: None,
+					cooldown_duration: None,
+					cooldown_exempt: 0,
+					max_open_requests: None,
+					daily_request_quota: None
+				}))
+			});
+		mock_user_repository
+			.expect_update_record()
+			.withf(|record| record.timestamp.as_ref().is_some())
+			.times(1)
+			.returning(|record| {
+				Ok(user::Model {
+					guild_id: record.guild_id.clone().unwrap(),
+					discord_id: record.discord_id.clone().unwrap(),
+					timestamp: record.timestamp.clone().unwrap(),
+					cooldown_duration: None,
+					cooldown_exempt: 0,
+					max_open_requests: None,
+					daily_request_quota: None
+				})
+			});
+
+		let request_manager = RequestManagerService {};
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		assert!(service
+			.make_level_request(2006, 99999999, 12345, level_request_submission())
+			.await
+			.is_ok());
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_make_level_request_should_return_error_when_level_is_ineligible() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_user_repository = MockUserRepository::new();
+		let mut mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_gd_client
+			.expect_get_gd_level_info()
+			.returning(|_| Ok(gd_level(5, FeatureState::NotFeatured)));
+
+		let request_manager = RequestManagerService {};
+		request_manager.set_request_config(RequestConfig {
+			guild_id: 2007,
+			cooldown_duration: Duration::zero(),
+			enable_requests: true,
+			enable_gd_requests: true,
+			queue_policy: QueuePolicy::Fifo,
+			max_open_requests: 0,
+			daily_request_quota: 0
+		});
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		assert_eq!(
+			service
+				.make_level_request(2007, 99999999, 12345, level_request_submission())
+				.await
+				.unwrap_err(),
+			LevelRequestError::IneligibleLevel(99999999, IneligibilityReason::AlreadyRated)
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_make_level_request_should_reject_level_without_info_when_creators_are_denied() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_level_request_repository.expect_create_record().never();
+
+		let request_manager = RequestManagerService {};
+		let level_eligibility_config = LevelEligibilityConfig {
+			denied_creator_account_ids: vec![1234],
+			..LevelEligibilityConfig::default()
+		};
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		assert_eq!(
+			service
+				.make_level_request(2021, 99999999, 12345, level_request_submission())
+				.await
+				.unwrap_err(),
+			LevelRequestError::IneligibleLevel(99999999, IneligibilityReason::LevelInfoUnavailable)
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_make_level_request_should_save_level_info_from_geometry_dash() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mut mock_user_repository = MockUserRepository::new();
+		let mut mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_level_request_repository
+			.expect_get_record_ignore_archived()
+			.returning(|_, _| Ok(None));
+		mock_level_request_repository
+			.expect_create_record()
+			.withf(|record| {
+				record.name == ActiveValue::Set(Some("Level Name".to_string()))
+					&& record.author_account_id == ActiveValue::Set(Some(1234.into()))
+			})
+			.times(1)
+			.returning(|_| {
+				Ok(InsertResult {
+					last_insert_id: (2008.into(), 99999999.into())
+				})
+			});
+		mock_user_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_user_repository.expect_create_record().returning(|_| {
+			Ok(InsertResult {
+				last_insert_id: (2008.into(), 12345.into())
+			})
+		});
+		mock_gd_client
+			.expect_get_gd_level_info()
+			.returning(|_| Ok(gd_level(0, FeatureState::NotFeatured)));
+
+		let request_manager = RequestManagerService {};
+		request_manager.set_request_config(RequestConfig {
+			guild_id: 2008,
+			cooldown_duration: Duration::zero(),
+			enable_requests: true,
+			enable_gd_requests: true,
+			queue_policy: QueuePolicy::Fifo,
+			max_open_requests: 0,
+			daily_request_quota: 0
+		});
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		let level_request = service
+			.make_level_request(2008, 99999999, 12345, level_request_submission())
+			.await
+			.unwrap();
+
+		assert_eq!(level_request.gd_level.unwrap().name, "Level Name");
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_make_level_request_should_save_without_level_info_when_circuit_is_open() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mut mock_user_repository = MockUserRepository::new();
+		let mut mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_level_request_repository
+			.expect_get_record_ignore_archived()
+			.returning(|_, _| Ok(None));
+		mock_level_request_repository
+			.expect_create_record()
+			.times(1)
+			.returning(|_| {
+				Ok(InsertResult {
+					last_insert_id: (2009.into(), 99999999.into())
+				})
+			});
+		mock_user_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_user_repository.expect_create_record().returning(|_| {
+			Ok(InsertResult {
+				last_insert_id: (2009.into(), 12345.into())
+			})
+		});
+		mock_gd_client
+			.expect_get_gd_level_info()
+			.returning(|_| Err(GeometryDashDashrsError::CircuitOpenError));
+
+		let request_manager = RequestManagerService {};
+		request_manager.set_request_config(RequestConfig {
+			guild_id: 2009,
+			cooldown_duration: Duration::zero(),
+			enable_requests: true,
+			enable_gd_requests: true,
+			queue_policy: QueuePolicy::Fifo,
+			max_open_requests: 0,
+			daily_request_quota: 0
+		});
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		let level_request = service
+			.make_level_request(2009, 99999999, 12345, level_request_submission())
+			.await
+			.unwrap();
+
+		assert!(level_request.gd_level.is_none());
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_make_level_request_should_return_error_when_level_does_not_exist() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_user_repository = MockUserRepository::new();
+		let mut mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_gd_client
+			.expect_get_gd_level_info()
+			.returning(|level_id| Err(GeometryDashDashrsError::LevelNotFoundError(level_id)));
+
+		let request_manager = RequestManagerService {};
+		request_manager.set_request_config(RequestConfig {
+			guild_id: 2010,
+			cooldown_duration: Duration::zero(),
+			enable_requests: true,
+			enable_gd_requests: true,
+			queue_policy: QueuePolicy::Fifo,
+			max_open_requests: 0,
+			daily_request_quota: 0
+		});
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		assert_eq!(
+			service
+				.make_level_request(2010, 99999999, 12345, level_request_submission())
+				.await
+				.unwrap_err(),
+			LevelRequestError::GeometryDashClientError(
+				99999999,
+				GeometryDashDashrsError::LevelNotFoundError(99999999)
+			)
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_level_requests_should_page_records() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_count_records()
+			.returning(|_, _| Ok(3));
+		mock_level_request_repository
+			.expect_get_records()
+			.withf(|guild_id, _, _, offset, limit| *guild_id == 2011 && *offset == 2 && *limit == 2)
+			.times(1)
+			.returning(|guild_id, _, _, _, _| {
+				Ok(vec![level_request_model(
+					guild_id,
+					99999999,
+					12345,
+					RequestStatus::Pending
+				)])
+			});
+
+		let request_manager = RequestManagerService {};
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		let level_request_page = service
+			.get_level_requests(
+				2011,
+				LevelRequestFilter::default(),
+				SortOrder::Ascending,
+				2,
+				2
+			)
+			.await
+			.unwrap();
+
+		assert_eq!(level_request_page.level_requests.len(), 1);
+		assert_eq!(level_request_page.page, 2);
+		assert_eq!(level_request_page.total_count, 3);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_level_requests_should_return_error_when_page_is_out_of_range() {
+		let mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		let request_manager = RequestManagerService {};
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		assert_eq!(
+			service
+				.get_level_requests(
+					2012,
+					LevelRequestFilter::default(),
+					SortOrder::Ascending,
+					0,
+					25
+				)
+				.await
+				.unwrap_err(),
+			LevelRequestError::MalformedRequest
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_level_requests_should_return_error_when_offset_overflows() {
+		let mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		let request_manager = RequestManagerService {};
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		assert_eq!(
+			service
+				.get_level_requests(
+					2029,
+					LevelRequestFilter::default(),
+					SortOrder::Ascending,
+					u64::MAX,
+					25
+				)
+				.await
+				.unwrap_err(),
+			LevelRequestError::MalformedRequest
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_level_request_should_return_database_error() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record_filter_feedback()
+			.returning(|_, _, _| Err(DbErr::Custom("Connection lost".to_string())));
+
+		let request_manager = RequestManagerService {};
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		assert_eq!(
+			service
+				.get_level_request(2013, 99999999, Some(true))
+				.await
+				.unwrap_err(),
+			LevelRequestError::DatabaseError(DbErr::Custom("Connection lost".to_string()))
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_update_level_request_should_return_error_when_editing_unowned_request() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id| {
+				Ok(Some(level_request_model(
+					guild_id,
+					level_id,
+					12345,
+					RequestStatus::Pending
+				)))
+			});
+
+		let request_manager = RequestManagerService {};
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		assert_eq!(
+			service
+				.update_level_request(
+					2014,
+					99999999,
+					54321,
+					false,
+					LevelRequestUpdate {
+						request_rating: Some(RequestRating::Four),
+						..LevelRequestUpdate::default()
+					}
+				)
+				.await
+				.unwrap_err(),
+			LevelRequestError::EditUnownedLevelRequest(99999999, 12345, 54321)
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_update_level_request_should_let_admin_edit_any_request() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id| {
+				Ok(Some(level_request_model(
+					guild_id,
+					level_id,
+					12345,
+					RequestStatus::Pending
+				)))
+			});
+		mock_level_request_repository
+			.expect_update_record()
+			.withf(|record| {
+				record.request_rating == ActiveValue::Set(sea_orm_active_enums::RequestRating::Four)
+			})
+			.times(1)
+			.returning(|_| {
+				let mut updated_level_request =
+					level_request_model(2015, 99999999, 12345, RequestStatus::Pending);
+				updated_level_request.request_rating = sea_orm_active_enums::RequestRating::Four;
+				Ok(updated_level_request)
+			});
+
+		let request_manager = RequestManagerService {};
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		let level_request = service
+			.update_level_request(
+				2015,
+				99999999,
+				54321,
+				true,
+				LevelRequestUpdate {
+					request_rating: Some(RequestRating::Four),
+					..LevelRequestUpdate::default()
+				}
+			)
+			.await
+			.unwrap();
+
+		assert!(matches!(level_request.request_rating, RequestRating::Four));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_update_level_request_status_should_return_error_for_illegal_transition() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id| {
+				Ok(Some(level_request_model(
+					guild_id,
+					level_id,
+					12345,
+					RequestStatus::Pending
+				)))
+			});
+
+		let request_manager = RequestManagerService {};
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		assert_eq!(
+			service
+				.update_level_request_status(2016, 99999999, RequestStatus::Sent)
+				.await
+				.unwrap_err(),
+			LevelRequestError::IllegalStatusTransition(RequestStatus::Pending, RequestStatus::Sent)
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_advance_level_request_status_should_not_move_status_backwards() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id| {
+				Ok(Some(level_request_model(
+					guild_id,
+					level_id,
+					12345,
+					RequestStatus::Sent
+				)))
+			});
+		mock_level_request_repository.expect_update_record().never();
+
+		let request_manager = RequestManagerService {};
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		let level_request = service
+			.advance_level_request_status(2017, 99999999, RequestStatus::Reviewed)
+			.await
+			.unwrap();
+
+		assert_eq!(level_request.status, RequestStatus::Sent);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_advance_level_request_status_should_save_each_step() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+		let mut sequence = Sequence::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id| {
+				Ok(Some(level_request_model(
+					guild_id,
+					level_id,
+					12345,
+					RequestStatus::Pending
+				)))
+			});
+		for status in [RequestStatus::InReview, RequestStatus::Reviewed] {
+			mock_level_request_repository
+				.expect_update_record()
+				.withf(move |record| record.status == ActiveValue::Set(status.into()))
+				.times(1)
+				.in_sequence(&mut sequence)
+				.returning(move |_| Ok(level_request_model(2018, 99999999, 12345, status)));
+		}
+
+		let request_manager = RequestManagerService {};
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		let level_request = service
+			.advance_level_request_status(2018, 99999999, RequestStatus::Reviewed)
+			.await
+			.unwrap();
+
+		assert_eq!(level_request.status, RequestStatus::Reviewed);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_advance_level_request_status_should_return_error_when_request_was_rejected() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id| {
+				Ok(Some(level_request_model(
+					guild_id,
+					level_id,
+					12345,
+					RequestStatus::Rejected
+				)))
+			});
+		mock_level_request_repository.expect_update_record().never();
+
+		let request_manager = RequestManagerService {};
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		assert_eq!(
+			service
+				.advance_level_request_status(2019, 99999999, RequestStatus::Reviewed)
+				.await
+				.unwrap_err(),
+			LevelRequestError::IllegalStatusTransition(
+				RequestStatus::Rejected,
+				RequestStatus::Reviewed
+			)
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_delete_level_request_should_archive_request() {
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_user_repository = MockUserRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id| {
+				Ok(Some(level_request_model(
+					guild_id,
+					level_id,
+					12345,
+					RequestStatus::Pending
+				)))
+			});
+		mock_level_request_repository
+			.expect_update_record()
+			.withf(|record| record.archived_at.as_ref().is_some())
+			.times(1)
+			.returning(|_| {
+				let mut archived_level_request =
+					level_request_model(2018, 99999999, 12345, RequestStatus::Pending);
+				archived_level_request.archived_at = Some(Utc::now());
+				Ok(archived_level_request)
+			});
+
+		let request_manager = RequestManagerService {};
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		let mock_ban_repository = mock_ban_repository();
+		let service = LevelRequestService::new(
+			&mock_level_request_repository,
+			&mock_user_repository,
+			&mock_gd_client,
+			&mock_ban_repository,
+			&request_manager,
+			&level_eligibility_config
+		);
+
+		assert!(service
+			.delete_level_request(2018, 99999999, 12345, false)
+			.await
+			.is_ok());
+	}
+
+	/// A ban repository with no active bans.
+	fn mock_ban_repository() -> MockBanRepository {
+		let mut mock_ban_repository = MockBanRepository::new();
+		mock_ban_repository
+			.expect_get_active_record()
+			.returning(|_, _, _, _| Ok(None));
+		mock_ban_repository
+	}
+
+	fn level_request_submission() -> LevelRequestSubmission {
+		LevelRequestSubmission {
+			youtube_video_link: YOUTUBE_VIDEO_LINK.to_string(),
+			request_rating: RequestRating::Two,
+			has_requested_feedback: false,
+			notify: false
+		}
+	}
+
+	fn gd_level(stars: u8, feature_state: FeatureState) -> GDLevel {
+		GDLevel {
+			name: "Level Name".to_string(),
+			description: Some("Level Description".to_string()),
+			creator: LevelCreator {
+				name: "Level Creator".to_string(),
+				account_id: 1234,
+				player_id: 5678
+			},
+			level_length: LevelLength::Medium,
+			difficulty: LevelDifficulty::Hard,
+			stars,
+			feature_state,
+			downloads: 100,
+			likes: 10,
+			game_version: None,
+			song: None,
+			object_count: Some(5000),
+			coin_count: 0
+		}
+	}
+}
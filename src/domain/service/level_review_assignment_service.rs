@@ -0,0 +1,922 @@
+use chrono::{DateTime, Duration, Utc};
+use sea_orm::ActiveValue;
+
+use crate::{
+	adapter::mysql::{
+		model::review_assignment::ActiveModel,
+		review_assignment_repository::ReviewAssignmentRepository,
+		reviewer_repository::ReviewerRepository
+	},
+	domain::{
+		model::{
+			error::{
+				level_request_error::LevelRequestError,
+				review_assignment_error::ReviewAssignmentError
+			},
+			gd_level::{GDLevelRequest, RequestStatus},
+			review_assignment::{AssignedLevelRequest, ReviewAssignment}
+		},
+		service::{
+			request_service::RequestService, review_assignment_service::ReviewAssignmentService
+		}
+	},
+	rocket::common::config::review_assignment_config::ReviewAssignmentConfig
+};
+
+pub struct LevelReviewAssignmentService<
+	'a,
+	A: ReviewAssignmentRepository,
+	R: ReviewerRepository,
+	L: RequestService
+> {
+	review_assignment_repository: &'a A,
+	reviewer_repository: &'a R,
+	level_request_service: L,
+	review_assignment_config: &'a ReviewAssignmentConfig
+}
+
+impl<'a, A: ReviewAssignmentRepository, R: ReviewerRepository, L: RequestService>
+	ReviewAssignmentService for LevelReviewAssignmentService<'a, A, R, L>
+{
+	async fn claim_level(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		reviewer_discord_id: u64
+	) -> Result<ReviewAssignment, ReviewAssignmentError> {
+		let now = Utc::now();
+		self.get_assignable_level_request(guild_id, level_id)
+			.await?;
+		self.check_active_reviewer(guild_id, reviewer_discord_id)
+			.await?;
+
+		match self.get_active_assignment(guild_id, level_id, &now).await? {
+			Some(active_assignment) => {
+				if active_assignment.reviewer_discord_id == reviewer_discord_id {
+					self.extend_assignment(active_assignment, &now).await
+				} else {
+					warn!(
+						"Reviewer {} attempted to claim level {} assigned to {}",
+						reviewer_discord_id, level_id, active_assignment.reviewer_discord_id
+					);
+					Err(ReviewAssignmentError::AssignedToAnotherReviewer(
+						active_assignment.reviewer_discord_id
+					))
+				}
+			}
+			None => {
+				self.create_assignment(
+					guild_id,
+					level_id,
+					reviewer_discord_id,
+					reviewer_discord_id,
+					&now
+				)
+				.await
+			}
+		}
+	}
+
+	async fn assign_level(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		reviewer_discord_id: Option<u64>,
+		assigned_by: u64
+	) -> Result<ReviewAssignment, ReviewAssignmentError> {
+		let now = Utc::now();
+		self.get_assignable_level_request(guild_id, level_id)
+			.await?;
+		let reviewer_discord_id = match reviewer_discord_id {
+			Some(reviewer_discord_id) => {
+				self.check_active_reviewer(guild_id, reviewer_discord_id)
+					.await?;
+				reviewer_discord_id
+			}
+			None => self.pick_reviewer(guild_id, &now).await?
+		};
+
+		if let Some(active_assignment) =
+			self.get_active_assignment(guild_id, level_id, &now).await?
+		{
+			if active_assignment.reviewer_discord_id == reviewer_discord_id {
+				return self.extend_assignment(active_assignment, &now).await;
+			}
+			self.end_assignment(active_assignment, &now).await?;
+		}
+
+		self.create_assignment(guild_id, level_id, reviewer_discord_id, assigned_by, &now)
+			.await
+	}
+
+	async fn release_level(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		reviewer_discord_id: u64,
+		is_moderator: bool
+	) -> Result<ReviewAssignment, ReviewAssignmentError> {
+		let now = Utc::now();
+
+		match self.get_active_assignment(guild_id, level_id, &now).await? {
+			Some(active_assignment) => {
+				if !is_moderator && active_assignment.reviewer_discord_id != reviewer_discord_id {
+					warn!(
+						"Reviewer {} attempted to release level {} assigned to {}",
+						reviewer_discord_id, level_id, active_assignment.reviewer_discord_id
+					);
+					return Err(ReviewAssignmentError::AssignedToAnotherReviewer(
+						active_assignment.reviewer_discord_id
+					));
+				}
+				let released_assignment = self.end_assignment(active_assignment, &now).await?;
+				self.return_to_queue(guild_id, level_id).await?;
+				Ok(released_assignment)
+			}
+			None => {
+				warn!("Level {} is not assigned to anyone", level_id);
+				Err(ReviewAssignmentError::AssignmentDoesNotExist)
+			}
+		}
+	}
+
+	async fn get_assigned_levels(
+		&self,
+		guild_id: u64,
+		reviewer_discord_id: u64
+	) -> Result<Vec<AssignedLevelRequest>, ReviewAssignmentError> {
+		match self
+			.review_assignment_repository
+			.get_active_records(guild_id, Some(reviewer_discord_id), Utc::now())
+			.await
+		{
+			Ok(assignments) => Ok(assignments
+				.into_iter()
+				.filter_map(|(assignment, level_request)| {
+					// Levels archived since they were assigned no longer need a
+					// review
+					level_request
+						.filter(|level_request| level_request.archived_at.is_none())
+						.map(|level_request| AssignedLevelRequest {
+							assignment: ReviewAssignment::from(assignment),
+							level_request: GDLevelRequest::from(level_request)
+						})
+				})
+				.collect()),
+			Err(db_err) => {
+				error!(
+					"Error getting levels assigned to reviewer {}: {}",
+					reviewer_discord_id, db_err
+				);
+				Err(ReviewAssignmentError::DatabaseError(db_err))
+			}
+		}
+	}
+}
+
+impl<'a, A: ReviewAssignmentRepository, R: ReviewerRepository, L: RequestService>
+	LevelReviewAssignmentService<'a, A, R, L>
+{
+	pub fn new(
+		review_assignment_repository: &'a A,
+		reviewer_repository: &'a R,
+		level_request_service: L,
+		review_assignment_config: &'a ReviewAssignmentConfig
+	) -> Self {
+		LevelReviewAssignmentService {
+			review_assignment_repository,
+			reviewer_repository,
+			level_request_service,
+			review_assignment_config
+		}
+	}
+
+	/// Only levels that asked for feedback and have not been reviewed yet
+	/// can be assigned.
+	async fn get_assignable_level_request(
+		&self,
+		guild_id: u64,
+		level_id: u64
+	) -> Result<GDLevelRequest, ReviewAssignmentError> {
+		match self
+			.level_request_service
+			.get_level_request(guild_id, level_id, Some(true))
+			.await
+		{
+			Ok(level_request) => match level_request.status {
+				RequestStatus::Pending | RequestStatus::InReview => Ok(level_request),
+				status => {
+					warn!("Level {} is {:?} and cannot be assigned", level_id, status);
+					Err(ReviewAssignmentError::LevelNotReviewable(status))
+				}
+			},
+			Err(LevelRequestError::DatabaseError(db_err)) => {
+				Err(ReviewAssignmentError::DatabaseError(db_err))
+			}
+			Err(_) => Err(ReviewAssignmentError::LevelRequestDoesNotExist)
+		}
+	}
+
+	async fn check_active_reviewer(
+		&self,
+		guild_id: u64,
+		reviewer_discord_id: u64
+	) -> Result<(), ReviewAssignmentError> {
+		match self
+			.reviewer_repository
+			.get_record(guild_id, reviewer_discord_id, Some(true))
+			.await
+		{
+			Ok(Some(_)) => Ok(()),
+			Ok(None) => {
+				warn!(
+					"Reviewer {} does not exist or is not active",
+					reviewer_discord_id
+				);
+				Err(ReviewAssignmentError::ReviewerDoesNotExist)
+			}
+			Err(db_err) => {
+				error!(
+					"Error getting reviewer with ID {}: {}",
+					reviewer_discord_id, db_err
+				);
+				Err(ReviewAssignmentError::DatabaseError(db_err))
+			}
+		}
+	}
+
+	async fn pick_reviewer(
+		&self,
+		guild_id: u64,
+		now: &DateTime<Utc>
+	) -> Result<u64, ReviewAssignmentError> {
+		let reviewers = self
+			.reviewer_repository
+			.get_records(guild_id, Some(true))
+			.await;
+		let active_assignments = self
+			.review_assignment_repository
+			.get_active_records(guild_id, None, *now)
+			.await;
+		let latest_assignment = self
+			.review_assignment_repository
+			.get_latest_record(guild_id)
+			.await;
+
+		match (reviewers, active_assignments, latest_assignment) {
+			(Ok(reviewers), Ok(active_assignments), Ok(latest_assignment)) => {
+				let active_assignments: Vec<ReviewAssignment> = active_assignments
+					.into_iter()
+					.map(|(assignment, _)| ReviewAssignment::from(assignment))
+					.collect();
+
+				self.review_assignment_config
+					.assignment_policy
+					.pick_reviewer(
+						reviewers
+							.into_iter()
+							.map(|reviewer| reviewer.discord_id.into())
+							.collect(),
+						&active_assignments,
+						latest_assignment.map(|assignment| assignment.discord_id.into())
+					)
+					.ok_or_else(|| {
+						warn!("No active reviewers in guild {}", guild_id);
+						ReviewAssignmentError::NoActiveReviewers
+					})
+			}
+			(Err(db_err), _, _) | (_, Err(db_err), _) | (_, _, Err(db_err)) => {
+				error!("Error picking a reviewer to assign: {}", db_err);
+				Err(ReviewAssignmentError::DatabaseError(db_err))
+			}
+		}
+	}
+
+	async fn get_active_assignment(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		now: &DateTime<Utc>
+	) -> Result<Option<ReviewAssignment>, ReviewAssignmentError> {
+		self.review_assignment_repository
+			.get_active_record(guild_id, level_id, *now)
+			.await
+			.map(|assignment| assignment.map(ReviewAssignment::from))
+			.map_err(|db_err| {
+				error!(
+					"Error getting active assignment on level {}: {}",
+					level_id, db_err
+				);
+				ReviewAssignmentError::DatabaseError(db_err)
+			})
+	}
+
+	/// Starts the review of the level, moving it in review.
+	async fn create_assignment(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		reviewer_discord_id: u64,
+		assigned_by: u64,
+		now: &DateTime<Utc>
+	) -> Result<ReviewAssignment, ReviewAssignmentError> {
+		let mut assignment = ReviewAssignment {
+			assignment_id: 0,
+			guild_id,
+			level_id,
+			reviewer_discord_id,
+			assigned_by,
+			assigned_at: *now,
+			expires_at: *now + self.claim_duration(),
+			completed_at: None,
+			released_at: None
+		};
+		let mut create_assignment_storable: ActiveModel = assignment.into();
+		create_assignment_storable.id = ActiveValue::NotSet;
+
+		loop {
+			match self
+				.review_assignment_repository
+				.create_record_if_unassigned(create_assignment_storable.clone(), *now)
+				.await
+			{
+				Ok(Some(insert_result)) => {
+					assignment.assignment_id = insert_result.last_insert_id as u64;
+					info!(
+						"User {} assigned level {} to reviewer {}",
+						assigned_by, level_id, reviewer_discord_id
+					);
+					break;
+				}
+				// Assigned concurrently since it was last checked
+				Ok(None) => match self.get_active_assignment(guild_id, level_id, now).await? {
+					Some(active_assignment)
+						if active_assignment.reviewer_discord_id == reviewer_discord_id =>
+					{
+						return Ok(active_assignment);
+					}
+					Some(active_assignment) => {
+						warn!(
+							"Level {} was assigned to {} before it could be assigned to {}",
+							level_id, active_assignment.reviewer_discord_id, reviewer_discord_id
+						);
+						return Err(ReviewAssignmentError::AssignedToAnotherReviewer(
+							active_assignment.reviewer_discord_id
+						));
+					}
+					None => continue
+				},
+				Err(db_err) => {
+					error!(
+						"Error assigning level {} to reviewer {}: {}",
+						level_id, reviewer_discord_id, db_err
+					);
+					return Err(ReviewAssignmentError::DatabaseError(db_err));
+				}
+			}
+		}
+
+		match self
+			.level_request_service
+			.advance_level_request_status(guild_id, level_id, RequestStatus::InReview)
+			.await
+		{
+			Ok(_) => Ok(assignment),
+			Err(LevelRequestError::DatabaseError(db_err)) => {
+				Err(ReviewAssignmentError::DatabaseError(db_err))
+			}
+			Err(LevelRequestError::IllegalStatusTransition(status, _)) => {
+				Err(ReviewAssignmentError::LevelNotReviewable(status))
+			}
+			Err(_) => Err(ReviewAssignmentError::LevelRequestDoesNotExist)
+		}
+	}
+
+	async fn extend_assignment(
+		&self,
+		mut assignment: ReviewAssignment,
+		now: &DateTime<Utc>
+	) -> Result<ReviewAssignment, ReviewAssignmentError> {
+		assignment.expires_at = *now + self.claim_duration();
+		self.save_assignment(assignment).await
+	}
+
+	async fn end_assignment(
+		&self,
+		mut assignment: ReviewAssignment,
+		now: &DateTime<Utc>
+	) -> Result<ReviewAssignment, ReviewAssignmentError> {
+		assignment.released_at = Some(*now);
+		self.save_assignment(assignment).await
+	}
+
+	/// Moves the level back to pending once nobody is reviewing it anymore, so
+	/// that it can be picked from the queue again. Levels that have moved on
+	/// since are left alone.
+	async fn return_to_queue(
+		&self,
+		guild_id: u64,
+		level_id: u64
+	) -> Result<(), ReviewAssignmentError> {
+		match self
+			.level_request_service
+			.return_level_request_to_queue(guild_id, level_id)
+			.await
+		{
+			Ok(true) => {
+				info!("Level {} returned to the queue", level_id);
+				Ok(())
+			}
+			Ok(false) => Ok(()),
+			Err(LevelRequestError::DatabaseError(db_err)) => {
+				Err(ReviewAssignmentError::DatabaseError(db_err))
+			}
+			Err(_) => Err(ReviewAssignmentError::LevelRequestDoesNotExist)
+		}
+	}
+
+	async fn save_assignment(
+		&self,
+		assignment: ReviewAssignment
+	) -> Result<ReviewAssignment, ReviewAssignmentError> {
+		match self
+			.review_assignment_repository
+			.update_record(assignment.into())
+			.await
+		{
+			Ok(_) => Ok(assignment),
+			Err(db_err) => {
+				error!(
+					"Error updating assignment {}: {}",
+					assignment.assignment_id, db_err
+				);
+				Err(ReviewAssignmentError::DatabaseError(db_err))
+			}
+		}
+	}
+
+	fn claim_duration(&self) -> Duration {
+		Duration::minutes(self.review_assignment_config.claim_duration_in_minutes as i64)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::{Duration, Utc};
+	use mockall::{predicate::eq, Sequence};
+	use sea_orm::{ActiveValue, InsertResult};
+
+	use crate::{
+		adapter::mysql::{
+			model::review_assignment, review_assignment_repository::MockReviewAssignmentRepository,
+			reviewer_repository::MockReviewerRepository
+		},
+		domain::{
+			model::{
+				error::review_assignment_error::ReviewAssignmentError,
+				gd_level::{GDLevelRequest, RequestStatus},
+				review_assignment::AssignmentPolicy
+			},
+			service::{
+				level_review_assignment_service::LevelReviewAssignmentService,
+				request_service::MockRequestService,
+				review_assignment_service::ReviewAssignmentService
+			}
+		},
+		rocket::common::{
+			config::review_assignment_config::ReviewAssignmentConfig,
+			testing::{level_request_model, review_assignment_model, reviewer_model}
+		}
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_claim_level_should_assign_level_and_move_it_in_review() {
+		let mut mock_review_assignment_repository = MockReviewAssignmentRepository::new();
+		let mock_reviewer_repository = mock_reviewer_repository(vec![12345]);
+		let mut mock_request_service = mock_request_service(RequestStatus::Pending);
+
+		mock_review_assignment_repository
+			.expect_get_active_record()
+			.returning(|_, _, _| Ok(None));
+		mock_review_assignment_repository
+			.expect_create_record_if_unassigned()
+			.withf(|record, _| {
+				record.id == ActiveValue::NotSet
+					&& record.discord_id == ActiveValue::Set(12345.into())
+					&& record.assigned_by == ActiveValue::Set(12345.into())
+			})
+			.times(1)
+			.returning(|_, _| Ok(Some(InsertResult { last_insert_id: 7 })));
+		mock_request_service
+			.expect_advance_level_request_status()
+			.with(eq(3101), eq(99999999), eq(RequestStatus::InReview))
+			.times(1)
+			.returning(|guild_id, level_id, status| Ok(level_request(guild_id, level_id, status)));
+
+		let review_assignment_config = review_assignment_config(AssignmentPolicy::RoundRobin);
+		let service = LevelReviewAssignmentService::new(
+			&mock_review_assignment_repository,
+			&mock_reviewer_repository,
+			mock_request_service,
+			&review_assignment_config
+		);
+
+		let assignment = service.claim_level(3101, 99999999, 12345).await.unwrap();
+
+		assert_eq!(assignment.assignment_id, 7);
+		assert_eq!(assignment.reviewer_discord_id, 12345);
+		assert_eq!(
+			assignment.expires_at - assignment.assigned_at,
+			Duration::minutes(60)
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_claim_level_should_reject_level_claimed_concurrently() {
+		let mut mock_review_assignment_repository = MockReviewAssignmentRepository::new();
+		let mock_reviewer_repository = mock_reviewer_repository(vec![12345, 54321]);
+		let mut mock_request_service = mock_request_service(RequestStatus::Pending);
+		let mut sequence = Sequence::new();
+
+		mock_review_assignment_repository
+			.expect_get_active_record()
+			.times(1)
+			.in_sequence(&mut sequence)
+			.returning(|_, _, _| Ok(None));
+		mock_review_assignment_repository
+			.expect_create_record_if_unassigned()
+			.times(1)
+			.in_sequence(&mut sequence)
+			.returning(|_, _| Ok(None));
+		mock_review_assignment_repository
+			.expect_get_active_record()
+			.times(1)
+			.in_sequence(&mut sequence)
+			.returning(|guild_id, level_id, _| {
+				Ok(Some(review_assignment_model(guild_id, level_id, 54321)))
+			});
+		mock_request_service
+			.expect_advance_level_request_status()
+			.never();
+
+		let review_assignment_config = review_assignment_config(AssignmentPolicy::RoundRobin);
+		let service = LevelReviewAssignmentService::new(
+			&mock_review_assignment_repository,
+			&mock_reviewer_repository,
+			mock_request_service,
+			&review_assignment_config
+		);
+
+		assert_eq!(
+			service
+				.claim_level(3108, 99999999, 12345)
+				.await
+				.unwrap_err(),
+			ReviewAssignmentError::AssignedToAnotherReviewer(54321)
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_claim_level_should_reject_level_assigned_to_another_reviewer() {
+		let mut mock_review_assignment_repository = MockReviewAssignmentRepository::new();
+		let mock_reviewer_repository = mock_reviewer_repository(vec![12345, 54321]);
+		let mock_request_service = mock_request_service(RequestStatus::InReview);
+
+		mock_review_assignment_repository
+			.expect_get_active_record()
+			.returning(|guild_id, level_id, _| {
+				Ok(Some(review_assignment_model(guild_id, level_id, 54321)))
+			});
+		mock_review_assignment_repository
+			.expect_create_record_if_unassigned()
+			.never();
+
+		let review_assignment_config = review_assignment_config(AssignmentPolicy::RoundRobin);
+		let service = LevelReviewAssignmentService::new(
+			&mock_review_assignment_repository,
+			&mock_reviewer_repository,
+			mock_request_service,
+			&review_assignment_config
+		);
+
+		assert_eq!(
+			service
+				.claim_level(3102, 99999999, 12345)
+				.await
+				.unwrap_err(),
+			ReviewAssignmentError::AssignedToAnotherReviewer(54321)
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_claim_level_should_reject_reviewed_level() {
+		let mock_review_assignment_repository = MockReviewAssignmentRepository::new();
+		let mock_reviewer_repository = mock_reviewer_repository(vec![12345]);
+		let mock_request_service = mock_request_service(RequestStatus::Reviewed);
+
+		let review_assignment_config = review_assignment_config(AssignmentPolicy::RoundRobin);
+		let service = LevelReviewAssignmentService::new(
+			&mock_review_assignment_repository,
+			&mock_reviewer_repository,
+			mock_request_service,
+			&review_assignment_config
+		);
+
+		assert_eq!(
+			service
+				.claim_level(3103, 99999999, 12345)
+				.await
+				.unwrap_err(),
+			ReviewAssignmentError::LevelNotReviewable(RequestStatus::Reviewed)
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_assign_level_should_take_turns_between_reviewers() {
+		let mut mock_review_assignment_repository =
+			mock_review_assignment_repository_with_history();
+		let mock_reviewer_repository = mock_reviewer_repository(vec![100, 200, 300]);
+		let mut mock_request_service = mock_request_service(RequestStatus::Pending);
+
+		mock_review_assignment_repository
+			.expect_create_record_if_unassigned()
+			.withf(|record, _| record.discord_id == ActiveValue::Set(200.into()))
+			.times(1)
+			.returning(|_, _| Ok(Some(InsertResult { last_insert_id: 8 })));
+		mock_request_service
+			.expect_advance_level_request_status()
+			.returning(|guild_id, level_id, status| Ok(level_request(guild_id, level_id, status)));
+
+		let review_assignment_config = review_assignment_config(AssignmentPolicy::RoundRobin);
+		let service = LevelReviewAssignmentService::new(
+			&mock_review_assignment_repository,
+			&mock_reviewer_repository,
+			mock_request_service,
+			&review_assignment_config
+		);
+
+		let assignment = service.assign_level(3104, 99999999, None, 0).await.unwrap();
+
+		assert_eq!(assignment.reviewer_discord_id, 200);
+		assert_eq!(assignment.assigned_by, 0);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_assign_level_should_pick_least_loaded_reviewer() {
+		let mut mock_review_assignment_repository =
+			mock_review_assignment_repository_with_history();
+		let mock_reviewer_repository = mock_reviewer_repository(vec![100, 200, 300]);
+		let mut mock_request_service = mock_request_service(RequestStatus::Pending);
+
+		mock_review_assignment_repository
+			.expect_create_record_if_unassigned()
+			.withf(|record, _| record.discord_id == ActiveValue::Set(100.into()))
+			.times(1)
+			.returning(|_, _| Ok(Some(InsertResult { last_insert_id: 8 })));
+		mock_request_service
+			.expect_advance_level_request_status()
+			.returning(|guild_id, level_id, status| Ok(level_request(guild_id, level_id, status)));
+
+		let review_assignment_config = review_assignment_config(AssignmentPolicy::LeastLoaded);
+		let service = LevelReviewAssignmentService::new(
+			&mock_review_assignment_repository,
+			&mock_reviewer_repository,
+			mock_request_service,
+			&review_assignment_config
+		);
+
+		let assignment = service.assign_level(3105, 99999999, None, 0).await.unwrap();
+
+		assert_eq!(assignment.reviewer_discord_id, 100);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_assign_level_should_release_previous_reviewer() {
+		let mut mock_review_assignment_repository = MockReviewAssignmentRepository::new();
+		let mock_reviewer_repository = mock_reviewer_repository(vec![12345, 54321]);
+		let mut mock_request_service = mock_request_service(RequestStatus::InReview);
+
+		mock_review_assignment_repository
+			.expect_get_active_record()
+			.returning(|guild_id, level_id, _| {
+				Ok(Some(review_assignment_model(guild_id, level_id, 54321)))
+			});
+		mock_review_assignment_repository
+			.expect_update_record()
+			.withf(|record| {
+				record.discord_id == ActiveValue::Set(54321.into())
+					&& matches!(record.released_at, ActiveValue::Set(Some(_)))
+			})
+			.times(1)
+			.returning(|_| Ok(review_assignment_model(3106, 99999999, 54321)));
+		mock_review_assignment_repository
+			.expect_create_record_if_unassigned()
+			.withf(|record, _| record.discord_id == ActiveValue::Set(12345.into()))
+			.times(1)
+			.returning(|_, _| Ok(Some(InsertResult { last_insert_id: 2 })));
+		mock_request_service
+			.expect_advance_level_request_status()
+			.returning(|guild_id, level_id, status| Ok(level_request(guild_id, level_id, status)));
+
+		let review_assignment_config = review_assignment_config(AssignmentPolicy::RoundRobin);
+		let service = LevelReviewAssignmentService::new(
+			&mock_review_assignment_repository,
+			&mock_reviewer_repository,
+			mock_request_service,
+			&review_assignment_config
+		);
+
+		let assignment = service
+			.assign_level(3106, 99999999, Some(12345), 0)
+			.await
+			.unwrap();
+
+		assert_eq!(assignment.reviewer_discord_id, 12345);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_assign_level_should_return_error_without_active_reviewers() {
+		let mut mock_review_assignment_repository = MockReviewAssignmentRepository::new();
+		let mock_reviewer_repository = mock_reviewer_repository(vec![]);
+		let mock_request_service = mock_request_service(RequestStatus::Pending);
+
+		mock_review_assignment_repository
+			.expect_get_active_records()
+			.returning(|_, _, _| Ok(vec![]));
+		mock_review_assignment_repository
+			.expect_get_latest_record()
+			.returning(|_| Ok(None));
+
+		let review_assignment_config = review_assignment_config(AssignmentPolicy::LeastLoaded);
+		let service = LevelReviewAssignmentService::new(
+			&mock_review_assignment_repository,
+			&mock_reviewer_repository,
+			mock_request_service,
+			&review_assignment_config
+		);
+
+		assert_eq!(
+			service
+				.assign_level(3107, 99999999, None, 0)
+				.await
+				.unwrap_err(),
+			ReviewAssignmentError::NoActiveReviewers
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_release_level_should_only_let_moderators_release_other_reviewers() {
+		let mut mock_review_assignment_repository = MockReviewAssignmentRepository::new();
+		let mock_reviewer_repository = MockReviewerRepository::new();
+		let mut mock_request_service = MockRequestService::new();
+
+		mock_review_assignment_repository
+			.expect_get_active_record()
+			.returning(|guild_id, level_id, _| {
+				Ok(Some(review_assignment_model(guild_id, level_id, 54321)))
+			});
+		mock_review_assignment_repository
+			.expect_update_record()
+			.times(1)
+			.returning(|_| Ok(review_assignment_model(3108, 99999999, 54321)));
+		mock_request_service
+			.expect_return_level_request_to_queue()
+			.with(eq(3108), eq(99999999))
+			.times(1)
+			.returning(|_, _| Ok(true));
+
+		let review_assignment_config = review_assignment_config(AssignmentPolicy::RoundRobin);
+		let service = LevelReviewAssignmentService::new(
+			&mock_review_assignment_repository,
+			&mock_reviewer_repository,
+			mock_request_service,
+			&review_assignment_config
+		);
+
+		assert_eq!(
+			service
+				.release_level(3108, 99999999, 12345, false)
+				.await
+				.unwrap_err(),
+			ReviewAssignmentError::AssignedToAnotherReviewer(54321)
+		);
+		let released_assignment = service
+			.release_level(3108, 99999999, 12345, true)
+			.await
+			.unwrap();
+		assert!(released_assignment.released_at.is_some());
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_assigned_levels_should_skip_archived_levels() {
+		let mut mock_review_assignment_repository = MockReviewAssignmentRepository::new();
+		let mock_reviewer_repository = MockReviewerRepository::new();
+		let mock_request_service = MockRequestService::new();
+
+		mock_review_assignment_repository
+			.expect_get_active_records()
+			.with(eq(3109), eq(Some(12345)), mockall::predicate::always())
+			.returning(|guild_id, _, _| {
+				let mut archived_level_request =
+					level_request_model(guild_id, 11111111, 54321, RequestStatus::InReview);
+				archived_level_request.archived_at = Some(Utc::now());
+				Ok(vec![
+					(
+						review_assignment_model(guild_id, 99999999, 12345),
+						Some(level_request_model(
+							guild_id,
+							99999999,
+							54321,
+							RequestStatus::InReview
+						))
+					),
+					(
+						review_assignment_model(guild_id, 11111111, 12345),
+						Some(archived_level_request)
+					),
+				])
+			});
+
+		let review_assignment_config = review_assignment_config(AssignmentPolicy::RoundRobin);
+		let service = LevelReviewAssignmentService::new(
+			&mock_review_assignment_repository,
+			&mock_reviewer_repository,
+			mock_request_service,
+			&review_assignment_config
+		);
+
+		let assigned_levels = service.get_assigned_levels(3109, 12345).await.unwrap();
+
+		assert_eq!(assigned_levels.len(), 1);
+		assert_eq!(assigned_levels[0].level_request.level_id, 99999999);
+	}
+
+	fn review_assignment_config(assignment_policy: AssignmentPolicy) -> ReviewAssignmentConfig {
+		ReviewAssignmentConfig {
+			claim_duration_in_minutes: 60,
+			assignment_policy,
+			requeue_interval_in_seconds: 60
+		}
+	}
+
+	fn mock_reviewer_repository(reviewer_discord_ids: Vec<u64>) -> MockReviewerRepository {
+		let mut mock_reviewer_repository = MockReviewerRepository::new();
+		let active_reviewer_discord_ids = reviewer_discord_ids.clone();
+		mock_reviewer_repository
+			.expect_get_record()
+			.returning(move |guild_id, discord_id, _| {
+				Ok(active_reviewer_discord_ids
+					.contains(&discord_id)
+					.then(|| reviewer_model(guild_id, discord_id, true)))
+			});
+		mock_reviewer_repository
+			.expect_get_records()
+			.with(mockall::predicate::always(), eq(Some(true)))
+			.returning(move |guild_id, _| {
+				Ok(reviewer_discord_ids
+					.iter()
+					.map(|discord_id| reviewer_model(guild_id, *discord_id, true))
+					.collect())
+			});
+		mock_reviewer_repository
+	}
+
+	fn mock_request_service(status: RequestStatus) -> MockRequestService {
+		let mut mock_request_service = MockRequestService::new();
+		mock_request_service
+			.expect_get_level_request()
+			.with(mockall::predicate::always(), eq(99999999), eq(Some(true)))
+			.returning(move |guild_id, level_id, _| Ok(level_request(guild_id, level_id, status)));
+		mock_request_service
+	}
+
+	/// Reviewer 100 has no assignments, 200 and 300 one each, and 100 was
+	/// assigned last.
+	fn mock_review_assignment_repository_with_history() -> MockReviewAssignmentRepository {
+		let mut mock_review_assignment_repository = MockReviewAssignmentRepository::new();
+		mock_review_assignment_repository
+			.expect_get_active_record()
+			.returning(|_, _, _| Ok(None));
+		mock_review_assignment_repository
+			.expect_get_active_records()
+			.returning(|guild_id, _, _| {
+				Ok(vec![
+					(review_assignment_model(guild_id, 11111111, 200), None),
+					(review_assignment_model(guild_id, 22222222, 300), None),
+				])
+			});
+		mock_review_assignment_repository
+			.expect_get_latest_record()
+			.returning(|guild_id| {
+				Ok(Some(review_assignment::Model {
+					completed_at: Some(Utc::now()),
+					..review_assignment_model(guild_id, 33333333, 100)
+				}))
+			});
+		mock_review_assignment_repository
+	}
+
+	fn level_request(guild_id: u64, level_id: u64, status: RequestStatus) -> GDLevelRequest {
+		GDLevelRequest::from(level_request_model(guild_id, level_id, 54321, status))
+	}
+}
@@ -1,31 +1,58 @@
-use sea_orm::ActiveValue::Set;
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveValue::Set, Condition};
 
 use crate::{
-	adapter::mysql::{model::review::ActiveModel, review_repository::ReviewRepository},
+	adapter::mysql::{
+		model::review::ActiveModel, review_assignment_repository::ReviewAssignmentRepository,
+		review_repository::ReviewRepository, review_revision_repository::ReviewRevisionRepository
+	},
 	domain::{
 		model::{
 			error::{level_request_error::LevelRequestError, level_review_error::LevelReviewError},
-			review::LevelReview
+			gd_level::RequestStatus,
+			level_request_query::SortOrder,
+			review::{LevelReview, LevelReviewSubmission},
+			review_assignment::ReviewAssignment,
+			review_query::{LevelReviewFilter, LevelReviewPage},
+			review_revision::{ReviewRevision, ReviewRevisionDiff},
+			review_rubric::{LevelRubricSummary, ReviewRubric}
 		},
 		service::{request_service::RequestService, review_service::ReviewService}
 	},
-	rocket::common::config::client_config::CLIENT_CONFIG
+	rocket::common::{config::review_rubric_config::ReviewRubricConfig, constants::MAX_PAGE_SIZE}
 };
 
-pub struct LevelReviewService<'a, R: ReviewRepository, L: RequestService> {
+pub struct LevelReviewService<
+	'a,
+	R: ReviewRepository,
+	V: ReviewRevisionRepository,
+	A: ReviewAssignmentRepository,
+	L: RequestService
+> {
 	review_repository: &'a R,
-	level_request_service: &'a L
+	review_revision_repository: &'a V,
+	review_assignment_repository: &'a A,
+	level_request_service: L,
+	review_rubric_config: &'a ReviewRubricConfig
 }
 
-impl<'a, R: ReviewRepository, L: RequestService> ReviewService for LevelReviewService<'a, R, L> {
+impl<
+		'a,
+		R: ReviewRepository,
+		V: ReviewRevisionRepository,
+		A: ReviewAssignmentRepository,
+		L: RequestService
+	> ReviewService for LevelReviewService<'a, R, V, A, L>
+{
 	async fn get_level_review(
 		&self,
+		guild_id: u64,
 		level_id: u64,
 		discord_id: u64
 	) -> Result<LevelReview, LevelReviewError> {
 		match self
 			.review_repository
-			.get_record(level_id, discord_id)
+			.get_record(guild_id, level_id, discord_id)
 			.await
 		{
 			Ok(Some(level_review)) => Ok(LevelReview::from(level_review)),
@@ -46,37 +73,123 @@ impl<'a, R: ReviewRepository, L: RequestService> ReviewService for LevelReviewSe
 		}
 	}
 
+	async fn get_level_reviews(
+		&self,
+		guild_id: u64,
+		filter: LevelReviewFilter,
+		sort_order: SortOrder,
+		page: u64,
+		page_size: u64
+	) -> Result<LevelReviewPage, LevelReviewError> {
+		if page == 0 || page_size == 0 || page_size > MAX_PAGE_SIZE {
+			warn!("Invalid page {} with page size {}", page, page_size);
+			return Err(LevelReviewError::MalformedRequest);
+		}
+		let offset = match (page - 1).checked_mul(page_size) {
+			Some(offset) => offset,
+			None => {
+				warn!("Page {} with page size {} is out of range", page, page_size);
+				return Err(LevelReviewError::MalformedRequest);
+			}
+		};
+
+		let condition: Condition = filter.into();
+		let total_count = self
+			.review_repository
+			.count_records(guild_id, condition.clone())
+			.await
+			.map_err(|db_err| {
+				error!(
+					"Error counting level review records from database: {}",
+					db_err
+				);
+				LevelReviewError::DatabaseError(db_err)
+			})?;
+
+		self.review_repository
+			.get_records(guild_id, condition, sort_order.into(), offset, page_size)
+			.await
+			.map(|level_reviews| LevelReviewPage {
+				level_reviews: level_reviews.into_iter().map(LevelReview::from).collect(),
+				page,
+				page_size,
+				total_count
+			})
+			.map_err(|db_err| {
+				error!(
+					"Error getting level review records from database: {}",
+					db_err
+				);
+				LevelReviewError::DatabaseError(db_err)
+			})
+	}
+
 	async fn review_level(
 		&self,
+		guild_id: u64,
 		level_id: u64,
 		reviewer_discord_id: u64,
-		discord_message_id: u64,
-		review_contents: String
+		is_admin: bool,
+		level_review_submission: LevelReviewSubmission
 	) -> Result<LevelReview, LevelReviewError> {
-		let level_request_result = if reviewer_discord_id.eq(&CLIENT_CONFIG.discord_bot_admin_id) {
+		let LevelReviewSubmission {
+			discord_message_id,
+			review_contents,
+			rubric
+		} = level_review_submission;
+		self.check_rubric(&rubric)?;
+
+		let level_request_result = if is_admin {
 			self.level_request_service
-				.get_level_request(level_id, None)
+				.get_level_request(guild_id, level_id, None)
 				.await
 		} else {
 			self.level_request_service
-				.get_level_request(level_id, Some(true))
+				.get_level_request(guild_id, level_id, Some(true))
 				.await
 		};
 
-		match level_request_result {
+		let now = Utc::now();
+		let active_assignment = self.get_active_assignment(guild_id, level_id, &now).await?;
+		if let Some(active_assignment) = active_assignment {
+			if !is_admin && active_assignment.reviewer_discord_id != reviewer_discord_id {
+				warn!(
+					"Reviewer {} attempted to review level {} assigned to {}",
+					reviewer_discord_id, level_id, active_assignment.reviewer_discord_id
+				);
+				return Err(LevelReviewError::AssignedToAnotherReviewer(
+					active_assignment.reviewer_discord_id
+				));
+			}
+		}
+
+		let level_review_result = match level_request_result {
+			Ok(level_request) if level_request.status == RequestStatus::Rejected => {
+				warn!(
+					"Reviewer {} attempted to review rejected level {}",
+					reviewer_discord_id, level_id
+				);
+				Err(LevelReviewError::LevelNotReviewable(level_request.status))
+			}
 			Ok(level_request) => {
+				// Advanced before anything is written, so that a review which
+				// fails to save can simply be submitted again
+				self.mark_level_reviewed(guild_id, level_id).await?;
+
 				let mut level_review = LevelReview {
+					guild_id,
 					reviewer_discord_id,
 					discord_message_id,
 					level_id,
 					review_contents,
+					rubric,
 					is_update: false
 				};
 				let mut level_review_storable: ActiveModel = level_review.clone().into();
 
 				match self
 					.review_repository
-					.get_record(level_id, reviewer_discord_id)
+					.get_record(guild_id, level_id, reviewer_discord_id)
 					.await
 				{
 					Ok(Some(existing_level_review)) => {
@@ -84,11 +197,11 @@ impl<'a, R: ReviewRepository, L: RequestService> ReviewService for LevelReviewSe
 							"Updating existing level review for level: {:?}",
 							level_request
 						);
-						level_review.discord_message_id = existing_level_review.message_id;
+						level_review.discord_message_id = existing_level_review.message_id.into();
 						level_review_storable.review_content =
 							Set(level_review.clone().review_contents);
 						level_review_storable.message_id =
-							Set(level_review.clone().discord_message_id);
+							Set(level_review.clone().discord_message_id.into());
 
 						if let Err(update_error) = self
 							.review_repository
@@ -140,19 +253,40 @@ impl<'a, R: ReviewRepository, L: RequestService> ReviewService for LevelReviewSe
 			Err(_) => {
 				unreachable!()
 			}
+		}?;
+
+		self.append_revision(&level_review_result, discord_message_id, &now)
+			.await?;
+
+		if let Some(mut active_assignment) = active_assignment {
+			active_assignment.completed_at = Some(now);
+			if let Err(db_err) = self
+				.review_assignment_repository
+				.update_record(active_assignment.into())
+				.await
+			{
+				error!(
+					"Error completing assignment {}: {}",
+					active_assignment.assignment_id, db_err
+				);
+				return Err(LevelReviewError::DatabaseError(db_err));
+			}
 		}
+
+		Ok(level_review_result)
 	}
 
 	async fn update_level_request_thread_id(
 		&self,
+		guild_id: u64,
 		level_id: u64,
 		discord_id: u64,
 		discord_message_id: u64
 	) -> Result<(), LevelReviewError> {
-		match self.get_level_review(level_id, discord_id).await {
+		match self.get_level_review(guild_id, level_id, discord_id).await {
 			Ok(level_review) => {
 				let mut update_level_review_storable: ActiveModel = level_review.into();
-				update_level_review_storable.message_id = Set(discord_message_id);
+				update_level_review_storable.message_id = Set(discord_message_id.into());
 
 				if let Err(db_err) = self
 					.review_repository
@@ -175,13 +309,1267 @@ impl<'a, R: ReviewRepository, L: RequestService> ReviewService for LevelReviewSe
 			Err(error) => Err(error)
 		}
 	}
+
+	async fn get_review_revisions(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		discord_id: u64
+	) -> Result<Vec<ReviewRevision>, LevelReviewError> {
+		match self
+			.review_revision_repository
+			.get_records(guild_id, level_id, discord_id)
+			.await
+		{
+			Ok(review_revisions) => {
+				if review_revisions.is_empty() {
+					warn!(
+						"Level review for ID {} by {} does not exist",
+						level_id, discord_id
+					);
+					Err(LevelReviewError::LevelRequestDoesNotExist)
+				} else {
+					Ok(review_revisions
+						.into_iter()
+						.map(ReviewRevision::from)
+						.collect())
+				}
+			}
+			Err(db_err) => {
+				error!(
+					"Error getting revisions of level review for level {} by {}: {}",
+					level_id, discord_id, db_err
+				);
+				Err(LevelReviewError::DatabaseError(db_err))
+			}
+		}
+	}
+
+	async fn diff_review_revisions(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		discord_id: u64,
+		from_revision: u32,
+		to_revision: u32
+	) -> Result<ReviewRevisionDiff, LevelReviewError> {
+		let review_revisions = self
+			.get_review_revisions(guild_id, level_id, discord_id)
+			.await?;
+		let find_revision = |revision: u32| {
+			review_revisions
+				.iter()
+				.find(|review_revision| review_revision.revision == revision)
+				.cloned()
+				.ok_or_else(|| {
+					warn!(
+						"Revision {} of level review for ID {} by {} does not exist",
+						revision, level_id, discord_id
+					);
+					LevelReviewError::RevisionDoesNotExist(revision)
+				})
+		};
+
+		Ok(ReviewRevisionDiff::new(
+			find_revision(from_revision)?,
+			find_revision(to_revision)?
+		))
+	}
+
+	async fn get_level_rubric_summary(
+		&self,
+		guild_id: u64,
+		level_id: u64
+	) -> Result<LevelRubricSummary, LevelReviewError> {
+		match self
+			.review_repository
+			.get_level_records(guild_id, level_id)
+			.await
+		{
+			Ok(level_reviews) => Ok(LevelRubricSummary::new(
+				level_id,
+				&self.review_rubric_config.categories,
+				&level_reviews
+					.into_iter()
+					.map(LevelReview::from)
+					.collect::<Vec<LevelReview>>()
+			)),
+			Err(db_err) => {
+				error!(
+					"Error getting level reviews for level {}: {}",
+					level_id, db_err
+				);
+				Err(LevelReviewError::DatabaseError(db_err))
+			}
+		}
+	}
 }
 
-impl<'a, R: ReviewRepository, L: RequestService> LevelReviewService<'a, R, L> {
-	pub fn new(review_repository: &'a R, level_request_service: &'a L) -> Self {
+impl<
+		'a,
+		R: ReviewRepository,
+		V: ReviewRevisionRepository,
+		A: ReviewAssignmentRepository,
+		L: RequestService
+	> LevelReviewService<'a, R, V, A, L>
+{
+	pub fn new(
+		review_repository: &'a R,
+		review_revision_repository: &'a V,
+		review_assignment_repository: &'a A,
+		level_request_service: L,
+		review_rubric_config: &'a ReviewRubricConfig
+	) -> Self {
 		LevelReviewService {
 			review_repository,
-			level_request_service
+			review_revision_repository,
+			review_assignment_repository,
+			level_request_service,
+			review_rubric_config
+		}
+	}
+
+	/// Scores have to be in the configured categories and range, at most once
+	/// per category.
+	fn check_rubric(&self, rubric: &ReviewRubric) -> Result<(), LevelReviewError> {
+		for (index, rubric_score) in rubric.scores.iter().enumerate() {
+			if !self
+				.review_rubric_config
+				.has_category(&rubric_score.category)
+			{
+				warn!("Rubric category {} does not exist", rubric_score.category);
+				return Err(LevelReviewError::InvalidRubric);
+			}
+			if !self.review_rubric_config.is_valid_score(rubric_score.score) {
+				warn!(
+					"Rubric score {} for {} is out of range",
+					rubric_score.score, rubric_score.category
+				);
+				return Err(LevelReviewError::InvalidRubric);
+			}
+			if rubric.scores[..index]
+				.iter()
+				.any(|other_rubric_score| other_rubric_score.category == rubric_score.category)
+			{
+				warn!("Rubric category {} scored twice", rubric_score.category);
+				return Err(LevelReviewError::InvalidRubric);
+			}
+		}
+
+		if self.review_rubric_config.require_all_categories
+			&& rubric.scores.len() != self.review_rubric_config.categories.len()
+		{
+			warn!("Rubric is missing categories");
+			return Err(LevelReviewError::InvalidRubric);
+		}
+
+		Ok(())
+	}
+
+	/// Keeps the submitted review contents and rubric as the review's next
+	/// revision, so edits never lose the earlier review. The revision records
+	/// the message it was submitted with rather than the review's original one.
+	async fn append_revision(
+		&self,
+		level_review: &LevelReview,
+		discord_message_id: u64,
+		now: &DateTime<Utc>
+	) -> Result<ReviewRevision, LevelReviewError> {
+		let guild_id = level_review.guild_id;
+		let level_id = level_review.level_id;
+		let reviewer_discord_id = level_review.reviewer_discord_id;
+		let latest_revision = match self
+			.review_revision_repository
+			.get_latest_record(guild_id, level_id, reviewer_discord_id)
+			.await
+		{
+			Ok(latest_revision) => {
+				latest_revision.map_or(0, |latest_revision| latest_revision.revision)
+			}
+			Err(db_err) => {
+				error!(
+					"Error getting latest revision of level review for level {} by {}: {}",
+					level_id, reviewer_discord_id, db_err
+				);
+				return Err(LevelReviewError::DatabaseError(db_err));
+			}
+		};
+
+		let review_revision = ReviewRevision {
+			guild_id,
+			level_id,
+			reviewer_discord_id,
+			revision: latest_revision + 1,
+			discord_message_id,
+			review_contents: level_review.review_contents.clone(),
+			rubric: level_review.rubric.clone(),
+			created_at: *now
+		};
+		if let Err(db_err) = self
+			.review_revision_repository
+			.create_record(review_revision.clone().into())
+			.await
+		{
+			error!(
+				"Error inserting revision of level review for level {} by {}: {}",
+				level_id, reviewer_discord_id, db_err
+			);
+			return Err(LevelReviewError::DatabaseError(db_err));
+		}
+
+		Ok(review_revision)
+	}
+
+	async fn mark_level_reviewed(
+		&self,
+		guild_id: u64,
+		level_id: u64
+	) -> Result<(), LevelReviewError> {
+		match self
+			.level_request_service
+			.advance_level_request_status(guild_id, level_id, RequestStatus::Reviewed)
+			.await
+		{
+			Ok(_) => Ok(()),
+			Err(LevelRequestError::DatabaseError(db_err)) => {
+				Err(LevelReviewError::DatabaseError(db_err))
+			}
+			Err(LevelRequestError::IllegalStatusTransition(status, _)) => {
+				Err(LevelReviewError::LevelNotReviewable(status))
+			}
+			Err(_) => Err(LevelReviewError::LevelRequestDoesNotExist)
+		}
+	}
+
+	/// Only the reviewer a level is assigned to may review it while the
+	/// assignment lasts.
+	async fn get_active_assignment(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		now: &DateTime<Utc>
+	) -> Result<Option<ReviewAssignment>, LevelReviewError> {
+		self.review_assignment_repository
+			.get_active_record(guild_id, level_id, *now)
+			.await
+			.map(|assignment| assignment.map(ReviewAssignment::from))
+			.map_err(|db_err| {
+				error!(
+					"Error getting active assignment on level {}: {}",
+					level_id, db_err
+				);
+				LevelReviewError::DatabaseError(db_err)
+			})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::Utc;
+	use mockall::predicate::{always, eq};
+	use sea_orm::{ActiveValue, DbErr, InsertResult, Order};
+
+	use crate::{
+		adapter::mysql::{
+			model::{
+				review, review_assignment,
+				sea_orm_active_enums::{Rating, Score}
+			},
+			review_assignment_repository::MockReviewAssignmentRepository,
+			review_repository::MockReviewRepository,
+			review_revision_repository::MockReviewRevisionRepository
+		},
+		domain::{
+			model::{
+				error::{
+					level_request_error::LevelRequestError, level_review_error::LevelReviewError
+				},
+				gd_level::{GDLevelRequest, RequestRating, RequestStatus},
+				level_request_query::SortOrder,
+				moderator::{SuggestedRating, SuggestedScore},
+				review::{LevelReview, LevelReviewSubmission},
+				review_query::LevelReviewFilter,
+				review_revision::{ReviewDiffLine, ReviewLineChange, RubricScoreChange},
+				review_rubric::{ReviewRubric, RubricCategorySummary, RubricScore}
+			},
+			service::{
+				level_review_service::LevelReviewService, request_service::MockRequestService,
+				review_service::ReviewService
+			}
+		},
+		rocket::common::{
+			config::review_rubric_config::ReviewRubricConfig,
+			testing::{review_assignment_model, review_revision_model, YOUTUBE_VIDEO_LINK}
+		}
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_review_level_should_create_review_and_advance_request() {
+		let mut mock_review_repository = MockReviewRepository::new();
+		let mut mock_request_service = MockRequestService::new();
+
+		mock_request_service
+			.expect_get_level_request()
+			.with(eq(3001), eq(99999999), eq(Some(true)))
+			.returning(|guild_id, level_id, _| Ok(level_request(guild_id, level_id)));
+		mock_request_service
+			.expect_advance_level_request_status()
+			.with(eq(3001), eq(99999999), eq(RequestStatus::Reviewed))
+			.times(1)
+			.returning(|guild_id, level_id, _| Ok(level_request(guild_id, level_id)));
+		mock_review_repository
+			.expect_get_record()
+			.returning(|_, _, _| Ok(None));
+		mock_review_repository
+			.expect_create_record()
+			.withf(|record| {
+				record.review_content == ActiveValue::Set("Great level".to_string())
+					&& record.suggested_score == ActiveValue::Set(Some(Score::Five))
+					&& record.rubric_scores.clone().unwrap().is_some()
+			})
+			.times(1)
+			.returning(|_| {
+				Ok(InsertResult {
+					last_insert_id: (3001.into(), 99999999.into(), 12345.into())
+				})
+			});
+
+		let mock_review_revision_repository = mock_review_revision_repository();
+		let mock_review_assignment_repository = mock_review_assignment_repository();
+		let review_rubric_config = ReviewRubricConfig::default();
+		let service = LevelReviewService::new(
+			&mock_review_repository,
+			&mock_review_revision_repository,
+			&mock_review_assignment_repository,
+			mock_request_service,
+			&review_rubric_config
+		);
+		let level_review = service
+			.review_level(
+				3001,
+				99999999,
+				12345,
+				false,
+				level_review_submission(review_rubric())
+			)
+			.await
+			.unwrap();
+
+		assert_eq!(level_review.discord_message_id, 11111);
+		assert_eq!(level_review.rubric, review_rubric());
+		assert!(!level_review.is_update);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_review_level_should_not_save_review_when_request_cannot_advance() {
+		let mock_review_repository = MockReviewRepository::new();
+		let mock_review_revision_repository = MockReviewRevisionRepository::new();
+		let mut mock_request_service = MockRequestService::new();
+
+		mock_request_service
+			.expect_get_level_request()
+			.returning(|guild_id, level_id, _| Ok(level_request(guild_id, level_id)));
+		mock_request_service
+			.expect_advance_level_request_status()
+			.returning(|_, _, _| {
+				Err(LevelRequestError::DatabaseError(DbErr::Custom(
+					String::from("database unavailable")
+				)))
+			});
+
+		let mock_review_assignment_repository = mock_review_assignment_repository();
+		let review_rubric_config = ReviewRubricConfig::default();
+		let service = LevelReviewService::new(
+			&mock_review_repository,
+			&mock_review_revision_repository,
+			&mock_review_assignment_repository,
+			mock_request_service,
+			&review_rubric_config
+		);
+
+		assert_eq!(
+			service
+				.review_level(
+					3020,
+					99999999,
+					12345,
+					false,
+					level_review_submission(review_rubric())
+				)
+				.await
+				.unwrap_err(),
+			LevelReviewError::DatabaseError(DbErr::Custom(String::from("database unavailable")))
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_review_level_should_update_existing_review() {
+		let mut mock_review_repository = MockReviewRepository::new();
+		let mut mock_request_service = MockRequestService::new();
+
+		mock_request_service
+			.expect_get_level_request()
+			.with(eq(3002), eq(99999999), eq(None))
+			.returning(|guild_id, level_id, _| Ok(level_request(guild_id, level_id)));
+		mock_request_service
+			.expect_advance_level_request_status()
+			.returning(|guild_id, level_id, _| Ok(level_request(guild_id, level_id)));
+		mock_review_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id, discord_id| {
+				Ok(Some(review::Model {
+					guild_id: guild_id.into(),
+					level_id: level_id.into(),
+					discord_id: discord_id.into(),
+					message_id: 11111.into(),
+					review_content: "Old review".to_string(),
+					rubric_scores: None,
+					suggested_score: None,
+					suggested_rating: None
+				}))
+			});
+		mock_review_repository
+			.expect_update_record()
+			.withf(|record| {
+				record.review_content == ActiveValue::Set("New review".to_string())
+					&& record.message_id == ActiveValue::Set(11111.into())
+			})
+			.times(1)
+			.returning(|record| {
+				Ok(review::Model {
+					guild_id: record.guild_id.clone().unwrap(),
+					level_id: record.level_id.clone().unwrap(),
+					discord_id: record.discord_id.clone().unwrap(),
+					message_id: record.message_id.clone().unwrap(),
+					review_content: record.review_content.clone().unwrap(),
+					rubric_scores: None,
+					suggested_score: None,
+					suggested_rating: None
+				})
+			});
+		mock_review_repository.expect_create_record().never();
+		let mut mock_review_revision_repository = MockReviewRevisionRepository::new();
+		mock_review_revision_repository
+			.expect_get_latest_record()
+			.returning(|guild_id, level_id, discord_id| {
+				Ok(Some(review_revision_model(
+					guild_id,
+					level_id,
+					discord_id,
+					1,
+					"Old review"
+				)))
+			});
+		mock_review_revision_repository
+			.expect_create_record()
+			.withf(|record| {
+				record.revision == ActiveValue::Set(2)
+					&& record.message_id == ActiveValue::Set(22222.into())
+					&& record.review_content == ActiveValue::Set("New review".to_string())
+					&& record.rubric_scores
+						== ActiveValue::Set(serde_json::to_value(review_rubric().scores).ok())
+					&& record.suggested_score == ActiveValue::Set(Some(Score::Five))
+					&& record.suggested_rating == ActiveValue::Set(Some(Rating::Feature))
+			})
+			.times(1)
+			.returning(|_| Ok(InsertResult { last_insert_id: 2 }));
+
+		let mock_review_assignment_repository = mock_review_assignment_repository();
+		let review_rubric_config = ReviewRubricConfig::default();
+		let service = LevelReviewService::new(
+			&mock_review_repository,
+			&mock_review_revision_repository,
+			&mock_review_assignment_repository,
+			mock_request_service,
+			&review_rubric_config
+		);
+		let level_review = service
+			.review_level(
+				3002,
+				99999999,
+				12345,
+				true,
+				LevelReviewSubmission {
+					discord_message_id: 22222,
+					review_contents: "New review".to_string(),
+					rubric: review_rubric()
+				}
+			)
+			.await
+			.unwrap();
+
+		assert_eq!(level_review.discord_message_id, 11111);
+		assert!(level_review.is_update);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_review_level_should_return_error_when_level_was_rejected() {
+		let mut mock_review_repository = MockReviewRepository::new();
+		let mut mock_request_service = MockRequestService::new();
+
+		mock_request_service
+			.expect_get_level_request()
+			.returning(|guild_id, level_id, _| {
+				let mut rejected_level_request = level_request(guild_id, level_id);
+				rejected_level_request.status = RequestStatus::Rejected;
+				Ok(rejected_level_request)
+			});
+		mock_request_service
+			.expect_advance_level_request_status()
+			.never();
+		mock_review_repository.expect_create_record().never();
+		mock_review_repository.expect_update_record().never();
+		let mut mock_review_revision_repository = MockReviewRevisionRepository::new();
+		mock_review_revision_repository
+			.expect_create_record()
+			.never();
+
+		let mock_review_assignment_repository = mock_review_assignment_repository();
+		let review_rubric_config = ReviewRubricConfig::default();
+		let service = LevelReviewService::new(
+			&mock_review_repository,
+			&mock_review_revision_repository,
+			&mock_review_assignment_repository,
+			mock_request_service,
+			&review_rubric_config
+		);
+
+		assert_eq!(
+			service
+				.review_level(
+					3010,
+					99999999,
+					12345,
+					false,
+					level_review_submission(ReviewRubric::default())
+				)
+				.await
+				.unwrap_err(),
+			LevelReviewError::LevelNotReviewable(RequestStatus::Rejected)
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_review_level_should_return_error_when_feedback_was_not_requested() {
+		let mut mock_review_repository = MockReviewRepository::new();
+		let mut mock_request_service = MockRequestService::new();
+
+		mock_request_service
+			.expect_get_level_request()
+			.returning(|_, _, _| Err(LevelRequestError::LevelRequestDoesNotExist));
+		mock_request_service
+			.expect_advance_level_request_status()
+			.never();
+		mock_review_repository.expect_create_record().never();
+		let mut mock_review_revision_repository = MockReviewRevisionRepository::new();
+		mock_review_revision_repository
+			.expect_create_record()
+			.never();
+
+		let mock_review_assignment_repository = mock_review_assignment_repository();
+		let review_rubric_config = ReviewRubricConfig::default();
+		let service = LevelReviewService::new(
+			&mock_review_repository,
+			&mock_review_revision_repository,
+			&mock_review_assignment_repository,
+			mock_request_service,
+			&review_rubric_config
+		);
+
+		assert_eq!(
+			service
+				.review_level(
+					3003,
+					99999999,
+					12345,
+					false,
+					level_review_submission(ReviewRubric::default())
+				)
+				.await
+				.unwrap_err(),
+			LevelReviewError::LevelRequestDoesNotExist
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_level_review_should_return_error_when_review_does_not_exist() {
+		let mut mock_review_repository = MockReviewRepository::new();
+		let mock_request_service = MockRequestService::new();
+
+		mock_review_repository
+			.expect_get_record()
+			.returning(|_, _, _| Ok(None));
+
+		let mock_review_revision_repository = mock_review_revision_repository();
+		let mock_review_assignment_repository = mock_review_assignment_repository();
+		let review_rubric_config = ReviewRubricConfig::default();
+		let service = LevelReviewService::new(
+			&mock_review_repository,
+			&mock_review_revision_repository,
+			&mock_review_assignment_repository,
+			mock_request_service,
+			&review_rubric_config
+		);
+
+		assert_eq!(
+			service
+				.get_level_review(3004, 99999999, 12345)
+				.await
+				.unwrap_err(),
+			LevelReviewError::LevelRequestDoesNotExist
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_update_level_request_thread_id_should_return_database_error() {
+		let mut mock_review_repository = MockReviewRepository::new();
+		let mock_request_service = MockRequestService::new();
+
+		mock_review_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id, discord_id| {
+				Ok(Some(review::Model {
+					guild_id: guild_id.into(),
+					level_id: level_id.into(),
+					discord_id: discord_id.into(),
+					message_id: 11111.into(),
+					review_content: "Great level".to_string(),
+					rubric_scores: None,
+					suggested_score: None,
+					suggested_rating: None
+				}))
+			});
+		mock_review_repository
+			.expect_update_record()
+			.returning(|_| Err(DbErr::Custom("Connection lost".to_string())));
+
+		let mock_review_revision_repository = mock_review_revision_repository();
+		let mock_review_assignment_repository = mock_review_assignment_repository();
+		let review_rubric_config = ReviewRubricConfig::default();
+		let service = LevelReviewService::new(
+			&mock_review_repository,
+			&mock_review_revision_repository,
+			&mock_review_assignment_repository,
+			mock_request_service,
+			&review_rubric_config
+		);
+
+		assert_eq!(
+			service
+				.update_level_request_thread_id(3005, 99999999, 12345, 22222)
+				.await
+				.unwrap_err(),
+			LevelReviewError::DatabaseError(DbErr::Custom("Connection lost".to_string()))
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_review_level_should_reject_level_assigned_to_another_reviewer() {
+		let mut mock_review_repository = MockReviewRepository::new();
+		let mut mock_review_assignment_repository = MockReviewAssignmentRepository::new();
+		let mut mock_request_service = MockRequestService::new();
+
+		mock_request_service
+			.expect_get_level_request()
+			.returning(|guild_id, level_id, _| Ok(level_request(guild_id, level_id)));
+		mock_request_service
+			.expect_advance_level_request_status()
+			.never();
+		mock_review_assignment_repository
+			.expect_get_active_record()
+			.returning(|guild_id, level_id, _| {
+				Ok(Some(review_assignment_model(guild_id, level_id, 54321)))
+			});
+		mock_review_repository.expect_create_record().never();
+
+		let mock_review_revision_repository = mock_review_revision_repository();
+		let review_rubric_config = ReviewRubricConfig::default();
+		let service = LevelReviewService::new(
+			&mock_review_repository,
+			&mock_review_revision_repository,
+			&mock_review_assignment_repository,
+			mock_request_service,
+			&review_rubric_config
+		);
+
+		assert_eq!(
+			service
+				.review_level(
+					3006,
+					99999999,
+					12345,
+					false,
+					level_review_submission(ReviewRubric::default())
+				)
+				.await
+				.unwrap_err(),
+			LevelReviewError::AssignedToAnotherReviewer(54321)
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_review_level_should_complete_assignment() {
+		let mut mock_review_repository = MockReviewRepository::new();
+		let mut mock_review_assignment_repository = MockReviewAssignmentRepository::new();
+		let mut mock_request_service = MockRequestService::new();
+
+		mock_request_service
+			.expect_get_level_request()
+			.returning(|guild_id, level_id, _| Ok(level_request(guild_id, level_id)));
+		mock_request_service
+			.expect_advance_level_request_status()
+			.returning(|guild_id, level_id, _| Ok(level_request(guild_id, level_id)));
+		mock_review_repository
+			.expect_get_record()
+			.returning(|_, _, _| Ok(None));
+		mock_review_repository
+			.expect_create_record()
+			.returning(|_| {
+				Ok(InsertResult {
+					last_insert_id: (3007.into(), 99999999.into(), 12345.into())
+				})
+			});
+		mock_review_assignment_repository
+			.expect_get_active_record()
+			.returning(|guild_id, level_id, _| {
+				Ok(Some(review_assignment_model(guild_id, level_id, 12345)))
+			});
+		mock_review_assignment_repository
+			.expect_update_record()
+			.withf(|record| matches!(record.completed_at, ActiveValue::Set(Some(_))))
+			.times(1)
+			.returning(|_| {
+				Ok(review_assignment::Model {
+					completed_at: Some(Utc::now()),
+					..review_assignment_model(3007, 99999999, 12345)
+				})
+			});
+
+		let mock_review_revision_repository = mock_review_revision_repository();
+		let review_rubric_config = ReviewRubricConfig::default();
+		let service = LevelReviewService::new(
+			&mock_review_repository,
+			&mock_review_revision_repository,
+			&mock_review_assignment_repository,
+			mock_request_service,
+			&review_rubric_config
+		);
+
+		assert!(service
+			.review_level(
+				3007,
+				99999999,
+				12345,
+				false,
+				level_review_submission(ReviewRubric::default())
+			)
+			.await
+			.is_ok());
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_diff_review_revisions_should_compare_lines() {
+		let mock_review_repository = MockReviewRepository::new();
+		let mock_request_service = MockRequestService::new();
+		let mut mock_review_revision_repository = MockReviewRevisionRepository::new();
+
+		mock_review_revision_repository
+			.expect_get_records()
+			.with(eq(3008), eq(99999999), eq(12345))
+			.returning(|guild_id, level_id, discord_id| {
+				Ok(vec![
+					review_revision_model(
+						guild_id,
+						level_id,
+						discord_id,
+						1,
+						"Great level\nBad sync"
+					),
+					review_revision_model(
+						guild_id,
+						level_id,
+						discord_id,
+						2,
+						"Great level\nGood sync"
+					),
+				])
+			});
+
+		let mock_review_assignment_repository = mock_review_assignment_repository();
+		let review_rubric_config = ReviewRubricConfig::default();
+		let service = LevelReviewService::new(
+			&mock_review_repository,
+			&mock_review_revision_repository,
+			&mock_review_assignment_repository,
+			mock_request_service,
+			&review_rubric_config
+		);
+		let review_revision_diff = service
+			.diff_review_revisions(3008, 99999999, 12345, 1, 2)
+			.await
+			.unwrap();
+
+		assert_eq!(review_revision_diff.from.revision, 1);
+		assert_eq!(review_revision_diff.to.revision, 2);
+		assert_eq!(
+			review_revision_diff.lines,
+			vec![
+				ReviewDiffLine {
+					change: ReviewLineChange::Unchanged,
+					line: "Great level".to_string()
+				},
+				ReviewDiffLine {
+					change: ReviewLineChange::Removed,
+					line: "Bad sync".to_string()
+				},
+				ReviewDiffLine {
+					change: ReviewLineChange::Added,
+					line: "Good sync".to_string()
+				},
+			]
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_diff_review_revisions_should_compare_rubrics() {
+		let mock_review_repository = MockReviewRepository::new();
+		let mock_request_service = MockRequestService::new();
+		let mut mock_review_revision_repository = MockReviewRevisionRepository::new();
+
+		mock_review_revision_repository
+			.expect_get_records()
+			.with(eq(3010), eq(99999999), eq(12345))
+			.returning(|guild_id, level_id, discord_id| {
+				let mut from_review_revision =
+					review_revision_model(guild_id, level_id, discord_id, 1, "Great level");
+				from_review_revision.rubric_scores = serde_json::to_value(vec![
+					rubric_score("gameplay", 6),
+					rubric_score("decoration", 4),
+				])
+				.ok();
+				from_review_revision.suggested_score = Some(Score::Four);
+				let mut to_review_revision =
+					review_revision_model(guild_id, level_id, discord_id, 2, "Great level");
+				to_review_revision.rubric_scores = serde_json::to_value(vec![
+					rubric_score("gameplay", 6),
+					rubric_score("decoration", 7),
+					rubric_score("sync", 9),
+				])
+				.ok();
+				to_review_revision.suggested_score = Some(Score::Five);
+				Ok(vec![from_review_revision, to_review_revision])
+			});
+
+		let mock_review_assignment_repository = mock_review_assignment_repository();
+		let review_rubric_config = ReviewRubricConfig::default();
+		let service = LevelReviewService::new(
+			&mock_review_repository,
+			&mock_review_revision_repository,
+			&mock_review_assignment_repository,
+			mock_request_service,
+			&review_rubric_config
+		);
+		let review_revision_diff = service
+			.diff_review_revisions(3010, 99999999, 12345, 1, 2)
+			.await
+			.unwrap();
+
+		assert_eq!(
+			review_revision_diff.from.rubric.suggested_score,
+			Some(SuggestedScore::Four)
+		);
+		assert_eq!(
+			review_revision_diff.to.rubric.suggested_score,
+			Some(SuggestedScore::Five)
+		);
+		assert_eq!(
+			review_revision_diff.rubric_score_changes,
+			vec![
+				RubricScoreChange {
+					category: "decoration".to_string(),
+					from: Some(rubric_score("decoration", 4)),
+					to: Some(rubric_score("decoration", 7))
+				},
+				RubricScoreChange {
+					category: "sync".to_string(),
+					from: None,
+					to: Some(rubric_score("sync", 9))
+				},
+			]
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_diff_review_revisions_should_return_error_when_revision_does_not_exist() {
+		let mock_review_repository = MockReviewRepository::new();
+		let mock_request_service = MockRequestService::new();
+		let mut mock_review_revision_repository = MockReviewRevisionRepository::new();
+
+		mock_review_revision_repository
+			.expect_get_records()
+			.returning(|guild_id, level_id, discord_id| {
+				Ok(vec![review_revision_model(
+					guild_id,
+					level_id,
+					discord_id,
+					1,
+					"Great level"
+				)])
+			});
+
+		let mock_review_assignment_repository = mock_review_assignment_repository();
+		let review_rubric_config = ReviewRubricConfig::default();
+		let service = LevelReviewService::new(
+			&mock_review_repository,
+			&mock_review_revision_repository,
+			&mock_review_assignment_repository,
+			mock_request_service,
+			&review_rubric_config
+		);
+
+		assert_eq!(
+			service
+				.diff_review_revisions(3009, 99999999, 12345, 1, 3)
+				.await
+				.unwrap_err(),
+			LevelReviewError::RevisionDoesNotExist(3)
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_review_revisions_should_return_error_when_review_does_not_exist() {
+		let mock_review_repository = MockReviewRepository::new();
+		let mock_request_service = MockRequestService::new();
+		let mut mock_review_revision_repository = MockReviewRevisionRepository::new();
+
+		mock_review_revision_repository
+			.expect_get_records()
+			.returning(|_, _, _| Ok(vec![]));
+
+		let mock_review_assignment_repository = mock_review_assignment_repository();
+		let review_rubric_config = ReviewRubricConfig::default();
+		let service = LevelReviewService::new(
+			&mock_review_repository,
+			&mock_review_revision_repository,
+			&mock_review_assignment_repository,
+			mock_request_service,
+			&review_rubric_config
+		);
+
+		assert_eq!(
+			service
+				.get_review_revisions(3010, 99999999, 12345)
+				.await
+				.unwrap_err(),
+			LevelReviewError::LevelRequestDoesNotExist
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_level_reviews_should_return_page() {
+		let mut mock_review_repository = MockReviewRepository::new();
+		let mock_request_service = MockRequestService::new();
+
+		mock_review_repository
+			.expect_count_records()
+			.with(eq(3011), always())
+			.returning(|_, _| Ok(3));
+		mock_review_repository
+			.expect_get_records()
+			.with(eq(3011), always(), eq(Order::Desc), eq(2), eq(2))
+			.times(1)
+			.returning(|guild_id, _, _, _, _| {
+				Ok(vec![review::Model {
+					guild_id: guild_id.into(),
+					level_id: 99999999.into(),
+					discord_id: 12345.into(),
+					message_id: 11111.into(),
+					review_content: "Great level".to_string(),
+					rubric_scores: None,
+					suggested_score: None,
+					suggested_rating: None
+				}])
+			});
+
+		let mock_review_revision_repository = mock_review_revision_repository();
+		let mock_review_assignment_repository = mock_review_assignment_repository();
+		let review_rubric_config = ReviewRubricConfig::default();
+		let service = LevelReviewService::new(
+			&mock_review_repository,
+			&mock_review_revision_repository,
+			&mock_review_assignment_repository,
+			mock_request_service,
+			&review_rubric_config
+		);
+		let level_review_page = service
+			.get_level_reviews(
+				3011,
+				LevelReviewFilter {
+					reviewer_discord_id: Some(12345),
+					..LevelReviewFilter::default()
+				},
+				SortOrder::Descending,
+				2,
+				2
+			)
+			.await
+			.unwrap();
+
+		assert_eq!(level_review_page.level_reviews.len(), 1);
+		assert_eq!(level_review_page.level_reviews[0].level_id, 99999999);
+		assert_eq!(level_review_page.total_count, 3);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_level_reviews_should_reject_invalid_page() {
+		let mut mock_review_repository = MockReviewRepository::new();
+		let mock_request_service = MockRequestService::new();
+
+		mock_review_repository.expect_count_records().never();
+		mock_review_repository.expect_get_records().never();
+
+		let mock_review_revision_repository = mock_review_revision_repository();
+		let mock_review_assignment_repository = mock_review_assignment_repository();
+		let review_rubric_config = ReviewRubricConfig::default();
+		let service = LevelReviewService::new(
+			&mock_review_repository,
+			&mock_review_revision_repository,
+			&mock_review_assignment_repository,
+			mock_request_service,
+			&review_rubric_config
+		);
+
+		assert_eq!(
+			service
+				.get_level_reviews(
+					3012,
+					LevelReviewFilter::default(),
+					SortOrder::Ascending,
+					0,
+					25
+				)
+				.await
+				.unwrap_err(),
+			LevelReviewError::MalformedRequest
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_level_reviews_should_reject_page_offset_overflow() {
+		let mut mock_review_repository = MockReviewRepository::new();
+		let mock_request_service = MockRequestService::new();
+
+		mock_review_repository.expect_count_records().never();
+		mock_review_repository.expect_get_records().never();
+
+		let mock_review_revision_repository = mock_review_revision_repository();
+		let mock_review_assignment_repository = mock_review_assignment_repository();
+		let review_rubric_config = ReviewRubricConfig::default();
+		let service = LevelReviewService::new(
+			&mock_review_repository,
+			&mock_review_revision_repository,
+			&mock_review_assignment_repository,
+			mock_request_service,
+			&review_rubric_config
+		);
+
+		assert_eq!(
+			service
+				.get_level_reviews(
+					3019,
+					LevelReviewFilter::default(),
+					SortOrder::Ascending,
+					u64::MAX,
+					25
+				)
+				.await
+				.unwrap_err(),
+			LevelReviewError::MalformedRequest
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_review_level_should_reject_scores_outside_rubric() {
+		let mut mock_review_repository = MockReviewRepository::new();
+		let mut mock_request_service = MockRequestService::new();
+
+		mock_request_service.expect_get_level_request().never();
+		mock_review_repository.expect_create_record().never();
+
+		let mock_review_revision_repository = mock_review_revision_repository();
+		let mock_review_assignment_repository = mock_review_assignment_repository();
+		let review_rubric_config = ReviewRubricConfig::default();
+		let service = LevelReviewService::new(
+			&mock_review_repository,
+			&mock_review_revision_repository,
+			&mock_review_assignment_repository,
+			mock_request_service,
+			&review_rubric_config
+		);
+
+		for scores in [
+			vec![rubric_score("music", 5)],
+			vec![rubric_score("gameplay", 11)],
+			vec![rubric_score("gameplay", 5), rubric_score("gameplay", 6)]
+		] {
+			assert_eq!(
+				service
+					.review_level(
+						3013,
+						99999999,
+						12345,
+						false,
+						level_review_submission(ReviewRubric {
+							scores,
+							..ReviewRubric::default()
+						})
+					)
+					.await
+					.unwrap_err(),
+				LevelReviewError::InvalidRubric
+			);
+		}
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_level_rubric_summary_should_combine_reviewers() {
+		let mut mock_review_repository = MockReviewRepository::new();
+		let mock_request_service = MockRequestService::new();
+
+		mock_review_repository
+			.expect_get_level_records()
+			.with(eq(3014), eq(99999999))
+			.returning(|guild_id, level_id| {
+				let level_review = |discord_id: u64, rubric: ReviewRubric| -> review::Model {
+					let level_review = LevelReview {
+						guild_id,
+						reviewer_discord_id: discord_id,
+						discord_message_id: 11111,
+						level_id,
+						review_contents: "Great level".to_string(),
+						rubric,
+						is_update: false
+					};
+					let level_review_storable: review::ActiveModel = level_review.into();
+					review::Model::try_from(level_review_storable).unwrap()
+				};
+				Ok(vec![
+					level_review(12345, review_rubric()),
+					level_review(
+						23456,
+						ReviewRubric {
+							scores: vec![rubric_score("gameplay", 8)],
+							suggested_score: Some(SuggestedScore::Five),
+							suggested_rating: Some(SuggestedRating::Epic)
+						}
+					),
+					level_review(34567, ReviewRubric::default()),
+				])
+			});
+
+		let mock_review_revision_repository = mock_review_revision_repository();
+		let mock_review_assignment_repository = mock_review_assignment_repository();
+		let review_rubric_config = ReviewRubricConfig::default();
+		let service = LevelReviewService::new(
+			&mock_review_repository,
+			&mock_review_revision_repository,
+			&mock_review_assignment_repository,
+			mock_request_service,
+			&review_rubric_config
+		);
+		let level_rubric_summary = service
+			.get_level_rubric_summary(3014, 99999999)
+			.await
+			.unwrap();
+
+		assert_eq!(level_rubric_summary.review_count, 3);
+		assert_eq!(level_rubric_summary.categories.len(), 5);
+		assert_eq!(
+			level_rubric_summary.categories[0],
+			RubricCategorySummary {
+				category: "gameplay".to_string(),
+				score_count: 2,
+				average_score: Some(7.0),
+				min_score: Some(6),
+				max_score: Some(8)
+			}
+		);
+		assert_eq!(level_rubric_summary.categories[1].score_count, 0);
+		assert_eq!(level_rubric_summary.categories[1].average_score, None);
+		assert_eq!(
+			level_rubric_summary.suggested_scores,
+			vec![(SuggestedScore::Five, 2)]
+		);
+		assert_eq!(
+			level_rubric_summary.suggested_ratings,
+			vec![(SuggestedRating::Feature, 1), (SuggestedRating::Epic, 1)]
+		);
+	}
+
+	fn rubric_score(category: &str, score: u8) -> RubricScore {
+		RubricScore {
+			category: category.to_string(),
+			score,
+			comment: None
+		}
+	}
+
+	fn review_rubric() -> ReviewRubric {
+		ReviewRubric {
+			scores: vec![
+				rubric_score("gameplay", 6),
+				RubricScore {
+					category: "sync".to_string(),
+					score: 9,
+					comment: Some("Perfectly on beat".to_string())
+				},
+			],
+			suggested_score: Some(SuggestedScore::Five),
+			suggested_rating: Some(SuggestedRating::Feature)
+		}
+	}
+
+	fn level_review_submission(rubric: ReviewRubric) -> LevelReviewSubmission {
+		LevelReviewSubmission {
+			discord_message_id: 11111,
+			review_contents: "Great level".to_string(),
+			rubric
+		}
+	}
+
+	fn mock_review_revision_repository() -> MockReviewRevisionRepository {
+		let mut mock_review_revision_repository = MockReviewRevisionRepository::new();
+		mock_review_revision_repository
+			.expect_get_latest_record()
+			.returning(|_, _, _| Ok(None));
+		mock_review_revision_repository
+			.expect_create_record()
+			.returning(|_| Ok(InsertResult { last_insert_id: 1 }));
+		mock_review_revision_repository
+	}
+
+	fn mock_review_assignment_repository() -> MockReviewAssignmentRepository {
+		let mut mock_review_assignment_repository = MockReviewAssignmentRepository::new();
+		mock_review_assignment_repository
+			.expect_get_active_record()
+			.returning(|_, _, _| Ok(None));
+		mock_review_assignment_repository
+	}
+
+	fn level_request(guild_id: u64, level_id: u64) -> GDLevelRequest {
+		GDLevelRequest {
+			guild_id,
+			gd_level: None,
+			level_id,
+			discord_user_id: 54321,
+			discord_message_data: None,
+			request_rating: RequestRating::Two,
+			youtube_video_link: YOUTUBE_VIDEO_LINK.to_string(),
+			has_requested_feedback: true,
+			notify: false,
+			status: RequestStatus::InReview,
+			timestamp: Utc::now(),
+			skipped_at: None
 		}
 	}
 }
@@ -1,26 +1,37 @@
+use chrono::Utc;
 use sea_orm::ActiveValue;
 
 use crate::{
-	adapter::mysql::{model::reviewer::ActiveModel, reviewer_repository::ReviewerRepository},
+	adapter::mysql::{
+		model::reviewer::ActiveModel, reviewer_activity_repository::ReviewerActivityRepository,
+		reviewer_repository::ReviewerRepository
+	},
 	domain::{
-		model::{error::reviewer_error::ReviewerError, reviewer::Reviewer},
+		model::{
+			error::reviewer_error::ReviewerError,
+			reviewer::{Reviewer, ReviewerActivity}
+		},
 		service::reviewer_service::ReviewerService
 	}
 };
 
-pub struct LevelReviewerService<'a, R: ReviewerRepository> {
-	reviewer_repository: &'a R
+pub struct LevelReviewerService<'a, R: ReviewerRepository, A: ReviewerActivityRepository> {
+	reviewer_repository: &'a R,
+	reviewer_activity_repository: &'a A
 }
 
-impl<'a, R: ReviewerRepository> ReviewerService for LevelReviewerService<'a, R> {
+impl<'a, R: ReviewerRepository, A: ReviewerActivityRepository> ReviewerService
+	for LevelReviewerService<'a, R, A>
+{
 	async fn get_reviewer(
 		&self,
+		guild_id: u64,
 		reviewer_discord_id: u64,
 		include_active: Option<bool>
 	) -> Result<Reviewer, ReviewerError> {
 		match self
 			.reviewer_repository
-			.get_record(reviewer_discord_id, include_active)
+			.get_record(guild_id, reviewer_discord_id, include_active)
 			.await
 		{
 			Ok(Some(level_reviewer)) => Ok(Reviewer::from(level_reviewer)),
@@ -38,13 +49,18 @@ impl<'a, R: ReviewerRepository> ReviewerService for LevelReviewerService<'a, R>
 		}
 	}
 
-	async fn create_reviewer(&self, reviewer_discord_id: u64) -> Result<(), ReviewerError> {
-		match self.get_reviewer(reviewer_discord_id, None).await {
+	async fn create_reviewer(
+		&self,
+		guild_id: u64,
+		reviewer_discord_id: u64
+	) -> Result<(), ReviewerError> {
+		match self.get_reviewer(guild_id, reviewer_discord_id, None).await {
 			Ok(level_reviewer) => {
 				warn!(
 					"reviewer {} already exists, updating state",
 					reviewer_discord_id
 				);
+				let was_active = level_reviewer.is_active;
 				let mut create_reviewer_request: ActiveModel = level_reviewer.into();
 				create_reviewer_request.active = ActiveValue::Set(1);
 
@@ -59,9 +75,14 @@ impl<'a, R: ReviewerRepository> ReviewerService for LevelReviewerService<'a, R>
 					);
 					return Err(ReviewerError::DatabaseError(db_err));
 				}
+				if !was_active {
+					self.record_activity(guild_id, reviewer_discord_id, true)
+						.await?;
+				}
 			}
 			Err(ReviewerError::ReviewerDoesNotExist) => {
 				let level_reviewer = Reviewer {
+					guild_id,
 					discord_id: reviewer_discord_id,
 					is_active: true
 				};
@@ -77,14 +98,23 @@ impl<'a, R: ReviewerRepository> ReviewerService for LevelReviewerService<'a, R>
 					);
 					return Err(ReviewerError::DatabaseError(db_err));
 				}
+				self.record_activity(guild_id, reviewer_discord_id, true)
+					.await?;
 			}
 			Err(reviewer_error) => return Err(reviewer_error)
 		}
 		Ok(())
 	}
 
-	async fn remove_reviewer(&self, reviewer_discord_id: u64) -> Result<(), ReviewerError> {
-		match self.get_reviewer(reviewer_discord_id, Some(true)).await {
+	async fn remove_reviewer(
+		&self,
+		guild_id: u64,
+		reviewer_discord_id: u64
+	) -> Result<(), ReviewerError> {
+		match self
+			.get_reviewer(guild_id, reviewer_discord_id, Some(true))
+			.await
+		{
 			Ok(existing_level_reviewer) => {
 				let mut remove_reviewer_request: ActiveModel = existing_level_reviewer.into();
 				remove_reviewer_request.active = ActiveValue::Set(0);
@@ -100,6 +130,8 @@ impl<'a, R: ReviewerRepository> ReviewerService for LevelReviewerService<'a, R>
 					);
 					return Err(ReviewerError::DatabaseError(db_err));
 				}
+				self.record_activity(guild_id, reviewer_discord_id, false)
+					.await?;
 			}
 			Err(reviewer_error) => return Err(reviewer_error)
 		}
@@ -108,10 +140,244 @@ impl<'a, R: ReviewerRepository> ReviewerService for LevelReviewerService<'a, R>
 	}
 }
 
-impl<'a, R: ReviewerRepository> LevelReviewerService<'a, R> {
-	pub fn new(reviewer_repository: &'a R) -> Self {
+impl<'a, R: ReviewerRepository, A: ReviewerActivityRepository> LevelReviewerService<'a, R, A> {
+	pub fn new(reviewer_repository: &'a R, reviewer_activity_repository: &'a A) -> Self {
 		LevelReviewerService {
-			reviewer_repository
+			reviewer_repository,
+			reviewer_activity_repository
+		}
+	}
+
+	async fn record_activity(
+		&self,
+		guild_id: u64,
+		reviewer_discord_id: u64,
+		is_active: bool
+	) -> Result<(), ReviewerError> {
+		let reviewer_activity = ReviewerActivity {
+			guild_id,
+			discord_id: reviewer_discord_id,
+			is_active,
+			changed_at: Utc::now()
+		};
+
+		match self
+			.reviewer_activity_repository
+			.create_record(reviewer_activity.into())
+			.await
+		{
+			Ok(_) => Ok(()),
+			Err(db_err) => {
+				error!(
+					"Error recording activity of reviewer {}: {}",
+					reviewer_discord_id, db_err
+				);
+				Err(ReviewerError::DatabaseError(db_err))
+			}
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use mockall::predicate::eq;
+	use sea_orm::{ActiveValue, DbErr, InsertResult};
+
+	use crate::{
+		adapter::mysql::{
+			reviewer_activity_repository::MockReviewerActivityRepository,
+			reviewer_repository::MockReviewerRepository
+		},
+		domain::{
+			model::error::reviewer_error::ReviewerError,
+			service::{
+				level_reviewer_service::LevelReviewerService, reviewer_service::ReviewerService
+			}
+		},
+		rocket::common::testing::reviewer_model
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_create_reviewer_should_create_new_reviewer() {
+		let mut mock_reviewer_repository = MockReviewerRepository::new();
+		let mut mock_reviewer_activity_repository = MockReviewerActivityRepository::new();
+
+		mock_reviewer_repository
+			.expect_get_record()
+			.returning(|_, _, _| Ok(None));
+		mock_reviewer_repository
+			.expect_create_record()
+			.withf(|record| record.active == ActiveValue::Set(1))
+			.times(1)
+			.returning(|_| {
+				Ok(InsertResult {
+					last_insert_id: (4001.into(), 12345.into())
+				})
+			});
+
+		mock_reviewer_activity_repository
+			.expect_create_record()
+			.withf(|record| record.active == ActiveValue::Set(1))
+			.times(1)
+			.returning(|_| Ok(InsertResult { last_insert_id: 1 }));
+
+		let service = LevelReviewerService::new(
+			&mock_reviewer_repository,
+			&mock_reviewer_activity_repository
+		);
+
+		assert!(service.create_reviewer(4001, 12345).await.is_ok());
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_create_reviewer_should_reactivate_existing_reviewer() {
+		let mut mock_reviewer_repository = MockReviewerRepository::new();
+		let mut mock_reviewer_activity_repository = MockReviewerActivityRepository::new();
+
+		mock_reviewer_repository
+			.expect_get_record()
+			.with(eq(4002), eq(12345), eq(None))
+			.returning(|guild_id, discord_id, _| {
+				Ok(Some(reviewer_model(guild_id, discord_id, false)))
+			});
+		mock_reviewer_repository
+			.expect_update_record()
+			.withf(|record| record.active == ActiveValue::Set(1))
+			.times(1)
+			.returning(|record| {
+				Ok(reviewer_model(
+					record.guild_id.clone().unwrap().into(),
+					record.discord_id.clone().unwrap().into(),
+					true
+				))
+			});
+		mock_reviewer_repository.expect_create_record().never();
+
+		mock_reviewer_activity_repository
+			.expect_create_record()
+			.withf(|record| record.active == ActiveValue::Set(1))
+			.times(1)
+			.returning(|_| Ok(InsertResult { last_insert_id: 1 }));
+
+		let service = LevelReviewerService::new(
+			&mock_reviewer_repository,
+			&mock_reviewer_activity_repository
+		);
+
+		assert!(service.create_reviewer(4002, 12345).await.is_ok());
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_remove_reviewer_should_deactivate_reviewer() {
+		let mut mock_reviewer_repository = MockReviewerRepository::new();
+		let mut mock_reviewer_activity_repository = MockReviewerActivityRepository::new();
+
+		mock_reviewer_repository
+			.expect_get_record()
+			.with(eq(4003), eq(12345), eq(Some(true)))
+			.returning(|guild_id, discord_id, _| {
+				Ok(Some(reviewer_model(guild_id, discord_id, true)))
+			});
+		mock_reviewer_repository
+			.expect_update_record()
+			.withf(|record| record.active == ActiveValue::Set(0))
+			.times(1)
+			.returning(|record| {
+				Ok(reviewer_model(
+					record.guild_id.clone().unwrap().into(),
+					record.discord_id.clone().unwrap().into(),
+					false
+				))
+			});
+
+		mock_reviewer_activity_repository
+			.expect_create_record()
+			.withf(|record| record.active == ActiveValue::Set(0))
+			.times(1)
+			.returning(|_| Ok(InsertResult { last_insert_id: 1 }));
+
+		let service = LevelReviewerService::new(
+			&mock_reviewer_repository,
+			&mock_reviewer_activity_repository
+		);
+
+		assert!(service.remove_reviewer(4003, 12345).await.is_ok());
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_remove_reviewer_should_return_error_when_reviewer_does_not_exist() {
+		let mut mock_reviewer_repository = MockReviewerRepository::new();
+		let mut mock_reviewer_activity_repository = MockReviewerActivityRepository::new();
+
+		mock_reviewer_repository
+			.expect_get_record()
+			.returning(|_, _, _| Ok(None));
+		mock_reviewer_repository.expect_update_record().never();
+
+		mock_reviewer_activity_repository
+			.expect_create_record()
+			.never();
+
+		let service = LevelReviewerService::new(
+			&mock_reviewer_repository,
+			&mock_reviewer_activity_repository
+		);
+
+		assert_eq!(
+			service.remove_reviewer(4004, 12345).await.unwrap_err(),
+			ReviewerError::ReviewerDoesNotExist
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_reviewer_should_return_database_error() {
+		let mut mock_reviewer_repository = MockReviewerRepository::new();
+		let mock_reviewer_activity_repository = MockReviewerActivityRepository::new();
+
+		mock_reviewer_repository
+			.expect_get_record()
+			.returning(|_, _, _| Err(DbErr::Custom("Connection lost".to_string())));
+
+		let service = LevelReviewerService::new(
+			&mock_reviewer_repository,
+			&mock_reviewer_activity_repository
+		);
+
+		assert_eq!(
+			service.get_reviewer(4005, 12345, None).await.unwrap_err(),
+			ReviewerError::DatabaseError(DbErr::Custom("Connection lost".to_string()))
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_create_reviewer_should_not_record_activity_for_active_reviewer() {
+		let mut mock_reviewer_repository = MockReviewerRepository::new();
+		let mut mock_reviewer_activity_repository = MockReviewerActivityRepository::new();
+
+		mock_reviewer_repository
+			.expect_get_record()
+			.returning(|guild_id, discord_id, _| {
+				Ok(Some(reviewer_model(guild_id, discord_id, true)))
+			});
+		mock_reviewer_repository
+			.expect_update_record()
+			.times(1)
+			.returning(|record| {
+				Ok(reviewer_model(
+					record.guild_id.clone().unwrap().into(),
+					record.discord_id.clone().unwrap().into(),
+					true
+				))
+			});
+		mock_reviewer_activity_repository
+			.expect_create_record()
+			.never();
+
+		let service = LevelReviewerService::new(
+			&mock_reviewer_repository,
+			&mock_reviewer_activity_repository
+		);
+
+		assert!(service.create_reviewer(4006, 12345).await.is_ok());
+	}
+}
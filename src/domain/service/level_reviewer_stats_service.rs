@@ -0,0 +1,664 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+	adapter::mysql::{
+		moderator_repository::ModeratorRepository,
+		review_revision_repository::ReviewRevisionRepository,
+		reviewer_activity_repository::ReviewerActivityRepository,
+		reviewer_repository::ReviewerRepository
+	},
+	domain::{
+		model::{
+			error::reviewer_error::ReviewerError,
+			reviewer::{Reviewer, ReviewerActivity},
+			reviewer_stats::{
+				ReviewedLevel, ReviewerLeaderboardEntry, ReviewerStats, ReviewerStatsMetric,
+				StatsPeriod, StatsRange
+			}
+		},
+		service::reviewer_stats_service::ReviewerStatsService
+	},
+	rocket::common::constants::MAX_PAGE_SIZE
+};
+
+pub struct LevelReviewerStatsService<
+	'a,
+	R: ReviewerRepository,
+	A: ReviewerActivityRepository,
+	E: ReviewRevisionRepository,
+	M: ModeratorRepository
+> {
+	reviewer_repository: &'a R,
+	reviewer_activity_repository: &'a A,
+	review_revision_repository: &'a E,
+	moderator_repository: &'a M
+}
+
+impl<
+		'a,
+		R: ReviewerRepository,
+		A: ReviewerActivityRepository,
+		E: ReviewRevisionRepository,
+		M: ModeratorRepository
+	> ReviewerStatsService for LevelReviewerStatsService<'a, R, A, E, M>
+{
+	async fn get_reviewer_stats(
+		&self,
+		guild_id: u64,
+		reviewer_discord_id: u64,
+		range: StatsRange,
+		period: StatsPeriod
+	) -> Result<ReviewerStats, ReviewerError> {
+		if !range.is_valid() {
+			warn!("Invalid timestamp range: {:?}", range);
+			return Err(ReviewerError::MalformedRequest);
+		}
+
+		let reviewer = match self
+			.reviewer_repository
+			.get_record(guild_id, reviewer_discord_id, None)
+			.await
+		{
+			Ok(Some(reviewer)) => Reviewer::from(reviewer),
+			Ok(None) => {
+				warn!("Reviewer with ID: {} does not exist", reviewer_discord_id);
+				return Err(ReviewerError::ReviewerDoesNotExist);
+			}
+			Err(get_reviewer_error) => {
+				error!(
+					"Error getting reviewer with ID {}: {}",
+					reviewer_discord_id, get_reviewer_error
+				);
+				return Err(ReviewerError::DatabaseError(get_reviewer_error));
+			}
+		};
+		let reviewed_levels = self
+			.get_reviewed_levels(guild_id, Some(reviewer_discord_id), &range)
+			.await?;
+		let activity = self
+			.get_activity(guild_id, Some(reviewer_discord_id))
+			.await?;
+
+		Ok(ReviewerStats::new(
+			reviewer,
+			&reviewed_levels,
+			activity,
+			&range,
+			period
+		))
+	}
+
+	async fn get_reviewer_leaderboard(
+		&self,
+		guild_id: u64,
+		metric: ReviewerStatsMetric,
+		range: StatsRange,
+		period: StatsPeriod,
+		limit: u64
+	) -> Result<Vec<ReviewerLeaderboardEntry>, ReviewerError> {
+		if limit == 0 || limit > MAX_PAGE_SIZE {
+			warn!("Invalid leaderboard limit {}", limit);
+			return Err(ReviewerError::MalformedRequest);
+		}
+		if !range.is_valid() {
+			warn!("Invalid timestamp range: {:?}", range);
+			return Err(ReviewerError::MalformedRequest);
+		}
+
+		let reviewers = match self.reviewer_repository.get_records(guild_id, None).await {
+			Ok(reviewers) => reviewers,
+			Err(db_err) => {
+				error!("Error getting reviewers in guild {}: {}", guild_id, db_err);
+				return Err(ReviewerError::DatabaseError(db_err));
+			}
+		};
+		let reviewed_levels = self.get_reviewed_levels(guild_id, None, &range).await?;
+		let mut activity_by_reviewer: HashMap<u64, Vec<ReviewerActivity>> = HashMap::new();
+		for reviewer_activity in self.get_activity(guild_id, None).await? {
+			activity_by_reviewer
+				.entry(reviewer_activity.discord_id)
+				.or_default()
+				.push(reviewer_activity);
+		}
+
+		let reviewer_stats = reviewers
+			.into_iter()
+			.map(|reviewer| {
+				let reviewer = Reviewer::from(reviewer);
+				let activity = activity_by_reviewer
+					.remove(&reviewer.discord_id)
+					.unwrap_or_default();
+				ReviewerStats::new(reviewer, &reviewed_levels, activity, &range, period)
+			})
+			.collect();
+
+		Ok(metric.rank(reviewer_stats, limit as usize))
+	}
+}
+
+impl<
+		'a,
+		R: ReviewerRepository,
+		A: ReviewerActivityRepository,
+		E: ReviewRevisionRepository,
+		M: ModeratorRepository
+	> LevelReviewerStatsService<'a, R, A, E, M>
+{
+	pub fn new(
+		reviewer_repository: &'a R,
+		reviewer_activity_repository: &'a A,
+		review_revision_repository: &'a E,
+		moderator_repository: &'a M
+	) -> Self {
+		LevelReviewerStatsService {
+			reviewer_repository,
+			reviewer_activity_repository,
+			review_revision_repository,
+			moderator_repository
+		}
+	}
+
+	/// Levels only count as sent when sent after the review was written.
+	async fn get_reviewed_levels(
+		&self,
+		guild_id: u64,
+		reviewer_discord_id: Option<u64>,
+		range: &StatsRange
+	) -> Result<Vec<ReviewedLevel>, ReviewerError> {
+		let first_review_revisions = self
+			.review_revision_repository
+			.get_first_records(
+				guild_id,
+				reviewer_discord_id,
+				range.from_timestamp,
+				range.to_timestamp
+			)
+			.await
+			.map_err(|db_err| {
+				error!("Error getting reviews in guild {}: {}", guild_id, db_err);
+				ReviewerError::DatabaseError(db_err)
+			})?;
+		let sent_at: HashMap<u64, DateTime<Utc>> = self
+			.moderator_repository
+			.get_sent_records(guild_id, reviewer_discord_id, range.from_timestamp)
+			.await
+			.map_err(|db_err| {
+				error!(
+					"Error getting sent levels in guild {}: {}",
+					guild_id, db_err
+				);
+				ReviewerError::DatabaseError(db_err)
+			})?
+			.into_iter()
+			.filter_map(|moderator| Some((moderator.level_id.into(), moderator.sent_at?)))
+			.collect();
+
+		Ok(first_review_revisions
+			.into_iter()
+			.filter_map(|(review_revision, level_request)| {
+				let level_id: u64 = review_revision.level_id.into();
+				let reviewed_at = review_revision.created_at;
+
+				Some(ReviewedLevel {
+					level_id,
+					reviewer_discord_id: review_revision.discord_id.into(),
+					requested_at: level_request?.timestamp,
+					reviewed_at,
+					is_sent: sent_at
+						.get(&level_id)
+						.is_some_and(|sent_at| sent_at.gt(&reviewed_at))
+				})
+			})
+			.collect())
+	}
+
+	async fn get_activity(
+		&self,
+		guild_id: u64,
+		reviewer_discord_id: Option<u64>
+	) -> Result<Vec<ReviewerActivity>, ReviewerError> {
+		match self
+			.reviewer_activity_repository
+			.get_records(guild_id, reviewer_discord_id)
+			.await
+		{
+			Ok(reviewer_activity) => Ok(reviewer_activity
+				.into_iter()
+				.map(ReviewerActivity::from)
+				.collect()),
+			Err(db_err) => {
+				error!(
+					"Error getting reviewer activity in guild {}: {}",
+					guild_id, db_err
+				);
+				Err(ReviewerError::DatabaseError(db_err))
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::{DateTime, Duration, TimeZone, Utc};
+	use mockall::predicate::{always, eq};
+	use sea_orm::DbErr;
+
+	use crate::{
+		adapter::mysql::{
+			model::{moderator, review_revision, reviewer_activity, sea_orm_active_enums},
+			moderator_repository::MockModeratorRepository,
+			review_revision_repository::MockReviewRevisionRepository,
+			reviewer_activity_repository::MockReviewerActivityRepository,
+			reviewer_repository::MockReviewerRepository
+		},
+		domain::{
+			model::{
+				error::reviewer_error::ReviewerError,
+				gd_level::RequestStatus,
+				reviewer_stats::{PeriodReviewCount, ReviewerStatsMetric, StatsPeriod, StatsRange}
+			},
+			service::{
+				level_reviewer_stats_service::LevelReviewerStatsService,
+				reviewer_stats_service::ReviewerStatsService
+			}
+		},
+		rocket::common::testing::{level_request_model, reviewer_model}
+	};
+
+	/// Level 99999998 is sent after its reviews, level 99999999 before its
+	/// review.
+	fn reviews() -> Vec<(u64, u64, DateTime<Utc>)> {
+		vec![
+			(99999998, 11111, requested_at() + Duration::hours(2)),
+			(99999999, 11111, requested_at() + Duration::days(30)),
+			(99999998, 22222, requested_at() + Duration::hours(1)),
+		]
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_reviewer_stats_should_count_reviews_turnaround_and_sent_levels() {
+		let range = StatsRange::default();
+		let mock_reviewer_repository = mock_reviewer_repository();
+		let mock_reviewer_activity_repository = mock_reviewer_activity_repository();
+		let mock_review_revision_repository = mock_review_revision_repository(range);
+		let mock_moderator_repository = mock_moderator_repository(range);
+
+		let service = LevelReviewerStatsService::new(
+			&mock_reviewer_repository,
+			&mock_reviewer_activity_repository,
+			&mock_review_revision_repository,
+			&mock_moderator_repository
+		);
+
+		let reviewer_stats = service
+			.get_reviewer_stats(4101, 11111, range, StatsPeriod::Month)
+			.await
+			.unwrap();
+		assert_eq!(reviewer_stats.review_count, 2);
+		assert_eq!(
+			reviewer_stats.reviews_per_period,
+			vec![
+				PeriodReviewCount {
+					period_start: Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap(),
+					review_count: 1
+				},
+				PeriodReviewCount {
+					period_start: Utc.with_ymd_and_hms(2025, 7, 1, 0, 0, 0).unwrap(),
+					review_count: 1
+				},
+			]
+		);
+		assert_eq!(
+			reviewer_stats.average_turnaround,
+			Some(Duration::hours(361))
+		);
+		assert_eq!(reviewer_stats.sent_level_count, 1);
+		assert_eq!(reviewer_stats.activity.len(), 1);
+		assert!(reviewer_stats.activity[0].is_active);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_reviewer_stats_should_only_count_reviews_within_range() {
+		let range = StatsRange {
+			from_timestamp: Some(Utc.with_ymd_and_hms(2025, 7, 1, 0, 0, 0).unwrap()),
+			to_timestamp: None
+		};
+		let mock_reviewer_repository = mock_reviewer_repository();
+		let mock_reviewer_activity_repository = mock_reviewer_activity_repository();
+		let mock_review_revision_repository = mock_review_revision_repository(range);
+		let mock_moderator_repository = mock_moderator_repository(range);
+
+		let service = LevelReviewerStatsService::new(
+			&mock_reviewer_repository,
+			&mock_reviewer_activity_repository,
+			&mock_review_revision_repository,
+			&mock_moderator_repository
+		);
+
+		let reviewer_stats = service
+			.get_reviewer_stats(4102, 11111, range, StatsPeriod::Week)
+			.await
+			.unwrap();
+		assert_eq!(reviewer_stats.review_count, 1);
+		assert_eq!(
+			reviewer_stats.reviews_per_period,
+			vec![PeriodReviewCount {
+				period_start: Utc.with_ymd_and_hms(2025, 6, 30, 0, 0, 0).unwrap(),
+				review_count: 1
+			}]
+		);
+		assert_eq!(reviewer_stats.average_turnaround, Some(Duration::days(30)));
+		assert_eq!(reviewer_stats.sent_level_count, 0);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_reviewer_stats_should_return_error_when_reviewer_does_not_exist() {
+		let mock_reviewer_repository = mock_reviewer_repository();
+		let mock_reviewer_activity_repository = MockReviewerActivityRepository::new();
+		let mock_review_revision_repository = MockReviewRevisionRepository::new();
+		let mock_moderator_repository = MockModeratorRepository::new();
+
+		let service = LevelReviewerStatsService::new(
+			&mock_reviewer_repository,
+			&mock_reviewer_activity_repository,
+			&mock_review_revision_repository,
+			&mock_moderator_repository
+		);
+
+		assert_eq!(
+			service
+				.get_reviewer_stats(4103, 44444, StatsRange::default(), StatsPeriod::Month)
+				.await
+				.unwrap_err(),
+			ReviewerError::ReviewerDoesNotExist
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_reviewer_leaderboard_should_rank_reviewers_with_reviews() {
+		let range = StatsRange::default();
+		let mock_reviewer_repository = mock_reviewer_repository();
+		let mock_reviewer_activity_repository = mock_reviewer_activity_repository();
+		let mock_review_revision_repository = mock_review_revision_repository(range);
+		let mock_moderator_repository = mock_moderator_repository(range);
+
+		let service = LevelReviewerStatsService::new(
+			&mock_reviewer_repository,
+			&mock_reviewer_activity_repository,
+			&mock_review_revision_repository,
+			&mock_moderator_repository
+		);
+
+		let leaderboard = service
+			.get_reviewer_leaderboard(
+				4104,
+				ReviewerStatsMetric::ReviewCount,
+				range,
+				StatsPeriod::Month,
+				25
+			)
+			.await
+			.unwrap();
+		assert_eq!(leaderboard.len(), 2);
+		assert_eq!(leaderboard[0].rank, 1);
+		assert_eq!(leaderboard[0].reviewer_stats.discord_id, 11111);
+		assert_eq!(leaderboard[1].rank, 2);
+		assert_eq!(leaderboard[1].reviewer_stats.discord_id, 22222);
+		assert!(!leaderboard[1].reviewer_stats.is_active);
+		assert_eq!(leaderboard[1].reviewer_stats.activity.len(), 2);
+
+		let leaderboard = service
+			.get_reviewer_leaderboard(
+				4104,
+				ReviewerStatsMetric::AverageTurnaround,
+				range,
+				StatsPeriod::Month,
+				1
+			)
+			.await
+			.unwrap();
+		assert_eq!(leaderboard.len(), 1);
+		assert_eq!(leaderboard[0].reviewer_stats.discord_id, 22222);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_reviewer_leaderboard_should_share_rank_between_tied_reviewers() {
+		let range = StatsRange {
+			from_timestamp: Some(requested_at()),
+			to_timestamp: Some(requested_at() + Duration::days(1))
+		};
+		let mock_reviewer_repository = mock_reviewer_repository();
+		let mock_reviewer_activity_repository = mock_reviewer_activity_repository();
+		let mock_review_revision_repository = mock_review_revision_repository(range);
+		let mock_moderator_repository = mock_moderator_repository(range);
+
+		let service = LevelReviewerStatsService::new(
+			&mock_reviewer_repository,
+			&mock_reviewer_activity_repository,
+			&mock_review_revision_repository,
+			&mock_moderator_repository
+		);
+
+		let leaderboard = service
+			.get_reviewer_leaderboard(
+				4105,
+				ReviewerStatsMetric::SentLevelCount,
+				range,
+				StatsPeriod::Month,
+				25
+			)
+			.await
+			.unwrap();
+		assert_eq!(
+			leaderboard
+				.iter()
+				.map(|entry| (entry.rank, entry.reviewer_stats.discord_id))
+				.collect::<Vec<(u32, u64)>>(),
+			vec![(1, 11111), (1, 22222)]
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_reviewer_leaderboard_should_reject_invalid_limit() {
+		let mock_reviewer_repository = MockReviewerRepository::new();
+		let mock_reviewer_activity_repository = MockReviewerActivityRepository::new();
+		let mock_review_revision_repository = MockReviewRevisionRepository::new();
+		let mock_moderator_repository = MockModeratorRepository::new();
+
+		let service = LevelReviewerStatsService::new(
+			&mock_reviewer_repository,
+			&mock_reviewer_activity_repository,
+			&mock_review_revision_repository,
+			&mock_moderator_repository
+		);
+
+		assert_eq!(
+			service
+				.get_reviewer_leaderboard(
+					4106,
+					ReviewerStatsMetric::ReviewCount,
+					StatsRange::default(),
+					StatsPeriod::Month,
+					0
+				)
+				.await
+				.unwrap_err(),
+			ReviewerError::MalformedRequest
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_reviewer_leaderboard_should_return_database_error() {
+		let mut mock_reviewer_repository = MockReviewerRepository::new();
+		let mock_reviewer_activity_repository = MockReviewerActivityRepository::new();
+		let mock_review_revision_repository = MockReviewRevisionRepository::new();
+		let mock_moderator_repository = MockModeratorRepository::new();
+
+		mock_reviewer_repository
+			.expect_get_records()
+			.with(eq(4107), eq(None))
+			.returning(|_, _| Err(DbErr::Custom("Connection lost".to_string())));
+
+		let service = LevelReviewerStatsService::new(
+			&mock_reviewer_repository,
+			&mock_reviewer_activity_repository,
+			&mock_review_revision_repository,
+			&mock_moderator_repository
+		);
+
+		assert_eq!(
+			service
+				.get_reviewer_leaderboard(
+					4107,
+					ReviewerStatsMetric::ReviewCount,
+					StatsRange::default(),
+					StatsPeriod::Month,
+					25
+				)
+				.await
+				.unwrap_err(),
+			ReviewerError::DatabaseError(DbErr::Custom("Connection lost".to_string()))
+		);
+	}
+
+	fn requested_at() -> DateTime<Utc> { Utc.with_ymd_and_hms(2025, 6, 2, 0, 0, 0).unwrap() }
+
+	fn mock_reviewer_repository() -> MockReviewerRepository {
+		let mut mock_reviewer_repository = MockReviewerRepository::new();
+		mock_reviewer_repository
+			.expect_get_record()
+			.returning(|guild_id, discord_id, _| {
+				Ok([11111, 22222, 33333]
+					.contains(&discord_id)
+					.then(|| reviewer_model(guild_id, discord_id, discord_id != 22222)))
+			});
+		mock_reviewer_repository
+			.expect_get_records()
+			.with(always(), eq(None))
+			.returning(|guild_id, _| {
+				Ok([11111, 22222, 33333]
+					.into_iter()
+					.map(|discord_id| reviewer_model(guild_id, discord_id, discord_id != 22222))
+					.collect())
+			});
+		mock_reviewer_repository
+	}
+
+	fn mock_reviewer_activity_repository() -> MockReviewerActivityRepository {
+		let mut mock_reviewer_activity_repository = MockReviewerActivityRepository::new();
+		mock_reviewer_activity_repository
+			.expect_get_records()
+			.returning(|guild_id, discord_id| {
+				Ok([(11111, 1), (22222, 1), (33333, 1), (22222, 0)]
+					.into_iter()
+					.filter(|(reviewer_discord_id, _)| {
+						discord_id.map_or(true, |discord_id| discord_id == *reviewer_discord_id)
+					})
+					.enumerate()
+					.map(
+						|(id, (reviewer_discord_id, active))| reviewer_activity::Model {
+							id: id as i32,
+							guild_id: guild_id.into(),
+							discord_id: reviewer_discord_id.into(),
+							active,
+							changed_at: requested_at() - Duration::days(30)
+						}
+					)
+					.collect())
+			});
+		mock_reviewer_activity_repository
+	}
+
+	fn filtered_reviews(
+		reviewer_discord_id: Option<u64>,
+		range: StatsRange
+	) -> Vec<(u64, u64, DateTime<Utc>)> {
+		reviews()
+			.into_iter()
+			.filter(|(_, discord_id, reviewed_at)| {
+				reviewer_discord_id.map_or(true, |reviewer_discord_id| {
+					reviewer_discord_id == *discord_id
+				}) && range.contains(reviewed_at)
+			})
+			.collect()
+	}
+
+	fn mock_review_revision_repository(range: StatsRange) -> MockReviewRevisionRepository {
+		let mut mock_review_revision_repository = MockReviewRevisionRepository::new();
+		mock_review_revision_repository
+			.expect_get_first_records()
+			.with(
+				always(),
+				always(),
+				eq(range.from_timestamp),
+				eq(range.to_timestamp)
+			)
+			.returning(move |guild_id, discord_id, _, _| {
+				Ok(filtered_reviews(discord_id, range)
+					.into_iter()
+					.map(|(level_id, reviewer_discord_id, reviewed_at)| {
+						let status = match level_id {
+							99999999 => RequestStatus::Sent,
+							_ => RequestStatus::Reviewed
+						};
+						let mut level_request =
+							level_request_model(guild_id, level_id, 54321, status);
+						level_request.timestamp = requested_at();
+						(
+							review_revision::Model {
+								id: 1,
+								guild_id: guild_id.into(),
+								level_id: level_id.into(),
+								discord_id: reviewer_discord_id.into(),
+								revision: 1,
+								message_id: 11111.into(),
+								review_content: "Great level".to_string(),
+								created_at: reviewed_at,
+								rubric_scores: None,
+								suggested_score: None,
+								suggested_rating: None
+							},
+							Some(level_request)
+						)
+					})
+					.collect())
+			});
+		mock_review_revision_repository
+	}
+
+	fn mock_moderator_repository(range: StatsRange) -> MockModeratorRepository {
+		let mut mock_moderator_repository = MockModeratorRepository::new();
+		mock_moderator_repository
+			.expect_get_sent_records()
+			.with(always(), always(), eq(range.from_timestamp))
+			.returning(|guild_id, discord_id, sent_from| {
+				Ok([
+					(99999998, requested_at() + Duration::hours(3)),
+					(99999999, requested_at() + Duration::days(1))
+				]
+				.into_iter()
+				.filter(|(level_id, sent_at)| {
+					sent_from.map_or(true, |sent_from| sent_from.le(sent_at))
+						&& reviews()
+							.iter()
+							.any(|(reviewed_level_id, reviewer_discord_id, _)| {
+								reviewed_level_id == level_id
+									&& discord_id.map_or(true, |discord_id| {
+										discord_id == *reviewer_discord_id
+									})
+							})
+				})
+				.map(|(level_id, sent_at)| moderator::Model {
+					guild_id: guild_id.into(),
+					level_id: level_id.into(),
+					score: sea_orm_active_enums::Score::Rated,
+					rating: sea_orm_active_enums::Rating::Rate,
+					sent_at: Some(sent_at)
+				})
+				.collect())
+			});
+		mock_moderator_repository
+	}
+}
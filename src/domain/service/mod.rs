@@ -1,13 +1,23 @@
 pub mod request_service;
 
 pub mod auth_service;
+pub mod ban_service;
 pub mod discord_user_service;
 pub mod internal;
+pub mod level_queue_service;
 pub mod level_request_service;
+pub mod level_review_assignment_service;
 pub mod level_review_service;
 pub mod level_reviewer_service;
+pub mod level_reviewer_stats_service;
 pub mod moderate_service;
 pub mod moderator_service;
+pub mod queue_service;
+pub mod request_ban_service;
+pub mod review_assignment_service;
 pub mod review_service;
 pub mod reviewer_service;
+pub mod reviewer_stats_service;
+pub mod role_service;
+pub mod user_role_service;
 pub mod user_service;
@@ -7,6 +7,7 @@ use crate::domain::model::{
 pub trait ModerateService {
 	async fn send_level(
 		&self,
+		guild_id: u64,
 		level_id: u64,
 		suggested_rating: SuggestedRating,
 		suggested_score: SuggestedScore
@@ -1,3 +1,4 @@
+use chrono::Utc;
 use sea_orm::{ActiveValue, IntoActiveModel};
 
 use crate::{
@@ -11,7 +12,7 @@ use crate::{
 	domain::{
 		model::{
 			error::moderator_error::ModeratorError,
-			gd_level::GDLevelRequest,
+			gd_level::{GDLevelRequest, RequestStatus},
 			moderator::{Moderator, SuggestedRating, SuggestedScore}
 		},
 		service::{
@@ -38,23 +39,35 @@ impl<'a, R: ModeratorRepository, L: LevelRequestRepository, G: GeometryDashClien
 {
 	async fn send_level(
 		&self,
+		guild_id: u64,
 		level_id: u64,
 		suggested_rating: SuggestedRating,
 		suggested_score: SuggestedScore
 	) -> Result<GDLevelRequest, ModeratorError> {
 		let moderator_data = Moderator {
+			guild_id,
 			level_id,
 			suggested_score,
-			suggested_rating
+			suggested_rating,
+			sent_at: Some(Utc::now())
 		};
 
 		match self
 			.level_request_repository
-			.get_record(moderator_data.level_id)
+			.get_record(guild_id, moderator_data.level_id)
 			.await
 		{
 			Ok(Some(level_request)) => {
-				if self.request_manager.get_enable_gd_request()
+				let level_request_status = RequestStatus::from(level_request.status.clone());
+				if level_request_status == RequestStatus::Rejected {
+					error!(
+						"Cannot send rejected level request with ID {}",
+						moderator_data.level_id
+					);
+					return Err(ModeratorError::UnsendableLevel);
+				}
+
+				if self.request_manager.get_enable_gd_request(guild_id)
 					&& (moderator_data.suggested_score != SuggestedScore::NoRate
 						&& moderator_data.suggested_score != SuggestedScore::Rated)
 				{
@@ -66,7 +79,7 @@ impl<'a, R: ModeratorRepository, L: LevelRequestRepository, G: GeometryDashClien
 
 				match self
 					.moderator_repository
-					.get_record(moderator_data.level_id)
+					.get_record(guild_id, moderator_data.level_id)
 					.await
 				{
 					Ok(Some(level_send)) => {
@@ -83,6 +96,7 @@ impl<'a, R: ModeratorRepository, L: LevelRequestRepository, G: GeometryDashClien
 							ActiveValue::Set(moderator_data.suggested_rating.into());
 						previous_level_send.score =
 							ActiveValue::Set(moderator_data.suggested_score.into());
+						previous_level_send.sent_at = ActiveValue::Set(moderator_data.sent_at);
 
 						if let Err(update_error) = self
 							.moderator_repository
@@ -114,6 +128,28 @@ impl<'a, R: ModeratorRepository, L: LevelRequestRepository, G: GeometryDashClien
 						return Err(ModeratorError::DatabaseError(db_error));
 					}
 				}
+				let mut level_request = level_request;
+				for advance_step in level_request_status
+					.advance_steps_to(RequestStatus::Sent)
+					.unwrap_or_default()
+				{
+					let mut advanced_level_request = level_request.into_active_model();
+					advanced_level_request.status = ActiveValue::Set(advance_step.into());
+					level_request = match self
+						.level_request_repository
+						.update_record(advanced_level_request)
+						.await
+					{
+						Ok(updated_level_request) => updated_level_request,
+						Err(update_error) => {
+							error!(
+								"Error updating level request status from database: {}",
+								update_error
+							);
+							return Err(ModeratorError::DatabaseError(update_error));
+						}
+					};
+				}
 				Ok(GDLevelRequest::from(level_request))
 			}
 			Ok(None) => {
@@ -134,13 +170,416 @@ impl<'a, R: ModeratorRepository, L: LevelRequestRepository, G: GeometryDashClien
 	pub fn new(
 		moderator_repository: &'a R,
 		level_request_repository: &'a L,
-		gd_client: &'a G
+		gd_client: &'a G,
+		request_manager: &'a RequestManagerService
 	) -> Self {
 		ModeratorService {
 			moderator_repository,
 			level_request_repository,
 			gd_client,
-			request_manager: &RequestManagerService {}
+			request_manager
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::Duration;
+	use mockall::Sequence;
+	use sea_orm::{ActiveValue, InsertResult};
+
+	use crate::{
+		adapter::{
+			geometry_dash::geometry_dash_client::MockGeometryDashClient,
+			mysql::{
+				level_request_repository::MockLevelRequestRepository,
+				model::{moderator, sea_orm_active_enums},
+				moderator_repository::MockModeratorRepository
+			}
+		},
+		domain::{
+			model::{
+				error::{
+					geometry_dash::geometry_dash_dashrs_error::GeometryDashDashrsError,
+					moderator_error::ModeratorError
+				},
+				gd_level::RequestStatus,
+				level_queue::QueuePolicy,
+				moderator::{SuggestedRating, SuggestedScore},
+				request_config::RequestConfig
+			},
+			service::{
+				internal::request_manager_service::RequestManagerService,
+				moderate_service::ModerateService, moderator_service::ModeratorService
+			}
+		},
+		rocket::common::testing::level_request_model
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_send_level_should_send_to_geometry_dash_and_mark_request_sent() {
+		let mut mock_moderator_repository = MockModeratorRepository::new();
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mut mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id| {
+				Ok(Some(level_request_model(
+					guild_id,
+					level_id,
+					12345,
+					RequestStatus::Reviewed
+				)))
+			});
+		mock_level_request_repository
+			.expect_update_record()
+			.withf(|record| {
+				record.status == ActiveValue::Set(sea_orm_active_enums::RequestStatus::Sent)
+			})
+			.times(1)
+			.returning(|_| {
+				Ok(level_request_model(
+					5001,
+					99999999,
+					12345,
+					RequestStatus::Sent
+				))
+			});
+		mock_moderator_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_moderator_repository
+			.expect_create_record()
+			.times(1)
+			.returning(|_| {
+				Ok(InsertResult {
+					last_insert_id: (5001.into(), 99999999.into())
+				})
+			});
+		mock_gd_client
+			.expect_send_gd_level()
+			.withf(|moderator_request| moderator_request.suggested_score == SuggestedScore::Seven)
+			.times(1)
+			.returning(|_| Ok(()));
+
+		let request_manager = RequestManagerService {};
+		request_manager.set_request_config(RequestConfig {
+			guild_id: 5001,
+			cooldown_duration: Duration::zero(),
+			enable_requests: true,
+			enable_gd_requests: true,
+			queue_policy: QueuePolicy::Fifo,
+			max_open_requests: 0,
+			daily_request_quota: 0
+		});
+		let service = ModeratorService::new(
+			&mock_moderator_repository,
+			&mock_level_request_repository,
+			&mock_gd_client,
+			&request_manager
+		);
+
+		let level_request = service
+			.send_level(
+				5001,
+				99999999,
+				SuggestedRating::Feature,
+				SuggestedScore::Seven
+			)
+			.await
+			.unwrap();
+
+		assert_eq!(level_request.status, RequestStatus::Sent);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_send_level_should_move_pending_request_through_each_status() {
+		let mut mock_moderator_repository = MockModeratorRepository::new();
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+		let mut sequence = Sequence::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id| {
+				Ok(Some(level_request_model(
+					guild_id,
+					level_id,
+					12345,
+					RequestStatus::Pending
+				)))
+			});
+		for status in [
+			RequestStatus::InReview,
+			RequestStatus::Reviewed,
+			RequestStatus::Sent
+		] {
+			mock_level_request_repository
+				.expect_update_record()
+				.withf(move |record| record.status == ActiveValue::Set(status.into()))
+				.times(1)
+				.in_sequence(&mut sequence)
+				.returning(move |_| Ok(level_request_model(5007, 99999999, 12345, status)));
 		}
+		mock_moderator_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_moderator_repository
+			.expect_create_record()
+			.times(1)
+			.returning(|_| {
+				Ok(InsertResult {
+					last_insert_id: (5007.into(), 99999999.into())
+				})
+			});
+
+		let request_manager = RequestManagerService {};
+		let service = ModeratorService::new(
+			&mock_moderator_repository,
+			&mock_level_request_repository,
+			&mock_gd_client,
+			&request_manager
+		);
+
+		let level_request = service
+			.send_level(5007, 99999999, SuggestedRating::Rate, SuggestedScore::Rated)
+			.await
+			.unwrap();
+
+		assert_eq!(level_request.status, RequestStatus::Sent);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_send_level_should_not_send_to_geometry_dash_when_disabled() {
+		let mut mock_moderator_repository = MockModeratorRepository::new();
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mut mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id| {
+				Ok(Some(level_request_model(
+					guild_id,
+					level_id,
+					12345,
+					RequestStatus::Pending
+				)))
+			});
+		mock_level_request_repository
+			.expect_update_record()
+			.returning(|_| {
+				Ok(level_request_model(
+					5002,
+					99999999,
+					12345,
+					RequestStatus::Sent
+				))
+			});
+		mock_moderator_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+		mock_moderator_repository
+			.expect_create_record()
+			.returning(|_| {
+				Ok(InsertResult {
+					last_insert_id: (5002.into(), 99999999.into())
+				})
+			});
+		mock_gd_client.expect_send_gd_level().never();
+
+		let request_manager = RequestManagerService {};
+		request_manager.set_request_config(RequestConfig {
+			guild_id: 5002,
+			cooldown_duration: Duration::zero(),
+			enable_requests: true,
+			enable_gd_requests: false,
+			queue_policy: QueuePolicy::Fifo,
+			max_open_requests: 0,
+			daily_request_quota: 0
+		});
+		let service = ModeratorService::new(
+			&mock_moderator_repository,
+			&mock_level_request_repository,
+			&mock_gd_client,
+			&request_manager
+		);
+
+		assert!(service
+			.send_level(5002, 99999999, SuggestedRating::Rate, SuggestedScore::Four)
+			.await
+			.is_ok());
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_send_level_should_update_previous_send() {
+		let mut mock_moderator_repository = MockModeratorRepository::new();
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id| {
+				Ok(Some(level_request_model(
+					guild_id,
+					level_id,
+					12345,
+					RequestStatus::Sent
+				)))
+			});
+		mock_level_request_repository.expect_update_record().never();
+		mock_moderator_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id| {
+				Ok(Some(moderator::Model {
+					guild_id: guild_id.into(),
+					level_id: level_id.into(),
+					score: sea_orm_active_enums::Score::Four,
+					rating: sea_orm_active_enums::Rating::Rate,
+					sent_at: None
+				}))
+			});
+		mock_moderator_repository
+			.expect_update_record()
+			.withf(|record| {
+				record.score == ActiveValue::Set(sea_orm_active_enums::Score::Rated)
+					&& record.rating == ActiveValue::Set(sea_orm_active_enums::Rating::Epic)
+					&& matches!(record.sent_at, ActiveValue::Set(Some(_)))
+			})
+			.times(1)
+			.returning(|record| {
+				Ok(moderator::Model {
+					guild_id: record.guild_id.clone().unwrap(),
+					level_id: record.level_id.clone().unwrap(),
+					score: record.score.clone().unwrap(),
+					rating: record.rating.clone().unwrap(),
+					sent_at: record.sent_at.clone().unwrap()
+				})
+			});
+		mock_moderator_repository.expect_create_record().never();
+
+		let request_manager = RequestManagerService {};
+		let service = ModeratorService::new(
+			&mock_moderator_repository,
+			&mock_level_request_repository,
+			&mock_gd_client,
+			&request_manager
+		);
+
+		let level_request = service
+			.send_level(5003, 99999999, SuggestedRating::Epic, SuggestedScore::Rated)
+			.await
+			.unwrap();
+
+		assert_eq!(level_request.status, RequestStatus::Sent);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_send_level_should_return_error_when_request_was_rejected() {
+		let mock_moderator_repository = MockModeratorRepository::new();
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id| {
+				Ok(Some(level_request_model(
+					guild_id,
+					level_id,
+					12345,
+					RequestStatus::Rejected
+				)))
+			});
+
+		let request_manager = RequestManagerService {};
+		let service = ModeratorService::new(
+			&mock_moderator_repository,
+			&mock_level_request_repository,
+			&mock_gd_client,
+			&request_manager
+		);
+
+		assert_eq!(
+			service
+				.send_level(5004, 99999999, SuggestedRating::Rate, SuggestedScore::Four)
+				.await
+				.unwrap_err(),
+			ModeratorError::UnsendableLevel
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_send_level_should_return_error_when_request_does_not_exist() {
+		let mock_moderator_repository = MockModeratorRepository::new();
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|_, _| Ok(None));
+
+		let request_manager = RequestManagerService {};
+		let service = ModeratorService::new(
+			&mock_moderator_repository,
+			&mock_level_request_repository,
+			&mock_gd_client,
+			&request_manager
+		);
+
+		assert_eq!(
+			service
+				.send_level(5005, 99999999, SuggestedRating::Rate, SuggestedScore::Four)
+				.await
+				.unwrap_err(),
+			ModeratorError::LevelRequestDoesNotExists
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_send_level_should_return_error_when_geometry_dash_send_fails() {
+		let mut mock_moderator_repository = MockModeratorRepository::new();
+		let mut mock_level_request_repository = MockLevelRequestRepository::new();
+		let mut mock_gd_client = MockGeometryDashClient::new();
+
+		mock_level_request_repository
+			.expect_get_record()
+			.returning(|guild_id, level_id| {
+				Ok(Some(level_request_model(
+					guild_id,
+					level_id,
+					12345,
+					RequestStatus::Reviewed
+				)))
+			});
+		mock_level_request_repository.expect_update_record().never();
+		mock_moderator_repository.expect_create_record().never();
+		mock_gd_client
+			.expect_send_gd_level()
+			.returning(|_| Err(GeometryDashDashrsError::CircuitOpenError));
+
+		let request_manager = RequestManagerService {};
+		request_manager.set_request_config(RequestConfig {
+			guild_id: 5006,
+			cooldown_duration: Duration::zero(),
+			enable_requests: true,
+			enable_gd_requests: true,
+			queue_policy: QueuePolicy::Fifo,
+			max_open_requests: 0,
+			daily_request_quota: 0
+		});
+		let service = ModeratorService::new(
+			&mock_moderator_repository,
+			&mock_level_request_repository,
+			&mock_gd_client,
+			&request_manager
+		);
+
+		assert_eq!(
+			service
+				.send_level(5006, 99999999, SuggestedRating::Rate, SuggestedScore::Four)
+				.await
+				.unwrap_err(),
+			ModeratorError::GeometryDashDashrsError
+		);
 	}
 }
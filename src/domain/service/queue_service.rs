@@ -0,0 +1,31 @@
+use std::collections::HashMap;
+
+use crate::domain::model::{
+	error::level_queue_error::LevelQueueError, level_queue::QueuedLevelRequest
+};
+
+pub trait QueueService {
+	async fn get_next_level_request(
+		&self,
+		guild_id: u64
+	) -> Result<QueuedLevelRequest, LevelQueueError>;
+
+	/// Queue position of every pending level request in the guild, keyed by
+	/// level ID.
+	async fn get_queue_positions(
+		&self,
+		guild_id: u64
+	) -> Result<HashMap<u64, u64>, LevelQueueError>;
+
+	async fn skip_level_request(
+		&self,
+		guild_id: u64,
+		level_id: u64
+	) -> Result<QueuedLevelRequest, LevelQueueError>;
+
+	async fn requeue_level_request(
+		&self,
+		guild_id: u64,
+		level_id: u64
+	) -> Result<QueuedLevelRequest, LevelQueueError>;
+}
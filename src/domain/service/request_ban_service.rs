@@ -0,0 +1,296 @@
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue;
+
+use crate::{
+	adapter::mysql::{ban_repository::BanRepository, model::request_ban::ActiveModel},
+	domain::{
+		model::{
+			ban::{Ban, BanTarget},
+			error::ban_error::BanError
+		},
+		service::ban_service::BanService
+	}
+};
+
+pub struct RequestBanService<'a, B: BanRepository> {
+	ban_repository: &'a B
+}
+
+impl<'a, B: BanRepository> BanService for RequestBanService<'a, B> {
+	async fn ban(
+		&self,
+		guild_id: u64,
+		target: BanTarget,
+		reason: Option<String>,
+		issued_by: u64,
+		expires_at: Option<DateTime<Utc>>
+	) -> Result<Ban, BanError> {
+		let now = Utc::now();
+		if let Some(expires_at) = expires_at {
+			if expires_at.le(&now) {
+				warn!("Ban on {:?} would expire before it starts", target);
+				return Err(BanError::MalformedBan);
+			}
+		}
+
+		match self
+			.ban_repository
+			.get_active_record(guild_id, target.target_type(), target.target_id(), now)
+			.await
+		{
+			Ok(Some(active_ban)) => {
+				warn!("{:?} is already banned by ban {}", target, active_ban.id);
+				return Err(BanError::AlreadyBanned(target, active_ban.id as u64));
+			}
+			Ok(None) => {}
+			Err(db_err) => {
+				error!("Error getting active ban on {:?}: {}", target, db_err);
+				return Err(BanError::DatabaseError(db_err));
+			}
+		}
+
+		let mut ban = Ban {
+			ban_id: 0,
+			guild_id,
+			target,
+			reason,
+			issued_by,
+			created_at: now,
+			expires_at,
+			lifted_at: None,
+			lifted_by: None
+		};
+		let mut create_ban_storable: ActiveModel = ban.clone().into();
+		create_ban_storable.id = ActiveValue::NotSet;
+
+		match self.ban_repository.create_record(create_ban_storable).await {
+			Ok(insert_result) => {
+				ban.ban_id = insert_result.last_insert_id as u64;
+				info!(
+					"User {} banned {:?} with ban {}",
+					issued_by, target, ban.ban_id
+				);
+				Ok(ban)
+			}
+			Err(db_err) => {
+				error!("Error creating ban on {:?}: {}", target, db_err);
+				Err(BanError::DatabaseError(db_err))
+			}
+		}
+	}
+
+	async fn get_bans(&self, guild_id: u64, include_inactive: bool) -> Result<Vec<Ban>, BanError> {
+		let active_at = if include_inactive {
+			None
+		} else {
+			Some(Utc::now())
+		};
+
+		match self.ban_repository.get_records(guild_id, active_at).await {
+			Ok(bans) => Ok(bans.into_iter().map(Ban::from).collect()),
+			Err(db_err) => {
+				error!("Error getting bans for guild {}: {}", guild_id, db_err);
+				Err(BanError::DatabaseError(db_err))
+			}
+		}
+	}
+
+	async fn lift_ban(&self, guild_id: u64, ban_id: u64, lifted_by: u64) -> Result<Ban, BanError> {
+		let now = Utc::now();
+		let ban = match self.ban_repository.get_record(guild_id, ban_id).await {
+			Ok(Some(ban)) => Ban::from(ban),
+			Ok(None) => {
+				warn!("Ban {} does not exist", ban_id);
+				return Err(BanError::BanDoesNotExist);
+			}
+			Err(db_err) => {
+				error!("Error getting ban {}: {}", ban_id, db_err);
+				return Err(BanError::DatabaseError(db_err));
+			}
+		};
+		if !ban.is_active(&now) {
+			warn!("Ban {} is no longer active", ban_id);
+			return Err(BanError::BanNotActive(ban_id));
+		}
+
+		let mut lift_ban_storable: ActiveModel = ban.into();
+		lift_ban_storable.lifted_at = ActiveValue::Set(Some(now));
+		lift_ban_storable.lifted_by = ActiveValue::Set(Some(lifted_by.into()));
+
+		match self.ban_repository.update_record(lift_ban_storable).await {
+			Ok(lifted_ban) => {
+				info!("User {} lifted ban {}", lifted_by, ban_id);
+				Ok(Ban::from(lifted_ban))
+			}
+			Err(db_err) => {
+				error!("Error lifting ban {}: {}", ban_id, db_err);
+				Err(BanError::DatabaseError(db_err))
+			}
+		}
+	}
+}
+
+impl<'a, B: BanRepository> RequestBanService<'a, B> {
+	pub fn new(ban_repository: &'a B) -> Self { RequestBanService { ban_repository } }
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::{Duration, Utc};
+	use sea_orm::{ActiveValue, InsertResult};
+
+	use crate::{
+		adapter::mysql::{
+			ban_repository::MockBanRepository, model::sea_orm_active_enums::BanTargetType
+		},
+		domain::{
+			model::{ban::BanTarget, error::ban_error::BanError},
+			service::{ban_service::BanService, request_ban_service::RequestBanService}
+		},
+		rocket::common::testing::ban_model
+	};
+
+	#[rocket_framework::async_test]
+	async fn test_ban_should_record_issuer_and_reason() {
+		let mut mock_ban_repository = MockBanRepository::new();
+
+		mock_ban_repository
+			.expect_get_active_record()
+			.returning(|_, _, _, _| Ok(None));
+		mock_ban_repository
+			.expect_create_record()
+			.withf(|record| {
+				record.id == ActiveValue::NotSet
+					&& record.issued_by == ActiveValue::Set(0.into())
+					&& record.target_type == ActiveValue::Set(BanTargetType::Level)
+					&& record.reason == ActiveValue::Set(Some("Stolen level".to_string()))
+			})
+			.times(1)
+			.returning(|_| Ok(InsertResult { last_insert_id: 7 }));
+
+		let service = RequestBanService::new(&mock_ban_repository);
+
+		let ban = service
+			.ban(
+				1,
+				BanTarget::Level(99999999),
+				Some("Stolen level".to_string()),
+				0,
+				None
+			)
+			.await
+			.unwrap();
+
+		assert_eq!(ban.ban_id, 7);
+		assert_eq!(ban.target, BanTarget::Level(99999999));
+		assert!(ban.is_active(&Utc::now()));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_ban_should_reject_expiry_in_the_past() {
+		let mock_ban_repository = MockBanRepository::new();
+		let service = RequestBanService::new(&mock_ban_repository);
+
+		assert_eq!(
+			service
+				.ban(
+					1,
+					BanTarget::User(12345),
+					None,
+					0,
+					Some(Utc::now() - Duration::minutes(1))
+				)
+				.await
+				.unwrap_err(),
+			BanError::MalformedBan
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_ban_should_return_error_when_target_is_already_banned() {
+		let mut mock_ban_repository = MockBanRepository::new();
+
+		mock_ban_repository
+			.expect_get_active_record()
+			.returning(|guild_id, _, _, _| Ok(Some(ban_model(3, guild_id, BanTargetType::User))));
+		mock_ban_repository.expect_create_record().never();
+
+		let service = RequestBanService::new(&mock_ban_repository);
+
+		assert_eq!(
+			service
+				.ban(1, BanTarget::User(12345), None, 0, None)
+				.await
+				.unwrap_err(),
+			BanError::AlreadyBanned(BanTarget::User(12345), 3)
+		);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_get_bans_should_only_ask_for_active_bans_by_default() {
+		let mut mock_ban_repository = MockBanRepository::new();
+
+		mock_ban_repository
+			.expect_get_records()
+			.withf(|_, active_at| active_at.is_some())
+			.times(1)
+			.returning(|guild_id, _| Ok(vec![ban_model(1, guild_id, BanTargetType::User)]));
+
+		let service = RequestBanService::new(&mock_ban_repository);
+
+		assert_eq!(service.get_bans(1, false).await.unwrap().len(), 1);
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_lift_ban_should_record_who_lifted_it() {
+		let mut mock_ban_repository = MockBanRepository::new();
+
+		mock_ban_repository
+			.expect_get_record()
+			.returning(|guild_id, ban_id| {
+				Ok(Some(ban_model(
+					ban_id as i32,
+					guild_id,
+					BanTargetType::User
+				)))
+			});
+		mock_ban_repository
+			.expect_update_record()
+			.withf(|record| record.lifted_by == ActiveValue::Set(Some(54321.into())))
+			.times(1)
+			.returning(|record| {
+				let mut lifted_ban = ban_model(1, 1, BanTargetType::User);
+				lifted_ban.lifted_at = record.lifted_at.clone().unwrap();
+				lifted_ban.lifted_by = record.lifted_by.clone().unwrap();
+				Ok(lifted_ban)
+			});
+
+		let service = RequestBanService::new(&mock_ban_repository);
+
+		let ban = service.lift_ban(1, 1, 54321).await.unwrap();
+
+		assert_eq!(ban.lifted_by, Some(54321));
+		assert!(!ban.is_active(&Utc::now()));
+	}
+
+	#[rocket_framework::async_test]
+	async fn test_lift_ban_should_return_error_when_ban_has_expired() {
+		let mut mock_ban_repository = MockBanRepository::new();
+
+		mock_ban_repository
+			.expect_get_record()
+			.returning(|guild_id, ban_id| {
+				let mut expired_ban = ban_model(ban_id as i32, guild_id, BanTargetType::User);
+				expired_ban.expires_at = Some(Utc::now() - Duration::hours(1));
+				Ok(Some(expired_ban))
+			});
+		mock_ban_repository.expect_update_record().never();
+
+		let service = RequestBanService::new(&mock_ban_repository);
+
+		assert_eq!(
+			service.lift_ban(1, 1, 54321).await.unwrap_err(),
+			BanError::BanNotActive(1)
+		);
+	}
+}
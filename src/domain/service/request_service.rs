@@ -1,42 +1,80 @@
 use crate::domain::model::{
 	error::level_request_error::LevelRequestError,
-	gd_level::{GDLevelRequest, RequestRating}
+	gd_level::{GDLevelRequest, LevelRequestSubmission, LevelRequestUpdate, RequestStatus},
+	level_request_query::{LevelRequestFilter, LevelRequestPage, SortOrder}
 };
 
+#[cfg_attr(test, mockall::automock)]
 pub trait RequestService {
 	async fn get_level_request(
 		&self,
+		guild_id: u64,
 		level_id: u64,
 		has_requested_feedback: Option<bool>
 	) -> Result<GDLevelRequest, LevelRequestError>;
 
+	async fn get_level_requests(
+		&self,
+		guild_id: u64,
+		filter: LevelRequestFilter,
+		sort_order: SortOrder,
+		page: u64,
+		page_size: u64
+	) -> Result<LevelRequestPage, LevelRequestError>;
+
 	async fn make_level_request(
 		&self,
+		guild_id: u64,
 		level_id: u64,
-		youtube_video_link: String,
 		discord_id: u64,
-		request_rating: RequestRating,
-		has_requested_feedback: bool,
-		notify: bool
+		level_request_submission: LevelRequestSubmission
 	) -> Result<GDLevelRequest, LevelRequestError>;
 
 	async fn update_level_request(
 		&self,
+		guild_id: u64,
 		level_id: u64,
 		discord_user_id: u64,
-		youtube_video_link: Option<String>,
-		request_rating: Option<RequestRating>,
-		has_requested_feedback: Option<bool>,
-		notify: Option<bool>
+		is_admin: bool,
+		level_request_update: LevelRequestUpdate
 	) -> Result<GDLevelRequest, LevelRequestError>;
 
-	async fn delete_level_request(
+	async fn update_level_request_status(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		status: RequestStatus
+	) -> Result<GDLevelRequest, LevelRequestError>;
+
+	/// Moves the level request forward to `status` one legal transition at a
+	/// time, saving each. Requests already at or past `status` are left as
+	/// they are.
+	async fn advance_level_request_status(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		status: RequestStatus
+	) -> Result<GDLevelRequest, LevelRequestError>;
+
+	/// Moves the level request back to pending when it is in review but
+	/// nobody is assigned to review it anymore. Returns whether it was moved.
+	async fn return_level_request_to_queue(
 		&self,
+		guild_id: u64,
 		level_id: u64
+	) -> Result<bool, LevelRequestError>;
+
+	async fn delete_level_request(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		discord_user_id: u64,
+		is_admin: bool
 	) -> Result<GDLevelRequest, LevelRequestError>;
 
 	async fn update_level_request_message_id(
 		&self,
+		guild_id: u64,
 		level_id: u64,
 		discord_message_id: u64
 	) -> Result<(), LevelRequestError>;
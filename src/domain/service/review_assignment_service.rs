@@ -0,0 +1,43 @@
+use crate::domain::model::{
+	error::review_assignment_error::ReviewAssignmentError,
+	review_assignment::{AssignedLevelRequest, ReviewAssignment}
+};
+
+pub trait ReviewAssignmentService {
+	/// Assigns the level to the reviewer claiming it. Claiming a level the
+	/// reviewer already holds extends their claim.
+	async fn claim_level(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		reviewer_discord_id: u64
+	) -> Result<ReviewAssignment, ReviewAssignmentError>;
+
+	/// Assigns the level to the reviewer, or to the reviewer picked by the
+	/// configured assignment policy when none is given. Another reviewer's
+	/// assignment on the level is released.
+	async fn assign_level(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		reviewer_discord_id: Option<u64>,
+		assigned_by: u64
+	) -> Result<ReviewAssignment, ReviewAssignmentError>;
+
+	/// Hands the level back so another reviewer can pick it up. Only
+	/// moderators may release another reviewer's assignment.
+	async fn release_level(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		reviewer_discord_id: u64,
+		is_moderator: bool
+	) -> Result<ReviewAssignment, ReviewAssignmentError>;
+
+	/// The levels actively assigned to the reviewer, oldest assignment first.
+	async fn get_assigned_levels(
+		&self,
+		guild_id: u64,
+		reviewer_discord_id: u64
+	) -> Result<Vec<AssignedLevelRequest>, ReviewAssignmentError>;
+}
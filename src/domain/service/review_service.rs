@@ -1,24 +1,67 @@
-use crate::domain::model::{error::level_review_error::LevelReviewError, review::LevelReview};
+use crate::domain::model::{
+	error::level_review_error::LevelReviewError,
+	level_request_query::SortOrder,
+	review::{LevelReview, LevelReviewSubmission},
+	review_query::{LevelReviewFilter, LevelReviewPage},
+	review_revision::{ReviewRevision, ReviewRevisionDiff},
+	review_rubric::LevelRubricSummary
+};
 
 pub trait ReviewService {
 	async fn get_level_review(
 		&self,
+		guild_id: u64,
 		level_id: u64,
 		discord_id: u64
 	) -> Result<LevelReview, LevelReviewError>;
 
+	async fn get_level_reviews(
+		&self,
+		guild_id: u64,
+		filter: LevelReviewFilter,
+		sort_order: SortOrder,
+		page: u64,
+		page_size: u64
+	) -> Result<LevelReviewPage, LevelReviewError>;
+
 	async fn review_level(
 		&self,
+		guild_id: u64,
 		level_id: u64,
 		reviewer_discord_id: u64,
-		discord_message_id: u64,
-		review_contents: String
+		is_admin: bool,
+		level_review_submission: LevelReviewSubmission
 	) -> Result<LevelReview, LevelReviewError>;
 
 	async fn update_level_request_thread_id(
 		&self,
+		guild_id: u64,
 		level_id: u64,
 		discord_id: u64,
 		discord_message_id: u64
 	) -> Result<(), LevelReviewError>;
+
+	/// Every revision of the review, oldest first.
+	async fn get_review_revisions(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		discord_id: u64
+	) -> Result<Vec<ReviewRevision>, LevelReviewError>;
+
+	async fn diff_review_revisions(
+		&self,
+		guild_id: u64,
+		level_id: u64,
+		discord_id: u64,
+		from_revision: u32,
+		to_revision: u32
+	) -> Result<ReviewRevisionDiff, LevelReviewError>;
+
+	/// The rubric scores of every review of the level, combined.
+	async fn get_level_rubric_summary(
+		&self,
+		guild_id: u64,
+		level_id: u64
+	) -> Result<LevelRubricSummary, LevelReviewError>;
 }
@@ -3,11 +3,20 @@ use crate::domain::model::{error::reviewer_error::ReviewerError, reviewer::Revie
 pub trait ReviewerService {
 	async fn get_reviewer(
 		&self,
+		guild_id: u64,
 		reviewer_discord_id: u64,
 		include_active: Option<bool>
 	) -> Result<Reviewer, ReviewerError>;
 
-	async fn create_reviewer(&self, reviewer_discord_id: u64) -> Result<(), ReviewerError>;
+	async fn create_reviewer(
+		&self,
+		guild_id: u64,
+		reviewer_discord_id: u64
+	) -> Result<(), ReviewerError>;
 
-	async fn remove_reviewer(&self, reviewer_discord_id: u64) -> Result<(), ReviewerError>;
+	async fn remove_reviewer(
+		&self,
+		guild_id: u64,
+		reviewer_discord_id: u64
+	) -> Result<(), ReviewerError>;
 }
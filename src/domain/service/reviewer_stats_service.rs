@@ -0,0 +1,25 @@
+use crate::domain::model::{
+	error::reviewer_error::ReviewerError,
+	reviewer_stats::{
+		ReviewerLeaderboardEntry, ReviewerStats, ReviewerStatsMetric, StatsPeriod, StatsRange
+	}
+};
+
+pub trait ReviewerStatsService {
+	async fn get_reviewer_stats(
+		&self,
+		guild_id: u64,
+		reviewer_discord_id: u64,
+		range: StatsRange,
+		period: StatsPeriod
+	) -> Result<ReviewerStats, ReviewerError>;
+
+	async fn get_reviewer_leaderboard(
+		&self,
+		guild_id: u64,
+		metric: ReviewerStatsMetric,
+		range: StatsRange,
+		period: StatsPeriod,
+		limit: u64
+	) -> Result<Vec<ReviewerLeaderboardEntry>, ReviewerError>;
+}
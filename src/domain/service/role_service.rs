@@ -0,0 +1,14 @@
+use crate::domain::model::{auth::role::Role, error::role_error::RoleError};
+
+pub trait RoleService {
+	async fn get_roles(&self, guild_id: u64, discord_id: u64) -> Result<Vec<Role>, RoleError>;
+
+	async fn add_role(&self, guild_id: u64, discord_id: u64, role: Role) -> Result<(), RoleError>;
+
+	async fn remove_role(
+		&self,
+		guild_id: u64,
+		discord_id: u64,
+		role: Role
+	) -> Result<(), RoleError>;
+}
@@ -0,0 +1,156 @@
+use crate::{
+	adapter::mysql::{
+		model::user_role::ActiveModel, reviewer_repository::ReviewerRepository,
+		user_role_repository::UserRoleRepository
+	},
+	domain::{
+		model::{
+			auth::{role::Role, user_role::UserRole},
+			error::role_error::RoleError
+		},
+		service::role_service::RoleService
+	},
+	rocket::common::config::client_config::CLIENT_CONFIG
+};
+
+pub struct UserRoleService<'a, U: UserRoleRepository, R: ReviewerRepository> {
+	user_role_repository: &'a U,
+	reviewer_repository: &'a R
+}
+
+impl<'a, U: UserRoleRepository, R: ReviewerRepository> RoleService for UserRoleService<'a, U, R> {
+	/// Every guild member is a requester and active reviewers are reviewers.
+	/// Moderator and admin come from `user_role`, and the bot owner is an
+	/// admin in every guild so that the first admins can be granted.
+	async fn get_roles(&self, guild_id: u64, discord_id: u64) -> Result<Vec<Role>, RoleError> {
+		let mut roles = vec![Role::Requester];
+
+		match self
+			.reviewer_repository
+			.get_record(guild_id, discord_id, Some(true))
+			.await
+		{
+			Ok(Some(_)) => roles.push(Role::Reviewer),
+			Ok(None) => {}
+			Err(get_reviewer_error) => {
+				error!(
+					"Error getting reviewer {} for guild {}: {}",
+					discord_id, guild_id, get_reviewer_error
+				);
+				return Err(RoleError::DatabaseError(get_reviewer_error));
+			}
+		}
+
+		for user_role in self.get_user_roles(guild_id, discord_id).await? {
+			roles.push(user_role.role);
+		}
+
+		if discord_id.eq(&CLIENT_CONFIG.discord_bot_admin_id) && !roles.contains(&Role::Admin) {
+			roles.push(Role::Admin);
+		}
+
+		Ok(roles)
+	}
+
+	async fn add_role(&self, guild_id: u64, discord_id: u64, role: Role) -> Result<(), RoleError> {
+		let user_role = UserRole {
+			guild_id,
+			discord_id,
+			role
+		};
+		let user_role_storable = ActiveModel::try_from(user_role)?;
+
+		if self
+			.get_user_roles(guild_id, discord_id)
+			.await?
+			.iter()
+			.any(|existing_user_role| existing_user_role.role.eq(&role))
+		{
+			warn!(
+				"User {} already has role {:?} in guild {}",
+				discord_id, role, guild_id
+			);
+			return Ok(());
+		}
+
+		if let Err(db_err) = self
+			.user_role_repository
+			.create_record(user_role_storable)
+			.await
+		{
+			error!(
+				"Error granting role {:?} to user {}: {}",
+				role, discord_id, db_err
+			);
+			return Err(RoleError::DatabaseError(db_err));
+		}
+
+		Ok(())
+	}
+
+	async fn remove_role(
+		&self,
+		guild_id: u64,
+		discord_id: u64,
+		role: Role
+	) -> Result<(), RoleError> {
+		match self
+			.get_user_roles(guild_id, discord_id)
+			.await?
+			.into_iter()
+			.find(|existing_user_role| existing_user_role.role.eq(&role))
+		{
+			Some(existing_user_role) => {
+				if let Err(db_err) = self
+					.user_role_repository
+					.delete_record(ActiveModel::try_from(existing_user_role)?)
+					.await
+				{
+					error!(
+						"Error removing role {:?} from user {}: {}",
+						role, discord_id, db_err
+					);
+					return Err(RoleError::DatabaseError(db_err));
+				}
+				Ok(())
+			}
+			None => {
+				warn!(
+					"User {} does not have role {:?} in guild {}",
+					discord_id, role, guild_id
+				);
+				Err(RoleError::RoleDoesNotExist)
+			}
+		}
+	}
+}
+
+impl<'a, U: UserRoleRepository, R: ReviewerRepository> UserRoleService<'a, U, R> {
+	pub fn new(user_role_repository: &'a U, reviewer_repository: &'a R) -> Self {
+		UserRoleService {
+			user_role_repository,
+			reviewer_repository
+		}
+	}
+
+	async fn get_user_roles(
+		&self,
+		guild_id: u64,
+		discord_id: u64
+	) -> Result<Vec<UserRole>, RoleError> {
+		match self
+			.user_role_repository
+			.get_records(guild_id, discord_id)
+			.await
+		{
+			Ok(user_roles) => Ok(user_roles.into_iter().map(UserRole::from).collect()),
+			Err(get_user_roles_error) => {
+				error!(
+					"Error getting roles for user {} in guild {}: {}",
+					discord_id, guild_id, get_user_roles_error
+				);
+				Err(RoleError::DatabaseError(get_user_roles_error))
+			}
+		}
+	}
+}
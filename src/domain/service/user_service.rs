@@ -1,7 +1,29 @@
 use crate::domain::model::{
-	discord::user::DiscordUser, error::discord::discord_error::DiscordError
+	discord::user::DiscordUser, error::discord::discord_error::DiscordError,
+	request_limits::RequestLimitOverrides, user_profile::UserProfile
 };
 
 pub trait UserService {
-	async fn get_user(&self, discord_user_id: u64) -> Result<DiscordUser, DiscordError>;
+	async fn get_user(
+		&self,
+		guild_id: u64,
+		discord_user_id: u64
+	) -> Result<DiscordUser, DiscordError>;
+
+	/// The user's requests with their reviews, along with the cooldown, quotas
+	/// and ban currently limiting the user's next request.
+	async fn get_user_profile(
+		&self,
+		guild_id: u64,
+		discord_user_id: u64
+	) -> Result<UserProfile, DiscordError>;
+
+	/// Replaces the user's request limit overrides, adding the user if they
+	/// have not requested yet.
+	async fn update_request_limit_overrides(
+		&self,
+		guild_id: u64,
+		discord_user_id: u64,
+		request_limit_overrides: RequestLimitOverrides
+	) -> Result<DiscordUser, DiscordError>;
 }
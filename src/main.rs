@@ -6,17 +6,37 @@ mod domain;
 
 mod rocket;
 
-use rocket_framework::Config;
+use std::{sync::Arc, time::Duration};
+
+use migration::{Migrator, MigratorTrait};
+use rocket_framework::{Build, Config, Rocket};
+use sea_orm::DatabaseConnection;
 
 use crate::{
-	adapter::controller::{
-		auth_controller, health, level_request_controller, level_review_controller,
-		reviewer_controller, user_controller
+	adapter::{
+		controller::{
+			auth_controller, catcher, health, level_queue_controller, level_request_controller,
+			level_review_controller, review_assignment_controller, reviewer_controller,
+			role_controller, user_controller
+		},
+		geometry_dash::fake_geometry_dash_server::mount_fake_geometry_dash_server,
+		mysql::{
+			mysql_level_request_repository::MySqlLevelRequestRepository,
+			mysql_request_config_repository::MySqlRequestConfigRepository
+		}
+	},
+	domain::service::{
+		internal::{
+			request_config_service::RequestConfigService,
+			request_manager_service::RequestManagerService
+		},
+		level_queue_service::LevelQueueService
 	},
 	rocket::common::{
+		app_state::AppState,
 		config::{
 			common_config::{init_app_config, APP_CONFIG},
-			mysql_database_config::MY_SQL_DATABASE_CONFIG
+			database_config::DATABASE_CONFIG
 		},
 		internal::internal::mount_internal_controllers
 	}
@@ -34,7 +54,7 @@ async fn launch() -> _ {
 	}
 
 	info!("Initializing database");
-	let db_conn = match MY_SQL_DATABASE_CONFIG.configure_mysql_database().await {
+	let db_conn = match DATABASE_CONFIG.configure_database().await {
 		Ok(conn) => conn,
 		Err(err) => {
 			error!("Failed to initialize database: {}", err);
@@ -42,29 +62,148 @@ async fn launch() -> _ {
 		}
 	};
 
+	info!("Running database migrations");
+	if let Err(err) = Migrator::up(&db_conn, None).await {
+		error!("Failed to run database migrations: {}", err);
+		panic!("{}", err)
+	}
+
+	let db_conn = Arc::new(db_conn);
+	spawn_request_config_reload(db_conn.clone());
+	spawn_unassigned_level_request_requeue(db_conn.clone());
+
+	info!("Initializing application state");
+	let app_state = match AppState::new(&APP_CONFIG, db_conn) {
+		Ok(app_state) => app_state,
+		Err(err) => {
+			error!("Failed to initialize application state: {}", err);
+			panic!("{}", err)
+		}
+	};
+
+	info!("Loading request configs");
+	if let Err(err) = app_state
+		.request_config_service()
+		.load_request_configs()
+		.await
+	{
+		error!("Failed to load request configs: {}", err);
+		panic!("{}", err)
+	}
+
 	let mut rocket = rocket_framework::custom(
 		Config::figment()
 			.merge(("address", &APP_CONFIG.client_config.host))
 			.merge(("port", &APP_CONFIG.client_config.port))
 	);
 
-	rocket = rocket.manage(db_conn).mount(
+	rocket = mount_controllers(rocket.manage(app_state));
+
+	if let Some(fixtures_path) = &APP_CONFIG.geometry_dash_config.fake_server_fixtures_path {
+		warn!(
+			"Serving the fake Geometry Dash server from fixtures in {}",
+			fixtures_path
+		);
+		rocket = mount_fake_geometry_dash_server(rocket, fixtures_path);
+	}
+
+	rocket
+}
+
+/// Keeps the request configs of this instance in line with changes made
+/// through other instances.
+fn spawn_request_config_reload(db_conn: Arc<DatabaseConnection>) {
+	let reload_interval_in_seconds = APP_CONFIG
+		.client_config
+		.request_config_reload_interval_in_seconds;
+	if reload_interval_in_seconds == 0 {
+		warn!("Request configs will not be reloaded");
+		return;
+	}
+
+	let request_config_repository = MySqlRequestConfigRepository::new(db_conn);
+	rocket_framework::tokio::spawn(async move {
+		let request_manager = RequestManagerService {};
+		RequestConfigService::new(&request_config_repository, &request_manager)
+			.reload_request_configs(Duration::from_secs(reload_interval_in_seconds))
+			.await
+	});
+}
+
+/// Returns levels whose review assignment ended without a review to the
+/// queue, so that reading the queue never has to.
+fn spawn_unassigned_level_request_requeue(db_conn: Arc<DatabaseConnection>) {
+	let requeue_interval_in_seconds = APP_CONFIG
+		.review_assignment_config
+		.requeue_interval_in_seconds;
+	if requeue_interval_in_seconds == 0 {
+		warn!("Levels will not be returned to the queue when their review assignment ends");
+		return;
+	}
+
+	let level_request_repository = MySqlLevelRequestRepository::new(db_conn);
+	rocket_framework::tokio::spawn(async move {
+		let request_manager = RequestManagerService {};
+		LevelQueueService::new(&level_request_repository, &request_manager)
+			.requeue_unassigned_level_requests(Duration::from_secs(requeue_interval_in_seconds))
+			.await
+	});
+}
+
+/// Mounts every controller and catcher. Shared with the controller tests so
+/// they exercise the same routes as the launched app.
+fn mount_controllers(rocket: Rocket<Build>) -> Rocket<Build> {
+	let rocket = rocket.mount(
 		"/api/v1",
 		routes![
 			auth_controller::generate_jwt,
+			auth_controller::refresh_jwt,
+			auth_controller::revoke_jwt,
 			user_controller::get_user,
+			user_controller::get_user_profile,
+			user_controller::update_request_limits,
 			level_request_controller::get_level_request,
+			level_request_controller::get_level_requests,
 			level_request_controller::request_level,
 			level_request_controller::update_level_request,
 			level_request_controller::delete_level_request,
+			level_queue_controller::get_next_level_request,
+			level_queue_controller::skip_level_request,
+			level_queue_controller::requeue_level_request,
 			level_review_controller::get_level_review,
+			level_review_controller::get_level_reviews,
 			level_review_controller::review_level,
+			level_review_controller::get_review_revisions,
+			level_review_controller::diff_review_revisions,
+			level_review_controller::get_review_rubric,
+			level_review_controller::get_level_rubric_summary,
+			review_assignment_controller::get_assigned_levels,
+			review_assignment_controller::claim_level,
+			review_assignment_controller::release_level,
+			review_assignment_controller::assign_level,
 			reviewer_controller::get_reviewer,
+			reviewer_controller::get_reviewer_stats,
+			reviewer_controller::get_reviewer_leaderboard,
 			reviewer_controller::create_reviewer,
 			reviewer_controller::remove_reviewer,
+			role_controller::get_roles,
+			role_controller::add_role,
+			role_controller::remove_role,
 			health::get_health
 		]
 	);
 
+	let rocket = rocket.register(
+		"/",
+		catchers![
+			catcher::bad_request,
+			catcher::unauthorized,
+			catcher::forbidden,
+			catcher::not_found,
+			catcher::unprocessable_entity,
+			catcher::internal_server_error
+		]
+	);
+
 	mount_internal_controllers(rocket)
 }
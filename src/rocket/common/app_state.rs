@@ -0,0 +1,254 @@
+use std::{sync::Arc, time::Duration};
+
+use sea_orm::DatabaseConnection;
+
+use crate::{
+	adapter::{
+		geometry_dash::{
+			caching_geometry_dash_client::CachingGeometryDashClient,
+			geometry_dash_dashrs_client::GeometryDashDashrsClient,
+			resilient_geometry_dash_client::ResilientGeometryDashClient
+		},
+		mysql::{
+			my_sql_moderator_repository::MySqlModeratorRepository,
+			mysql_ban_repository::MySqlBanRepository,
+			mysql_level_request_repository::MySqlLevelRequestRepository,
+			mysql_request_config_repository::MySqlRequestConfigRepository,
+			mysql_review_assignment_repository::MySqlReviewAssignmentRepository,
+			mysql_review_repository::MySqlReviewRepository,
+			mysql_review_revision_repository::MySqlReviewRevisionRepository,
+			mysql_reviewer_activity_repository::MySqlReviewerActivityRepository,
+			mysql_reviewer_repository::MySqlReviewerRepository,
+			mysql_revoked_token_repository::MySqlRevokedTokenRepository,
+			mysql_user_repository::MySqlUserRepository,
+			mysql_user_role_repository::MySqlUserRoleRepository
+		}
+	},
+	domain::{
+		model::{auth::signing_key::SigningKeys, error::auth_error::AuthError},
+		service::{
+			auth_service::AuthService,
+			discord_user_service::DiscordUserService,
+			internal::{
+				request_config_service::RequestConfigService,
+				request_manager_service::RequestManagerService
+			},
+			level_queue_service::LevelQueueService,
+			level_request_service::LevelRequestService,
+			level_review_assignment_service::LevelReviewAssignmentService,
+			level_review_service::LevelReviewService,
+			level_reviewer_service::LevelReviewerService,
+			level_reviewer_stats_service::LevelReviewerStatsService,
+			moderator_service::ModeratorService,
+			request_ban_service::RequestBanService,
+			user_role_service::UserRoleService
+		}
+	},
+	rocket::common::config::{common_config::AppConfig, geometry_dash_config::GeometryDashConfig}
+};
+
+pub type GdClient =
+	CachingGeometryDashClient<ResilientGeometryDashClient<GeometryDashDashrsClient>>;
+
+/// Everything a request needs, built once at launch and managed by Rocket.
+/// Services only borrow from the state, so handing one out is free.
+pub struct AppState {
+	pub app_config: &'static AppConfig,
+	pub signing_keys: SigningKeys,
+	pub gd_client: GdClient,
+	pub request_manager: RequestManagerService,
+	ban_repository: MySqlBanRepository,
+	level_request_repository: MySqlLevelRequestRepository,
+	moderator_repository: MySqlModeratorRepository,
+	request_config_repository: MySqlRequestConfigRepository,
+	review_assignment_repository: MySqlReviewAssignmentRepository,
+	review_repository: MySqlReviewRepository,
+	review_revision_repository: MySqlReviewRevisionRepository,
+	reviewer_activity_repository: MySqlReviewerActivityRepository,
+	reviewer_repository: MySqlReviewerRepository,
+	revoked_token_repository: MySqlRevokedTokenRepository,
+	user_repository: MySqlUserRepository,
+	user_role_repository: MySqlUserRoleRepository
+}
+
+impl AppState {
+	pub fn new(
+		app_config: &'static AppConfig,
+		db_conn: Arc<DatabaseConnection>
+	) -> Result<Self, AuthError> {
+		Ok(AppState {
+			app_config,
+			signing_keys: SigningKeys::from_auth_config(&app_config.auth_config)?,
+			gd_client: build_gd_client(&app_config.geometry_dash_config),
+			request_manager: RequestManagerService {},
+			ban_repository: MySqlBanRepository::new(db_conn.clone()),
+			level_request_repository: MySqlLevelRequestRepository::new(db_conn.clone()),
+			moderator_repository: MySqlModeratorRepository::new(db_conn.clone()),
+			request_config_repository: MySqlRequestConfigRepository::new(db_conn.clone()),
+			review_assignment_repository: MySqlReviewAssignmentRepository::new(db_conn.clone()),
+			review_repository: MySqlReviewRepository::new(db_conn.clone()),
+			review_revision_repository: MySqlReviewRevisionRepository::new(db_conn.clone()),
+			reviewer_activity_repository: MySqlReviewerActivityRepository::new(db_conn.clone()),
+			reviewer_repository: MySqlReviewerRepository::new(db_conn.clone()),
+			revoked_token_repository: MySqlRevokedTokenRepository::new(db_conn.clone()),
+			user_repository: MySqlUserRepository::new(db_conn.clone()),
+			user_role_repository: MySqlUserRoleRepository::new(db_conn)
+		})
+	}
+
+	pub fn auth_service(&self) -> AuthService<'_, MySqlRevokedTokenRepository> {
+		AuthService::new(&self.revoked_token_repository, &self.signing_keys)
+	}
+
+	pub fn ban_service(&self) -> RequestBanService<'_, MySqlBanRepository> {
+		RequestBanService::new(&self.ban_repository)
+	}
+
+	pub fn discord_user_service(
+		&self
+	) -> DiscordUserService<'_, MySqlUserRepository, MySqlBanRepository> {
+		DiscordUserService::new(
+			&self.user_repository,
+			&self.ban_repository,
+			&self.request_manager
+		)
+	}
+
+	pub fn level_queue_service(&self) -> LevelQueueService<'_, MySqlLevelRequestRepository> {
+		LevelQueueService::new(&self.level_request_repository, &self.request_manager)
+	}
+
+	pub fn level_request_service(
+		&self
+	) -> LevelRequestService<
+		'_,
+		MySqlLevelRequestRepository,
+		MySqlUserRepository,
+		GdClient,
+		MySqlBanRepository
+	> {
+		LevelRequestService::new(
+			&self.level_request_repository,
+			&self.user_repository,
+			&self.gd_client,
+			&self.ban_repository,
+			&self.request_manager,
+			&self.app_config.level_eligibility_config
+		)
+	}
+
+	pub fn level_review_assignment_service(
+		&self
+	) -> LevelReviewAssignmentService<
+		'_,
+		MySqlReviewAssignmentRepository,
+		MySqlReviewerRepository,
+		LevelRequestService<
+			'_,
+			MySqlLevelRequestRepository,
+			MySqlUserRepository,
+			GdClient,
+			MySqlBanRepository
+		>
+	> {
+		LevelReviewAssignmentService::new(
+			&self.review_assignment_repository,
+			&self.reviewer_repository,
+			self.level_request_service(),
+			&self.app_config.review_assignment_config
+		)
+	}
+
+	pub fn level_review_service(
+		&self
+	) -> LevelReviewService<
+		'_,
+		MySqlReviewRepository,
+		MySqlReviewRevisionRepository,
+		MySqlReviewAssignmentRepository,
+		LevelRequestService<
+			'_,
+			MySqlLevelRequestRepository,
+			MySqlUserRepository,
+			GdClient,
+			MySqlBanRepository
+		>
+	> {
+		LevelReviewService::new(
+			&self.review_repository,
+			&self.review_revision_repository,
+			&self.review_assignment_repository,
+			self.level_request_service(),
+			&self.app_config.review_rubric_config
+		)
+	}
+
+	pub fn level_reviewer_service(
+		&self
+	) -> LevelReviewerService<'_, MySqlReviewerRepository, MySqlReviewerActivityRepository> {
+		LevelReviewerService::new(
+			&self.reviewer_repository,
+			&self.reviewer_activity_repository
+		)
+	}
+
+	pub fn level_reviewer_stats_service(
+		&self
+	) -> LevelReviewerStatsService<
+		'_,
+		MySqlReviewerRepository,
+		MySqlReviewerActivityRepository,
+		MySqlReviewRevisionRepository,
+		MySqlModeratorRepository
+	> {
+		LevelReviewerStatsService::new(
+			&self.reviewer_repository,
+			&self.reviewer_activity_repository,
+			&self.review_revision_repository,
+			&self.moderator_repository
+		)
+	}
+
+	pub fn moderator_service(
+		&self
+	) -> ModeratorService<'_, MySqlModeratorRepository, MySqlLevelRequestRepository, GdClient> {
+		ModeratorService::new(
+			&self.moderator_repository,
+			&self.level_request_repository,
+			&self.gd_client,
+			&self.request_manager
+		)
+	}
+
+	pub fn request_config_service(&self) -> RequestConfigService<'_, MySqlRequestConfigRepository> {
+		RequestConfigService::new(&self.request_config_repository, &self.request_manager)
+	}
+
+	pub fn role_service(
+		&self
+	) -> UserRoleService<'_, MySqlUserRoleRepository, MySqlReviewerRepository> {
+		UserRoleService::new(&self.user_role_repository, &self.reviewer_repository)
+	}
+}
+
+/// Lookups are cached in front of the retries and circuit breaker, so a cached
+/// level is still served while Geometry Dash is down.
+fn build_gd_client(geometry_dash_config: &GeometryDashConfig) -> GdClient {
+	let dashrs_client = GeometryDashDashrsClient::new(
+		&geometry_dash_config.base_url,
+		Duration::from_millis(geometry_dash_config.connect_timeout_in_millis),
+		Duration::from_millis(geometry_dash_config.read_timeout_in_millis)
+	);
+	let resilient_client = ResilientGeometryDashClient::new(
+		dashrs_client,
+		geometry_dash_config.max_retries,
+		Duration::from_millis(geometry_dash_config.retry_backoff_in_millis),
+		geometry_dash_config.circuit_breaker_failure_threshold,
+		Duration::from_secs(geometry_dash_config.circuit_breaker_reset_in_seconds)
+	);
+	CachingGeometryDashClient::new(
+		resilient_client,
+		Duration::from_secs(geometry_dash_config.level_cache_ttl_in_seconds),
+		Duration::from_secs(geometry_dash_config.level_not_found_cache_ttl_in_seconds)
+	)
+}
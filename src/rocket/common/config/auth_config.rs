@@ -5,10 +5,54 @@ use crate::rocket::common::config::common_config::APP_CONFIG;
 
 #[derive(Debug, Deserialize)]
 pub struct AuthConfig {
+	#[serde(default)]
 	pub secret_token: String,
-	pub access_token: String
+	pub access_token: String,
+	#[serde(default = "default_access_token_duration")]
+	pub access_token_duration_in_minutes: u32,
+	#[serde(default = "default_refresh_token_duration")]
+	pub refresh_token_duration_in_minutes: u32,
+	/// Key used to sign new tokens. Defaults to `secret_token`, which is
+	/// registered as the HS256 key `default`.
+	#[serde(default = "default_signing_kid")]
+	pub signing_kid: String,
+	/// Additional keys accepted for verification. Keep a retired key here until
+	/// every token signed with it has expired.
+	#[serde(default)]
+	pub signing_keys: Vec<SigningKeyConfig>
 }
 
+#[derive(Debug, Deserialize)]
+pub struct SigningKeyConfig {
+	pub kid: String,
+	#[serde(default)]
+	pub algorithm: SigningAlgorithm,
+	/// Shared secret for `hs256` keys.
+	pub secret: Option<String>,
+	/// PEM private key for `rs256` and `eddsa` keys. Only required for the
+	/// signing key.
+	pub private_key_path: Option<String>,
+	/// PEM public key for `rs256` and `eddsa` keys.
+	pub public_key_path: Option<String>
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SigningAlgorithm {
+	#[default]
+	Hs256,
+	Rs256,
+	EdDsa
+}
+
+pub static DEFAULT_SIGNING_KID: &str = "default";
+
+fn default_access_token_duration() -> u32 { 60 * 24 * 7 }
+
+fn default_refresh_token_duration() -> u32 { 60 * 24 * 30 }
+
+fn default_signing_kid() -> String { DEFAULT_SIGNING_KID.to_string() }
+
 lazy_static! {
 	pub static ref AUTH_CONFIG: &'static AuthConfig = &APP_CONFIG.auth_config;
 }
@@ -1,10 +1,12 @@
-use std::sync::Mutex;
+use std::{collections::HashMap, sync::Mutex};
 
-use chrono::Duration;
 use lazy_static::lazy_static;
 use serde_derive::Deserialize;
 
-use crate::rocket::common::config::common_config::APP_CONFIG;
+use crate::{
+	domain::model::{level_queue::QueuePolicy, request_config::RequestConfig},
+	rocket::common::config::common_config::APP_CONFIG
+};
 
 #[derive(Debug, Deserialize)]
 pub struct ClientConfig {
@@ -14,22 +16,29 @@ pub struct ClientConfig {
 	pub discord_bot_admin_id: u64,
 	pub cooldown_duration: u16,
 	pub enable_requests: bool,
-	pub enable_gd_requests: bool
+	pub enable_gd_requests: bool,
+	/// Default queue order for guilds that have not picked their own.
+	#[serde(default)]
+	pub queue_policy: QueuePolicy,
+	/// Most pending or in review requests a user may have, 0 for no limit.
+	#[serde(default)]
+	pub max_open_requests: u32,
+	/// Most requests a user may make in a rolling day, 0 for no limit.
+	#[serde(default)]
+	pub daily_request_quota: u32,
+	/// How often guild request configs are reloaded from the database, so
+	/// that changes made through other instances are picked up. `0` disables
+	/// reloading.
+	#[serde(default = "default_request_config_reload_interval")]
+	pub request_config_reload_interval_in_seconds: u64
 }
 
-lazy_static! {
-	pub static ref CLIENT_CONFIG: &'static ClientConfig = &APP_CONFIG.client_config;
-}
+fn default_request_config_reload_interval() -> u64 { 60 }
 
 lazy_static! {
-	pub static ref COOLDOWN_DURATION: Mutex<Duration> =
-		Mutex::new(Duration::minutes(CLIENT_CONFIG.cooldown_duration as i64));
-}
-
-lazy_static! {
-	pub static ref ENABLE_REQUESTS: Mutex<bool> = Mutex::new(CLIENT_CONFIG.enable_requests);
+	pub static ref CLIENT_CONFIG: &'static ClientConfig = &APP_CONFIG.client_config;
 }
 
 lazy_static! {
-	pub static ref ENABLE_GD_REQUESTS: Mutex<bool> = Mutex::new(CLIENT_CONFIG.enable_gd_requests);
+	pub static ref REQUEST_CONFIGS: Mutex<HashMap<u64, RequestConfig>> = Mutex::new(HashMap::new());
 }
@@ -5,15 +5,26 @@ use lazy_static::lazy_static;
 use serde_derive::Deserialize;
 
 use crate::rocket::common::config::{
-	auth_config::AuthConfig, client_config::ClientConfig, geometry_dash_config::GeometryDashConfig,
-	mysql_database_config::MySqlDatabaseConfig
+	auth_config::AuthConfig, client_config::ClientConfig, database_config::DatabaseConfig,
+	geometry_dash_config::GeometryDashConfig, level_eligibility_config::LevelEligibilityConfig,
+	mysql_database_config::MySqlDatabaseConfig, review_assignment_config::ReviewAssignmentConfig,
+	review_rubric_config::ReviewRubricConfig, sqlite_database_config::SqliteDatabaseConfig
 };
 
 #[derive(Debug, Deserialize)]
 pub struct AppConfig {
-	pub mysql_database_config: MySqlDatabaseConfig,
+	#[serde(default)]
+	pub database_config: DatabaseConfig,
+	pub mysql_database_config: Option<MySqlDatabaseConfig>,
+	pub sqlite_database_config: Option<SqliteDatabaseConfig>,
 	pub auth_config: AuthConfig,
 	pub geometry_dash_config: GeometryDashConfig,
+	#[serde(default)]
+	pub level_eligibility_config: LevelEligibilityConfig,
+	#[serde(default)]
+	pub review_assignment_config: ReviewAssignmentConfig,
+	#[serde(default)]
+	pub review_rubric_config: ReviewRubricConfig,
 	pub client_config: ClientConfig
 }
 
@@ -0,0 +1,46 @@
+use lazy_static::lazy_static;
+use sea_orm::{DatabaseConnection, DbErr};
+use serde_derive::Deserialize;
+
+use crate::rocket::common::config::common_config::APP_CONFIG;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct DatabaseConfig {
+	#[serde(default)]
+	pub backend: DatabaseBackend
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DatabaseBackend {
+	#[default]
+	MySql,
+	Sqlite
+}
+
+impl DatabaseConfig {
+	pub async fn configure_database(&self) -> Result<DatabaseConnection, DbErr> {
+		match self.backend {
+			DatabaseBackend::MySql => match &APP_CONFIG.mysql_database_config {
+				Some(mysql_database_config) => {
+					mysql_database_config.configure_mysql_database().await
+				}
+				None => Err(DbErr::Custom(
+					"mysql_database_config is required for the mysql backend".to_string()
+				))
+			},
+			DatabaseBackend::Sqlite => match &APP_CONFIG.sqlite_database_config {
+				Some(sqlite_database_config) => {
+					sqlite_database_config.configure_sqlite_database().await
+				}
+				None => Err(DbErr::Custom(
+					"sqlite_database_config is required for the sqlite backend".to_string()
+				))
+			}
+		}
+	}
+}
+
+lazy_static! {
+	pub static ref DATABASE_CONFIG: &'static DatabaseConfig = &APP_CONFIG.database_config;
+}
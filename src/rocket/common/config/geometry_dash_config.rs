@@ -6,9 +6,57 @@ use crate::rocket::common::config::common_config::APP_CONFIG;
 #[derive(Debug, Deserialize)]
 pub struct GeometryDashConfig {
 	pub gd_username: String,
-	pub gd_password: String
+	pub gd_password: String,
+	/// Points the client at another server, such as the fake server below.
+	#[serde(default = "default_base_url")]
+	pub base_url: String,
+	/// Serves canned responses from this directory under
+	/// `/fake_geometry_dash` when set. Only meant for tests and development.
+	#[serde(default)]
+	pub fake_server_fixtures_path: Option<String>,
+	/// How long a fetched level is served from cache. `0` disables caching.
+	#[serde(default = "default_level_cache_ttl")]
+	pub level_cache_ttl_in_seconds: u64,
+	/// How long a level that Geometry Dash reported as missing is remembered.
+	#[serde(default = "default_level_not_found_cache_ttl")]
+	pub level_not_found_cache_ttl_in_seconds: u64,
+	#[serde(default = "default_connect_timeout")]
+	pub connect_timeout_in_millis: u64,
+	#[serde(default = "default_read_timeout")]
+	pub read_timeout_in_millis: u64,
+	/// Extra attempts made for a level lookup that failed to reach Geometry
+	/// Dash. Sending levels is never retried.
+	#[serde(default = "default_max_retries")]
+	pub max_retries: u32,
+	/// Upper bound of the first backoff, doubled on every following retry.
+	#[serde(default = "default_retry_backoff")]
+	pub retry_backoff_in_millis: u64,
+	/// Consecutive failed calls before Geometry Dash is considered down.
+	#[serde(default = "default_circuit_breaker_failure_threshold")]
+	pub circuit_breaker_failure_threshold: u32,
+	/// How long calls are skipped once Geometry Dash is considered down.
+	#[serde(default = "default_circuit_breaker_reset")]
+	pub circuit_breaker_reset_in_seconds: u64
 }
 
+fn default_base_url() -> String { String::from("http://www.boomlings.com/database") }
+
+fn default_level_cache_ttl() -> u64 { 60 * 10 }
+
+fn default_level_not_found_cache_ttl() -> u64 { 60 }
+
+fn default_connect_timeout() -> u64 { 2000 }
+
+fn default_read_timeout() -> u64 { 5000 }
+
+fn default_max_retries() -> u32 { 2 }
+
+fn default_retry_backoff() -> u64 { 250 }
+
+fn default_circuit_breaker_failure_threshold() -> u32 { 5 }
+
+fn default_circuit_breaker_reset() -> u64 { 30 }
+
 lazy_static! {
 	pub static ref GEOMETRY_DASH_CONFIG: &'static GeometryDashConfig =
 		&APP_CONFIG.geometry_dash_config;
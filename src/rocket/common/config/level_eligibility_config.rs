@@ -0,0 +1,195 @@
+use serde_derive::Deserialize;
+
+use crate::domain::model::{
+	gd_level::{FeatureState, GDLevel, GameVersion},
+	level_eligibility::IneligibilityReason
+};
+
+/// Rules a level has to pass before it can be requested.
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct LevelEligibilityConfig {
+	pub reject_rated_levels: bool,
+	pub reject_featured_levels: bool,
+	/// `0` accepts levels of any size.
+	pub min_object_count: u32,
+	/// Written as `major.minor`, e.g. `"2.1"`. Levels of an unknown version are
+	/// accepted.
+	pub min_game_version: Option<GameVersion>,
+	pub denied_creator_account_ids: Vec<u64>,
+	/// Rejects levels saved without Geometry Dash level info, such as when
+	/// Geometry Dash requests are disabled or it is down. They are rejected
+	/// regardless while `denied_creator_account_ids` is not empty, since their
+	/// creator cannot be checked.
+	pub reject_levels_without_info: bool
+}
+
+impl Default for LevelEligibilityConfig {
+	fn default() -> Self {
+		LevelEligibilityConfig {
+			reject_rated_levels: true,
+			reject_featured_levels: true,
+			min_object_count: 0,
+			min_game_version: None,
+			denied_creator_account_ids: Vec::new(),
+			reject_levels_without_info: false
+		}
+	}
+}
+
+impl LevelEligibilityConfig {
+	/// Returns the first rule the level breaks. `gd_level` is `None` when the
+	/// level has no Geometry Dash level info.
+	pub fn check_level(&self, gd_level: Option<&GDLevel>) -> Result<(), IneligibilityReason> {
+		let gd_level = match gd_level {
+			Some(gd_level) => gd_level,
+			None if self.reject_levels_without_info
+				|| !self.denied_creator_account_ids.is_empty() =>
+			{
+				return Err(IneligibilityReason::LevelInfoUnavailable);
+			}
+			None => return Ok(())
+		};
+		if self.reject_rated_levels && gd_level.stars > 0 {
+			return Err(IneligibilityReason::AlreadyRated);
+		}
+		if self.reject_featured_levels && gd_level.feature_state != FeatureState::NotFeatured {
+			return Err(IneligibilityReason::AlreadyFeatured);
+		}
+		if gd_level
+			.object_count
+			.is_some_and(|object_count| object_count < self.min_object_count)
+		{
+			return Err(IneligibilityReason::TooFewObjects);
+		}
+		if let (Some(min_game_version), Some(game_version)) =
+			(self.min_game_version, gd_level.game_version)
+		{
+			if game_version < min_game_version {
+				return Err(IneligibilityReason::OutdatedGameVersion);
+			}
+		}
+		if self
+			.denied_creator_account_ids
+			.contains(&gd_level.creator.account_id)
+		{
+			return Err(IneligibilityReason::DeniedCreator);
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		domain::model::{
+			gd_level::{
+				FeatureState, GDLevel, GameVersion, LevelCreator, LevelDifficulty, LevelLength
+			},
+			level_eligibility::IneligibilityReason
+		},
+		rocket::common::config::level_eligibility_config::LevelEligibilityConfig
+	};
+
+	#[test]
+	fn test_check_level_should_reject_levels_below_min_object_count() {
+		let level_eligibility_config = LevelEligibilityConfig {
+			min_object_count: 5000,
+			..LevelEligibilityConfig::default()
+		};
+
+		assert_eq!(
+			level_eligibility_config.check_level(Some(&gd_level(Some(4999), None))),
+			Err(IneligibilityReason::TooFewObjects)
+		);
+		assert_eq!(
+			level_eligibility_config.check_level(Some(&gd_level(Some(5000), None))),
+			Ok(())
+		);
+		assert_eq!(
+			level_eligibility_config.check_level(Some(&gd_level(None, None))),
+			Ok(())
+		);
+	}
+
+	#[test]
+	fn test_check_level_should_compare_game_versions_by_major_then_minor() {
+		let level_eligibility_config = LevelEligibilityConfig {
+			min_game_version: Some(game_version(2, 1)),
+			..LevelEligibilityConfig::default()
+		};
+
+		for (major, minor, expected) in [
+			(1, 9, Err(IneligibilityReason::OutdatedGameVersion)),
+			(2, 0, Err(IneligibilityReason::OutdatedGameVersion)),
+			(2, 1, Ok(())),
+			(2, 2, Ok(())),
+			(3, 0, Ok(()))
+		] {
+			assert_eq!(
+				level_eligibility_config
+					.check_level(Some(&gd_level(None, Some(game_version(major, minor))))),
+				expected,
+				"game version {}.{}",
+				major,
+				minor
+			);
+		}
+		assert_eq!(
+			level_eligibility_config.check_level(Some(&gd_level(None, None))),
+			Ok(())
+		);
+	}
+
+	#[test]
+	fn test_check_level_should_reject_levels_without_info_when_creators_are_denied() {
+		let level_eligibility_config = LevelEligibilityConfig::default();
+		assert_eq!(level_eligibility_config.check_level(None), Ok(()));
+
+		let level_eligibility_config = LevelEligibilityConfig {
+			denied_creator_account_ids: vec![1234],
+			..LevelEligibilityConfig::default()
+		};
+		assert_eq!(
+			level_eligibility_config.check_level(None),
+			Err(IneligibilityReason::LevelInfoUnavailable)
+		);
+		assert_eq!(
+			level_eligibility_config.check_level(Some(&gd_level(None, None))),
+			Err(IneligibilityReason::DeniedCreator)
+		);
+
+		let level_eligibility_config = LevelEligibilityConfig {
+			reject_levels_without_info: true,
+			..LevelEligibilityConfig::default()
+		};
+		assert_eq!(
+			level_eligibility_config.check_level(None),
+			Err(IneligibilityReason::LevelInfoUnavailable)
+		);
+	}
+
+	fn game_version(major: u8, minor: u8) -> GameVersion { GameVersion { major, minor } }
+
+	fn gd_level(object_count: Option<u32>, game_version: Option<GameVersion>) -> GDLevel {
+		GDLevel {
+			name: "Level Name".to_string(),
+			description: None,
+			creator: LevelCreator {
+				name: "Level Creator".to_string(),
+				account_id: 1234,
+				player_id: 5678
+			},
+			level_length: LevelLength::Medium,
+			difficulty: LevelDifficulty::Hard,
+			stars: 0,
+			feature_state: FeatureState::NotFeatured,
+			downloads: 100,
+			likes: 10,
+			game_version,
+			song: None,
+			object_count,
+			coin_count: 0
+		}
+	}
+}
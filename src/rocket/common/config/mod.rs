@@ -1,5 +1,10 @@
 pub mod auth_config;
 pub mod client_config;
 pub mod common_config;
+pub mod database_config;
 pub mod geometry_dash_config;
+pub mod level_eligibility_config;
 pub mod mysql_database_config;
+pub mod review_assignment_config;
+pub mod review_rubric_config;
+pub mod sqlite_database_config;
@@ -0,0 +1,26 @@
+use serde_derive::Deserialize;
+
+use crate::domain::model::review_assignment::AssignmentPolicy;
+
+/// How levels are handed out to reviewers.
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct ReviewAssignmentConfig {
+	/// How long a claim or assignment lasts before the level can be picked up
+	/// by another reviewer.
+	pub claim_duration_in_minutes: u64,
+	pub assignment_policy: AssignmentPolicy,
+	/// How often levels whose claim or assignment ended without a review are
+	/// returned to the queue. `0` disables it.
+	pub requeue_interval_in_seconds: u64
+}
+
+impl Default for ReviewAssignmentConfig {
+	fn default() -> Self {
+		ReviewAssignmentConfig {
+			claim_duration_in_minutes: 1440,
+			assignment_policy: AssignmentPolicy::RoundRobin,
+			requeue_interval_in_seconds: 60
+		}
+	}
+}
@@ -0,0 +1,41 @@
+use serde_derive::Deserialize;
+
+/// The categories reviewers score levels in and the range of those scores.
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct ReviewRubricConfig {
+	pub categories: Vec<String>,
+	pub min_score: u8,
+	pub max_score: u8,
+	/// Whether a review has to score every category rather than only some.
+	pub require_all_categories: bool
+}
+
+impl ReviewRubricConfig {
+	pub fn has_category(&self, category: &str) -> bool {
+		self.categories
+			.iter()
+			.any(|rubric_category| rubric_category == category)
+	}
+
+	pub fn is_valid_score(&self, score: u8) -> bool {
+		score >= self.min_score && score <= self.max_score
+	}
+}
+
+impl Default for ReviewRubricConfig {
+	fn default() -> Self {
+		ReviewRubricConfig {
+			categories: vec![
+				"gameplay".to_string(),
+				"decoration".to_string(),
+				"sync".to_string(),
+				"balancing".to_string(),
+				"overall".to_string(),
+			],
+			min_score: 1,
+			max_score: 10,
+			require_all_categories: false
+		}
+	}
+}
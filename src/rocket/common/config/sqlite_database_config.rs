@@ -0,0 +1,25 @@
+use sea_orm::{Database, DatabaseConnection, DbErr};
+use serde_derive::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct SqliteDatabaseConfig {
+	path: String
+}
+
+impl SqliteDatabaseConfig {
+	pub async fn configure_sqlite_database(&self) -> Result<DatabaseConnection, DbErr> {
+		let url = if self.path.eq(":memory:") {
+			"sqlite::memory:".to_string()
+		} else {
+			format!("sqlite://{}?mode=rwc", &self.path)
+		};
+
+		Database::connect(&url).await.map_err(|err| {
+			error!(
+				"Unable to connect to database during initialization {}",
+				err
+			);
+			err
+		})
+	}
+}
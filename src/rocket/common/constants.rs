@@ -1,4 +1,14 @@
-pub static CONTENT_TYPE: &'static str = "Content-Type";
-pub static APPLICATION_FORM_URL_ENCODED: &'static str = "application/x-www-form-urlencoded";
-pub static YOUTUBE_LINK_REGEX: &'static str = "^((?:https?:)?\\/\\/)?((?:www|m)\\.)?((?:youtube(-nocookie)?\\.com|youtu.be))(\\/(?:[\\w\\-]+\\?v=|embed\\/|v\\/)?)([\\w\\-]+)(\\S+)?$";
-pub static TIMESTAMP_HEADER_NAME: &'static str = "timestamp";
+pub static CONTENT_TYPE: &str = "Content-Type";
+pub static APPLICATION_FORM_URL_ENCODED: &str = "application/x-www-form-urlencoded";
+pub static YOUTUBE_LINK_REGEX: &str = "^((?:https?:)?\\/\\/)?((?:www|m)\\.)?((?:youtube(-nocookie)?\\.com|youtu.be))(\\/(?:[\\w\\-]+\\?v=|embed\\/|v\\/)?)([\\w\\-]+)(\\S+)?$";
+pub static TIMESTAMP_HEADER_NAME: &str = "timestamp";
+pub static RETRY_AFTER_HEADER_NAME: &str = "Retry-After";
+pub static DEFAULT_PAGE_SIZE: u64 = 25;
+pub static MAX_PAGE_SIZE: u64 = 100;
+pub static AUTHORIZATION_HEADER_NAME: &str = "AUTHORIZATION";
+pub static DISCORD_APP_ID_HEADER_NAME: &str = "X-REQUESTX-DISCORD-APP-ID";
+pub static ACCESS_TOKEN_HEADER_NAME: &str = "X-REQUESTX-ACCESS-TOKEN";
+pub static GUILD_ID_HEADER_NAME: &str = "X-REQUESTX-GUILD-ID";
+pub static DISCORD_USER_ID_HEADER_NAME: &str = "X-REQUESTX-DISCORD-USER-ID";
+pub static REFRESH_TOKEN_HEADER_NAME: &str = "X-REQUESTX-REFRESH-TOKEN";
+pub static FAKE_GEOMETRY_DASH_SERVER_PATH: &str = "/fake_geometry_dash";
@@ -1,3 +1,6 @@
+pub mod app_state;
 pub mod config;
 pub mod constants;
 pub mod internal;
+#[cfg(test)]
+pub mod testing;
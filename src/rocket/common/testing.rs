@@ -0,0 +1,272 @@
+use std::{
+	net::TcpStream,
+	sync::{Arc, Once},
+	thread,
+	time::Duration
+};
+
+use chrono::Utc;
+use migration::{Migrator, MigratorTrait};
+use reqwest::Url;
+use rocket_framework::{
+	http::{Header, Status},
+	local::asynchronous::{Client, LocalRequest},
+	serde::json::json,
+	Config
+};
+
+use crate::{
+	adapter::{
+		geometry_dash::fake_geometry_dash_server::mount_fake_geometry_dash_server,
+		mysql::model::{
+			level_request, request_ban, review_assignment, review_revision, reviewer,
+			sea_orm_active_enums, sea_orm_active_enums::BanTargetType
+		}
+	},
+	domain::model::gd_level::RequestStatus,
+	mount_controllers,
+	rocket::common::{
+		app_state::AppState,
+		config::{common_config::APP_CONFIG, database_config::DATABASE_CONFIG},
+		constants::{
+			ACCESS_TOKEN_HEADER_NAME, AUTHORIZATION_HEADER_NAME, DISCORD_APP_ID_HEADER_NAME,
+			DISCORD_USER_ID_HEADER_NAME, GUILD_ID_HEADER_NAME
+		}
+	}
+};
+
+pub const YOUTUBE_VIDEO_LINK: &str = "https://www.youtube.com/watch?v=dQw4w9WgXcQ";
+
+/// The bot admin from `Config_test.toml`, who holds every role in every guild.
+pub const ADMIN_DISCORD_ID: u64 = 0;
+
+/// A client for the full app, backed by its own in-memory SQLite database.
+/// Request configs are global, so tests that change them use their own guild.
+pub async fn test_client() -> Client {
+	let db_conn = DATABASE_CONFIG.configure_database().await.unwrap();
+	Migrator::up(&db_conn, None).await.unwrap();
+	let app_state = AppState::new(&APP_CONFIG, Arc::new(db_conn)).unwrap();
+
+	Client::tracked(mount_controllers(
+		rocket_framework::build().manage(app_state)
+	))
+	.await
+	.unwrap()
+}
+
+pub async fn generate_jwt(client: &Client, guild_id: u64, discord_user_id: u64) -> String {
+	let response = client
+		.post("/api/v1/auth")
+		.header(discord_app_id_header())
+		.header(Header::new(
+			ACCESS_TOKEN_HEADER_NAME,
+			APP_CONFIG.auth_config.access_token.clone()
+		))
+		.header(Header::new(GUILD_ID_HEADER_NAME, guild_id.to_string()))
+		.header(Header::new(
+			DISCORD_USER_ID_HEADER_NAME,
+			discord_user_id.to_string()
+		))
+		.dispatch()
+		.await;
+	assert_eq!(response.status(), Status::Created);
+
+	response
+		.headers()
+		.get_one(AUTHORIZATION_HEADER_NAME)
+		.unwrap()
+		.to_string()
+}
+
+/// Requests a level on behalf of `discord_user_id`, without any Geometry Dash
+/// level info unless the guild uses the fake Geometry Dash server.
+pub async fn create_level_request(client: &Client, jwt: &str, level_id: u64, discord_user_id: u64) {
+	let response = authorized(client.post("/api/v1/request_level"), jwt)
+		.json(&json!({
+			"level_id": level_id,
+			"youtube_video_link": YOUTUBE_VIDEO_LINK,
+			"discord_id": discord_user_id,
+			"request_rating": "Two",
+			"has_requested_feedback": true,
+			"notify": false
+		}))
+		.dispatch()
+		.await;
+	assert_eq!(response.status(), Status::Created);
+}
+
+/// Makes `reviewer_discord_id` an active reviewer. The reviewer role is only
+/// in JWTs generated afterwards.
+pub async fn create_reviewer(client: &Client, guild_id: u64, reviewer_discord_id: u64) {
+	let admin_jwt = generate_jwt(client, guild_id, ADMIN_DISCORD_ID).await;
+	let response = authorized(client.post("/api/v1/reviewer"), &admin_jwt)
+		.json(&json!({ "reviewer_discord_id": reviewer_discord_id }))
+		.dispatch()
+		.await;
+	assert_eq!(response.status(), Status::Ok);
+}
+
+/// Serves the fake Geometry Dash server that `base_url` in `Config_test.toml`
+/// points at, then turns Geometry Dash requests on for the guild. The server
+/// is shared by every test, on its own thread since each test has its own
+/// runtime.
+pub async fn use_fake_geometry_dash_server(client: &Client, guild_id: u64) {
+	static FAKE_GEOMETRY_DASH_SERVER: Once = Once::new();
+	FAKE_GEOMETRY_DASH_SERVER.call_once(|| {
+		let port = Url::parse(&APP_CONFIG.geometry_dash_config.base_url)
+			.unwrap()
+			.port()
+			.unwrap();
+		let fixtures_path = APP_CONFIG
+			.geometry_dash_config
+			.fake_server_fixtures_path
+			.clone()
+			.unwrap();
+		thread::spawn(move || {
+			let rocket = rocket_framework::custom(
+				Config::figment()
+					.merge(("port", port))
+					.merge(("log_level", "off"))
+			);
+			let _ = rocket_framework::execute(
+				mount_fake_geometry_dash_server(rocket, &fixtures_path).launch()
+			);
+		});
+
+		for _ in 0..50 {
+			if TcpStream::connect(("127.0.0.1", port)).is_ok() {
+				return;
+			}
+			thread::sleep(Duration::from_millis(100));
+		}
+		panic!("Fake Geometry Dash server did not start on port {}", port);
+	});
+
+	let admin_jwt = generate_jwt(client, guild_id, ADMIN_DISCORD_ID).await;
+	let response = authorized(client.patch("/api/v1/internal/request_config"), &admin_jwt)
+		.json(&json!({ "enable_gd_requests": true }))
+		.dispatch()
+		.await;
+	assert_eq!(response.status(), Status::Ok);
+}
+
+pub fn authorized<'c>(request: LocalRequest<'c>, jwt: &str) -> LocalRequest<'c> {
+	request.header(discord_app_id_header()).header(Header::new(
+		AUTHORIZATION_HEADER_NAME,
+		format!("Bearer {}", jwt)
+	))
+}
+
+pub fn discord_app_id_header() -> Header<'static> {
+	Header::new(
+		DISCORD_APP_ID_HEADER_NAME,
+		APP_CONFIG.client_config.discord_app_id.to_string()
+	)
+}
+
+/// An unarchived level request record saved without Geometry Dash level info.
+pub fn level_request_model(
+	guild_id: u64,
+	level_id: u64,
+	discord_id: u64,
+	status: RequestStatus
+) -> level_request::Model {
+	level_request::Model {
+		guild_id: guild_id.into(),
+		level_id: level_id.into(),
+		discord_id: discord_id.into(),
+		discord_message_id: None,
+		name: None,
+		author: None,
+		request_rating: sea_orm_active_enums::RequestRating::Two,
+		level_length: None,
+		you_tube_video_link: YOUTUBE_VIDEO_LINK.to_string(),
+		has_requested_feedback: 1,
+		notify: 0,
+		status: status.into(),
+		timestamp: Utc::now(),
+		archived_at: None,
+		description: None,
+		author_account_id: None,
+		author_player_id: None,
+		difficulty: None,
+		stars: None,
+		feature_state: None,
+		downloads: None,
+		likes: None,
+		game_version: None,
+		song_id: None,
+		song_name: None,
+		song_artist: None,
+		is_custom_song: None,
+		object_count: None,
+		coin_count: None,
+		skipped_at: None
+	}
+}
+
+/// An active ban issued a day ago by the bot admin.
+pub fn ban_model(id: i32, guild_id: u64, target_type: BanTargetType) -> request_ban::Model {
+	request_ban::Model {
+		id,
+		guild_id: guild_id.into(),
+		target_type,
+		target_id: 12345.into(),
+		reason: Some("Spamming requests".to_string()),
+		issued_by: ADMIN_DISCORD_ID.into(),
+		created_at: Utc::now() - chrono::Duration::days(1),
+		expires_at: None,
+		lifted_at: None,
+		lifted_by: None
+	}
+}
+
+pub fn reviewer_model(guild_id: u64, discord_id: u64, active: bool) -> reviewer::Model {
+	reviewer::Model {
+		guild_id: guild_id.into(),
+		discord_id: discord_id.into(),
+		active: i8::from(active)
+	}
+}
+
+/// An open assignment the reviewer claimed themselves, expiring in an hour.
+pub fn review_assignment_model(
+	guild_id: u64,
+	level_id: u64,
+	reviewer_discord_id: u64
+) -> review_assignment::Model {
+	review_assignment::Model {
+		id: 1,
+		guild_id: guild_id.into(),
+		level_id: level_id.into(),
+		discord_id: reviewer_discord_id.into(),
+		assigned_by: reviewer_discord_id.into(),
+		assigned_at: Utc::now(),
+		expires_at: Utc::now() + chrono::Duration::hours(1),
+		completed_at: None,
+		released_at: None
+	}
+}
+
+/// A review revision without rubric scores.
+pub fn review_revision_model(
+	guild_id: u64,
+	level_id: u64,
+	discord_id: u64,
+	revision: u32,
+	review_content: &str
+) -> review_revision::Model {
+	review_revision::Model {
+		id: revision as i32,
+		guild_id: guild_id.into(),
+		level_id: level_id.into(),
+		discord_id: discord_id.into(),
+		revision,
+		message_id: 11111.into(),
+		review_content: review_content.to_string(),
+		created_at: Utc::now(),
+		rubric_scores: None,
+		suggested_score: None,
+		suggested_rating: None
+	}
+}